@@ -43,7 +43,11 @@ use frame_support::{
 use serde::{Serialize, Deserialize};
 
 use sp_core::U256;
-use sp_runtime::{ArithmeticError, sp_std::prelude::Vec};
+use sp_runtime::{
+	ArithmeticError,
+	sp_std::prelude::Vec,
+	traits::{Saturating, Zero},
+};
 use codec::{Decode, Encode, EncodeLike, MaxEncodedLen};
 use frame_support::{BoundedVec, traits::ConstU32};
 use derivative::Derivative;
@@ -289,6 +293,70 @@ impl<T> Default for SponsorshipState<T> {
 	}
 }
 
+/// Accounts whose transactions are paid by the collection sponsor.
+#[derive(Encode, Decode, Debug, Clone, Copy, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub enum SponsoredAccounts {
+	/// Any account.
+	All,
+	/// Only accounts added to the sponsor allowlist of the collection.
+	Allowlist,
+	/// Only active whitelisted investors.
+	WhitelistedInvestors,
+}
+
+impl Default for SponsoredAccounts {
+	fn default() -> Self {
+		Self::All
+	}
+}
+
+/// Restrictions put on sponsored transactions of a collection.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct SponsorshipSettings<Balance, BlockNumber> {
+	/// Length of the period budget and quotas are counted for, in blocks.
+	pub period: BlockNumber,
+
+	/// Maximum amount of fees paid by the sponsor per period.
+	///
+	/// Unlimited if `None`.
+	pub budget: Option<Balance>,
+
+	/// Maximum amount of sponsored transactions per account per period.
+	///
+	/// Unlimited if `None`.
+	pub account_quota: Option<u32>,
+
+	/// Accounts eligible for sponsorship.
+	pub sponsored_accounts: SponsoredAccounts,
+}
+
+/// Amount of sponsorship used within the current period.
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen, Default)]
+pub struct SponsorshipUsage<Value, BlockNumber> {
+	/// Block at which the current period started.
+	pub period_start: BlockNumber,
+
+	/// Amount used since the start of the period.
+	pub used: Value,
+}
+
+impl<Value: Zero, BlockNumber: Saturating + PartialOrd + Copy> SponsorshipUsage<Value, BlockNumber> {
+	/// Usage within the period containing `block_number`.
+	///
+	/// Usage of a past period is dropped, a new period starts at `block_number` then.
+	pub fn current(usage: Option<Self>, period: BlockNumber, block_number: BlockNumber) -> Self {
+		match usage {
+			Some(usage) if block_number < usage.period_start.saturating_add(period) => usage,
+			_ => Self {
+				period_start: block_number,
+				used: Value::zero(),
+			},
+		}
+	}
+}
+
 pub type CollectionName = BoundedVec<u16, ConstU32<MAX_COLLECTION_NAME_LENGTH>>;
 pub type CollectionDescription = BoundedVec<u16, ConstU32<MAX_COLLECTION_DESCRIPTION_LENGTH>>;
 pub type CollectionTokenPrefix = BoundedVec<u8, ConstU32<MAX_TOKEN_PREFIX_LENGTH>>;
@@ -546,6 +614,67 @@ pub enum SponsoringRateLimit {
 	Blocks(u32),
 }
 
+/// Holding restrictions of refungible tokens.
+///
+/// Can be set for the whole collection and overridden for a single token.
+/// All fields are wrapped in [`Option`], where `None` means no restriction.
+#[derive(Encode, Decode, Debug, Default, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
+pub struct HoldingLimits {
+	/// How many distinct accounts can hold pieces of one token.
+	pub max_token_holders: Option<u32>,
+
+	/// Minimal non-zero amount of pieces an account can hold.
+	pub min_holding: Option<u128>,
+
+	/// Granularity of pieces. Every minted, transferred or burned amount
+	/// must be a multiple of it.
+	pub transfer_increment: Option<u128>,
+}
+
+impl HoldingLimits {
+	/// Whether all set limits are non-zero.
+	pub fn is_valid(&self) -> bool {
+		self.max_token_holders != Some(0)
+			&& self.min_holding != Some(0)
+			&& self.transfer_increment != Some(0)
+	}
+
+	/// Fill fields which are not set with values from `fallback`.
+	pub fn or(self, fallback: Self) -> Self {
+		Self {
+			max_token_holders: self.max_token_holders.or(fallback.max_token_holders),
+			min_holding: self.min_holding.or(fallback.min_holding),
+			transfer_increment: self.transfer_increment.or(fallback.transfer_increment),
+		}
+	}
+
+	/// Get effective value for [`max_token_holders`](self.max_token_holders).
+	pub fn max_token_holders(&self) -> u32 {
+		self.max_token_holders.unwrap_or(u32::MAX)
+	}
+
+	/// Get effective value for [`min_holding`](self.min_holding).
+	pub fn min_holding(&self) -> u128 {
+		self.min_holding.unwrap_or(1)
+	}
+
+	/// Get effective value for [`transfer_increment`](self.transfer_increment).
+	pub fn transfer_increment(&self) -> u128 {
+		self.transfer_increment.unwrap_or(1).max(1)
+	}
+
+	/// Returns **true** if `amount` of pieces may be moved at once.
+	pub fn is_allowed_amount(&self, amount: u128) -> bool {
+		amount % self.transfer_increment() == 0
+	}
+
+	/// Returns **true** if an account may end up with `balance` pieces.
+	pub fn is_allowed_holding(&self, balance: u128) -> bool {
+		balance == 0 || balance >= self.min_holding()
+	}
+}
+
 /// Data used to describe an NFT at creation.
 #[derive(Encode, Decode, MaxEncodedLen, Default, PartialEq, Clone, Derivative, TypeInfo)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
@@ -735,7 +864,6 @@ pub type PropertyKey = BoundedBytes<ConstU32<MAX_PROPERTY_KEY_LENGTH>>;
 /// Property value.
 pub type PropertyValue = BoundedBytes<ConstU32<MAX_PROPERTY_VALUE_LENGTH>>;
 
-// LOG: simplified to just mutable
 /// Property permission.
 #[derive(Encode, Decode, TypeInfo, Debug, MaxEncodedLen, PartialEq, Clone)]
 #[cfg_attr(feature = "serde1", derive(Serialize, Deserialize))]
@@ -743,22 +871,36 @@ pub struct PropertyPermission {
 	/// Permission to change the property and property permission.
 	///
 	/// If it **false** then you can not change corresponding property even if [`collection_admin`] and [`token_owner`] are **true**.
-	pub mutable: bool
+	pub mutable: bool,
+
+	/// Change permission for the collection administrator.
+	pub collection_admin: bool,
+
+	/// Permission to change the property for the owner of the token.
+	pub token_owner: bool,
 }
 
-// LOG: PropertyPermission::none restricted to mutable = false
 impl PropertyPermission {
-	/// Immutable property permission
-	pub fn unmutable() -> Self {
+	/// Creates a [`PropertyPermission`] that forbids everything.
+	pub fn none() -> Self {
 		Self {
 			mutable: false,
+			collection_admin: false,
+			token_owner: false,
 		}
 	}
 
-	/// Mutable property permission
+	/// Immutable property permission
+	pub fn unmutable() -> Self {
+		Self::none()
+	}
+
+	/// Property permission which could be changed by collection owner and admins
 	pub fn mutable() -> Self {
 		Self {
 			mutable: true,
+			collection_admin: true,
+			token_owner: false,
 		}
 	}
 }
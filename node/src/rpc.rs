@@ -52,8 +52,36 @@ pub struct FullDeps<C, P> {
 	pub deny_unsafe: DenyUnsafe,
 }
 
+/// RPC extensions which depend on pallets included in the runtime.
+///
+/// Implemented by native executors, runtimes without such pallets keep the default.
+pub trait RuntimeRpcExtensions<C> {
+	/// Merge runtime specific RPC extensions into `module`.
+	fn extend(
+		_module: &mut RpcExtension,
+		_client: Arc<C>,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+		Ok(())
+	}
+}
+
+/// Merge RPC extensions of the collections pallets.
+pub fn extend_with_collections<C>(
+	module: &mut RpcExtension,
+	client: Arc<C>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+where
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: collections_rpc::CollectionsRuntimeApi<Block, AccountId>,
+{
+	use collections_rpc::{Collections, CollectionsApiServer};
+
+	module.merge(Collections::new(client).into_rpc())?;
+	Ok(())
+}
+
 /// Instantiate all RPC extensions.
-pub fn create_full<C, P>(
+pub fn create_full<C, P, E>(
 	deps: FullDeps<C, P>,
 ) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
 where
@@ -67,15 +95,20 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: parachain_staking_rpc::StakingRuntimeApi<Block, AccountId, Balance>,
 	P: TransactionPool + Sync + Send + 'static,
+	E: RuntimeRpcExtensions<C>,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
+	use parachain_staking_rpc::{Staking, StakingApiServer};
 
 	let mut module = RpcExtension::new(());
 	let FullDeps { client, pool, deny_unsafe } = deps;
 
 	module.merge(System::new(Arc::clone(&client), Arc::clone(&pool), deny_unsafe).into_rpc())?;
 	module.merge(TransactionPayment::new(Arc::clone(&client)).into_rpc())?;
+	module.merge(Staking::new(Arc::clone(&client)).into_rpc())?;
+	E::extend(&mut module, client)?;
 	Ok(module)
 }
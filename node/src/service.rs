@@ -62,6 +62,8 @@ use sc_consensus::ImportQueue;
 
 use polkadot_service::CollatorPair;
 
+use crate::rpc::{RpcExtension, RuntimeRpcExtensions};
+
 /// Mainnet native executor instance.
 #[cfg(feature = "curio-mainnet-runtime")]
 pub struct MainnetRuntimeExecutor;
@@ -108,6 +110,12 @@ impl NativeExecutionDispatch for TestnetRuntimeExecutor {
 	}
 }
 
+#[cfg(feature = "curio-mainnet-runtime")]
+impl<C> RuntimeRpcExtensions<C> for MainnetRuntimeExecutor {}
+
+#[cfg(feature = "curio-testnet-runtime")]
+impl<C> RuntimeRpcExtensions<C> for TestnetRuntimeExecutor {}
+
 impl NativeExecutionDispatch for DevnetRuntimeExecutor {
 	type ExtendHostFunctions = frame_benchmarking::benchmarking::HostFunctions;
 
@@ -120,6 +128,20 @@ impl NativeExecutionDispatch for DevnetRuntimeExecutor {
 	}
 }
 
+// Collections pallets are included only in the devnet runtime
+impl<C> RuntimeRpcExtensions<C> for DevnetRuntimeExecutor
+where
+	C: sp_api::ProvideRuntimeApi<Block> + sp_blockchain::HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: collections_rpc::CollectionsRuntimeApi<Block, AccountId>,
+{
+	fn extend(
+		module: &mut RpcExtension,
+		client: Arc<C>,
+	) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+		crate::rpc::extend_with_collections(module, client)
+	}
+}
+
 type FullClient<RuntimeApi, ExecutorDispatch> =
 	sc_service::TFullClient<Block, RuntimeApi, NativeElseWasmExecutor<ExecutorDispatch>>;
 type FullBackend = sc_service::TFullBackend<Block>;
@@ -295,8 +317,11 @@ where
 		+ sp_block_builder::BlockBuilder<Block>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
-		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
-	ExecutorDispatch: NativeExecutionDispatch + 'static,
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+		+ parachain_staking_rpc::StakingRuntimeApi<Block, AccountId, Balance>,
+	ExecutorDispatch: NativeExecutionDispatch
+		+ RuntimeRpcExtensions<FullClient<RuntimeApi, ExecutorDispatch>>
+		+ 'static,
 	BIQ: FnOnce(
 			Arc<FullClient<RuntimeApi, ExecutorDispatch>>,
 			Arc<FullBackend>,
@@ -378,7 +403,7 @@ where
 				deny_unsafe,
 			};
 
-			crate::rpc::create_full(deps).map_err(Into::into)
+			crate::rpc::create_full::<_, _, ExecutorDispatch>(deps).map_err(Into::into)
 		})
 	};
 
@@ -556,8 +581,11 @@ where
 		+ pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>
 		+ cumulus_primitives_core::CollectCollationInfo<Block>
 		+ sp_api::ApiExt<Block, StateBackend = sc_client_api::StateBackendFor<FullBackend, Block>>
-		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
-	ExecutorDispatch: NativeExecutionDispatch + 'static,
+		+ substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>
+		+ parachain_staking_rpc::StakingRuntimeApi<Block, AccountId, Balance>,
+	ExecutorDispatch: NativeExecutionDispatch
+		+ RuntimeRpcExtensions<FullClient<RuntimeApi, ExecutorDispatch>>
+		+ 'static,
 {
 	start_node_impl::<RuntimeApi, ExecutorDispatch, _, _>(
 		parachain_config,
@@ -0,0 +1,353 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! RPC interface for querying collections, tokens and token holders.
+
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use collection_primitives::{
+	CollectionId, CollectionLimits, CollectionStats, Property, PropertyKeyPermission,
+	RpcCollection, TokenId,
+};
+pub use collections_runtime_api::CollectionsApi as CollectionsRuntimeApi;
+
+/// Error code for failures of the runtime API call itself.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code for calls rejected by the runtime, e.g. for an unknown collection.
+const DISPATCH_ERROR: i32 = 2;
+
+#[rpc(client, server)]
+pub trait CollectionsApi<BlockHash, AccountId> {
+	/// Get the collection with all its properties and property permissions.
+	#[method(name = "collections_collectionById")]
+	fn collection_by_id(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RpcCollection<AccountId>>>;
+
+	/// Get collection properties, optionally filtered by keys.
+	#[method(name = "collections_collectionProperties")]
+	fn collection_properties(
+		&self,
+		collection: CollectionId,
+		keys: Option<Vec<String>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Property>>;
+
+	/// Get token properties, optionally filtered by keys.
+	#[method(name = "collections_tokenProperties")]
+	fn token_properties(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		keys: Option<Vec<String>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<Property>>;
+
+	/// Get property permissions of the collection, optionally filtered by keys.
+	#[method(name = "collections_propertyPermissions")]
+	fn property_permissions(
+		&self,
+		collection: CollectionId,
+		keys: Option<Vec<String>>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<PropertyKeyPermission>>;
+
+	/// Get the amount of token pieces owned by the account.
+	#[method(name = "collections_balance")]
+	fn balance(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		account: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<String>;
+
+	/// Get the total amount of pieces the token is split into.
+	#[method(name = "collections_totalSupply")]
+	fn total_supply(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> RpcResult<String>;
+
+	/// Get the amount of token pieces the spender is allowed to use on behalf of the owner.
+	#[method(name = "collections_allowance")]
+	fn allowance(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		owner: AccountId,
+		spender: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<String>;
+
+	/// Get the list of collection admins.
+	#[method(name = "collections_adminlist")]
+	fn adminlist(&self, collection: CollectionId, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+	/// Get statistics of created, destroyed and alive collections.
+	#[method(name = "collections_collectionStats")]
+	fn collection_stats(&self, at: Option<BlockHash>) -> RpcResult<CollectionStats>;
+
+	/// Get collection limits with chain defaults substituted for unset fields.
+	#[method(name = "collections_effectiveCollectionLimits")]
+	fn effective_collection_limits(
+		&self,
+		collection: CollectionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CollectionLimits>>;
+
+	/// Get the number of blocks until the next sponsored transfer of the token by the account.
+	#[method(name = "collections_nextSponsored")]
+	fn next_sponsored(
+		&self,
+		collection: CollectionId,
+		account: AccountId,
+		token: TokenId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<u64>>;
+
+	/// Get a page of refungible tokens partially owned by the account across all collections.
+	#[method(name = "collections_accountTokens")]
+	fn account_tokens(
+		&self,
+		account: AccountId,
+		start_after: Option<(CollectionId, TokenId)>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(CollectionId, TokenId)>>;
+
+	/// Get a page of token holders with their amounts of pieces.
+	#[method(name = "collections_tokenHolders")]
+	fn token_holders(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		start_after: Option<AccountId>,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, String)>>;
+
+	/// Get id of the last token minted in the collection.
+	#[method(name = "collections_lastTokenId")]
+	fn last_token_id(&self, collection: CollectionId, at: Option<BlockHash>) -> RpcResult<TokenId>;
+}
+
+/// Provides RPC methods to query collections.
+pub struct Collections<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Collections<C, Block> {
+	/// Create new `Collections` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error<E: Debug>(err: E) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query collections.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+fn dispatch_error<E: Debug>(err: E) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		DISPATCH_ERROR,
+		"Collections query rejected by the runtime.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+fn string_keys_to_bytes_keys(keys: Option<Vec<String>>) -> Option<Vec<Vec<u8>>> {
+	keys.map(|keys| keys.into_iter().map(|key| key.into_bytes()).collect())
+}
+
+macro_rules! query {
+	($self:ident, $at:ident, $method:ident($($arg:expr),*)) => {{
+		let api = $self.client.runtime_api();
+		let at = $at.unwrap_or_else(|| $self.client.info().best_hash);
+		api.$method(at, $($arg),*).map_err(runtime_error)
+	}};
+}
+
+macro_rules! query_result {
+	($self:ident, $at:ident, $method:ident($($arg:expr),*)) => {{
+		query!($self, $at, $method($($arg),*))?.map_err(dispatch_error)
+	}};
+}
+
+impl<C, Block, AccountId> CollectionsApiServer<<Block as BlockT>::Hash, AccountId>
+	for Collections<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CollectionsRuntimeApi<Block, AccountId>,
+	AccountId: Codec + Clone + Send + Sync + 'static,
+	AccountId: serde::Serialize + serde::de::DeserializeOwned,
+{
+	fn collection_by_id(
+		&self,
+		collection: CollectionId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RpcCollection<AccountId>>> {
+		query!(self, at, collection_by_id(collection))
+	}
+
+	fn collection_properties(
+		&self,
+		collection: CollectionId,
+		keys: Option<Vec<String>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Property>> {
+		query_result!(self, at, collection_properties(collection, string_keys_to_bytes_keys(keys)))
+	}
+
+	fn token_properties(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		keys: Option<Vec<String>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<Property>> {
+		query_result!(self, at, token_properties(collection, token, string_keys_to_bytes_keys(keys)))
+	}
+
+	fn property_permissions(
+		&self,
+		collection: CollectionId,
+		keys: Option<Vec<String>>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<PropertyKeyPermission>> {
+		query_result!(self, at, property_permissions(collection, string_keys_to_bytes_keys(keys)))
+	}
+
+	fn balance(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<String> {
+		query_result!(self, at, balance(collection, token, account)).map(|b| b.to_string())
+	}
+
+	fn total_supply(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<String> {
+		query_result!(self, at, total_supply(collection, token)).map(|s| s.to_string())
+	}
+
+	fn allowance(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		owner: AccountId,
+		spender: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<String> {
+		query_result!(self, at, allowance(collection, token, owner, spender)).map(|a| a.to_string())
+	}
+
+	fn adminlist(
+		&self,
+		collection: CollectionId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<AccountId>> {
+		query!(self, at, adminlist(collection))
+	}
+
+	fn collection_stats(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<CollectionStats> {
+		query!(self, at, collection_stats())
+	}
+
+	fn effective_collection_limits(
+		&self,
+		collection: CollectionId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CollectionLimits>> {
+		query!(self, at, effective_collection_limits(collection))
+	}
+
+	fn next_sponsored(
+		&self,
+		collection: CollectionId,
+		account: AccountId,
+		token: TokenId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<u64>> {
+		query!(self, at, next_sponsored(collection, account, token))
+	}
+
+	fn account_tokens(
+		&self,
+		account: AccountId,
+		start_after: Option<(CollectionId, TokenId)>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(CollectionId, TokenId)>> {
+		query!(self, at, account_tokens(account, start_after, limit))
+	}
+
+	fn token_holders(
+		&self,
+		collection: CollectionId,
+		token: TokenId,
+		start_after: Option<AccountId>,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, String)>> {
+		query_result!(self, at, token_holders(collection, token, start_after, limit)).map(|holders| {
+			holders.into_iter().map(|(holder, amount)| (holder, amount.to_string())).collect()
+		})
+	}
+
+	fn last_token_id(
+		&self,
+		collection: CollectionId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<TokenId> {
+		query_result!(self, at, last_token_id(collection))
+	}
+}
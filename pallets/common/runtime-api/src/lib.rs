@@ -0,0 +1,96 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use collection_primitives::{
+	CollectionId, CollectionLimits, CollectionStats, Property, PropertyKeyPermission,
+	RpcCollection, TokenId,
+};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+type Result<T> = core::result::Result<T, DispatchError>;
+
+sp_api::decl_runtime_apis! {
+	/// The API to query collections, their tokens and token holders.
+	pub trait CollectionsApi<AccountId>
+	where
+		AccountId: Codec
+	{
+		/// Returns the collection with all its properties and property permissions.
+		fn collection_by_id(collection: CollectionId) -> Option<RpcCollection<AccountId>>;
+		/// Returns collection properties, optionally filtered by keys.
+		fn collection_properties(collection: CollectionId, keys: Option<Vec<Vec<u8>>>) -> Result<Vec<Property>>;
+		/// Returns token properties, optionally filtered by keys.
+		fn token_properties(
+			collection: CollectionId,
+			token: TokenId,
+			keys: Option<Vec<Vec<u8>>>
+		) -> Result<Vec<Property>>;
+		/// Returns property permissions of the collection, optionally filtered by keys.
+		fn property_permissions(
+			collection: CollectionId,
+			keys: Option<Vec<Vec<u8>>>
+		) -> Result<Vec<PropertyKeyPermission>>;
+		/// Returns the amount of token pieces owned by the account.
+		///
+		/// For NFT collections it is either 0 or 1.
+		fn balance(collection: CollectionId, token: TokenId, account: AccountId) -> Result<u128>;
+		/// Returns the total amount of pieces the token is split into.
+		///
+		/// For NFT collections it is either 0 or 1.
+		fn total_supply(collection: CollectionId, token: TokenId) -> Result<u128>;
+		/// Returns the amount of token pieces the spender is allowed to use on behalf of the owner.
+		fn allowance(
+			collection: CollectionId,
+			token: TokenId,
+			owner: AccountId,
+			spender: AccountId
+		) -> Result<u128>;
+		/// Returns the list of collection admins.
+		fn adminlist(collection: CollectionId) -> Vec<AccountId>;
+		/// Returns statistics of created, destroyed and alive collections.
+		fn collection_stats() -> CollectionStats;
+		/// Returns collection limits with chain defaults substituted for unset fields.
+		fn effective_collection_limits(collection: CollectionId) -> Option<CollectionLimits>;
+		/// Returns the number of blocks until the next sponsored transfer of the token by the account,
+		/// or `None` if transfers of the token are not sponsored.
+		fn next_sponsored(collection: CollectionId, account: AccountId, token: TokenId) -> Option<u64>;
+		/// Returns a page of refungible tokens partially owned by the account across all collections,
+		/// starting after `start_after`.
+		fn account_tokens(
+			account: AccountId,
+			start_after: Option<(CollectionId, TokenId)>,
+			limit: u32
+		) -> Vec<(CollectionId, TokenId)>;
+		/// Returns a page of token holders with their amounts of pieces, starting after `start_after`.
+		fn token_holders(
+			collection: CollectionId,
+			token: TokenId,
+			start_after: Option<AccountId>,
+			limit: u32
+		) -> Result<Vec<(AccountId, u128)>>;
+		/// Returns id of the last token minted in the collection.
+		fn last_token_id(collection: CollectionId) -> Result<TokenId>;
+	}
+}
@@ -42,7 +42,8 @@ pub struct CollectionInitializer<T: crate::Config> {
 	token_prefix: CollectionTokenPrefix,
 	properties: Vec<Property>,
 	property_permissions: Vec<PropertyKeyPermission>,
-	admins: Vec<T::AccountId>
+	admins: Vec<T::AccountId>,
+	mode: CollectionMode
 }
 
 impl<T: crate::Config> CollectionInitializer<T> {
@@ -53,7 +54,15 @@ impl<T: crate::Config> CollectionInitializer<T> {
 			token_prefix: CollectionTokenPrefix::truncate_from("STP".as_bytes().into()),
 			properties: vec![],
 			property_permissions: vec![],
-			admins: vec![]
+			admins: vec![],
+			mode: CollectionMode::ReFungible
+		}
+	}
+
+	pub fn mode(self, mode: CollectionMode) -> Self {
+		Self {
+			mode,
+			..self
 		}
 	}
 
@@ -78,7 +87,9 @@ impl<T: crate::Config> CollectionInitializer<T> {
 				PropertyKeyPermission { 
 					key: property.key.clone(), 
 					permission: PropertyPermission {
-						mutable: *mutable
+						mutable: *mutable,
+						collection_admin: true,
+						token_owner: false
 					} 
 				}
 			})
@@ -104,7 +115,9 @@ impl<T: crate::Config> CollectionInitializer<T> {
 			.map(|k| PropertyKeyPermission {
 				key: k.clone(),
 				permission: PropertyPermission {
-					mutable: true
+					mutable: true,
+					collection_admin: true,
+					token_owner: false
 				}
 			})
 			.into_iter();
@@ -136,7 +149,7 @@ impl<T: crate::Config> CollectionInitializer<T> {
 
 	pub fn init(self, owner: T::AccountId) -> Result<CollectionId, DispatchError> {
 		let data = CreateCollectionData::<T::AccountId> {
-			mode: CollectionMode::ReFungible,
+			mode: self.mode,
 			name: self.name,
 			description: self.description,
 			token_prefix: self.token_prefix,
@@ -159,13 +172,15 @@ impl<T: crate::Config> CollectionInitializer<T> {
 	}
 
 	pub fn init_default(self, owner: T::AccountId) -> Result<CollectionId, DispatchError> {
-		let data = default_create_collection_data::<T>();
+		let mut data = default_create_collection_data::<T>();
+		data.mode = self.mode;
 
 		crate::Pallet::<T>::init_collection(owner.clone(), owner, data)
 	}
 
 	pub fn init_with_sponsor(self, sponsor: T::AccountId, owner: T::AccountId, is_confirmed: bool) -> Result<CollectionId, DispatchError> {
 		let mut data = default_create_collection_data::<T>();
+		data.mode = self.mode;
 		data.pending_sponsor = Some(sponsor.clone());
 		let mut collection_limits = CollectionLimits::default();
 		collection_limits.sponsored_data_rate_limit = Some(SponsoringRateLimit::Blocks(5)); 
@@ -101,6 +101,9 @@ use collection_primitives::{
 	TokenData,
 	TrySetProperty,
 	PropertyScope,
+	SponsoredAccounts,
+	SponsorshipSettings,
+	SponsorshipUsage,
 };
 
 use pallet_whitelist::traits::WhitelistInterface;
@@ -108,12 +111,17 @@ use pallet_whitelist::traits::WhitelistInterface;
 pub use handle::CollectionHandle;
 
 pub use pallet::*;
-use sp_runtime::{ArithmeticError, DispatchError};
+use sp_runtime::{ArithmeticError, DispatchError, traits::{Saturating, Zero}};
+
+/// Balance type of the currency used to pay for collections.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 pub mod mock;
 pub mod tests;
 pub mod handle;
 pub mod collection_initializer;
+pub mod migration;
 
 macro_rules! limit_default {
 	($old:ident, $new:ident, $($field:ident $(($arg:expr))? => $check:expr),* $(,)?) => {{
@@ -168,7 +176,7 @@ pub mod pallet {
 		type Whitelist: WhitelistInterface<Self>;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -330,7 +338,72 @@ pub mod pallet {
 		SponsorshipRemoved(
 			/// ID of collection from which sponsor has been removed.
 			CollectionId
-		)
+		),
+
+		/// A new owner of a collection has been proposed.
+		CollectionOwnershipTransferProposed(
+			/// ID of collection whose ownership is being transferred.
+			CollectionId,
+			/// Current owner of a collection.
+			T::AccountId,
+			/// Proposed owner of a collection.
+			T::AccountId
+		),
+
+		/// A pending ownership transfer of a collection has been cancelled.
+		CollectionOwnershipTransferCancelled(
+			/// ID of collection whose ownership transfer has been cancelled.
+			CollectionId
+		),
+
+		/// The owner of a collection has been changed.
+		CollectionOwnerChanged(
+			/// ID of collection whose owner has been changed.
+			CollectionId,
+			/// Previous owner of a collection.
+			T::AccountId,
+			/// New owner of a collection.
+			T::AccountId
+		),
+
+		/// Sponsorship settings of a collection have been changed.
+		SponsorshipSettingsSet(
+			/// ID of collection whose sponsorship settings have been changed.
+			CollectionId
+		),
+
+		/// Account has been added to or removed from the sponsor allowlist of a collection.
+		SponsorAllowlistToggled(
+			/// ID of collection whose allowlist has been changed.
+			CollectionId,
+			/// Account which status has been changed.
+			T::AccountId,
+			/// Current status (true = sponsored, false = not sponsored).
+			bool
+		),
+
+		/// Sponsor budget of a collection is exhausted for the current period,
+		/// the fee has been charged to the transaction sender.
+		SponsorshipBudgetExhausted(
+			/// ID of collection whose sponsor budget is exhausted.
+			CollectionId,
+			/// Sender of the transaction who paid the fee.
+			T::AccountId
+		),
+
+		/// Holding limits of a collection have been set.
+		CollectionHoldingLimitsSet(
+			/// ID of collection to which holding limits have been set.
+			CollectionId
+		),
+
+		/// Holding limits of a token have been set.
+		TokenHoldingLimitsSet(
+			/// ID of collection to which the token belongs.
+			CollectionId,
+			/// ID of token to which holding limits have been set.
+			TokenId
+		),
 	}
 
 	#[pallet::error]
@@ -429,6 +502,21 @@ pub mod pallet {
 
 		/// Sponsorship already disabled
 		SponsorshipAlreadyDisabled,
+
+		/// Proposed account already owns the collection
+		AlreadyCollectionOwner,
+
+		/// There is no pending ownership transfer for the collection
+		NoPendingOwnershipTransfer,
+
+		/// Given account (call sender) is not the proposed collection owner
+		NotPendingOwner,
+
+		/// Sponsorship settings can only be changed by the collection owner or the confirmed sponsor
+		NoSponsorshipPermission,
+
+		/// Sponsorship period should be longer than zero blocks
+		ZeroSponsorshipPeriod,
 	}
 
 	/// Storage of the count of created collections. Essentially contains the last collection ID.
@@ -508,6 +596,62 @@ pub mod pallet {
 		QueryKind = OptionQuery,
 	>;
 
+	/// Accounts proposed by collection owners to take over their collections.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_owner)]
+	pub type PendingOwner<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = T::AccountId,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Restrictions put on sponsored transactions of a collection.
+	///
+	/// Transactions are sponsored without restrictions if settings are not set.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsorship_settings)]
+	pub type CollectionSponsorshipSettings<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = SponsorshipSettings<BalanceOf<T>, T::BlockNumber>,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Accounts sponsored in [`SponsoredAccounts::Allowlist`] mode.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsor_allowlist)]
+	pub type SponsorAllowlist<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = CollectionId,
+		Hasher2 = Blake2_128Concat,
+		Key2 = T::AccountId,
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Fees paid by the collection sponsor within the current period.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsor_budget_usage)]
+	pub type SponsorBudgetUsage<T: Config> = StorageMap<
+		Hasher = Blake2_128Concat,
+		Key = CollectionId,
+		Value = SponsorshipUsage<BalanceOf<T>, T::BlockNumber>,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Amount of sponsored transactions of an account within the current period.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_account_usage)]
+	pub type SponsoredAccountUsage<T: Config> = StorageDoubleMap<
+		Hasher1 = Blake2_128Concat,
+		Key1 = CollectionId,
+		Hasher2 = Blake2_128Concat,
+		Key2 = T::AccountId,
+		Value = SponsorshipUsage<u32, T::BlockNumber>,
+		QueryKind = OptionQuery,
+	>;
+
 	/// (Collection id (controlled?2), who created (real))
 	/// TODO: Off chain worker should remove from this map when collection gets removed
 	#[pallet::storage]
@@ -664,6 +808,11 @@ impl<T: Config> Pallet<T> {
 		<AdminAmount<T>>::remove(collection.id);
 		let _ = <IsAdmin<T>>::clear_prefix((collection.id,), u32::MAX, None);
 		<CollectionProperties<T>>::remove(collection.id);
+		<PendingOwner<T>>::remove(collection.id);
+		<CollectionSponsorshipSettings<T>>::remove(collection.id);
+		<SponsorBudgetUsage<T>>::remove(collection.id);
+		let _ = <SponsorAllowlist<T>>::clear_prefix(collection.id, u32::MAX, None);
+		let _ = <SponsoredAccountUsage<T>>::clear_prefix(collection.id, u32::MAX, None);
 
 		<Pallet<T>>::deposit_event(Event::CollectionDestroyed(collection.id));
 
@@ -933,6 +1082,37 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Check that `sender` is allowed to change token property with `key`
+	/// after the token was created.
+	///
+	/// The property must be mutable, and `sender` must be the owner or an admin
+	/// of the collection if `collection_admin` is set, or the owner of the token
+	/// if `token_owner` is set.
+	///
+	/// * `is_token_owner` - Checks if `sender` owns the token. Called only when needed.
+	pub fn check_token_property_permission(
+		collection: &CollectionHandle<T>,
+		sender: &T::AccountId,
+		key: &PropertyKey,
+		is_token_owner: impl FnOnce() -> bool,
+	) -> DispatchResult {
+		let permission = Self::property_permissions(collection.id)
+			.get(key)
+			.cloned()
+			.unwrap_or_else(PropertyPermission::none);
+
+		let allowed = match permission {
+			PropertyPermission { mutable: false, .. } => false,
+			PropertyPermission { collection_admin, token_owner, .. } => {
+				(collection_admin && collection.is_owner_or_admin(sender))
+					|| (token_owner && is_token_owner())
+			}
+		};
+
+		ensure!(allowed, <Error<T>>::NoPermission);
+		Ok(())
+	}
+
 	/// Get collection property.
 	pub fn get_collection_property(
 		collection_id: CollectionId,
@@ -966,27 +1146,35 @@ impl<T: Config> Pallet<T> {
 		collection_id: CollectionId,
 		keys: Option<Vec<PropertyKey>>,
 	) -> Result<Vec<Property>, DispatchError> {
-		let properties = Self::collection_properties(collection_id);
+		Ok(Self::filter_properties(
+			Self::collection_properties(collection_id),
+			keys,
+		))
+	}
 
-		let properties = keys
-			.map(|keys| {
-				keys.into_iter()
-					.filter_map(|key| {
-						properties.get(&key).map(|value| Property {
-							key,
-							value: value.clone(),
-						})
+	/// Get properties from the given container according to given keys.
+	///
+	/// Used to filter both collection and token properties.
+	pub fn filter_properties(
+		properties: Properties,
+		keys: Option<Vec<PropertyKey>>,
+	) -> Vec<Property> {
+		keys.map(|keys| {
+			keys.into_iter()
+				.filter_map(|key| {
+					properties.get(&key).map(|value| Property {
+						key,
+						value: value.clone(),
 					})
-					.collect()
-			})
-			.unwrap_or_else(|| {
-				properties
-					.into_iter()
-					.map(|(key, value)| Property { key, value })
-					.collect()
-			});
-
-		Ok(properties)
+				})
+				.collect()
+		})
+		.unwrap_or_else(|| {
+			properties
+				.into_iter()
+				.map(|(key, value)| Property { key, value })
+				.collect()
+		})
 	}
 
 	/// Get property permissions according to given keys.
@@ -1092,6 +1280,9 @@ impl<T: Config> Pallet<T> {
 
 		collection.confirm_sponsorship(sender)?;
 
+		// Budget spent by the previous sponsor is not counted against the new one
+		<SponsorBudgetUsage<T>>::remove(collection.id);
+
 		Self::deposit_event(Event::SponsorhipConfirmed(collection.id, sender.clone()));
 
 		collection.save()?;
@@ -1114,6 +1305,261 @@ impl<T: Config> Pallet<T> {
 		collection.save()?;
 		Ok(())
 	}
+
+	/// Set restrictions on sponsored transactions of the collection.
+	///
+	/// * `sender` - The owner of the collection or its confirmed sponsor.
+	/// * `settings` - New sponsorship settings, `None` removes all restrictions.
+	///
+	/// Budget usage is reset, quota usage of accounts is kept until the end of their periods.
+	pub fn set_sponsorship_settings(
+		collection_id: CollectionId,
+		sender: &T::AccountId,
+		settings: Option<SponsorshipSettings<BalanceOf<T>, T::BlockNumber>>
+	) -> DispatchResult {
+		let collection = CollectionHandle::<T>::try_get(collection_id)?;
+
+		Self::check_can_manage_sponsorship(&collection, sender)?;
+
+		if let Some(settings) = &settings {
+			ensure!(
+				!settings.period.is_zero(),
+				Error::<T>::ZeroSponsorshipPeriod
+			);
+		}
+
+		// =========
+
+		<CollectionSponsorshipSettings<T>>::set(collection.id, settings);
+		<SponsorBudgetUsage<T>>::remove(collection.id);
+
+		Self::deposit_event(Event::SponsorshipSettingsSet(collection.id));
+		Ok(())
+	}
+
+	/// Add an account to or remove it from the sponsor allowlist of the collection.
+	///
+	/// * `sender` - The owner of the collection or its confirmed sponsor.
+	/// * `account` - Account which status is changed.
+	/// * `value` - Whether transactions of the account should be sponsored.
+	pub fn toggle_sponsor_allowlist(
+		collection_id: CollectionId,
+		sender: &T::AccountId,
+		account: T::AccountId,
+		value: bool
+	) -> DispatchResult {
+		let collection = CollectionHandle::<T>::try_get(collection_id)?;
+
+		Self::check_can_manage_sponsorship(&collection, sender)?;
+
+		// =========
+
+		if value {
+			<SponsorAllowlist<T>>::insert(collection.id, &account, true);
+		} else {
+			<SponsorAllowlist<T>>::remove(collection.id, &account);
+		}
+
+		Self::deposit_event(Event::SponsorAllowlistToggled(collection.id, account, value));
+		Ok(())
+	}
+
+	fn check_can_manage_sponsorship(
+		collection: &CollectionHandle<T>,
+		sender: &T::AccountId
+	) -> DispatchResult {
+		ensure!(
+			collection.owner == *sender || collection.sponsorship.sponsor() == Some(sender),
+			Error::<T>::NoSponsorshipPermission
+		);
+		Ok(())
+	}
+
+	/// Check that the sponsor of the collection may pay `fee` for a transaction of `who`.
+	///
+	/// Does not account the fee, call [`charge_sponsorship`](Self::charge_sponsorship)
+	/// once the transaction is sponsored. Emits [`Event::SponsorshipBudgetExhausted`]
+	/// if the fee exceeds the budget left for the current period.
+	pub fn check_sponsorship_limits(
+		collection_id: CollectionId,
+		who: &T::AccountId,
+		fee: BalanceOf<T>
+	) -> bool {
+		let settings = match <CollectionSponsorshipSettings<T>>::get(collection_id) {
+			Some(settings) => settings,
+			None => return true,
+		};
+
+		let is_eligible = match settings.sponsored_accounts {
+			SponsoredAccounts::All => true,
+			SponsoredAccounts::Allowlist => <SponsorAllowlist<T>>::get(collection_id, who),
+			SponsoredAccounts::WhitelistedInvestors => T::Whitelist::is_active_investor(who),
+		};
+		if !is_eligible {
+			return false;
+		}
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		if let Some(quota) = settings.account_quota {
+			let used = SponsorshipUsage::current(
+				<SponsoredAccountUsage<T>>::get(collection_id, who),
+				settings.period,
+				block_number
+			).used;
+			if used >= quota {
+				return false;
+			}
+		}
+
+		if let Some(budget) = settings.budget {
+			let used = SponsorshipUsage::current(
+				<SponsorBudgetUsage<T>>::get(collection_id),
+				settings.period,
+				block_number
+			).used;
+			if used.saturating_add(fee) > budget {
+				Self::deposit_event(Event::SponsorshipBudgetExhausted(collection_id, who.clone()));
+				return false;
+			}
+		}
+
+		true
+	}
+
+	/// Account `fee` paid by the sponsor of the collection for a transaction of `who`.
+	pub fn charge_sponsorship(
+		collection_id: CollectionId,
+		who: &T::AccountId,
+		fee: BalanceOf<T>
+	) {
+		let settings = match <CollectionSponsorshipSettings<T>>::get(collection_id) {
+			Some(settings) => settings,
+			None => return,
+		};
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		if settings.account_quota.is_some() {
+			<SponsoredAccountUsage<T>>::mutate(collection_id, who, |usage| {
+				let mut current = SponsorshipUsage::current(usage.take(), settings.period, block_number);
+				current.used = current.used.saturating_add(1);
+				*usage = Some(current);
+			});
+		}
+
+		if settings.budget.is_some() {
+			<SponsorBudgetUsage<T>>::mutate(collection_id, |usage| {
+				let mut current = SponsorshipUsage::current(usage.take(), settings.period, block_number);
+				current.used = current.used.saturating_add(fee);
+				*usage = Some(current);
+			});
+		}
+	}
+
+	/// Propose `new_owner` as the owner of the collection.
+	///
+	/// Ownership is not changed until `new_owner` accepts it with
+	/// [`accept_collection_ownership`](Self::accept_collection_ownership).
+	/// A new proposal replaces the previous one.
+	///
+	/// * `sender` - The owner of the collection.
+	/// * `new_owner` - Whitelist admin who will own the collection.
+	pub fn propose_collection_owner(
+		collection_id: CollectionId,
+		sender: &T::AccountId,
+		new_owner: T::AccountId
+	) -> DispatchResult {
+		let collection = CollectionHandle::<T>::try_get(collection_id)?;
+
+		collection.check_is_owner(sender)?;
+
+		ensure!(
+			collection.owner != new_owner,
+			Error::<T>::AlreadyCollectionOwner
+		);
+		T::Whitelist::ensure_admin(&new_owner)?;
+
+		<PendingOwner<T>>::insert(collection.id, new_owner.clone());
+
+		Self::deposit_event(Event::CollectionOwnershipTransferProposed(
+			collection.id,
+			sender.clone(),
+			new_owner
+		));
+
+		Ok(())
+	}
+
+	/// Cancel a pending ownership transfer of the collection.
+	///
+	/// * `sender` - The owner of the collection.
+	pub fn cancel_collection_ownership_transfer(
+		collection_id: CollectionId,
+		sender: &T::AccountId
+	) -> DispatchResult {
+		let collection = CollectionHandle::<T>::try_get(collection_id)?;
+
+		collection.check_is_owner(sender)?;
+
+		ensure!(
+			<PendingOwner<T>>::contains_key(collection.id),
+			Error::<T>::NoPendingOwnershipTransfer
+		);
+
+		<PendingOwner<T>>::remove(collection.id);
+
+		Self::deposit_event(Event::CollectionOwnershipTransferCancelled(collection.id));
+
+		Ok(())
+	}
+
+	/// Accept ownership of the collection proposed to `sender`.
+	///
+	/// The new owner is removed from the collection admins, since the owner
+	/// already has all admin rights. An unconfirmed sponsor proposed by the
+	/// previous owner is dropped, a confirmed sponsor is kept.
+	///
+	/// * `sender` - The proposed owner of the collection.
+	pub fn accept_collection_ownership(
+		collection_id: CollectionId,
+		sender: &T::AccountId
+	) -> DispatchResult {
+		let mut collection = CollectionHandle::<T>::try_get(collection_id)?;
+
+		let pending_owner = <PendingOwner<T>>::get(collection.id)
+			.ok_or(Error::<T>::NoPendingOwnershipTransfer)?;
+		ensure!(
+			pending_owner == *sender,
+			Error::<T>::NotPendingOwner
+		);
+		// Whitelist admin status may have been revoked since the proposal
+		T::Whitelist::ensure_admin(sender)?;
+
+		// =========
+
+		if <IsAdmin<T>>::take((collection.id, sender)) {
+			<AdminAmount<T>>::mutate(collection.id, |amount| *amount = amount.saturating_sub(1));
+			Self::deposit_event(Event::AdminToggled(sender.clone(), false));
+		}
+
+		if let SponsorshipState::Unconfirmed(_) = collection.sponsorship {
+			collection.sponsorship = SponsorshipState::Disabled;
+			Self::deposit_event(Event::SponsorshipRemoved(collection.id));
+		}
+
+		let old_owner = core::mem::replace(&mut collection.owner, sender.clone());
+		<PendingOwner<T>>::remove(collection.id);
+
+		Self::deposit_event(Event::CollectionOwnerChanged(
+			collection.id,
+			old_owner,
+			sender.clone()
+		));
+
+		collection.save()?;
+		Ok(())
+	}
 }
 
 /// Indicates unsupported methods by returning [Error::UnsupportedOperation].
@@ -0,0 +1,124 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use core::marker::PhantomData;
+use codec::Decode;
+use frame_support::{
+	dispatch::GetStorageVersion,
+	pallet_prelude::StorageVersion,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use collection_primitives::{
+	PropertiesMap, PropertiesPermissionMap, PropertyPermission, TrySetProperty,
+};
+
+use crate::{Config, Pallet, PropertyPermissions};
+
+#[cfg(feature = "try-runtime")]
+use sp_std::{vec, vec::Vec};
+
+/// Property permission before `collection_admin` and `token_owner` flags were added.
+#[derive(Decode)]
+struct OldPropertyPermission {
+	mutable: bool,
+}
+
+/// Maps single `mutable` flag of property permissions to [`PropertyPermission`].
+///
+/// Previously token properties could be changed only by collection owner or
+/// admins, so `collection_admin` is set and `token_owner` is not.
+pub struct MigratePropertyPermissions<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigratePropertyPermissions<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current = Pallet::<T>::current_storage_version();
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		log::info!(
+			"Running common migration with current storage version {:?} / onchain {:?}",
+			current,
+			onchain
+		);
+
+		if current == 2 && onchain == 1 {
+			let mut translated = 0u64;
+			<PropertyPermissions<T>>::translate::<PropertiesMap<OldPropertyPermission>, _>(
+				|_, old| {
+					translated += 1;
+					let mut permissions = PropertiesPermissionMap::new();
+					for (key, permission) in old {
+						// Keys were validated when old permissions were set
+						let _ = permissions.try_set(key, PropertyPermission {
+							mutable: permission.mutable,
+							collection_admin: true,
+							token_owner: false,
+						});
+					}
+					Some(permissions)
+				},
+			);
+
+			log::info!("Migrated property permissions of {:?} collections", translated);
+			StorageVersion::new(2).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(translated, translated.saturating_add(1))
+		} else {
+			log::info!("MigratePropertyPermissions did not execute. This probably should be removed");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 1,
+			"Common on-chain StorageVersion is {:?} instead of 1",
+			onchain
+		);
+
+		Ok(vec![])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 2,
+			"Common StorageVersion post-migration is not 2, but {:?} instead.",
+			onchain
+		);
+
+		for permissions in <PropertyPermissions<T>>::iter_values() {
+			for (_, permission) in permissions {
+				assert!(
+					permission.collection_admin && !permission.token_owner,
+					"Property permission was not migrated"
+				);
+			}
+		}
+
+		Ok(())
+	}
+}
@@ -53,7 +53,8 @@ pub use collection_primitives::{
 	CollectionDescription, CollectionTokenPrefix, PropertiesPermissionsVec,
 	CollectionPropertiesVec, Property, PropertyKeyPermission,
 	CollectionId, Collection, CollectionLimits,
-	PropertyValue, PropertyKey, SponsorshipState, PropertyPermission
+	PropertyValue, PropertyKey, SponsorshipState, PropertyPermission,
+	SponsorshipSettings, SponsoredAccounts
 };
 
 pub use pallet_common::collection_initializer::CollectionInitializer;
@@ -125,6 +126,9 @@ ord_parameter_types! {
 impl pallet_whitelist::Config for MockRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type RolesRoot = EnsureSignedBy<RolesRootAccountId, AccountId>;
+	type OffchainSignature = sp_runtime::testing::TestSignature;
+	type OffchainPublic = sp_runtime::testing::UintAuthorityId;
+	type MaxKycAttributesLength = frame_support::traits::ConstU32<256>;
 	type WeightInfo = ();
 }
 
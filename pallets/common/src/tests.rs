@@ -32,7 +32,8 @@ use pallet_whitelist::Error as WhitelistError;
 use crate::mock::*;
 use crate::{
     CollectionById, CreatedCollectionCount, DestroyedCollectionCount, Error,
-    CollectionHandle, AdminAmount, CollectionProperties, IsAdmin
+    CollectionHandle, AdminAmount, CollectionProperties, IsAdmin, PendingOwner,
+    PropertyPermissions, CollectionSponsorshipSettings
 };
 
 // TODO: different init parameters
@@ -714,4 +715,416 @@ pub fn remove_sponsor_fails_if_sponsorship_already_disabled() {
             Error::<MockRuntime>::SponsorshipAlreadyDisabled
         );
     });
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn collection_ownership_transfer_works() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_ok!(Common::propose_collection_owner(collection_id, &ADMIN_1, ADMIN_2));
+        System::assert_last_event(RuntimeEvent::Common(crate::Event::CollectionOwnershipTransferProposed(
+            collection_id,
+            ADMIN_1,
+            ADMIN_2
+        )));
+        assert_eq!(PendingOwner::<MockRuntime>::get(collection_id), Some(ADMIN_2));
+
+        // Ownership is unchanged until the proposal is accepted
+        let collection = CollectionById::<MockRuntime>::get(collection_id).expect("Collection not found");
+        assert_eq!(collection.owner, ADMIN_1);
+
+        assert_ok!(Common::accept_collection_ownership(collection_id, &ADMIN_2));
+        System::assert_last_event(RuntimeEvent::Common(crate::Event::CollectionOwnerChanged(
+            collection_id,
+            ADMIN_1,
+            ADMIN_2
+        )));
+
+        let collection = CollectionById::<MockRuntime>::get(collection_id).expect("Collection not found");
+        assert_eq!(collection.owner, ADMIN_2);
+        assert_eq!(PendingOwner::<MockRuntime>::get(collection_id), None);
+
+        // Previous owner lost owner rights
+        let collection_handle = CollectionHandle::new(collection_id).expect("Collection not found");
+        assert_noop!(
+            Common::toggle_admin(&collection_handle, &ADMIN_1, &ADMIN_1, true),
+            Error::<MockRuntime>::NoPermission
+        );
+    });
+}
+
+#[test]
+pub fn propose_collection_owner_fails_if_not_owner() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_noop!(
+            Common::propose_collection_owner(collection_id, &ADMIN_2, ADMIN_2),
+            Error::<MockRuntime>::NoPermission
+        );
+    });
+}
+
+#[test]
+pub fn propose_collection_owner_fails_if_new_owner_not_whitelist_admin() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_noop!(
+            Common::propose_collection_owner(collection_id, &ADMIN_1, ALICE),
+            WhitelistError::<MockRuntime>::NotWhitelistAdmin
+        );
+    });
+}
+
+#[test]
+pub fn propose_collection_owner_fails_if_already_owner() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_noop!(
+            Common::propose_collection_owner(collection_id, &ADMIN_1, ADMIN_1),
+            Error::<MockRuntime>::AlreadyCollectionOwner
+        );
+    });
+}
+
+#[test]
+pub fn accept_collection_ownership_fails_if_not_pending_owner() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2, ADMIN_3])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_noop!(
+            Common::accept_collection_ownership(collection_id, &ADMIN_2),
+            Error::<MockRuntime>::NoPendingOwnershipTransfer
+        );
+
+        assert_ok!(Common::propose_collection_owner(collection_id, &ADMIN_1, ADMIN_2));
+
+        assert_noop!(
+            Common::accept_collection_ownership(collection_id, &ADMIN_3),
+            Error::<MockRuntime>::NotPendingOwner
+        );
+    });
+}
+
+#[test]
+pub fn cancel_collection_ownership_transfer_works() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_noop!(
+            Common::cancel_collection_ownership_transfer(collection_id, &ADMIN_1),
+            Error::<MockRuntime>::NoPendingOwnershipTransfer
+        );
+
+        assert_ok!(Common::propose_collection_owner(collection_id, &ADMIN_1, ADMIN_2));
+        assert_ok!(Common::cancel_collection_ownership_transfer(collection_id, &ADMIN_1));
+        System::assert_last_event(RuntimeEvent::Common(crate::Event::CollectionOwnershipTransferCancelled(
+            collection_id
+        )));
+
+        assert_noop!(
+            Common::accept_collection_ownership(collection_id, &ADMIN_2),
+            Error::<MockRuntime>::NoPendingOwnershipTransfer
+        );
+    });
+}
+
+#[test]
+pub fn accept_collection_ownership_clears_stale_admin_and_sponsor() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let mut data = default_create_collection_data::<MockRuntime>();
+        data.pending_sponsor = Some(ALICE);
+        let collection_id = Common::init_collection(ADMIN_1, ADMIN_1, data.clone())
+            .expect("Init collection failed");
+
+        let collection_handle = CollectionHandle::new(collection_id).expect("Collection not found");
+        assert_ok!(Common::toggle_admin(&collection_handle, &ADMIN_1, &ADMIN_2, true));
+        assert_eq!(AdminAmount::<MockRuntime>::get(collection_id), 1);
+
+        assert_ok!(Common::propose_collection_owner(collection_id, &ADMIN_1, ADMIN_2));
+        assert_ok!(Common::accept_collection_ownership(collection_id, &ADMIN_2));
+
+        assert_eq!(IsAdmin::<MockRuntime>::get((collection_id, ADMIN_2)), false);
+        assert_eq!(AdminAmount::<MockRuntime>::get(collection_id), 0);
+
+        let collection = CollectionById::<MockRuntime>::get(collection_id).expect("Collection not found");
+        assert_eq!(collection.sponsorship, SponsorshipState::Disabled);
+    });
+}
+
+#[test]
+pub fn accept_collection_ownership_keeps_confirmed_sponsor() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let mut data = default_create_collection_data::<MockRuntime>();
+        data.pending_sponsor = Some(ALICE);
+        let collection_id = Common::init_collection(ADMIN_1, ADMIN_1, data.clone())
+            .expect("Init collection failed");
+        assert_ok!(Common::confirm_sponsorship(collection_id, &ALICE));
+
+        assert_ok!(Common::propose_collection_owner(collection_id, &ADMIN_1, ADMIN_2));
+        assert_ok!(Common::accept_collection_ownership(collection_id, &ADMIN_2));
+
+        let collection = CollectionById::<MockRuntime>::get(collection_id).expect("Collection not found");
+        assert_eq!(collection.sponsorship, SponsorshipState::Confirmed(ALICE));
+    });
+}
+
+
+/// All combinations of property permission flags.
+fn all_property_permissions() -> Vec<PropertyPermission> {
+    let mut permissions = vec![];
+    for mutable in [false, true] {
+        for collection_admin in [false, true] {
+            for token_owner in [false, true] {
+                permissions.push(PropertyPermission { mutable, collection_admin, token_owner });
+            }
+        }
+    }
+    permissions
+}
+
+#[test]
+pub fn check_token_property_permission_works_for_all_combinations() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .admins(vec![ADMIN_2])
+            .init(ADMIN_1)
+            .expect("Collection init failed");
+        let collection = CollectionHandle::new(collection_id).expect("Collection not found");
+
+        for (i, permission) in all_property_permissions().into_iter().enumerate() {
+            let key = create_property_key(&format!("Key{}", i));
+            assert_ok!(Common::set_property_permission_unchecked(collection_id, PropertyKeyPermission {
+                key: key.clone(),
+                permission: permission.clone()
+            }));
+
+            // (sender, is sender owner or admin of collection, is sender owner of token)
+            let senders = [
+                (ADMIN_1, true, false),
+                (ADMIN_2, true, false),
+                (ALICE, false, true),
+                (BOB, false, false),
+            ];
+            for (sender, is_admin, is_token_owner) in senders {
+                let result = Common::check_token_property_permission(
+                    &collection,
+                    &sender,
+                    &key,
+                    || is_token_owner
+                );
+                let allowed = permission.mutable
+                    && ((permission.collection_admin && is_admin)
+                        || (permission.token_owner && is_token_owner));
+
+                if allowed {
+                    assert_ok!(result);
+                } else {
+                    assert_noop!(result, Error::<MockRuntime>::NoPermission);
+                }
+            }
+        }
+    });
+}
+
+#[test]
+pub fn check_token_property_permission_fails_without_permission() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init(ADMIN_1)
+            .expect("Collection init failed");
+        let collection = CollectionHandle::new(collection_id).expect("Collection not found");
+
+        assert_noop!(
+            Common::check_token_property_permission(
+                &collection,
+                &ADMIN_1,
+                &create_property_key("UnknownKey"),
+                || true
+            ),
+            Error::<MockRuntime>::NoPermission
+        );
+    });
+}
+
+#[test]
+pub fn migrate_property_permissions_works() {
+    use std::collections::BTreeMap;
+    use codec::Encode;
+    use frame_support::{
+        BoundedBTreeMap,
+        storage::unhashed,
+        traits::{OnRuntimeUpgrade, StorageVersion},
+    };
+    use collection_primitives::MAX_PROPERTIES_PER_ITEM;
+    use crate::migration::MigratePropertyPermissions;
+
+    ExtBuilder::new()
+    .build()
+    .execute_with(|| {
+        StorageVersion::new(1).put::<Common>();
+
+        let collection_id = CollectionId(1);
+        let mutable_key = create_property_key("Mutable");
+        let unmutable_key = create_property_key("Unmutable");
+
+        // Old permissions map encoded with a single `mutable` flag
+        let old: BoundedBTreeMap<PropertyKey, bool, ConstU32<MAX_PROPERTIES_PER_ITEM>> =
+            vec![(mutable_key.clone(), true), (unmutable_key.clone(), false)]
+                .into_iter()
+                .collect::<BTreeMap<_, _>>()
+                .try_into()
+                .unwrap();
+        unhashed::put_raw(&PropertyPermissions::<MockRuntime>::hashed_key_for(collection_id), &old.encode());
+
+        MigratePropertyPermissions::<MockRuntime>::on_runtime_upgrade();
+
+        assert_eq!(StorageVersion::get::<Common>(), 2);
+        assert_eq!(
+            Common::get_collection_property_permission(collection_id, &mutable_key),
+            Some(PropertyPermission { mutable: true, collection_admin: true, token_owner: false })
+        );
+        assert_eq!(
+            Common::get_collection_property_permission(collection_id, &unmutable_key),
+            Some(PropertyPermission { mutable: false, collection_admin: true, token_owner: false })
+        );
+    });
+}
+
+#[test]
+pub fn set_sponsorship_settings_works() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let settings = SponsorshipSettings {
+            period: 10,
+            budget: Some(100),
+            account_quota: Some(1),
+            sponsored_accounts: SponsoredAccounts::All
+        };
+
+        assert_noop!(
+            Common::set_sponsorship_settings(collection_id, &BOB, Some(settings.clone())),
+            Error::<MockRuntime>::NoSponsorshipPermission
+        );
+        assert_noop!(
+            Common::set_sponsorship_settings(collection_id, &ADMIN_1, Some(SponsorshipSettings { period: 0, ..settings.clone() })),
+            Error::<MockRuntime>::ZeroSponsorshipPeriod
+        );
+
+        // Confirmed sponsor may manage sponsorship settings
+        assert_ok!(Common::set_sponsor(collection_id, &ADMIN_1, BOB));
+        assert_ok!(Common::confirm_sponsorship(collection_id, &BOB));
+        assert_ok!(Common::set_sponsorship_settings(collection_id, &BOB, Some(settings.clone())));
+        System::assert_last_event(RuntimeEvent::Common(crate::Event::SponsorshipSettingsSet(collection_id)));
+        assert_eq!(CollectionSponsorshipSettings::<MockRuntime>::get(collection_id), Some(settings));
+
+        assert_ok!(Common::set_sponsorship_settings(collection_id, &ADMIN_1, None));
+        assert_eq!(CollectionSponsorshipSettings::<MockRuntime>::get(collection_id), None);
+    });
+}
+
+#[test]
+pub fn sponsorship_limits_are_checked() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_ok!(Common::set_sponsorship_settings(collection_id, &ADMIN_1, Some(SponsorshipSettings {
+            period: 10,
+            budget: Some(100),
+            account_quota: Some(1),
+            sponsored_accounts: SponsoredAccounts::Allowlist
+        })));
+
+        // Not in allowlist
+        assert!(!Common::check_sponsorship_limits(collection_id, &BOB, 10));
+
+        assert_ok!(Common::toggle_sponsor_allowlist(collection_id, &ADMIN_1, BOB, true));
+        System::assert_last_event(RuntimeEvent::Common(crate::Event::SponsorAllowlistToggled(collection_id, BOB, true)));
+        assert_ok!(Common::toggle_sponsor_allowlist(collection_id, &ADMIN_1, CHARLIE, true));
+
+        // Budget exceeded
+        assert!(!Common::check_sponsorship_limits(collection_id, &BOB, 101));
+        System::assert_last_event(RuntimeEvent::Common(crate::Event::SponsorshipBudgetExhausted(collection_id, BOB)));
+
+        assert!(Common::check_sponsorship_limits(collection_id, &BOB, 60));
+        Common::charge_sponsorship(collection_id, &BOB, 60);
+
+        // Account quota exceeded
+        assert!(!Common::check_sponsorship_limits(collection_id, &BOB, 10));
+        // Budget left is not enough
+        assert!(!Common::check_sponsorship_limits(collection_id, &CHARLIE, 60));
+        assert!(Common::check_sponsorship_limits(collection_id, &CHARLIE, 40));
+
+        // Limits are reset once the period is over
+        System::set_block_number(11);
+        assert!(Common::check_sponsorship_limits(collection_id, &BOB, 60));
+    });
+}
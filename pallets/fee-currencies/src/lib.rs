@@ -0,0 +1,475 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! # Fee currencies pallet
+//!
+//! ## Overview
+//!
+//! Payment of transaction fees in non-native currencies, e.g. stablecoins bridged by new users.
+//!
+//! - `UpdateOrigin` approves currencies fees may be paid in, along with the `pallet_dex`
+//!   swap path from the currency into the native one.
+//! - [ChargeFeeInCurrency] signed extension lets the sender choose the fee currency. It swaps
+//!   the amount of native currency the sender lacks to pay the fee, bounded by the maximum amount
+//!   of the fee currency given by the sender. It wraps the transaction payment extension,
+//!   which charges the fee in native currency as usual, and replaces it in the runtime.
+//!
+//! Validation only checks the quote of the DEX against the maximum amount, the swap is done
+//! on dispatch. Choosing a fee currency adds the weight of a swap along the longest path
+//! to the weight the fee is charged for.
+//!
+//! The extension asks the sponsorship handler of the transaction payment extension first,
+//! nothing is swapped for transactions whose fee is paid by a sponsor.
+//!
+//! The existential deposit is swapped for along with the fee only while the sender lacks it,
+//! the fee charge keeps the account alive afterwards.
+//!
+//! Tip isn't swapped, it is always paid in native currency. Transactions swapping for the fee
+//! can't be tipped, the sender holds no native currency besides the swapped fee.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	dispatch::{DispatchInfo, PostDispatchInfo},
+	pallet_prelude::*,
+	storage::{with_storage_layer, with_transaction},
+};
+use frame_system::pallet_prelude::*;
+
+use orml_traits::MultiCurrency;
+
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion, Saturating, SignedExtension, Zero,
+	},
+	transaction_validity::{InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction},
+	DispatchError, FixedPointOperand, RuntimeDebug, TransactionOutcome,
+};
+use sp_std::{fmt, prelude::*};
+
+use pallet_charge_transaction::ChargeTransactionPayment;
+use pallet_transaction_payment::OnChargeTransaction;
+use up_sponsorship::SponsorshipHandler;
+
+use pallet_dex::{traits::DEXManager, types::SwapLimit};
+use primitives::Balance;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod mock_currency;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+type BalanceOf<T> =
+	<<T as pallet_transaction_payment::Config>::OnChargeTransaction as OnChargeTransaction<T>>::Balance;
+
+/// Fee currency chosen by the sender of a transaction
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct FeePayment<CurrencyId> {
+	/// Currency the fee is paid in
+	pub currency_id: CurrencyId,
+	/// Maximum amount of the currency swapped to pay the fee, bounds the slippage
+	pub max_amount: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_transaction_payment::Config + pallet_charge_transaction::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		type CurrencyId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// Currency fees are swapped in
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = Self::CurrencyId, Balance = Balance>;
+
+		/// Currency transaction fees are charged in
+		#[pallet::constant]
+		type NativeCurrencyId: Get<Self::CurrencyId>;
+
+		/// DEX fees are swapped on
+		type DEX: DEXManager<Self::AccountId, Balance, Self::CurrencyId>;
+
+		/// Origin allowed to approve fee currencies
+		type UpdateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Maximum length of a swap path of a fee currency
+		#[pallet::constant]
+		type MaxSwapPathLength: Get<u32>;
+
+		type WeightInfo: weights::WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Currencies approved for fee payment mapped to the swap path into the native currency
+	#[pallet::storage]
+	#[pallet::getter(fn fee_swap_path)]
+	pub type FeeCurrencies<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::CurrencyId,
+		BoundedVec<T::CurrencyId, T::MaxSwapPathLength>
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Currency approved for fee payment or its swap path changed
+		FeeCurrencySet {
+			currency_id: T::CurrencyId,
+			path: Vec<T::CurrencyId>,
+		},
+		/// Currency is no longer accepted for fee payment
+		FeeCurrencyRemoved {
+			currency_id: T::CurrencyId,
+		},
+		/// Fee currency of the sender swapped into native currency to pay the fee
+		FeeSwapped {
+			who: T::AccountId,
+			currency_id: T::CurrencyId,
+			supply_amount: Balance,
+			native_amount: Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Swap path doesn't lead from the currency into the native one or is too long
+		InvalidSwapPath,
+		/// Fees are charged in native currency anyway
+		NativeFeeCurrency,
+		/// Currency isn't approved for fee payment
+		FeeCurrencyNotFound,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Approve currency for fee payment.
+		///
+		/// Fees are swapped along `path`, which starts with `currency_id`
+		/// and ends with the native currency.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_fee_currency())]
+		pub fn set_fee_currency(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+			path: Vec<T::CurrencyId>,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			let native_currency_id = T::NativeCurrencyId::get();
+			ensure!(currency_id != native_currency_id, Error::<T>::NativeFeeCurrency);
+			ensure!(
+				path.len() >= 2
+					&& path.first() == Some(&currency_id)
+					&& path.last() == Some(&native_currency_id),
+				Error::<T>::InvalidSwapPath
+			);
+
+			let bounded_path: BoundedVec<_, T::MaxSwapPathLength> = path
+				.clone()
+				.try_into()
+				.map_err(|_| Error::<T>::InvalidSwapPath)?;
+
+			<FeeCurrencies<T>>::insert(currency_id, bounded_path);
+
+			Self::deposit_event(Event::FeeCurrencySet {
+				currency_id,
+				path
+			});
+
+			Ok(())
+		}
+
+		/// Stop accepting currency for fee payment.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_fee_currency())]
+		pub fn remove_fee_currency(
+			origin: OriginFor<T>,
+			currency_id: T::CurrencyId,
+		) -> DispatchResult {
+			T::UpdateOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				<FeeCurrencies<T>>::contains_key(currency_id),
+				Error::<T>::FeeCurrencyNotFound
+			);
+
+			<FeeCurrencies<T>>::remove(currency_id);
+
+			Self::deposit_event(Event::FeeCurrencyRemoved { currency_id });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Amount of native currency `who` lacks to pay `fee` and keep the account alive
+	///
+	/// The existential deposit is only included while the account doesn't hold it.
+	pub fn fee_shortfall(who: &T::AccountId, fee: Balance) -> Balance {
+		let native_currency_id = T::NativeCurrencyId::get();
+		let existential_deposit = T::MultiCurrency::minimum_balance(native_currency_id);
+		let free_balance = T::MultiCurrency::free_balance(native_currency_id, who);
+
+		if free_balance < existential_deposit {
+			fee.saturating_add(existential_deposit - free_balance)
+		} else {
+			fee.saturating_sub(free_balance - existential_deposit)
+		}
+	}
+
+	/// Whether the fee of `call` sent by `who` is paid by a sponsor.
+	///
+	/// The sponsorship handler accounts the fee it sponsors, so the check is rolled back,
+	/// the transaction payment extension asks the handler again.
+	pub fn is_sponsored(who: &T::AccountId, call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		with_transaction(|| {
+			let sponsor = <T as pallet_charge_transaction::Config>::SponsorshipHandler::get_sponsor(who, call);
+			TransactionOutcome::Rollback(Ok::<_, DispatchError>(sponsor.is_some()))
+		})
+		.unwrap_or(false)
+	}
+
+	/// Weight of swapping the fee along the longest path, charged on top of the transaction weight
+	pub fn swap_weight() -> Weight {
+		<T as Config>::WeightInfo::swap_fee(T::MaxSwapPathLength::get())
+	}
+
+	/// Dispatch info the fee of a transaction swapping for it is computed with
+	pub fn info_with_swap(info: &DispatchInfo) -> DispatchInfo {
+		DispatchInfo {
+			weight: info.weight.saturating_add(Self::swap_weight()),
+			..*info
+		}
+	}
+
+	/// Amount of native currency to swap for, so `fee` can be charged, according to the current quote.
+	///
+	/// Doesn't swap. Fails if the currency isn't approved, the swap needs more than `max_amount`
+	/// of it or `who` doesn't hold that much.
+	pub fn quote_fee_swap(
+		who: &T::AccountId,
+		payment: &FeePayment<T::CurrencyId>,
+		fee: Balance,
+	) -> Result<Balance, TransactionValidityError> {
+		let path = Self::fee_swap_path(payment.currency_id).ok_or(InvalidTransaction::Payment)?;
+
+		if fee.is_zero() {
+			return Ok(Zero::zero());
+		}
+
+		let shortfall = Self::fee_shortfall(who, fee);
+		if shortfall.is_zero() {
+			return Ok(Zero::zero());
+		}
+
+		let (supply_amount, _) = T::DEX::get_swap_amount(
+			&path,
+			SwapLimit::ExactTarget(payment.max_amount, shortfall)
+		)
+		.ok_or(InvalidTransaction::Payment)?;
+
+		ensure!(
+			T::MultiCurrency::free_balance(payment.currency_id, who) >= supply_amount,
+			InvalidTransaction::Payment
+		);
+
+		Ok(shortfall)
+	}
+
+	/// Swap fee currency of `who` into native currency, so `fee` can be charged.
+	///
+	/// Fails if the currency isn't approved or the swap needs more than `max_amount` of it.
+	pub fn swap_fee(
+		who: &T::AccountId,
+		payment: &FeePayment<T::CurrencyId>,
+		fee: Balance,
+	) -> Result<(), TransactionValidityError> {
+		let path = Self::fee_swap_path(payment.currency_id).ok_or(InvalidTransaction::Payment)?;
+
+		if fee.is_zero() {
+			return Ok(());
+		}
+
+		let shortfall = Self::fee_shortfall(who, fee);
+		if shortfall.is_zero() {
+			return Ok(());
+		}
+
+		let (supply_amount, native_amount) = with_storage_layer(|| {
+			T::DEX::swap_with_specific_path(
+				who,
+				&path,
+				SwapLimit::ExactTarget(payment.max_amount, shortfall)
+			)
+		})
+		.map_err(|_| InvalidTransaction::Payment)?;
+
+		Self::deposit_event(Event::FeeSwapped {
+			who: who.clone(),
+			currency_id: payment.currency_id,
+			supply_amount,
+			native_amount
+		});
+
+		Ok(())
+	}
+}
+
+/// Signed extension paying the fee in the currency chosen by the sender, if any.
+///
+/// Wraps the transaction payment extension charging the fee and tip in native currency.
+/// Validation checks the quote only, the swap is done right before the fee is charged
+/// on dispatch. Sponsored transactions are not swapped for.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeFeeInCurrency<T: Config>(Option<FeePayment<T::CurrencyId>>, #[codec(compact)] BalanceOf<T>);
+
+impl<T: Config> ChargeFeeInCurrency<T> {
+	/// Pay the fee according to `payment`, in native currency if `None`, along with `tip`
+	pub fn from(payment: Option<FeePayment<T::CurrencyId>>, tip: BalanceOf<T>) -> Self {
+		Self(payment, tip)
+	}
+
+	fn charge_transaction(&self) -> ChargeTransactionPayment<T> {
+		ChargeTransactionPayment::<T>::new(self.1)
+	}
+}
+
+impl<T: Config> fmt::Debug for ChargeFeeInCurrency<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "ChargeFeeInCurrency<{:?}, {:?}>", self.0, self.1)
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut fmt::Formatter) -> fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for ChargeFeeInCurrency<T>
+where
+	BalanceOf<T>: Send + Sync + From<u64> + FixedPointOperand,
+	<T as frame_system::Config>::RuntimeCall: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "ChargeFeeInCurrency";
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::RuntimeCall;
+	type AdditionalSigned = ();
+	/// Weight of the swap charged along with the transaction and the pre dispatch data of the payment
+	type Pre = (Weight, <ChargeTransactionPayment<T> as SignedExtension>::Pre);
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		let payment = match &self.0 {
+			Some(payment) if !Pallet::<T>::is_sponsored(who, call) => payment,
+			_ => return self.charge_transaction().validate(who, call, info, len),
+		};
+
+		let info = Pallet::<T>::info_with_swap(info);
+		let fee = <pallet_transaction_payment::Pallet<T>>::compute_fee(len as u32, &info, Zero::zero());
+		let shortfall = Pallet::<T>::quote_fee_swap(who, payment, fee.saturated_into())?;
+		if shortfall.is_zero() {
+			return self.charge_transaction().validate(who, call, &info, len);
+		}
+
+		// The payment extension can't validate the fee until the swap is done
+		ensure!(self.1.is_zero(), InvalidTransaction::Payment);
+
+		Ok(ValidTransaction {
+			priority: pallet_transaction_payment::ChargeTransactionPayment::<T>::get_priority(&info, len, self.1, fee),
+			..Default::default()
+		})
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let payment = match &self.0 {
+			Some(payment) if !Pallet::<T>::is_sponsored(who, call) => payment,
+			_ => return Ok((Weight::zero(), self.charge_transaction().pre_dispatch(who, call, info, len)?)),
+		};
+
+		let swap_weight = Pallet::<T>::swap_weight();
+		let info = Pallet::<T>::info_with_swap(info);
+		let fee = <pallet_transaction_payment::Pallet<T>>::compute_fee(len as u32, &info, Zero::zero());
+		Pallet::<T>::swap_fee(who, payment, fee.saturated_into())?;
+
+		// Block weight was checked without the swap
+		<frame_system::Pallet<T>>::register_extra_weight_unchecked(swap_weight, info.class);
+
+		Ok((swap_weight, self.charge_transaction().pre_dispatch(who, call, &info, len)?))
+	}
+
+	fn post_dispatch(
+		pre: Option<Self::Pre>,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		match pre {
+			Some((swap_weight, pre)) => {
+				// Refund is computed against the weight the fee was charged for
+				let info = DispatchInfo {
+					weight: info.weight.saturating_add(swap_weight),
+					..*info
+				};
+				let post_info = PostDispatchInfo {
+					actual_weight: post_info.actual_weight.map(|weight| weight.saturating_add(swap_weight)),
+					..*post_info
+				};
+				ChargeTransactionPayment::<T>::post_dispatch(Some(pre), &info, &post_info, len, result)
+			}
+			None => ChargeTransactionPayment::<T>::post_dispatch(None, info, post_info, len, result),
+		}
+	}
+}
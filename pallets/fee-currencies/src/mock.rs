@@ -0,0 +1,259 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use frame_support::{
+	construct_runtime,
+	parameter_types,
+	traits::{ConstU32, ConstU64, ConstU8, Everything, Nothing},
+	weights::IdentityFee,
+	PalletId,
+};
+use frame_system::EnsureRoot;
+
+use orml_traits::parameter_type_with_key;
+
+use sp_core::H256;
+use sp_runtime::{testing::Header, traits::IdentityLookup};
+
+use pallet_dex::types::TradingPair;
+use up_sponsorship::SponsorshipHandler;
+pub use primitives::{Balance, Amount};
+
+pub use crate::mock_currency::{CurrencyId, TokenSymbol};
+
+mod pallet_fee_currencies {
+	pub use super::super::*;
+}
+
+pub type BlockNumber = u64;
+pub type AccountId = u128;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const LIQUIDITY_PROVIDER: AccountId = 3;
+pub const SPONSORED: AccountId = 4;
+pub const SPONSOR: AccountId = 5;
+
+pub const CGT: CurrencyId = CurrencyId::Token(TokenSymbol::CGT);
+pub const DOT: CurrencyId = CurrencyId::Token(TokenSymbol::DOT);
+pub const USDT: CurrencyId = CurrencyId::Token(TokenSymbol::USDT);
+
+pub const INITIAL_BALANCE: Balance = 1_000_000_000_000_000_000;
+
+impl frame_system::Config for Runtime {
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = ::sp_runtime::traits::BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = frame_support::traits::ConstU128<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type HoldIdentifier = ();
+	type MaxHolds = ();
+}
+
+impl pallet_transaction_payment::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// Native currency is held in `Tokens`, as the fee is swapped for
+	type OnChargeTransaction = pallet_transaction_payment::CurrencyAdapter<NativeCurrency, ()>;
+	type WeightToFee = IdentityFee<Balance>;
+	type LengthToFee = IdentityFee<Balance>;
+	type FeeMultiplierUpdate = ();
+	type OperationalFeeMultiplier = ConstU8<5>;
+}
+
+/// Fees of [`SPONSORED`] are paid by [`SPONSOR`]
+pub struct MockSponsorshipHandler;
+impl SponsorshipHandler<AccountId, RuntimeCall> for MockSponsorshipHandler {
+	fn get_sponsor(who: &AccountId, _call: &RuntimeCall) -> Option<AccountId> {
+		(*who == SPONSORED).then_some(SPONSOR)
+	}
+}
+
+impl pallet_charge_transaction::Config for Runtime {
+	type SponsorshipHandler = MockSponsorshipHandler;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |currency_id: CurrencyId| -> Balance {
+		if *currency_id == CGT { return 1; }
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type DustRemovalWhitelist = Nothing;
+}
+
+parameter_types! {
+	pub const GetExchangeFee: (u32, u32) = (3, 1000);
+	pub const DEXPalletId: PalletId = PalletId(*b"cur/dexm");
+}
+
+impl pallet_dex::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CurrencyId = CurrencyId;
+	type Currency = Tokens;
+	type GetExchangeFee = GetExchangeFee;
+	type TradingPathLimit = ConstU32<3>;
+	type PalletId = DEXPalletId;
+	type WeightInfo = ();
+	type DEXIncentives = ();
+	type ListingOrigin = EnsureRoot<AccountId>;
+	type ExtendedProvisioningBlocks = ConstU64<2000>;
+	type OnLiquidityPoolUpdated = ();
+}
+
+parameter_types! {
+	pub const NativeCurrencyId: CurrencyId = CGT;
+}
+
+pub type NativeCurrency = orml_tokens::CurrencyAdapter<Runtime, NativeCurrencyId>;
+
+impl pallet_fee_currencies::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CurrencyId = CurrencyId;
+	type MultiCurrency = Tokens;
+	type NativeCurrencyId = NativeCurrencyId;
+	type DEX = Dex;
+	type UpdateOrigin = EnsureRoot<AccountId>;
+	type MaxSwapPathLength = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Runtime>;
+type Block = frame_system::mocking::MockBlock<Runtime>;
+
+construct_runtime!(
+	pub enum Runtime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Storage, Config, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Event<T>},
+		Tokens: orml_tokens::{Pallet, Storage, Event<T>, Config<T>},
+		Dex: pallet_dex::{Pallet, Storage, Call, Event<T>, Config<T>},
+		FeeCurrencies: pallet_fee_currencies::{Pallet, Storage, Call, Event<T>},
+	}
+);
+
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			balances: vec![
+				(LIQUIDITY_PROVIDER, CGT, INITIAL_BALANCE),
+				(LIQUIDITY_PROVIDER, DOT, INITIAL_BALANCE),
+				(LIQUIDITY_PROVIDER, USDT, INITIAL_BALANCE),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn balances(mut self, balances: Vec<(AccountId, CurrencyId, Balance)>) -> Self {
+		self.balances.extend(balances);
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<Runtime>()
+			.unwrap();
+
+		orml_tokens::GenesisConfig::<Runtime> {
+			balances: self.balances,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let cgt_usdt = TradingPair::from_currency_ids(CGT, USDT).unwrap();
+		let dot_usdt = TradingPair::from_currency_ids(DOT, USDT).unwrap();
+
+		pallet_dex::GenesisConfig::<Runtime> {
+			initial_listing_trading_pairs: vec![],
+			initial_enabled_trading_pairs: vec![cgt_usdt, dot_usdt],
+			initial_added_liquidity_pools: vec![(
+				LIQUIDITY_PROVIDER,
+				vec![
+					(cgt_usdt, (1_000_000_000_000_000, 2_000_000_000_000_000)),
+					(dot_usdt, (1_000_000_000_000_000, 1_000_000_000_000_000)),
+				],
+			)],
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
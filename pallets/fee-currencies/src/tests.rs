@@ -0,0 +1,297 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchInfo, Pays, PostDispatchInfo},
+	weights::Weight,
+};
+use orml_traits::MultiCurrency;
+use sp_runtime::{
+	traits::{BadOrigin, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+use crate::{mock::*, ChargeFeeInCurrency, Error, Event, FeePayment};
+
+const LEN: usize = 100;
+
+fn call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn info() -> DispatchInfo {
+	DispatchInfo {
+		weight: Weight::from_parts(1_000, 0),
+		..Default::default()
+	}
+}
+
+/// Fee of a transaction paying in native currency
+fn native_fee() -> Balance {
+	TransactionPayment::compute_fee(LEN as u32, &info(), 0)
+}
+
+/// Fee of a transaction paying in another currency, the swap is charged for
+fn fee() -> Balance {
+	TransactionPayment::compute_fee(LEN as u32, &FeeCurrencies::info_with_swap(&info()), 0)
+}
+
+fn pay_in(currency_id: CurrencyId, max_amount: Balance) -> ChargeFeeInCurrency<Runtime> {
+	ChargeFeeInCurrency::from(Some(FeePayment { currency_id, max_amount }), 0)
+}
+
+#[test]
+fn set_fee_currency_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			FeeCurrencies::set_fee_currency(RuntimeOrigin::signed(ALICE), USDT, vec![USDT, CGT]),
+			BadOrigin
+		);
+		assert_noop!(
+			FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), CGT, vec![CGT]),
+			Error::<Runtime>::NativeFeeCurrency
+		);
+		assert_noop!(
+			FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![DOT, CGT]),
+			Error::<Runtime>::InvalidSwapPath
+		);
+		assert_noop!(
+			FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, DOT]),
+			Error::<Runtime>::InvalidSwapPath
+		);
+		assert_noop!(
+			FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, DOT, USDT, CGT]),
+			Error::<Runtime>::InvalidSwapPath
+		);
+
+		assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]));
+		assert_eq!(FeeCurrencies::fee_swap_path(USDT).unwrap().into_inner(), vec![USDT, CGT]);
+		System::assert_last_event(RuntimeEvent::FeeCurrencies(Event::FeeCurrencySet {
+			currency_id: USDT,
+			path: vec![USDT, CGT]
+		}));
+	});
+}
+
+#[test]
+fn remove_fee_currency_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			FeeCurrencies::remove_fee_currency(RuntimeOrigin::root(), USDT),
+			Error::<Runtime>::FeeCurrencyNotFound
+		);
+
+		assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]));
+		assert_noop!(
+			FeeCurrencies::remove_fee_currency(RuntimeOrigin::signed(ALICE), USDT),
+			BadOrigin
+		);
+		assert_ok!(FeeCurrencies::remove_fee_currency(RuntimeOrigin::root(), USDT));
+		assert_eq!(FeeCurrencies::fee_swap_path(USDT), None);
+		System::assert_last_event(RuntimeEvent::FeeCurrencies(Event::FeeCurrencyRemoved {
+			currency_id: USDT
+		}));
+	});
+}
+
+#[test]
+fn fee_is_swapped_into_native_currency() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, USDT, 1_000_000_000_000), (BOB, DOT, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]));
+			assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), DOT, vec![DOT, USDT, CGT]));
+
+			assert_ok!(pay_in(USDT, 1_000_000_000_000).pre_dispatch(&ALICE, &call(), &info(), LEN));
+			// Fee and existential deposit swapped for, the fee is charged
+			assert_eq!(Tokens::free_balance(CGT, &ALICE), 1);
+
+			let supply_amount = 1_000_000_000_000 - Tokens::free_balance(USDT, &ALICE);
+			System::assert_has_event(RuntimeEvent::FeeCurrencies(Event::FeeSwapped {
+				who: ALICE,
+				currency_id: USDT,
+				supply_amount,
+				native_amount: fee() + 1
+			}));
+
+			assert_ok!(pay_in(DOT, 1_000_000_000_000).pre_dispatch(&BOB, &call(), &info(), LEN));
+			assert_eq!(Tokens::free_balance(CGT, &BOB), 1);
+		});
+}
+
+#[test]
+fn fee_is_not_swapped_if_native_balance_is_enough() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, USDT, 1_000_000_000_000), (ALICE, CGT, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]));
+
+			assert_ok!(pay_in(USDT, 1_000_000_000_000).pre_dispatch(&ALICE, &call(), &info(), LEN));
+			assert_eq!(Tokens::free_balance(USDT, &ALICE), 1_000_000_000_000);
+			assert_eq!(Tokens::free_balance(CGT, &ALICE), 1_000_000_000_000 - fee());
+
+			// Fee is charged in native currency if not chosen otherwise, the swap isn't charged for
+			assert_ok!(ChargeFeeInCurrency::<Runtime>::from(None, 0).pre_dispatch(&ALICE, &call(), &info(), LEN));
+			assert_eq!(Tokens::free_balance(CGT, &ALICE), 1_000_000_000_000 - fee() - native_fee());
+		});
+}
+
+#[test]
+fn fee_payment_fails_if_currency_not_approved_or_slippage_exceeded() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, USDT, 1_000_000_000_000), (ALICE, DOT, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]));
+
+			assert_eq!(
+				pay_in(DOT, 1_000_000_000_000).validate(&ALICE, &call(), &info(), LEN),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+
+			// Fee costs about twice as much USDT as CGT
+			assert_eq!(
+				pay_in(USDT, fee()).validate(&ALICE, &call(), &info(), LEN),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+			assert_eq!(
+				pay_in(USDT, fee()).pre_dispatch(&ALICE, &call(), &info(), LEN),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+			assert_eq!(Tokens::free_balance(USDT, &ALICE), 1_000_000_000_000);
+			assert_eq!(Tokens::free_balance(CGT, &ALICE), 0);
+
+			// Tip can't be paid in native currency
+			let tipped = ChargeFeeInCurrency::<Runtime>::from(
+				Some(FeePayment { currency_id: USDT, max_amount: 1_000_000_000_000 }),
+				1
+			);
+			assert_eq!(
+				tipped.validate(&ALICE, &call(), &info(), LEN),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+
+			// Sender lacks the fee currency
+			assert_eq!(
+				pay_in(USDT, 1_000_000_000_000).validate(&BOB, &call(), &info(), LEN),
+				Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+			);
+		});
+}
+
+#[test]
+fn existential_deposit_is_swapped_for_once() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, USDT, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]));
+
+			assert_ok!(pay_in(USDT, 1_000_000_000_000).pre_dispatch(&ALICE, &call(), &info(), LEN));
+			// Fee charged by the transaction payment extension, the account is kept alive
+			assert_eq!(Tokens::free_balance(CGT, &ALICE), 1);
+			let usdt_balance = Tokens::free_balance(USDT, &ALICE);
+
+			assert_ok!(pay_in(USDT, 1_000_000_000_000).pre_dispatch(&ALICE, &call(), &info(), LEN));
+			assert_eq!(Tokens::free_balance(CGT, &ALICE), 1);
+			System::assert_has_event(RuntimeEvent::FeeCurrencies(Event::FeeSwapped {
+				who: ALICE,
+				currency_id: USDT,
+				supply_amount: usdt_balance - Tokens::free_balance(USDT, &ALICE),
+				native_amount: fee()
+			}));
+		});
+}
+
+#[test]
+fn sponsored_fee_is_not_swapped() {
+	ExtBuilder::default()
+		.balances(vec![(SPONSORED, USDT, 1_000_000_000_000), (SPONSOR, CGT, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]));
+
+			assert_ok!(pay_in(USDT, 1_000_000_000_000).validate(&SPONSORED, &call(), &info(), LEN));
+			assert_ok!(pay_in(USDT, 1_000_000_000_000).pre_dispatch(&SPONSORED, &call(), &info(), LEN));
+			assert_eq!(Tokens::free_balance(USDT, &SPONSORED), 1_000_000_000_000);
+			assert_eq!(Tokens::free_balance(CGT, &SPONSORED), 0);
+			// Sponsor pays the fee without the swap
+			assert_eq!(Tokens::free_balance(CGT, &SPONSOR), 1_000_000_000_000 - native_fee());
+		});
+}
+
+#[test]
+fn validation_does_not_swap() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, USDT, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]));
+			System::reset_events();
+
+			assert_ok!(pay_in(USDT, 1_000_000_000_000).validate(&ALICE, &call(), &info(), LEN));
+			assert_eq!(Tokens::free_balance(USDT, &ALICE), 1_000_000_000_000);
+			assert_eq!(Tokens::free_balance(CGT, &ALICE), 0);
+			assert!(System::events().is_empty());
+		});
+}
+
+#[test]
+fn swap_is_charged_for() {
+	ExtBuilder::default()
+		.balances(vec![(ALICE, USDT, 1_000_000_000_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]));
+
+			let pre = pay_in(USDT, 1_000_000_000_000).pre_dispatch(&ALICE, &call(), &info(), LEN).unwrap();
+			assert_eq!(pre.0, FeeCurrencies::swap_weight());
+			assert_eq!(fee(), native_fee() + FeeCurrencies::swap_weight().ref_time() as Balance);
+			assert_eq!(System::block_weight().total(), FeeCurrencies::swap_weight());
+
+			// Refund of unused weight keeps the swap charged
+			let post_info = PostDispatchInfo {
+				actual_weight: Some(Weight::zero()),
+				pays_fee: Pays::Yes,
+			};
+			assert_ok!(ChargeFeeInCurrency::<Runtime>::post_dispatch(
+				Some(pre),
+				&info(),
+				&post_info,
+				LEN,
+				&Ok(())
+			));
+			assert_eq!(
+				Tokens::free_balance(CGT, &ALICE),
+				1 + fee() - TransactionPayment::compute_fee(LEN as u32, &DispatchInfo {
+					weight: FeeCurrencies::swap_weight(),
+					..info()
+				}, 0)
+			);
+		});
+}
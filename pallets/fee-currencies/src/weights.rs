@@ -0,0 +1,65 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Weights for pallet_fee_currencies
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::weights::constants::RocksDbWeight;
+
+/// Weight functions needed for pallet_fee_currencies.
+pub trait WeightInfo {
+	fn set_fee_currency() -> Weight;
+	fn remove_fee_currency() -> Weight;
+	fn swap_fee(u: u32, ) -> Weight;
+}
+
+impl WeightInfo for () {
+	// Storage: FeeCurrencies FeeCurrencies (r:0 w:1)
+	fn set_fee_currency() -> Weight {
+		Weight::from_parts(14_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: FeeCurrencies FeeCurrencies (r:1 w:1)
+	fn remove_fee_currency() -> Weight {
+		Weight::from_parts(16_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: FeeCurrencies FeeCurrencies (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Dex LiquidityPool (r:2 w:2)
+	// Storage: System Account (r:2 w:2)
+	fn swap_fee(u: u32, ) -> Weight {
+		Weight::from_parts(55_000_000 as u64, 0)
+			// Standard Error: 270_000
+			.saturating_add(Weight::from_parts(9_500_000 as u64, 0).saturating_mul(u as u64))
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(u as u64)))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(u as u64)))
+	}
+}
@@ -0,0 +1,491 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! # Holder governance pallet
+//!
+//! ## Overview
+//!
+//! Proposals voted by holders of a RFT token, weighted by pieces they hold.
+//!
+//! - Collection owner or admin sets quorum, threshold and voting period for the token.
+//! - Any holder proposes a call, reserving `ProposalDeposit` until the proposal is closed.
+//!   Balances of all holders are snapshotted at this moment and used as voting power,
+//!   so pieces moved during voting don't count twice.
+//!   Tokens with more than `MaxSnapshotHolders` holders can't be governed.
+//!   A token can have at most `MaxActiveProposals` open proposals.
+//! - After the voting period anyone closes the proposal. If turnout reaches `quorum`
+//!   of all pieces and aye votes reach `threshold` of the turnout, the call is dispatched
+//!   with the signed origin of the token account.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::MaxEncodedLen;
+use scale_info::TypeInfo;
+
+use frame_support::{
+	pallet_prelude::*,
+	dispatch::{GetDispatchInfo, PostDispatchInfo},
+	traits::ReservableCurrency,
+	PalletId
+};
+use frame_system::pallet_prelude::*;
+
+use sp_runtime::{
+	traits::{AccountIdConversion, Dispatchable, Saturating, Zero},
+	ArithmeticError, Perbill
+};
+use sp_std::prelude::*;
+
+use collection_primitives::{CollectionId, TokenId};
+use pallet_common::Error as CommonError;
+use pallet_refungible::{
+	RefungibleHandle, TokenBalance,
+	Balance as PiecesBalance, TokenHolders, TotalSupply
+};
+use primitives::Balance;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type ProposalId = u32;
+
+type ProposalOf<T> = Proposal<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config>::RuntimeCall
+>;
+
+/// Voting settings of RFT token
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct GovernanceParams<BlockNumber> {
+	/// Minimum share of all pieces that must vote
+	pub quorum: Perbill,
+	/// Minimum share of aye votes among cast ones
+	pub threshold: Perbill,
+	/// Number of blocks proposals are open for voting
+	pub voting_period: BlockNumber,
+}
+
+/// Proposal of holders of RFT token
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, Debug)]
+pub struct Proposal<AccountId, BlockNumber, Call> {
+	pub collection_id: CollectionId,
+	pub token_id: TokenId,
+	pub proposer: AccountId,
+	/// Call dispatched by the token account if approved
+	pub call: Call,
+	/// Voting is closed starting from this block
+	pub end: BlockNumber,
+	/// Total supply of the token at snapshot
+	pub total_power: TokenBalance,
+	pub ayes: TokenBalance,
+	pub nays: TokenBalance,
+	pub quorum: Perbill,
+	pub threshold: Perbill,
+	/// Reserved from the proposer until the proposal is closed
+	pub deposit: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_refungible::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Call approved proposals dispatch
+		type RuntimeCall: Parameter
+			+ Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+			+ GetDispatchInfo
+			+ From<frame_system::Call<Self>>;
+
+		/// Token accounts are derived from this id
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of holders snapshotted for a proposal
+		#[pallet::constant]
+		type MaxSnapshotHolders: Get<u32>;
+
+		/// Currency proposal deposits are reserved in
+		type Currency: ReservableCurrency<Self::AccountId, Balance = Balance>;
+
+		/// Deposit reserved from the proposer until the proposal is closed
+		#[pallet::constant]
+		type ProposalDeposit: Get<Balance>;
+
+		/// Maximum number of open proposals of one token
+		#[pallet::constant]
+		type MaxActiveProposals: Get<u32>;
+
+		type WeightInfo: weights::WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T>(_);
+
+	/// Voting settings of RFT tokens
+	#[pallet::storage]
+	#[pallet::getter(fn governance_params)]
+	pub type Params<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectionId,
+		Twox64Concat,
+		TokenId,
+		GovernanceParams<T::BlockNumber>
+	>;
+
+	/// Id of the next proposal
+	#[pallet::storage]
+	#[pallet::getter(fn next_proposal_id)]
+	pub type NextProposalId<T: Config> = StorageValue<_, ProposalId, ValueQuery>;
+
+	/// [ProposalId] mapped to [Proposal]
+	#[pallet::storage]
+	#[pallet::getter(fn proposal)]
+	pub type Proposals<T: Config> = StorageMap<_, Twox64Concat, ProposalId, ProposalOf<T>>;
+
+	/// Number of open proposals of RFT tokens
+	#[pallet::storage]
+	#[pallet::getter(fn active_proposals)]
+	pub type ActiveProposals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectionId,
+		Twox64Concat,
+		TokenId,
+		u32,
+		ValueQuery
+	>;
+
+	/// Pieces held by account when the proposal was created
+	#[pallet::storage]
+	#[pallet::getter(fn voting_power)]
+	pub type Snapshots<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		TokenBalance,
+		ValueQuery
+	>;
+
+	/// Votes cast, `true` for aye
+	#[pallet::storage]
+	#[pallet::getter(fn vote_of)]
+	pub type Votes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProposalId,
+		Blake2_128Concat,
+		T::AccountId,
+		bool
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Voting settings of token changed
+		GovernanceParamsSet {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			params: GovernanceParams<T::BlockNumber>,
+		},
+		/// New proposal created
+		Proposed {
+			proposal_id: ProposalId,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			proposer: T::AccountId,
+			end: T::BlockNumber,
+		},
+		/// Holder voted
+		Voted {
+			proposal_id: ProposalId,
+			who: T::AccountId,
+			aye: bool,
+			power: TokenBalance,
+		},
+		/// Proposal approved and its call dispatched
+		Approved {
+			proposal_id: ProposalId,
+			result: DispatchResult,
+		},
+		/// Proposal rejected
+		Rejected {
+			proposal_id: ProposalId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Voting period can't be zero
+		InvalidParams,
+		/// Voting settings aren't set for the token
+		GovernanceNotConfigured,
+		/// Only holders can propose
+		NotHolder,
+		/// Token has more holders than can be snapshotted
+		TooManyHolders,
+		/// Token has the maximum amount of open proposals
+		TooManyProposals,
+		/// Proposal with given id doesn't exist
+		ProposalNotFound,
+		/// Account held no pieces at snapshot
+		NoVotingPower,
+		/// Account already voted
+		AlreadyVoted,
+		/// Voting period is over
+		VotingClosed,
+		/// Voting period isn't over yet
+		VotingNotClosed,
+		/// Given weight bound is below weight of the proposal call
+		WrongCallWeight,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set voting settings of RFT token.
+		///
+		/// Sender must be owner or admin of the collection. Doesn't affect existing proposals.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_governance_params())]
+		pub fn set_governance_params(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			params: GovernanceParams<T::BlockNumber>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let collection = RefungibleHandle::<T>::try_get(collection_id)?;
+			collection.check_is_owner_or_admin(&who)?;
+
+			ensure!(
+				<TotalSupply<T>>::contains_key((collection_id, token_id)),
+				<CommonError<T>>::TokenNotFound
+			);
+			ensure!(!params.voting_period.is_zero(), Error::<T>::InvalidParams);
+
+			<Params<T>>::insert(collection_id, token_id, params.clone());
+
+			Self::deposit_event(Event::GovernanceParamsSet {
+				collection_id,
+				token_id,
+				params
+			});
+
+			Ok(())
+		}
+
+		/// Propose a call to be dispatched by the token account.
+		///
+		/// Sender must hold pieces of the token. Balances of all holders are snapshotted.
+		/// `ProposalDeposit` is reserved from the sender until the proposal is closed.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose(T::MaxSnapshotHolders::get()))]
+		pub fn propose(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			call: Box<<T as Config>::RuntimeCall>,
+		) -> DispatchResultWithPostInfo {
+			let proposer = ensure_signed(origin)?;
+
+			let params = Self::governance_params(collection_id, token_id)
+				.ok_or(Error::<T>::GovernanceNotConfigured)?;
+			ensure!(
+				!<PiecesBalance<T>>::get((collection_id, token_id, &proposer)).is_zero(),
+				Error::<T>::NotHolder
+			);
+
+			let holders = <TokenHolders<T>>::get((collection_id, token_id));
+			ensure!(holders <= T::MaxSnapshotHolders::get(), Error::<T>::TooManyHolders);
+
+			let active_proposals = Self::active_proposals(collection_id, token_id);
+			ensure!(active_proposals < T::MaxActiveProposals::get(), Error::<T>::TooManyProposals);
+
+			let proposal_id = Self::next_proposal_id();
+			let next_proposal_id = proposal_id
+				.checked_add(1)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			let deposit = T::ProposalDeposit::get();
+			T::Currency::reserve(&proposer, deposit)?;
+
+			for (holder, balance) in <PiecesBalance<T>>::iter_prefix((collection_id, token_id)) {
+				if !balance.is_zero() {
+					<Snapshots<T>>::insert(proposal_id, holder, balance);
+				}
+			}
+
+			let end = <frame_system::Pallet<T>>::block_number().saturating_add(params.voting_period);
+			<Proposals<T>>::insert(proposal_id, Proposal {
+				collection_id,
+				token_id,
+				proposer: proposer.clone(),
+				call: *call,
+				end,
+				total_power: <TotalSupply<T>>::get((collection_id, token_id)),
+				ayes: 0,
+				nays: 0,
+				quorum: params.quorum,
+				threshold: params.threshold,
+				deposit,
+			});
+			<ActiveProposals<T>>::insert(collection_id, token_id, active_proposals + 1);
+			<NextProposalId<T>>::put(next_proposal_id);
+
+			Self::deposit_event(Event::Proposed {
+				proposal_id,
+				collection_id,
+				token_id,
+				proposer,
+				end
+			});
+
+			Ok(Some(<T as Config>::WeightInfo::propose(holders)).into())
+		}
+
+		/// Vote with pieces held at snapshot.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::vote())]
+		pub fn vote(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			aye: bool,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut proposal = Self::proposal(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() < proposal.end,
+				Error::<T>::VotingClosed
+			);
+
+			let power = Self::voting_power(proposal_id, &who);
+			ensure!(!power.is_zero(), Error::<T>::NoVotingPower);
+			ensure!(!<Votes<T>>::contains_key(proposal_id, &who), Error::<T>::AlreadyVoted);
+
+			if aye {
+				proposal.ayes = proposal.ayes.saturating_add(power);
+			} else {
+				proposal.nays = proposal.nays.saturating_add(power);
+			}
+
+			<Proposals<T>>::insert(proposal_id, proposal);
+			<Votes<T>>::insert(proposal_id, &who, aye);
+
+			Self::deposit_event(Event::Voted {
+				proposal_id,
+				who,
+				aye,
+				power
+			});
+
+			Ok(())
+		}
+
+		/// Close proposal after its voting period, dispatching the call if approved.
+		///
+		/// `call_weight_bound` must be at least the weight of the proposal call.
+		/// The proposal deposit is returned to the proposer.
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::close(T::MaxSnapshotHolders::get())
+				.saturating_add(*call_weight_bound)
+		)]
+		pub fn close(
+			origin: OriginFor<T>,
+			proposal_id: ProposalId,
+			call_weight_bound: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let proposal = Self::proposal(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= proposal.end,
+				Error::<T>::VotingNotClosed
+			);
+
+			let call_weight = proposal.call.get_dispatch_info().weight;
+			ensure!(call_weight.all_lte(call_weight_bound), Error::<T>::WrongCallWeight);
+
+			<Proposals<T>>::remove(proposal_id);
+			<ActiveProposals<T>>::mutate(proposal.collection_id, proposal.token_id, |active| {
+				*active = active.saturating_sub(1)
+			});
+			T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+			let cleared = <Snapshots<T>>::clear_prefix(proposal_id, T::MaxSnapshotHolders::get(), None).unique;
+			let _ = <Votes<T>>::clear_prefix(proposal_id, T::MaxSnapshotHolders::get(), None);
+
+			let close_weight = <T as Config>::WeightInfo::close(cleared);
+
+			if !Self::is_approved(&proposal) {
+				Self::deposit_event(Event::Rejected { proposal_id });
+
+				return Ok(Some(close_weight).into());
+			}
+
+			let origin = frame_system::RawOrigin::Signed(
+				Self::token_account(proposal.collection_id, proposal.token_id)
+			);
+			let result = proposal.call.dispatch(origin.into());
+			let dispatch_weight = frame_support::dispatch::extract_actual_weight(&result, &proposal.call.get_dispatch_info());
+
+			Self::deposit_event(Event::Approved {
+				proposal_id,
+				result: result.map(|_| ()).map_err(|e| e.error)
+			});
+
+			Ok(Some(close_weight.saturating_add(dispatch_weight)).into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account dispatching approved calls of the token holders
+	pub fn token_account(collection_id: CollectionId, token_id: TokenId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((collection_id, token_id))
+	}
+
+	fn is_approved(proposal: &ProposalOf<T>) -> bool {
+		let turnout = proposal.ayes.saturating_add(proposal.nays);
+
+		!turnout.is_zero()
+			&& turnout >= proposal.quorum.mul_ceil(proposal.total_power)
+			&& proposal.ayes >= proposal.threshold.mul_ceil(turnout)
+	}
+}
@@ -0,0 +1,372 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok, dispatch::GetDispatchInfo, weights::Weight};
+use sp_runtime::{traits::Hash as _, Perbill};
+
+use pallet_refungible::TokenBalance;
+use primitives::BlockNumber;
+
+use crate::mock::*;
+use crate::{Error, Event, GovernanceParams, Proposals, Snapshots, Votes};
+
+const VOTING_PERIOD: BlockNumber = 10;
+
+fn params(quorum: u32, threshold: u32) -> GovernanceParams<BlockNumber> {
+	GovernanceParams {
+		quorum: Perbill::from_percent(quorum),
+		threshold: Perbill::from_percent(threshold),
+		voting_period: VOTING_PERIOD,
+	}
+}
+
+fn remark_call() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::remark_with_event { remark: b"hello".to_vec() }))
+}
+
+fn call_weight() -> Weight {
+	remark_call().get_dispatch_info().weight
+}
+
+/// Create token governed with 50% quorum and 60% threshold, ALICE proposes remark
+fn setup_proposal(balances: Vec<(AccountId, TokenBalance)>) -> (CollectionId, TokenId) {
+	let (collection_id, token_id) = create_token(balances).unwrap();
+	assert_ok!(HolderGovernance::set_governance_params(
+		RuntimeOrigin::signed(ISSUER),
+		collection_id,
+		token_id,
+		params(50, 60)
+	));
+	assert_ok!(HolderGovernance::propose(RuntimeOrigin::signed(ALICE), collection_id, token_id, remark_call()));
+
+	(collection_id, token_id)
+}
+
+fn end_voting() {
+	System::set_block_number(1 + VOTING_PERIOD);
+}
+
+#[test]
+fn set_governance_params_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10)]).unwrap();
+
+		assert_ok!(HolderGovernance::set_governance_params(
+			RuntimeOrigin::signed(ISSUER),
+			collection_id,
+			token_id,
+			params(50, 60)
+		));
+		System::assert_last_event(RuntimeEvent::HolderGovernance(Event::GovernanceParamsSet {
+			collection_id,
+			token_id,
+			params: params(50, 60)
+		}));
+		assert_eq!(HolderGovernance::governance_params(collection_id, token_id), Some(params(50, 60)));
+	});
+}
+
+#[test]
+fn set_governance_params_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10)]).unwrap();
+
+		assert_noop!(
+			HolderGovernance::set_governance_params(RuntimeOrigin::signed(ALICE), collection_id, token_id, params(50, 60)),
+			pallet_common::Error::<MockRuntime>::NoPermission
+		);
+		assert_noop!(
+			HolderGovernance::set_governance_params(RuntimeOrigin::signed(ISSUER), collection_id, TokenId(2), params(50, 60)),
+			pallet_common::Error::<MockRuntime>::TokenNotFound
+		);
+
+		let mut zero_period = params(50, 60);
+		zero_period.voting_period = 0;
+		assert_noop!(
+			HolderGovernance::set_governance_params(RuntimeOrigin::signed(ISSUER), collection_id, token_id, zero_period),
+			Error::<MockRuntime>::InvalidParams
+		);
+	});
+}
+
+#[test]
+fn propose_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = setup_proposal(vec![(ALICE, 10), (BOB, 20), (CHARLIE, 30)]);
+
+		System::assert_last_event(RuntimeEvent::HolderGovernance(Event::Proposed {
+			proposal_id: 0,
+			collection_id,
+			token_id,
+			proposer: ALICE,
+			end: 1 + VOTING_PERIOD
+		}));
+
+		let proposal = HolderGovernance::proposal(0).unwrap();
+		assert_eq!(proposal.total_power, 60);
+		assert_eq!(proposal.quorum, Perbill::from_percent(50));
+		assert_eq!(proposal.threshold, Perbill::from_percent(60));
+
+		assert_eq!(HolderGovernance::voting_power(0, ALICE), 10);
+		assert_eq!(HolderGovernance::voting_power(0, BOB), 20);
+		assert_eq!(HolderGovernance::voting_power(0, CHARLIE), 30);
+		assert_eq!(HolderGovernance::next_proposal_id(), 1);
+
+		assert_eq!(proposal.deposit, ProposalDeposit::get());
+		assert_eq!(Balances::reserved_balance(ALICE), ProposalDeposit::get());
+		assert_eq!(HolderGovernance::active_proposals(collection_id, token_id), 1);
+	});
+}
+
+#[test]
+fn propose_skips_empty_balances_in_snapshot() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10), (BOB, 20)]).unwrap();
+		pallet_refungible::Balance::<MockRuntime>::insert((collection_id, token_id, CHARLIE), 0);
+		assert_ok!(HolderGovernance::set_governance_params(
+			RuntimeOrigin::signed(ISSUER),
+			collection_id,
+			token_id,
+			params(50, 60)
+		));
+
+		assert_ok!(HolderGovernance::propose(RuntimeOrigin::signed(ALICE), collection_id, token_id, remark_call()));
+
+		assert!(Snapshots::<MockRuntime>::contains_key(0, BOB));
+		assert!(!Snapshots::<MockRuntime>::contains_key(0, CHARLIE));
+	});
+}
+
+#[test]
+fn active_proposals_are_bounded() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = setup_proposal(vec![(ALICE, 10), (BOB, 20)]);
+		assert_ok!(HolderGovernance::propose(RuntimeOrigin::signed(BOB), collection_id, token_id, remark_call()));
+
+		assert_noop!(
+			HolderGovernance::propose(RuntimeOrigin::signed(BOB), collection_id, token_id, remark_call()),
+			Error::<MockRuntime>::TooManyProposals
+		);
+
+		end_voting();
+		assert_ok!(HolderGovernance::close(RuntimeOrigin::signed(EVE), 0, call_weight()));
+
+		assert_eq!(Balances::reserved_balance(ALICE), 0);
+		assert_eq!(HolderGovernance::active_proposals(collection_id, token_id), 1);
+		assert_ok!(HolderGovernance::propose(RuntimeOrigin::signed(BOB), collection_id, token_id, remark_call()));
+	});
+}
+
+#[test]
+fn propose_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10), (BOB, 20), (CHARLIE, 30), (DAVE, 40)]).unwrap();
+
+		assert_noop!(
+			HolderGovernance::propose(RuntimeOrigin::signed(ALICE), collection_id, token_id, remark_call()),
+			Error::<MockRuntime>::GovernanceNotConfigured
+		);
+
+		assert_ok!(HolderGovernance::set_governance_params(
+			RuntimeOrigin::signed(ISSUER),
+			collection_id,
+			token_id,
+			params(50, 60)
+		));
+
+		assert_noop!(
+			HolderGovernance::propose(RuntimeOrigin::signed(EVE), collection_id, token_id, remark_call()),
+			Error::<MockRuntime>::NotHolder
+		);
+		// More holders than MaxSnapshotHolders
+		assert_noop!(
+			HolderGovernance::propose(RuntimeOrigin::signed(ALICE), collection_id, token_id, remark_call()),
+			Error::<MockRuntime>::TooManyHolders
+		);
+	});
+}
+
+#[test]
+fn vote_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_proposal(vec![(ALICE, 10), (BOB, 20), (CHARLIE, 30)]);
+
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(BOB), 0, true));
+		System::assert_last_event(RuntimeEvent::HolderGovernance(Event::Voted {
+			proposal_id: 0,
+			who: BOB,
+			aye: true,
+			power: 20
+		}));
+
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(CHARLIE), 0, false));
+
+		let proposal = HolderGovernance::proposal(0).unwrap();
+		assert_eq!(proposal.ayes, 20);
+		assert_eq!(proposal.nays, 30);
+		assert_eq!(HolderGovernance::vote_of(0, BOB), Some(true));
+		assert_eq!(HolderGovernance::vote_of(0, CHARLIE), Some(false));
+	});
+}
+
+#[test]
+fn vote_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_proposal(vec![(ALICE, 10), (BOB, 20)]);
+
+		assert_noop!(
+			HolderGovernance::vote(RuntimeOrigin::signed(ALICE), 1, true),
+			Error::<MockRuntime>::ProposalNotFound
+		);
+		assert_noop!(
+			HolderGovernance::vote(RuntimeOrigin::signed(CHARLIE), 0, true),
+			Error::<MockRuntime>::NoVotingPower
+		);
+
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(ALICE), 0, true));
+		assert_noop!(
+			HolderGovernance::vote(RuntimeOrigin::signed(ALICE), 0, false),
+			Error::<MockRuntime>::AlreadyVoted
+		);
+
+		end_voting();
+		assert_noop!(
+			HolderGovernance::vote(RuntimeOrigin::signed(BOB), 0, true),
+			Error::<MockRuntime>::VotingClosed
+		);
+	});
+}
+
+#[test]
+fn voting_power_is_snapshotted() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = setup_proposal(vec![(ALICE, 10), (BOB, 20)]);
+
+		// Pieces moved after proposal don't add voting power
+		assert_ok!(Refungible::transfer(RuntimeOrigin::signed(BOB), collection_id, token_id, CHARLIE, 20));
+		assert_noop!(
+			HolderGovernance::vote(RuntimeOrigin::signed(CHARLIE), 0, true),
+			Error::<MockRuntime>::NoVotingPower
+		);
+
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(BOB), 0, true));
+		assert_eq!(HolderGovernance::proposal(0).unwrap().ayes, 20);
+	});
+}
+
+#[test]
+fn close_approved_dispatches_call() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = setup_proposal(vec![(ALICE, 10), (BOB, 20), (CHARLIE, 30)]);
+
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(BOB), 0, true));
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(CHARLIE), 0, true));
+
+		assert_noop!(
+			HolderGovernance::close(RuntimeOrigin::signed(EVE), 0, call_weight()),
+			Error::<MockRuntime>::VotingNotClosed
+		);
+
+		end_voting();
+
+		assert_noop!(
+			HolderGovernance::close(RuntimeOrigin::signed(EVE), 0, Weight::zero()),
+			Error::<MockRuntime>::WrongCallWeight
+		);
+
+		assert_ok!(HolderGovernance::close(RuntimeOrigin::signed(EVE), 0, call_weight()));
+
+		System::assert_has_event(RuntimeEvent::System(frame_system::Event::Remarked {
+			sender: HolderGovernance::token_account(collection_id, token_id),
+			hash: <MockRuntime as frame_system::Config>::Hashing::hash(b"hello")
+		}));
+		System::assert_last_event(RuntimeEvent::HolderGovernance(Event::Approved {
+			proposal_id: 0,
+			result: Ok(())
+		}));
+
+		assert!(!Proposals::<MockRuntime>::contains_key(0));
+		assert!(!Snapshots::<MockRuntime>::contains_key(0, BOB));
+		assert!(!Votes::<MockRuntime>::contains_key(0, BOB));
+
+		assert_noop!(
+			HolderGovernance::close(RuntimeOrigin::signed(EVE), 0, call_weight()),
+			Error::<MockRuntime>::ProposalNotFound
+		);
+	});
+}
+
+#[test]
+fn close_rejected_without_quorum() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_proposal(vec![(ALICE, 10), (BOB, 20), (CHARLIE, 30)]);
+
+		// 20 of 60 pieces voted, quorum is 30
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(BOB), 0, true));
+
+		end_voting();
+		assert_ok!(HolderGovernance::close(RuntimeOrigin::signed(EVE), 0, call_weight()));
+
+		System::assert_last_event(RuntimeEvent::HolderGovernance(Event::Rejected { proposal_id: 0 }));
+		assert!(!Proposals::<MockRuntime>::contains_key(0));
+	});
+}
+
+#[test]
+fn close_rejected_below_threshold() {
+	ExtBuilder::default().build().execute_with(|| {
+		setup_proposal(vec![(ALICE, 10), (BOB, 20), (CHARLIE, 30)]);
+
+		// 30 of 50 voted pieces are aye, threshold is 60%
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(ALICE), 0, true));
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(BOB), 0, true));
+		assert_ok!(HolderGovernance::vote(RuntimeOrigin::signed(CHARLIE), 0, false));
+		assert_eq!(HolderGovernance::proposal(0).unwrap().ayes, 30);
+
+		end_voting();
+		assert_ok!(HolderGovernance::close(RuntimeOrigin::signed(EVE), 0, call_weight()));
+
+		System::assert_last_event(RuntimeEvent::HolderGovernance(Event::Rejected { proposal_id: 0 }));
+	});
+}
+
+#[test]
+fn close_rejected_without_votes() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10)]).unwrap();
+		assert_ok!(HolderGovernance::set_governance_params(
+			RuntimeOrigin::signed(ISSUER),
+			collection_id,
+			token_id,
+			params(0, 0)
+		));
+		assert_ok!(HolderGovernance::propose(RuntimeOrigin::signed(ALICE), collection_id, token_id, remark_call()));
+
+		end_voting();
+		assert_ok!(HolderGovernance::close(RuntimeOrigin::signed(EVE), 0, call_weight()));
+
+		System::assert_last_event(RuntimeEvent::HolderGovernance(Event::Rejected { proposal_id: 0 }));
+	});
+}
@@ -0,0 +1,90 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Weights for pallet_holder_governance
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::weights::constants::RocksDbWeight;
+
+/// Weight functions needed for pallet_holder_governance.
+pub trait WeightInfo {
+	fn set_governance_params() -> Weight;
+	fn propose(h: u32, ) -> Weight;
+	fn vote() -> Weight;
+	fn close(h: u32, ) -> Weight;
+}
+
+impl WeightInfo for () {
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common IsAdmin (r:1 w:0)
+	// Storage: Refungible TotalSupply (r:1 w:0)
+	// Storage: HolderGovernance Params (r:0 w:1)
+	fn set_governance_params() -> Weight {
+		Weight::from_parts(24_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: HolderGovernance Params (r:1 w:0)
+	// Storage: Refungible Balance (r:101 w:0)
+	// Storage: Refungible TokenHolders (r:1 w:0)
+	// Storage: HolderGovernance ActiveProposals (r:1 w:1)
+	// Storage: HolderGovernance NextProposalId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Refungible TotalSupply (r:1 w:0)
+	// Storage: HolderGovernance Snapshots (r:0 w:100)
+	// Storage: HolderGovernance Proposals (r:0 w:1)
+	/// The range of component `h` is `[1, 100]`.
+	fn propose(h: u32, ) -> Weight {
+		Weight::from_parts(45_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(6_000_000 as u64, 0).saturating_mul(h as u64))
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(h as u64)))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(h as u64)))
+	}
+	// Storage: HolderGovernance Proposals (r:1 w:1)
+	// Storage: HolderGovernance Snapshots (r:1 w:0)
+	// Storage: HolderGovernance Votes (r:1 w:1)
+	fn vote() -> Weight {
+		Weight::from_parts(26_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: HolderGovernance Proposals (r:1 w:1)
+	// Storage: HolderGovernance ActiveProposals (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: HolderGovernance Snapshots (r:0 w:100)
+	// Storage: HolderGovernance Votes (r:0 w:100)
+	/// The range of component `h` is `[1, 100]`.
+	fn close(h: u32, ) -> Weight {
+		Weight::from_parts(40_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(2_000_000 as u64, 0).saturating_mul(h as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(h as u64)))
+	}
+}
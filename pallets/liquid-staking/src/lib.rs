@@ -0,0 +1,601 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! # Liquid staking pallet
+//!
+//! ## Overview
+//!
+//! Liquid staking derivative for CGT. Holders deposit CGT into the pool and get
+//! a fungible derivative currency, which can be transferred and traded on `pallet_dex`.
+//!
+//! - The pool account delegates deposits across the collators chosen by [Config::ManagerOrigin]
+//!   using the calls of `parachain_staking`, always topping up the smallest delegation.
+//! - Once per staking round the pool claims its rewards and restakes them, so the
+//!   exchange rate of the derivative grows.
+//! - Redemptions burn the derivative at the current exchange rate and are unbonded in
+//!   batches every [Config::BatchPeriod] blocks. The CGT can be withdrawn once the
+//!   batch went through the unstaking queue of `parachain_staking`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+	pallet_prelude::*,
+	storage::{with_storage_layer, with_transaction},
+	traits::{Currency, ExistenceRequirement},
+	PalletId,
+};
+use frame_system::{pallet_prelude::*, RawOrigin};
+
+use orml_traits::MultiCurrency;
+
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{AccountIdConversion, Saturating, StaticLookup, Zero},
+	ArithmeticError, Rounding, TransactionOutcome,
+};
+use sp_std::vec::Vec;
+
+use parachain_staking::Pallet as ParachainStaking;
+use primitives::Balance;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub(crate) const LOG_TARGET: &str = "runtime::liquid-staking";
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + parachain_staking::Config<CurrencyBalance = Balance> {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		type CurrencyId: Parameter + Member + Copy + MaxEncodedLen;
+
+		/// Currency the derivative is minted in
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = Self::CurrencyId, Balance = Balance>;
+
+		/// Currency id of the derivative
+		#[pallet::constant]
+		type DerivativeCurrencyId: Get<Self::CurrencyId>;
+
+		/// Pooled CGT is held and staked by the account derived from this id
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Origin allowed to choose the collators the pool delegates to
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Minimum amount of a single deposit.
+		///
+		/// Should not be lower than the minimum delegator stake of `parachain_staking`.
+		#[pallet::constant]
+		type MinDeposit: Get<Balance>;
+
+		/// Number of blocks redemptions are collected before they are unbonded together.
+		///
+		/// `StakeDuration / BatchPeriod` must stay below `MaxUnstakeRequests - 1`
+		/// of `parachain_staking`, otherwise the unstaking queue of the pool fills up.
+		#[pallet::constant]
+		type BatchPeriod: Get<Self::BlockNumber>;
+
+		/// Maximum number of not withdrawn redemptions per account
+		#[pallet::constant]
+		type MaxRedemptions: Get<u32>;
+
+		type WeightInfo: weights::WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Collators the pool delegates to
+	#[pallet::storage]
+	#[pallet::getter(fn collators)]
+	pub type Collators<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxCollatorsPerDelegator>, ValueQuery>;
+
+	/// CGT owed to redeemers, either being unbonded or ready to withdraw
+	#[pallet::storage]
+	#[pallet::getter(fn total_unbonding)]
+	pub type TotalUnbonding<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Batch new redemptions are added to
+	#[pallet::storage]
+	#[pallet::getter(fn current_batch)]
+	pub type CurrentBatch<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// CGT redeemed in the current batch and not unbonded yet
+	#[pallet::storage]
+	#[pallet::getter(fn pending_unbond)]
+	pub type PendingUnbond<T: Config> = StorageValue<_, Balance, ValueQuery>;
+
+	/// Block from which CGT of an unbonded batch can be withdrawn
+	#[pallet::storage]
+	#[pallet::getter(fn batch_unlock)]
+	pub type BatchUnlocks<T: Config> = StorageMap<_, Twox64Concat, u32, T::BlockNumber, OptionQuery>;
+
+	/// Not withdrawn redemptions of an account as (batch, CGT amount)
+	#[pallet::storage]
+	#[pallet::getter(fn redemptions)]
+	pub type Redemptions<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(u32, Balance), T::MaxRedemptions>,
+		ValueQuery
+	>;
+
+	/// Last staking round rewards of the pool were compounded in
+	#[pallet::storage]
+	#[pallet::getter(fn last_compounded_round)]
+	pub type LastCompoundedRound<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Collator added to the pool
+		CollatorAdded { collator: T::AccountId },
+		/// Collator removed from the pool and its delegation revoked
+		CollatorRemoved { collator: T::AccountId },
+		/// CGT deposited and derivative minted
+		Deposited {
+			who: T::AccountId,
+			amount: Balance,
+			minted: Balance,
+		},
+		/// Derivative burned and CGT queued for unbonding
+		Redeemed {
+			who: T::AccountId,
+			amount: Balance,
+			value: Balance,
+			batch: u32,
+		},
+		/// Redemptions of the batch unbonded
+		BatchUnbonded {
+			batch: u32,
+			amount: Balance,
+			unlock_at: T::BlockNumber,
+		},
+		/// Redeemed CGT withdrawn
+		Withdrawn { who: T::AccountId, amount: Balance },
+		/// Claimed rewards and idle CGT of the pool staked again
+		Restaked { amount: Balance },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No collators to delegate to
+		NoCollators,
+		/// Collator is already in the pool
+		AlreadyAdded,
+		/// Collator isn't in the pool
+		CollatorNotFound,
+		/// Account isn't a collator candidate
+		NotCandidate,
+		/// Pool has the maximum number of collators
+		TooManyCollators,
+		/// Deposit is lower than `MinDeposit`
+		DepositBelowMin,
+		/// Amount can't be zero
+		ZeroAmount,
+		/// Account has the maximum number of not withdrawn redemptions
+		TooManyRedemptions,
+		/// No redemption is ready to withdraw
+		NothingToWithdraw,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			let mut weight = T::DbWeight::get().reads(2);
+
+			let round = ParachainStaking::<T>::round().current;
+			if round > Self::last_compounded_round() {
+				<LastCompoundedRound<T>>::put(round);
+				Self::compound();
+				weight.saturating_accrue(<T as Config>::WeightInfo::on_initialize_compound());
+			}
+
+			if (now % T::BatchPeriod::get()).is_zero() && !Self::pending_unbond().is_zero() {
+				Self::process_batch(now);
+				weight.saturating_accrue(<T as Config>::WeightInfo::on_initialize_process_batch());
+			}
+
+			weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Add collator candidate the pool delegates new deposits to.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_collator())]
+		pub fn add_collator(origin: OriginFor<T>, collator: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				ParachainStaking::<T>::is_active_candidate(&collator).is_some(),
+				Error::<T>::NotCandidate
+			);
+
+			<Collators<T>>::try_mutate(|collators| -> DispatchResult {
+				ensure!(!collators.contains(&collator), Error::<T>::AlreadyAdded);
+				collators.try_push(collator.clone()).map_err(|_| Error::<T>::TooManyCollators)?;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CollatorAdded { collator });
+
+			Ok(())
+		}
+
+		/// Remove collator from the pool.
+		///
+		/// Delegation of the pool to the collator is revoked, revoked CGT
+		/// is staked again once it is unlocked.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_collator())]
+		pub fn remove_collator(origin: OriginFor<T>, collator: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			<Collators<T>>::try_mutate(|collators| -> DispatchResult {
+				let index = collators
+					.iter()
+					.position(|c| *c == collator)
+					.ok_or(Error::<T>::CollatorNotFound)?;
+				collators.remove(index);
+				Ok(())
+			})?;
+
+			if !Self::delegation(&collator).is_zero() {
+				ParachainStaking::<T>::revoke_delegation(
+					Self::pool_origin(),
+					T::Lookup::unlookup(collator.clone())
+				)?;
+			}
+
+			Self::deposit_event(Event::CollatorRemoved { collator });
+
+			Ok(())
+		}
+
+		/// Deposit `amount` CGT into the pool and mint the derivative at the current exchange rate.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::deposit())]
+		pub fn deposit(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(amount >= T::MinDeposit::get(), Error::<T>::DepositBelowMin);
+			ensure!(!Self::collators().is_empty(), Error::<T>::NoCollators);
+
+			let minted = Self::derivative_for(amount)?;
+			ensure!(!minted.is_zero(), Error::<T>::ZeroAmount);
+
+			<T as parachain_staking::Config>::Currency::transfer(
+				&who,
+				&Self::account_id(),
+				amount,
+				ExistenceRequirement::KeepAlive
+			)?;
+			T::MultiCurrency::deposit(T::DerivativeCurrencyId::get(), &who, minted)?;
+
+			// CGT which can't be staked right away is staked with the next round
+			Self::stake(amount);
+
+			Self::deposit_event(Event::Deposited { who, amount, minted });
+
+			Ok(())
+		}
+
+		/// Burn `amount` of the derivative and queue its value in CGT for unbonding.
+		///
+		/// The CGT can be withdrawn with [Pallet::withdraw_redeemed] once the batch
+		/// went through the unstaking queue.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem())]
+		pub fn redeem(origin: OriginFor<T>, amount: Balance) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+
+			let value = Self::value_of(amount)?;
+			ensure!(!value.is_zero(), Error::<T>::ZeroAmount);
+
+			let batch = Self::current_batch();
+			let mut redemptions = Self::redemptions(&who);
+			match redemptions.iter_mut().find(|(b, _)| *b == batch) {
+				Some((_, queued)) => *queued = queued.saturating_add(value),
+				None => redemptions
+					.try_push((batch, value))
+					.map_err(|_| Error::<T>::TooManyRedemptions)?,
+			}
+
+			T::MultiCurrency::withdraw(T::DerivativeCurrencyId::get(), &who, amount)?;
+
+			<Redemptions<T>>::insert(&who, redemptions);
+			<PendingUnbond<T>>::mutate(|pending| *pending = pending.saturating_add(value));
+			<TotalUnbonding<T>>::mutate(|total| *total = total.saturating_add(value));
+
+			Self::deposit_event(Event::Redeemed { who, amount, value, batch });
+
+			Ok(())
+		}
+
+		/// Withdraw CGT of all redemptions of the sender which went through the unstaking queue.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_redeemed())]
+		pub fn withdraw_redeemed(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			let mut redemptions = Self::redemptions(&who);
+			let mut amount: Balance = Zero::zero();
+			redemptions.retain(|(batch, value)| {
+				let unlocked = Self::batch_unlock(batch).map_or(false, |unlock_at| unlock_at <= now);
+				if unlocked {
+					amount = amount.saturating_add(*value);
+				}
+				!unlocked
+			});
+			ensure!(!amount.is_zero(), Error::<T>::NothingToWithdraw);
+
+			// funds may have been unlocked already
+			let _ = with_storage_layer(|| {
+				ParachainStaking::<T>::unlock_unstaked(
+					Self::pool_origin(),
+					T::Lookup::unlookup(Self::account_id())
+				)
+			});
+
+			<T as parachain_staking::Config>::Currency::transfer(
+				&Self::account_id(),
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath
+			)?;
+
+			if redemptions.is_empty() {
+				<Redemptions<T>>::remove(&who);
+			} else {
+				<Redemptions<T>>::insert(&who, redemptions);
+			}
+			<TotalUnbonding<T>>::mutate(|total| *total = total.saturating_sub(amount));
+
+			Self::deposit_event(Event::Withdrawn { who, amount });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding and staking pooled CGT
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// CGT backing the derivative: staked, unstaking and idle funds as well as
+	/// unclaimed staking rewards of the pool without CGT owed to redeemers
+	pub fn pooled_balance() -> Balance {
+		let pool = Self::account_id();
+		<T as parachain_staking::Config>::Currency::free_balance(&pool)
+			.saturating_add(ParachainStaking::<T>::get_unclaimed_staking_rewards(&pool))
+			.saturating_sub(Self::total_unbonding())
+	}
+
+	/// Amount of the derivative minted for `amount` CGT
+	pub fn derivative_for(amount: Balance) -> Result<Balance, DispatchError> {
+		let issuance = T::MultiCurrency::total_issuance(T::DerivativeCurrencyId::get());
+		let pooled = Self::pooled_balance();
+
+		if issuance.is_zero() || pooled.is_zero() {
+			return Ok(amount);
+		}
+
+		multiply_by_rational_with_rounding(amount, issuance, pooled, Rounding::Down)
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Amount of CGT `amount` of the derivative is redeemed for
+	pub fn value_of(amount: Balance) -> Result<Balance, DispatchError> {
+		let issuance = T::MultiCurrency::total_issuance(T::DerivativeCurrencyId::get());
+		ensure!(amount <= issuance, ArithmeticError::Underflow);
+
+		multiply_by_rational_with_rounding(amount, Self::pooled_balance(), issuance, Rounding::Down)
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// CGT of the pool which is neither staked, unstaking nor owed to redeemers
+	pub fn idle_balance() -> Balance {
+		let pool = Self::account_id();
+		let staked = ParachainStaking::<T>::delegator_state(&pool).map_or(Zero::zero(), |d| d.total);
+		let unstaking = ParachainStaking::<T>::unstaking(&pool)
+			.values()
+			.fold(Balance::zero(), |acc, amount| acc.saturating_add(*amount));
+
+		<T as parachain_staking::Config>::Currency::free_balance(&pool)
+			.saturating_sub(staked)
+			.saturating_sub(unstaking)
+			.saturating_sub(Self::total_unbonding())
+	}
+
+	// Delegation of the pool to the collator
+	fn delegation(collator: &T::AccountId) -> Balance {
+		ParachainStaking::<T>::delegator_state(Self::account_id())
+			.and_then(|d| d.delegation(collator))
+			.unwrap_or_else(Zero::zero)
+	}
+
+	fn pool_origin() -> OriginFor<T> {
+		RawOrigin::Signed(Self::account_id()).into()
+	}
+
+	// Stake `amount` with the pool collator having the smallest delegation. New
+	// delegations need `MinDelegatorStake`, smaller amounts top up an existing one.
+	// Returns whether the amount was staked, otherwise it stays idle.
+	fn stake(amount: Balance) -> bool {
+		if amount.is_zero() {
+			return false;
+		}
+
+		let mut collators: Vec<(T::AccountId, Balance)> = Self::collators()
+			.into_iter()
+			.map(|c| {
+				let delegated = Self::delegation(&c);
+				(c, delegated)
+			})
+			.collect();
+		collators.sort_by_key(|(_, delegated)| *delegated);
+
+		let is_delegator = ParachainStaking::<T>::delegator_state(Self::account_id()).is_some();
+		let can_join = amount >= ParachainStaking::<T>::staking_params().min_delegator_stake;
+
+		for (collator, delegated) in collators {
+			let collator = T::Lookup::unlookup(collator);
+			let result = with_storage_layer(|| {
+				if !delegated.is_zero() {
+					ParachainStaking::<T>::delegator_stake_more(Self::pool_origin(), collator, amount)
+						.map(|_| ())
+						.map_err(|e| e.error)
+				} else if !can_join {
+					Err(Error::<T>::DepositBelowMin.into())
+				} else if is_delegator {
+					ParachainStaking::<T>::delegate_another_candidate(Self::pool_origin(), collator, amount)
+						.map(|_| ())
+						.map_err(|e| e.error)
+				} else {
+					ParachainStaking::<T>::join_delegators(Self::pool_origin(), collator, amount)
+						.map(|_| ())
+						.map_err(|e| e.error)
+				}
+			});
+
+			if result.is_ok() {
+				return true;
+			}
+		}
+
+		false
+	}
+
+	// Unbond `amount` from the pool delegations, largest first. A delegation is revoked
+	// entirely when the rest would fall below `MinDelegatorStake`.
+	fn unbond(amount: Balance) -> DispatchResult {
+		let pool = Self::account_id();
+		let mut delegations: Vec<(T::AccountId, Balance)> = match ParachainStaking::<T>::delegator_state(&pool) {
+			Some(delegator) => delegator
+				.delegations
+				.into_iter()
+				.map(|stake| (stake.owner, stake.amount))
+				.collect(),
+			None => Vec::new(),
+		};
+		delegations.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+		let min_stake = ParachainStaking::<T>::staking_params().min_delegator_stake;
+		let mut remaining = amount;
+
+		for (collator, delegated) in delegations {
+			if remaining.is_zero() {
+				break;
+			}
+
+			let collator = T::Lookup::unlookup(collator);
+			if remaining >= delegated || delegated.saturating_sub(remaining) < min_stake {
+				ParachainStaking::<T>::revoke_delegation(Self::pool_origin(), collator)?;
+				remaining = remaining.saturating_sub(delegated);
+			} else {
+				ParachainStaking::<T>::delegator_stake_less(Self::pool_origin(), collator, remaining)
+					.map_err(|e| e.error)?;
+				remaining = Zero::zero();
+			}
+		}
+
+		// the rest is covered by idle funds of the pool
+		Ok(())
+	}
+
+	// Claim rewards of the pool and stake them together with other idle funds
+	fn compound() {
+		let pool = T::Lookup::unlookup(Self::account_id());
+
+		// errors only mean there is nothing to claim or unlock
+		let _ = with_storage_layer(|| {
+			ParachainStaking::<T>::increment_delegator_rewards(Self::pool_origin())
+				.map(|_| ())
+				.map_err(|e| e.error)
+		});
+		let _ = with_storage_layer(|| ParachainStaking::<T>::claim_rewards(Self::pool_origin()));
+		let _ = with_storage_layer(|| {
+			ParachainStaking::<T>::unlock_unstaked(Self::pool_origin(), pool)
+				.map(|_| ())
+				.map_err(|e| e.error)
+		});
+
+		let idle = Self::idle_balance();
+		if Self::stake(idle) {
+			Self::deposit_event(Event::Restaked { amount: idle });
+		}
+	}
+
+	// Unbond redemptions of the current batch and start a new one
+	fn process_batch(now: T::BlockNumber) {
+		let amount = Self::pending_unbond();
+
+		let result = with_transaction(|| match Self::unbond(amount) {
+			Ok(()) => TransactionOutcome::Commit(Ok(())),
+			Err(e) => TransactionOutcome::Rollback(Err(e)),
+		});
+
+		match result {
+			Ok(()) => {
+				let batch = Self::current_batch();
+				let unlock_at = now.saturating_add(ParachainStaking::<T>::staking_params().stake_duration);
+
+				<BatchUnlocks<T>>::insert(batch, unlock_at);
+				<CurrentBatch<T>>::put(batch.saturating_add(1));
+				<PendingUnbond<T>>::kill();
+
+				Self::deposit_event(Event::BatchUnbonded { batch, amount, unlock_at });
+			},
+			Err(e) => {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to unbond batch {}: {:?}, retrying with the next period",
+					Self::current_batch(),
+					e
+				);
+			},
+		}
+	}
+}
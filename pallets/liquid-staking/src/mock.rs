@@ -0,0 +1,338 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use codec::{Encode, Decode, MaxEncodedLen};
+use frame_support::{
+	construct_runtime, parameter_types, PalletId,
+	traits::{ConstU128, ConstU32, ConstU64, Everything, GenesisBuild, Nothing, OnFinalize, OnInitialize},
+};
+use pallet_authorship::EventHandler;
+use parachain_staking::InflationInfo;
+use scale_info::TypeInfo;
+use sp_consensus_aura::sr25519::AuthorityId;
+use sp_core::H256;
+use sp_runtime::{
+	impl_opaque_keys, Perquintill, RuntimeDebug,
+	testing::{Header, UintAuthorityId},
+	traits::{BlakeTwo256, ConvertInto, IdentityLookup, OpaqueKeys},
+};
+
+use primitives::Balance;
+
+mod pallet_liquid_staking {
+	pub use super::super::*;
+}
+
+pub type AccountId = u64;
+pub type BlockNumber = u64;
+
+pub const DECIMALS: Balance = 1_000_000_000_000_000_000;
+pub const BLOCKS_PER_ROUND: BlockNumber = 5;
+pub const BATCH_PERIOD: BlockNumber = 2;
+pub const STAKE_DURATION: BlockNumber = 2;
+
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+pub enum CurrencyId {
+	LCGT,
+	DAI,
+}
+
+pub const LCGT: CurrencyId = CurrencyId::LCGT;
+
+impl frame_system::Config for MockRuntime {
+	type BaseCallFilter = Everything;
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 1;
+}
+
+impl pallet_balances::Config for MockRuntime {
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type MaxHolds = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type HoldIdentifier = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+orml_traits::parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type DustRemovalWhitelist = Nothing;
+}
+
+impl pallet_aura::Config for MockRuntime {
+	type AuthorityId = AuthorityId;
+	type DisabledValidators = ();
+	type MaxAuthorities = MaxCollatorCandidates;
+}
+
+impl pallet_authorship::Config for MockRuntime {
+	type FindAuthor = pallet_session::FindAccountFromAuthorIndex<Self, Aura>;
+	type EventHandler = ParachainStaking;
+}
+
+impl_opaque_keys! {
+	pub struct MockSessionKeys {
+		pub aura: Aura,
+	}
+}
+
+impl pallet_session::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type ValidatorId = AccountId;
+	type ValidatorIdOf = ConvertInto;
+	type ShouldEndSession = ParachainStaking;
+	type NextSessionRotation = ParachainStaking;
+	type SessionManager = ParachainStaking;
+	type SessionHandler = <MockSessionKeys as OpaqueKeys>::KeyTypeIdProviders;
+	type Keys = MockSessionKeys;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const MinBlocksPerRound: BlockNumber = 3;
+	pub const DefaultBlocksPerRound: BlockNumber = BLOCKS_PER_ROUND;
+	pub const StakeDuration: BlockNumber = STAKE_DURATION;
+	pub const MinCollators: u32 = 2;
+	pub const EarlyExitPenaltyDestination: parachain_staking::PenaltyDestination =
+		parachain_staking::PenaltyDestination::Treasury;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxDelegatorsPerCollator: u32 = 4;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxCollatorsPerDelegator: u32 = 2;
+	pub const MinCollatorStake: Balance = 10;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxCollatorCandidates: u32 = 10;
+	pub const MinDelegatorStake: Balance = 5;
+	pub const MaxUnstakeRequests: u32 = 6;
+	pub const NetworkRewardRate: Perquintill = Perquintill::from_percent(10);
+	pub const MaxNetworkRewardRate: Perquintill = Perquintill::from_percent(20);
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxMetadataLength: u32 = 16;
+	pub const NetworkRewardStart: BlockNumber = BlockNumber::MAX;
+}
+
+impl parachain_staking::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type CurrencyBalance = Balance;
+	type MinBlocksPerRound = MinBlocksPerRound;
+	type DefaultBlocksPerRound = DefaultBlocksPerRound;
+	type StakeDuration = StakeDuration;
+	type MaxStakeDuration = ConstU64<100>;
+	type ExitQueueDelay = ConstU32<2>;
+	type MaxExitQueueDelay = ConstU32<10>;
+	type CommissionChangeDelay = ConstU32<2>;
+	type MinCollators = MinCollators;
+	type MinRequiredCollators = MinCollators;
+	type DelegationWarmUp = ConstU32<1>;
+	type MinDelegationDuration = ConstU32<0>;
+	type EarlyExitPenalty = ();
+	type EarlyExitPenaltyDestination = EarlyExitPenaltyDestination;
+	type EarlyExitPenaltyBeneficiary = ();
+	type MaxDelegatorsPerCollator = MaxDelegatorsPerCollator;
+	type MaxCollatorsPerDelegator = MaxCollatorsPerDelegator;
+	type MinCollatorStake = MinCollatorStake;
+	type MinCollatorCandidateStake = MinCollatorStake;
+	type MaxTopCandidates = MaxCollatorCandidates;
+	type MinDelegatorStake = MinDelegatorStake;
+	type MinStakeGracePeriod = ConstU32<2>;
+	type MaxUnstakeRequests = MaxUnstakeRequests;
+	type NetworkRewardRate = NetworkRewardRate;
+	type MaxNetworkRewardRate = MaxNetworkRewardRate;
+	type NetworkRewardStart = NetworkRewardStart;
+	type NetworkRewardBeneficiary = ();
+	type RewardSource = parachain_staking::InflationaryRewards;
+	type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type ParametersOrigin = frame_system::EnsureRoot<AccountId>;
+	type SlashDeferDuration = ConstU32<2>;
+	type Slash = ();
+	type MaxRewardHistory = ConstU32<3>;
+	type MaxMetadataLength = MaxMetadataLength;
+	type MetadataDepositBase = ConstU128<10>;
+	type MetadataDepositPerByte = ConstU128<1>;
+	type WeightInfo = ();
+	const BLOCKS_PER_YEAR: Self::BlockNumber = 5 * 60 * 24 * 36525 / 100;
+}
+
+parameter_types! {
+	pub const DerivativeCurrencyId: CurrencyId = LCGT;
+	pub const LiquidStakingPalletId: PalletId = PalletId(*b"cur/lstk");
+	pub const MinDeposit: Balance = 5;
+	pub const BatchPeriod: BlockNumber = BATCH_PERIOD;
+}
+
+impl pallet_liquid_staking::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type CurrencyId = CurrencyId;
+	type MultiCurrency = Tokens;
+	type DerivativeCurrencyId = DerivativeCurrencyId;
+	type PalletId = LiquidStakingPalletId;
+	type ManagerOrigin = frame_system::EnsureRoot<AccountId>;
+	type MinDeposit = MinDeposit;
+	type BatchPeriod = BatchPeriod;
+	type MaxRedemptions = ConstU32<3>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<MockRuntime>;
+type Block = frame_system::mocking::MockBlock<MockRuntime>;
+
+construct_runtime! {
+	pub enum MockRuntime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		Aura: pallet_aura,
+		Session: pallet_session,
+		ParachainStaking: parachain_staking,
+		Authorship: pallet_authorship,
+		Tokens: orml_tokens,
+		LiquidStaking: pallet_liquid_staking,
+	}
+}
+
+#[derive(Default)]
+pub struct ExtBuilder {
+	balances: Vec<(AccountId, Balance)>,
+	collators: Vec<(AccountId, Balance)>,
+}
+
+impl ExtBuilder {
+	pub fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+		self.balances = balances;
+		self
+	}
+
+	pub fn with_collators(mut self, collators: Vec<(AccountId, Balance)>) -> Self {
+		self.collators = collators;
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<MockRuntime>()
+			.unwrap();
+
+		pallet_balances::GenesisConfig::<MockRuntime> {
+			balances: self.balances
+		}.assimilate_storage(&mut t).unwrap();
+
+		parachain_staking::GenesisConfig::<MockRuntime> {
+			stakers: self.collators.iter().map(|(acc, stake)| (*acc, None, *stake)).collect(),
+			inflation_config: InflationInfo::new(
+				<MockRuntime as parachain_staking::Config>::BLOCKS_PER_YEAR,
+				Perquintill::from_percent(10),
+				Perquintill::from_percent(15),
+				Perquintill::from_percent(40),
+				Perquintill::from_percent(10),
+			),
+			max_candidate_stake: 160_000_000 * DECIMALS,
+		}.assimilate_storage(&mut t).unwrap();
+
+		let keys = self.collators
+			.iter()
+			.map(|(acc, _)| (*acc, *acc, MockSessionKeys { aura: UintAuthorityId(*acc).to_public_key() }))
+			.collect();
+		pallet_session::GenesisConfig::<MockRuntime> { keys }
+			.assimilate_storage(&mut t)
+			.unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+/// Move to block `n`, noting `authors[i]` as author of block `i`
+pub fn roll_to(n: BlockNumber, authors: Vec<Option<AccountId>>) {
+	while System::block_number() < n {
+		if let Some(Some(author)) = authors.get(System::block_number() as usize) {
+			ParachainStaking::note_author(*author);
+		}
+		<AllPalletsWithSystem as OnFinalize<BlockNumber>>::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+		<AllPalletsWithSystem as OnInitialize<BlockNumber>>::on_initialize(System::block_number());
+	}
+}
+
+pub fn events() -> Vec<pallet_liquid_staking::Event<MockRuntime>> {
+	System::events()
+		.into_iter()
+		.filter_map(|r| match r.event {
+			RuntimeEvent::LiquidStaking(inner) => Some(inner),
+			_ => None,
+		})
+		.collect()
+}
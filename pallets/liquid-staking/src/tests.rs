@@ -0,0 +1,300 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use orml_traits::MultiCurrency;
+use sp_runtime::DispatchError;
+
+use crate::mock::*;
+use crate::{Error, Event};
+
+const COLLATOR_1: AccountId = 1;
+const COLLATOR_2: AccountId = 2;
+const COLLATOR_3: AccountId = 3;
+const ALICE: AccountId = 10;
+const BOB: AccountId = 11;
+
+const STAKE: Balance = 100_000 * DECIMALS;
+const DEPOSIT: Balance = 10_000 * DECIMALS;
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(COLLATOR_1, STAKE),
+			(COLLATOR_2, STAKE),
+			(COLLATOR_3, STAKE),
+			(ALICE, 10 * DEPOSIT),
+			(BOB, 10 * DEPOSIT),
+		])
+		.with_collators(vec![(COLLATOR_1, STAKE), (COLLATOR_2, STAKE), (COLLATOR_3, STAKE)])
+		.build()
+}
+
+fn add_collators() {
+	assert_ok!(LiquidStaking::add_collator(RuntimeOrigin::root(), COLLATOR_1));
+	assert_ok!(LiquidStaking::add_collator(RuntimeOrigin::root(), COLLATOR_2));
+}
+
+fn delegation(collator: AccountId) -> Balance {
+	ParachainStaking::delegator_state(LiquidStaking::account_id())
+		.and_then(|d| d.delegation(&collator))
+		.unwrap_or_default()
+}
+
+#[test]
+fn add_collator_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(LiquidStaking::add_collator(RuntimeOrigin::root(), COLLATOR_1));
+
+		System::assert_last_event(RuntimeEvent::LiquidStaking(Event::CollatorAdded { collator: COLLATOR_1 }));
+		assert_eq!(LiquidStaking::collators().into_inner(), vec![COLLATOR_1]);
+	});
+}
+
+#[test]
+fn add_collator_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LiquidStaking::add_collator(RuntimeOrigin::signed(ALICE), COLLATOR_1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			LiquidStaking::add_collator(RuntimeOrigin::root(), ALICE),
+			Error::<MockRuntime>::NotCandidate
+		);
+
+		add_collators();
+
+		assert_noop!(
+			LiquidStaking::add_collator(RuntimeOrigin::root(), COLLATOR_1),
+			Error::<MockRuntime>::AlreadyAdded
+		);
+		assert_noop!(
+			LiquidStaking::add_collator(RuntimeOrigin::root(), COLLATOR_3),
+			Error::<MockRuntime>::TooManyCollators
+		);
+	});
+}
+
+#[test]
+fn remove_collator_revokes_delegation() {
+	new_test_ext().execute_with(|| {
+		add_collators();
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), DEPOSIT));
+		assert_eq!(delegation(COLLATOR_1), DEPOSIT);
+
+		assert_ok!(LiquidStaking::remove_collator(RuntimeOrigin::root(), COLLATOR_1));
+
+		System::assert_last_event(RuntimeEvent::LiquidStaking(Event::CollatorRemoved { collator: COLLATOR_1 }));
+		assert_eq!(LiquidStaking::collators().into_inner(), vec![COLLATOR_2]);
+		assert!(ParachainStaking::delegator_state(LiquidStaking::account_id()).is_none());
+		assert_noop!(
+			LiquidStaking::remove_collator(RuntimeOrigin::root(), COLLATOR_1),
+			Error::<MockRuntime>::CollatorNotFound
+		);
+
+		// revoked stake is unlocked and staked again with the next round
+		roll_to(BLOCKS_PER_ROUND, vec![]);
+
+		assert_eq!(delegation(COLLATOR_2), DEPOSIT);
+		assert!(events().contains(&Event::Restaked { amount: DEPOSIT }));
+	});
+}
+
+#[test]
+fn deposit_works() {
+	new_test_ext().execute_with(|| {
+		add_collators();
+
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), DEPOSIT));
+
+		System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Deposited {
+			who: ALICE,
+			amount: DEPOSIT,
+			minted: DEPOSIT,
+		}));
+		assert_eq!(Tokens::free_balance(LCGT, &ALICE), DEPOSIT);
+		assert_eq!(Balances::free_balance(ALICE), 9 * DEPOSIT);
+		assert_eq!(delegation(COLLATOR_1), DEPOSIT);
+
+		// new deposits top up the smallest delegation
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), DEPOSIT));
+		assert_eq!(delegation(COLLATOR_2), DEPOSIT);
+
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), DEPOSIT / 2));
+		assert_eq!(Tokens::free_balance(LCGT, &ALICE), DEPOSIT + DEPOSIT / 2);
+		assert_eq!(delegation(COLLATOR_1) + delegation(COLLATOR_2), 2 * DEPOSIT + DEPOSIT / 2);
+		assert_eq!(LiquidStaking::pooled_balance(), 2 * DEPOSIT + DEPOSIT / 2);
+	});
+}
+
+#[test]
+fn deposit_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), DEPOSIT),
+			Error::<MockRuntime>::NoCollators
+		);
+
+		add_collators();
+
+		assert_noop!(
+			LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), 4),
+			Error::<MockRuntime>::DepositBelowMin
+		);
+	});
+}
+
+#[test]
+fn redeem_and_withdraw_works() {
+	new_test_ext().execute_with(|| {
+		add_collators();
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), DEPOSIT));
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), DEPOSIT));
+
+		assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), DEPOSIT / 4));
+
+		System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Redeemed {
+			who: ALICE,
+			amount: DEPOSIT / 4,
+			value: DEPOSIT / 4,
+			batch: 0,
+		}));
+		assert_eq!(Tokens::free_balance(LCGT, &ALICE), DEPOSIT - DEPOSIT / 4);
+		assert_eq!(LiquidStaking::redemptions(ALICE).into_inner(), vec![(0, DEPOSIT / 4)]);
+		assert_eq!(LiquidStaking::pending_unbond(), DEPOSIT / 4);
+		assert_eq!(LiquidStaking::total_unbonding(), DEPOSIT / 4);
+		// exchange rate isn't affected
+		assert_eq!(LiquidStaking::value_of(DEPOSIT).unwrap(), DEPOSIT);
+
+		assert_noop!(
+			LiquidStaking::withdraw_redeemed(RuntimeOrigin::signed(ALICE)),
+			Error::<MockRuntime>::NothingToWithdraw
+		);
+
+		roll_to(BATCH_PERIOD, vec![]);
+
+		assert!(events().contains(&Event::BatchUnbonded {
+			batch: 0,
+			amount: DEPOSIT / 4,
+			unlock_at: BATCH_PERIOD + STAKE_DURATION,
+		}));
+		assert_eq!(LiquidStaking::current_batch(), 1);
+		assert_eq!(LiquidStaking::pending_unbond(), 0);
+		assert_eq!(delegation(COLLATOR_1) + delegation(COLLATOR_2), 2 * DEPOSIT - DEPOSIT / 4);
+		assert_noop!(
+			LiquidStaking::withdraw_redeemed(RuntimeOrigin::signed(ALICE)),
+			Error::<MockRuntime>::NothingToWithdraw
+		);
+
+		roll_to(BATCH_PERIOD + STAKE_DURATION, vec![]);
+
+		assert_ok!(LiquidStaking::withdraw_redeemed(RuntimeOrigin::signed(ALICE)));
+
+		System::assert_last_event(RuntimeEvent::LiquidStaking(Event::Withdrawn {
+			who: ALICE,
+			amount: DEPOSIT / 4,
+		}));
+		assert_eq!(Balances::free_balance(ALICE), 9 * DEPOSIT + DEPOSIT / 4);
+		assert!(LiquidStaking::redemptions(ALICE).is_empty());
+		assert_eq!(LiquidStaking::total_unbonding(), 0);
+	});
+}
+
+#[test]
+fn redeem_fails() {
+	new_test_ext().execute_with(|| {
+		add_collators();
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), DEPOSIT));
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), DEPOSIT));
+
+		assert_noop!(
+			LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), 0),
+			Error::<MockRuntime>::ZeroAmount
+		);
+		assert_noop!(
+			LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), 2 * DEPOSIT),
+			orml_tokens::Error::<MockRuntime>::BalanceTooLow
+		);
+
+		// one redemption per batch
+		for batch in 1..=3 {
+			assert_ok!(LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), DEPOSIT / 10));
+			roll_to(batch * BATCH_PERIOD, vec![]);
+		}
+		assert_eq!(LiquidStaking::redemptions(ALICE).len(), 3);
+
+		assert_noop!(
+			LiquidStaking::redeem(RuntimeOrigin::signed(ALICE), DEPOSIT / 10),
+			Error::<MockRuntime>::TooManyRedemptions
+		);
+	});
+}
+
+#[test]
+fn rewards_are_compounded() {
+	new_test_ext().execute_with(|| {
+		add_collators();
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), DEPOSIT));
+
+		roll_to(2 * BLOCKS_PER_ROUND + 1, vec![Some(COLLATOR_1); 2 * BLOCKS_PER_ROUND as usize + 1]);
+
+		assert_eq!(LiquidStaking::last_compounded_round(), 2);
+		assert!(events().iter().any(|e| matches!(e, Event::Restaked { .. })));
+		assert!(LiquidStaking::pooled_balance() > DEPOSIT);
+		assert_eq!(LiquidStaking::idle_balance(), 0);
+
+		let value = LiquidStaking::value_of(DEPOSIT).unwrap();
+		assert!(value > DEPOSIT);
+
+		// later deposits get less of the derivative
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), DEPOSIT));
+		assert!(Tokens::free_balance(LCGT, &BOB) < DEPOSIT);
+	});
+}
+
+#[test]
+fn unclaimed_rewards_count_towards_exchange_rate() {
+	new_test_ext().execute_with(|| {
+		add_collators();
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(ALICE), DEPOSIT));
+
+		// the delegation of the pool earns rewards from the next round on
+		let mut authors = vec![None; BLOCKS_PER_ROUND as usize];
+		authors.extend(vec![Some(COLLATOR_1); BLOCKS_PER_ROUND as usize - 1]);
+		roll_to(2 * BLOCKS_PER_ROUND - 1, authors);
+
+		// the rewards are not compounded before the next round
+		let unclaimed = ParachainStaking::get_unclaimed_staking_rewards(&LiquidStaking::account_id());
+		assert!(unclaimed > 0);
+		assert_eq!(delegation(COLLATOR_1), DEPOSIT);
+		assert_eq!(LiquidStaking::pooled_balance(), DEPOSIT + unclaimed);
+
+		// deposits right before compounding do not dilute earlier holders
+		assert_ok!(LiquidStaking::deposit(RuntimeOrigin::signed(BOB), DEPOSIT));
+		assert!(Tokens::free_balance(LCGT, &BOB) < DEPOSIT);
+		assert!(LiquidStaking::value_of(DEPOSIT).unwrap() > DEPOSIT);
+	});
+}
@@ -0,0 +1,165 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Weights for pallet_liquid_staking
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::weights::constants::RocksDbWeight;
+
+/// Weight functions needed for pallet_liquid_staking.
+pub trait WeightInfo {
+	fn add_collator() -> Weight;
+	fn remove_collator() -> Weight;
+	fn deposit() -> Weight;
+	fn redeem() -> Weight;
+	fn withdraw_redeemed() -> Weight;
+	fn on_initialize_compound() -> Weight;
+	fn on_initialize_process_batch() -> Weight;
+}
+
+impl WeightInfo for () {
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: LiquidStaking Collators (r:1 w:1)
+	fn add_collator() -> Weight {
+		Weight::from_parts(18_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: LiquidStaking Collators (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking AutoCompound (r:0 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	fn remove_collator() -> Weight {
+		Weight::from_parts(64_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: LiquidStaking Collators (r:1 w:0)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking BlocksRewarded (r:0 w:1)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:0)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:0)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	fn deposit() -> Weight {
+		Weight::from_parts(108_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(22 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+	}
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: System Account (r:1 w:0)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	// Storage: LiquidStaking CurrentBatch (r:1 w:0)
+	// Storage: LiquidStaking Redemptions (r:1 w:1)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking Rewards (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:0)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: Balances TotalIssuance (r:1 w:0)
+	fn redeem() -> Weight {
+		Weight::from_parts(38_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: LiquidStaking Redemptions (r:1 w:1)
+	// Storage: LiquidStaking BatchUnlocks (r:1 w:0)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	fn withdraw_redeemed() -> Weight {
+		Weight::from_parts(52_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking BlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking RewardHistory (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: ParachainStaking AutoCompound (r:1 w:0)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: LiquidStaking Collators (r:1 w:0)
+	// Storage: LiquidStaking TotalUnbonding (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	// Storage: LiquidStaking LastCompoundedRound (r:1 w:1)
+	fn on_initialize_compound() -> Weight {
+		Weight::from_parts(142_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(20 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
+	}
+	// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: LiquidStaking CurrentBatch (r:1 w:1)
+	// Storage: LiquidStaking BatchUnlocks (r:0 w:1)
+	fn on_initialize_process_batch() -> Weight {
+		Weight::from_parts(71_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,1207 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// Source https://github.com/UniqueNetwork/unique-chain
+// Subject to the GPL-3.0 license.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+// ! # Nonfungible Pallet
+// !
+// ! The Nonfungible pallet provides functionality for handling NFT collections and tokens.
+// !
+// ! ## Overview
+// !
+// ! The Nonfungible pallet provides functions for:
+// !
+// ! - NFT collection creation and removal
+// ! - Minting and burning of NFT tokens
+// ! - Retrieving account balances
+// ! - Transfering NFT tokens
+// ! - Setting and checking allowance for NFT tokens
+// !
+// ! ### Terminology
+// !
+// ! - **NFT token:** Unique token which can't be partitioned, e.g. a title or a certificate.
+// !   Every NFT token has exactly one owner.
+// !
+// ! - **NFT Collection:** A collection of NFT tokens. All NFT tokens are part of a collection.
+// !   Each collection has its own settings and set of permissions.
+// !
+// ! - **Balance:** Amount of NFT tokens owned by an account within a collection.
+// !
+// ! - **Allowance:** Account which is allowed to transfer or burn a token on behalf of its owner.
+// !
+// ! - **Burning:** The process of “deleting” a token from a collection.
+// !
+// ! ## Interface
+// !
+// ! ### Dispatchable Functions
+// !
+// ! - `init_collection` - Create NFT collection.
+// ! - `destroy_collection` - Destroy exising NFT collection. There should be no tokens in the collection.
+// ! - `create_item` - Mint NFT token in collection. Sender should be owner or admin of the collection.
+// ! - `transfer` - Transfer NFT token. Transfers should be enabled for NFT collection.
+// ! - `approve` - Allow another account to transfer or burn sender's token.
+// ! - `burn` - Burn NFT token owned by account.
+// !
+// ! ## Assumptions
+// !
+// ! * Collection mode should be `CollectionMode::NFT`.
+// ! * Sender should be active investor or collection admin to perform operations on tokens.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::ops::Deref;
+use frame_support::{
+	ensure, storage::with_transaction, transactional,
+	pallet_prelude::{Decode, Encode, MaxEncodedLen, TypeInfo},
+	dispatch::{DispatchResult}
+};
+use pallet_common::{
+	BalanceOf, Error as CommonError,
+	Event as CommonEvent, Pallet as PalletCommon,
+};
+use sp_runtime::{ArithmeticError, DispatchError, TransactionOutcome};
+use sp_std::{vec::Vec, vec, collections::{btree_map::BTreeMap}};
+use collection_primitives::{
+	CollectionId, CollectionMode, CreateCollectionData, CreateNftExData,
+	MAX_ITEMS_PER_BATCH, MAX_PROPERTIES_PER_ITEM, Property, PropertyKey,
+	PropertyKeyPermission, PropertyValue, SponsorshipSettings, TokenId, TrySetProperty,
+};
+
+use pallet_whitelist::{
+	traits::WhitelistInterface
+};
+
+pub use weights::WeightInfo;
+
+pub use pallet::*;
+pub mod weights;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+/// NFT token data
+#[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo, MaxEncodedLen)]
+pub struct ItemData<AccountId> {
+	/// Owner of the token
+	pub owner: AccountId,
+}
+
+pub struct NonfungibleHandle<T: Config>(pallet_common::CollectionHandle<T>);
+impl<T: Config> NonfungibleHandle<T> {
+	pub fn cast(inner: pallet_common::CollectionHandle<T>) -> Self {
+		Self(inner)
+	}
+
+	pub fn into_inner(self) -> pallet_common::CollectionHandle<T> {
+		self.0
+	}
+
+	pub fn common_mut(&mut self) -> &mut pallet_common::CollectionHandle<T> {
+		&mut self.0
+	}
+
+	/// Same as [`pallet_common::CollectionHandle::try_get`] but fails
+	/// if the collection is not an NFT collection.
+	pub fn try_get(collection_id: CollectionId) -> Result<Self, DispatchError> {
+		let common_collection = pallet_common::CollectionHandle::<T>::try_get(collection_id)?;
+		ensure!(
+			common_collection.mode == CollectionMode::NFT,
+			<CommonError<T>>::UnsupportedOperation
+		);
+
+		Ok(NonfungibleHandle::cast(common_collection))
+	}
+}
+
+impl<T: Config> Deref for NonfungibleHandle<T> {
+	type Target = pallet_common::CollectionHandle<T>;
+
+	fn deref(&self) -> &Self::Target {
+		&self.0
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		Blake2_128Concat, Twox64Concat, pallet_prelude::*, storage::Key,
+	};
+	use frame_system::pallet_prelude::*;
+	use collection_primitives::{CollectionId, TokenId};
+	use super::weights::WeightInfo;
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Not whitelisted or not active investor given
+		NeitherWhitelistedNorCollectionAdmin,
+	}
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config + pallet_common::Config
+	{
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Total amount of minted tokens in a collection.
+	#[pallet::storage]
+	pub type TokensMinted<T: Config> =
+		StorageMap<Hasher = Twox64Concat, Key = CollectionId, Value = u32, QueryKind = ValueQuery>;
+
+	/// Amount of burnt tokens in a collection.
+	#[pallet::storage]
+	pub type TokensBurnt<T: Config> =
+		StorageMap<Hasher = Twox64Concat, Key = CollectionId, Value = u32, QueryKind = ValueQuery>;
+
+	/// Token data, used to partially describe a token.
+	#[pallet::storage]
+	#[pallet::getter(fn token_data)]
+	pub type TokenData<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = ItemData<T::AccountId>,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Map of key-value pairs, describing the metadata of a token.
+	#[pallet::storage]
+	#[pallet::getter(fn token_properties)]
+	pub type TokenProperties<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = collection_primitives::Properties,
+		QueryKind = ValueQuery,
+		OnEmpty = collection_primitives::TokenProperties,
+	>;
+
+	/// Used to enumerate tokens owned by account.
+	#[pallet::storage]
+	pub type Owned<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			Key<Blake2_128Concat, T::AccountId>,
+			Key<Twox64Concat, TokenId>,
+		),
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Amount of tokens owned by an account in a collection.
+	#[pallet::storage]
+	pub type AccountBalance<T: Config> = StorageNMap<
+		Key = (
+			Key<Twox64Concat, CollectionId>,
+			// Owner
+			Key<Blake2_128Concat, T::AccountId>,
+		),
+		Value = u32,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Allowance set by a token owner for another user to transfer or burn a token.
+	#[pallet::storage]
+	pub type Allowance<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		// Spender
+		Value = T::AccountId,
+		QueryKind = OptionQuery,
+	>;
+
+	/// Last sponsoring of NFT transfer
+	#[pallet::storage]
+	#[pallet::getter(fn nft_transfer_basket)]
+	pub type NftTransferBasket<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	/// Last sponsoring of NFT approval
+	#[pallet::storage]
+	#[pallet::getter(fn nft_approve_basket)]
+	pub type NftApproveBasket<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CollectionId,
+		Blake2_128Concat,
+		TokenId,
+		T::BlockNumber,
+		OptionQuery,
+	>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create NFT collection
+		///
+		/// `init_collection` will take non-refundable deposit for collection creation.
+		///
+		/// - `data`: Contains settings for collection limits and permissions.
+		///   Collection mode should be `CollectionMode::NFT`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::init_collection(
+			data.property_permissions.len().try_into().unwrap(),
+			data.properties.len().try_into().unwrap()
+		))]
+		pub fn init_collection(
+			origin: OriginFor<T>,
+			data: CreateCollectionData<T::AccountId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::_init_collection(who.clone(), who, data)?;
+
+			Ok(())
+		}
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_collection())]
+		pub fn destroy_collection(
+			origin: OriginFor<T>,
+			collection_id: CollectionId
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_destroy_collection(collection, &who)
+		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_collection_property())]
+		pub fn set_collection_property(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			property: Property
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			<PalletCommon<T>>::set_collection_property(&collection, &who, property)
+		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::delete_collection_property())]
+		pub fn delete_collection_property(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			property_key: PropertyKey
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			<PalletCommon<T>>::delete_collection_property(&collection, &who, property_key)
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_collection_properties(properties.len().try_into().unwrap_or(MAX_PROPERTIES_PER_ITEM)))]
+		pub fn set_collection_properties(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			properties: Vec<Property>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			<PalletCommon<T>>::set_collection_properties(&collection, &who, properties)
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::delete_collection_properties(property_keys.len().try_into().unwrap_or(MAX_PROPERTIES_PER_ITEM)))]
+		pub fn delete_collection_properties(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			property_keys: Vec<PropertyKey>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			<PalletCommon<T>>::delete_collection_properties(&collection, &who, property_keys)
+		}
+
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_property_permission())]
+		pub fn set_property_permission(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			property_permission: PropertyKeyPermission
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			<PalletCommon<T>>::set_property_permission(&collection, &who, property_permission)
+		}
+
+		/// Mint NFT token.
+		///
+		/// - `data`: Owner of the token and its initial properties.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_item(
+			data.properties.len().try_into().unwrap_or(MAX_PROPERTIES_PER_ITEM)
+		))]
+		pub fn create_item(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			data: CreateNftExData<T::AccountId>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_create_item(&collection, &who, data)
+		}
+
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_max_item().saturating_mul(data.len().try_into().unwrap_or(MAX_ITEMS_PER_BATCH as u64)))]
+		pub fn create_multiple_items(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			data: Vec<CreateNftExData<T::AccountId>>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_create_multiple_items(&collection, &who, data)
+		}
+
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_token_property())]
+		pub fn set_token_property(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			property: Property
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_set_token_properties(&collection, &who, token_id, [property].into_iter(), false)
+		}
+
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_token_properties(properties.len().try_into().unwrap_or(MAX_PROPERTIES_PER_ITEM)))]
+		pub fn set_token_properties(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			properties: Vec<Property>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_set_token_properties(&collection, &who, token_id, properties.into_iter(), false)
+		}
+
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::delete_token_property())]
+		pub fn delete_token_property(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			property_key: PropertyKey
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_delete_token_properties(&collection, &who, token_id, [property_key].into_iter())
+		}
+
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::delete_token_properties(properties_keys.len().try_into().unwrap_or(MAX_PROPERTIES_PER_ITEM)))]
+		pub fn delete_token_properties(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			properties_keys: Vec<PropertyKey>
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_delete_token_properties(&collection, &who, token_id, properties_keys.into_iter())
+		}
+
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer())]
+		pub fn transfer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			to: T::AccountId
+		) -> DispatchResult {
+			let from = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_transfer(&collection, &from, &to, token_id)
+		}
+
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_from())]
+		pub fn transfer_from(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			from: T::AccountId,
+			to: T::AccountId
+		) -> DispatchResult {
+			let spender = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_transfer_from(&collection, &spender, &from, &to, token_id)
+		}
+
+		/// Allow `spender` to transfer or burn the token on behalf of the sender.
+		///
+		/// - `spender`: New spender of the token. `None` removes the allowance.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve())]
+		pub fn approve(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			spender: Option<T::AccountId>
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_approve(&collection, &sender, token_id, spender.as_ref())
+		}
+
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::burn())]
+		pub fn burn(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_burn(&collection, &who, token_id)
+		}
+
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::burn_from())]
+		pub fn burn_from(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			from: T::AccountId
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			Self::_burn_from(&collection, &who, &from, token_id)
+		}
+
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::toggle_admin())]
+		pub fn toggle_admin(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			user: T::AccountId,
+			admin: bool
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let collection = NonfungibleHandle::try_get(collection_id)?;
+
+			<PalletCommon<T>>::toggle_admin(&collection, &who, &user, admin)
+		}
+
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsor())]
+		pub fn set_sponsor(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			sponsor: T::AccountId
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			NonfungibleHandle::<T>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::set_sponsor(collection_id, &sender, sponsor)
+		}
+
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::confirm_sponsorship())]
+		pub fn confirm_sponsorship(
+			origin: OriginFor<T>,
+			collection_id: CollectionId
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			NonfungibleHandle::<T>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::confirm_sponsorship(collection_id, &sender)
+		}
+
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sponsor())]
+		pub fn remove_sponsor(
+			origin: OriginFor<T>,
+			collection_id: CollectionId
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			NonfungibleHandle::<T>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::remove_sponsor(collection_id, &sender)
+		}
+
+		/// Propose a new owner of the collection.
+		///
+		/// - `new_owner`: Whitelist admin who will own the collection.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_collection_owner())]
+		pub fn propose_collection_owner(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			new_owner: T::AccountId
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			NonfungibleHandle::<T>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::propose_collection_owner(collection_id, &sender, new_owner)
+		}
+
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_collection_ownership_transfer())]
+		pub fn cancel_collection_ownership_transfer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			NonfungibleHandle::<T>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::cancel_collection_ownership_transfer(collection_id, &sender)
+		}
+
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_collection_ownership())]
+		pub fn accept_collection_ownership(
+			origin: OriginFor<T>,
+			collection_id: CollectionId
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			NonfungibleHandle::<T>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::accept_collection_ownership(collection_id, &sender)
+		}
+
+		/// Set restrictions on sponsored transactions of the collection.
+		///
+		/// Only collection owner or confirmed sponsor can change sponsorship settings.
+		/// Budget usage is reset.
+		///
+		/// - `settings`: Sponsorship period, budget, per-account quota and sponsored accounts.
+		///   `None` removes all restrictions.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsorship_settings())]
+		pub fn set_sponsorship_settings(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			settings: Option<SponsorshipSettings<BalanceOf<T>, T::BlockNumber>>
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			NonfungibleHandle::<T>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::set_sponsorship_settings(collection_id, &sender, settings)
+		}
+
+		/// Add an account to or remove it from the sponsor allowlist of the collection.
+		///
+		/// Only collection owner or confirmed sponsor can change the allowlist.
+		///
+		/// - `account`: Account which status is changed.
+		/// - `value`: Whether transactions of the account should be sponsored.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config>::WeightInfo::toggle_sponsor_allowlist())]
+		pub fn toggle_sponsor_allowlist(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			account: T::AccountId,
+			value: bool
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			NonfungibleHandle::<T>::try_get(collection_id)?;
+
+			<PalletCommon<T>>::toggle_sponsor_allowlist(collection_id, &sender, account, value)
+		}
+	}
+}
+
+// Collection related methods
+impl<T: Config> Pallet<T> {
+	/// Create NFT collection
+	///
+	/// `init_collection` will take non-refundable deposit for collection creation.
+	fn _init_collection(
+		owner: T::AccountId,
+		payer: T::AccountId,
+		data: CreateCollectionData<T::AccountId>,
+	) -> Result<CollectionId, DispatchError> {
+		ensure!(
+			data.mode == CollectionMode::NFT,
+			<CommonError<T>>::UnsupportedOperation
+		);
+
+		<PalletCommon<T>>::init_collection(owner, payer, data)
+	}
+
+	/// Destroy NFT collection
+	///
+	/// `destroy_collection` will throw error if collection contains any tokens.
+	/// Only owner can destroy collection.
+	fn _destroy_collection(
+		collection: NonfungibleHandle<T>,
+		sender: &T::AccountId,
+	) -> DispatchResult {
+		let id = collection.id;
+
+		if Self::_collection_has_tokens(id) {
+			return Err(<CommonError<T>>::CantDestroyNotEmptyCollection.into());
+		}
+
+		// =========
+
+		PalletCommon::destroy_collection(collection.0, sender)?;
+
+		<TokensMinted<T>>::remove(id);
+		<TokensBurnt<T>>::remove(id);
+		let _ = <TokenData<T>>::clear_prefix((id,), u32::MAX, None);
+		let _ = <Allowance<T>>::clear_prefix((id,), u32::MAX, None);
+		let _ = <Owned<T>>::clear_prefix((id,), u32::MAX, None);
+		let _ = <AccountBalance<T>>::clear_prefix((id,), u32::MAX, None);
+		let _ = <NftTransferBasket<T>>::clear_prefix(id, u32::MAX, None);
+		let _ = <NftApproveBasket<T>>::clear_prefix(id, u32::MAX, None);
+		Ok(())
+	}
+
+	fn _collection_has_tokens(collection_id: CollectionId) -> bool {
+		<TokenData<T>>::iter_prefix((collection_id,))
+			.next()
+			.is_some()
+	}
+}
+
+// Token related methods
+impl<T: Config> Pallet<T> {
+	/// Create NFT token.
+	///
+	/// The sender should be the owner/admin of the collection.
+	///
+	/// - `data`: Contains owner of the token and its initial properties.
+	fn _create_item(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::AccountId,
+		data: CreateNftExData<T::AccountId>,
+	) -> DispatchResult {
+		Self::_create_multiple_items(collection, sender, vec![data])
+	}
+
+	/// Batched operation to create multiple NFT tokens.
+	///
+	/// Same as `create_item` but creates multiple tokens.
+	fn _create_multiple_items(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::AccountId,
+		data: Vec<CreateNftExData<T::AccountId>>,
+	) -> DispatchResult {
+		collection.check_is_owner_or_admin(sender)?;
+		for item in data.iter() {
+			Self::_check_whitelisted_or_collection_admin(collection, &item.owner)?;
+		}
+
+		let first_token_id = <TokensMinted<T>>::get(collection.id);
+		let tokens_minted = first_token_id
+			.checked_add(data.len() as u32)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			tokens_minted < collection.limits.token_limit(),
+			<CommonError<T>>::CollectionTokenLimitExceeded
+		);
+
+		let mut balances = BTreeMap::new();
+		for item in &data {
+			let balance = balances
+				.entry(&item.owner)
+				.or_insert_with(|| <AccountBalance<T>>::get((collection.id, &item.owner)));
+			*balance = balance.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+
+			ensure!(
+				*balance <= collection.limits.account_token_ownership_limit(),
+				<CommonError<T>>::AccountTokenLimitExceeded,
+			);
+		}
+
+		// =========
+
+		with_transaction(|| {
+			for (i, item) in data.iter().enumerate() {
+				let token_id = TokenId(first_token_id + i as u32 + 1);
+				<TokenData<T>>::insert((collection.id, token_id), ItemData {
+					owner: item.owner.clone(),
+				});
+				<Owned<T>>::insert((collection.id, &item.owner, token_id), true);
+
+				if let Err(e) = Self::_set_token_properties(
+					collection,
+					sender,
+					token_id,
+					item.properties.clone().into_iter(),
+					true,
+				) {
+					return TransactionOutcome::Rollback(Err(e));
+				}
+			}
+			TransactionOutcome::Commit(Ok(()))
+		})?;
+
+		<TokensMinted<T>>::insert(collection.id, tokens_minted);
+
+		for (account, balance) in balances {
+			<AccountBalance<T>>::insert((collection.id, account), balance);
+		}
+
+		for (i, item) in data.into_iter().enumerate() {
+			<PalletCommon<T>>::deposit_event(CommonEvent::ItemCreated(
+				collection.id,
+				TokenId(first_token_id + i as u32 + 1),
+				item.owner,
+				1,
+			));
+		}
+		Ok(())
+	}
+
+	// Check that user is either whitelisted or collection admin
+	fn _check_whitelisted_or_collection_admin(collection: &NonfungibleHandle<T>, user: &T::AccountId) -> DispatchResult {
+		ensure!(
+			T::Whitelist::is_active_investor(user) || collection.is_owner_or_admin(user),
+			<Error<T>>::NeitherWhitelistedNorCollectionAdmin
+		);
+
+		Ok(())
+	}
+
+	fn _set_token_properties(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::AccountId,
+		token_id: TokenId,
+		properties: impl Iterator<Item = Property>,
+		is_token_create: bool,
+	) -> DispatchResult {
+		Self::_modify_token_properties(
+			collection,
+			sender,
+			token_id,
+			properties.map(|p| (p.key, Some(p.value))),
+			is_token_create,
+		)
+	}
+
+	fn _delete_token_properties(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::AccountId,
+		token_id: TokenId,
+		property_keys: impl Iterator<Item = PropertyKey>,
+	) -> DispatchResult {
+		let is_token_create = false;
+
+		Self::_modify_token_properties(
+			collection,
+			sender,
+			token_id,
+			property_keys.map(|key| (key, None)),
+			is_token_create,
+		)
+	}
+
+	// Same rules as for RFT tokens: properties are set on creation by
+	// owner or admins of the collection, and after that according to
+	// property permissions.
+	#[transactional]
+	fn _modify_token_properties(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::AccountId,
+		token_id: TokenId,
+		properties: impl Iterator<Item = (PropertyKey, Option<PropertyValue>)>,
+		is_token_create: bool,
+	) -> DispatchResult {
+		if is_token_create {
+			collection.check_is_owner_or_admin(sender)?;
+		}
+		let owner = Self::token_owner(collection.id, token_id)
+			.ok_or(<CommonError<T>>::TokenNotFound)?;
+
+		for (key, value) in properties {
+			if !is_token_create {
+				<PalletCommon<T>>::check_token_property_permission(
+					collection,
+					sender,
+					&key,
+					|| owner == *sender,
+				)?;
+			}
+
+			match value {
+				Some(value) => {
+					<TokenProperties<T>>::try_mutate((collection.id, token_id), |properties| {
+						properties.try_set(key.clone(), value)
+					})
+					.map_err(<CommonError<T>>::from)?;
+
+					<PalletCommon<T>>::deposit_event(CommonEvent::TokenPropertySet(
+						collection.id,
+						token_id,
+						key,
+					));
+				}
+				None => {
+					<TokenProperties<T>>::try_mutate((collection.id, token_id), |properties| {
+						properties.remove(&key)
+					})
+					.map_err(<CommonError<T>>::from)?;
+
+					<PalletCommon<T>>::deposit_event(CommonEvent::TokenPropertyDeleted(
+						collection.id,
+						token_id,
+						key,
+					));
+				}
+			}
+		}
+
+		Ok(())
+	}
+
+	/// Transfer NFT token from one account to another.
+	///
+	/// Allowance set for the token is removed.
+	///
+	/// - `from`: Owner of the token.
+	/// - `to`: Recepient of the token.
+	fn _transfer(
+		collection: &NonfungibleHandle<T>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token: TokenId,
+	) -> DispatchResult {
+		ensure!(
+			collection.limits.transfers_enabled(),
+			<CommonError<T>>::TransfersDisabled
+		);
+
+		Self::_check_whitelisted_or_collection_admin(collection, from)?;
+		Self::_check_whitelisted_or_collection_admin(collection, to)?;
+
+		let token_data =
+			<TokenData<T>>::get((collection.id, token)).ok_or(<CommonError<T>>::TokenNotFound)?;
+		ensure!(&token_data.owner == from, <CommonError<T>>::MustBeTokenOwner);
+
+		let balance_from = <AccountBalance<T>>::get((collection.id, from))
+			.checked_sub(1)
+			// Should not occur
+			.ok_or(ArithmeticError::Underflow)?;
+		let balance_to = if from != to {
+			let balance_to = <AccountBalance<T>>::get((collection.id, to))
+				.checked_add(1)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				balance_to < collection.limits.account_token_ownership_limit(),
+				<CommonError<T>>::AccountTokenLimitExceeded,
+			);
+
+			Some(balance_to)
+		} else {
+			None
+		};
+
+		// =========
+
+		if let Some(balance_to) = balance_to {
+			// from != to
+			if balance_from == 0 {
+				<AccountBalance<T>>::remove((collection.id, from));
+			} else {
+				<AccountBalance<T>>::insert((collection.id, from), balance_from);
+			}
+			<AccountBalance<T>>::insert((collection.id, to), balance_to);
+			<Owned<T>>::remove((collection.id, from, token));
+			<Owned<T>>::insert((collection.id, to, token), true);
+			<TokenData<T>>::insert((collection.id, token), ItemData {
+				owner: to.clone(),
+			});
+		}
+		<Allowance<T>>::remove((collection.id, token));
+
+		<PalletCommon<T>>::deposit_event(CommonEvent::Transfer(
+			collection.id,
+			token,
+			from.clone(),
+			to.clone(),
+			1,
+		));
+
+		Ok(())
+	}
+
+	/// Transfer NFT token from one account to another.
+	///
+	/// Same as the [`transfer`] but spender doesn't needs to be an owner of the token.
+	/// The owner should approve the spender to transfer the token.
+	///
+	/// [`transfer`]: struct.Pallet.html#method.transfer
+	fn _transfer_from(
+		collection: &NonfungibleHandle<T>,
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token: TokenId,
+	) -> DispatchResult {
+		Self::_check_allowed(collection, spender, from, token)?;
+
+		// =========
+
+		Self::_transfer(collection, from, to, token)
+	}
+
+	// Check that spender is allowed to transfer or burn the token of `from`
+	fn _check_allowed(
+		collection: &NonfungibleHandle<T>,
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		token: TokenId,
+	) -> DispatchResult {
+		if spender == from {
+			return Ok(());
+		}
+
+		Self::_check_whitelisted_or_collection_admin(collection, spender)?;
+		Self::_check_whitelisted_or_collection_admin(collection, from)?;
+
+		if <Allowance<T>>::get((collection.id, token)).as_ref() != Some(spender) {
+			ensure!(
+				collection.ignores_allowance(spender),
+				<CommonError<T>>::ApprovedValueTooLow
+			);
+		}
+		Ok(())
+	}
+
+	/// Set or remove the account allowed to `transfer` or `burn` sender's token.
+	fn _approve(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::AccountId,
+		token: TokenId,
+		spender: Option<&T::AccountId>,
+	) -> DispatchResult {
+		Self::_check_whitelisted_or_collection_admin(collection, sender)?;
+		if let Some(spender) = spender {
+			Self::_check_whitelisted_or_collection_admin(collection, spender)?;
+		}
+
+		let token_data =
+			<TokenData<T>>::get((collection.id, token)).ok_or(<CommonError<T>>::TokenNotFound)?;
+		if &token_data.owner != sender {
+			ensure!(
+				collection.ignores_owned_amount(sender),
+				<CommonError<T>>::CantApproveMoreThanOwned
+			);
+		}
+
+		// =========
+
+		Self::_set_allowance_unchecked(collection, sender, token, spender);
+		Ok(())
+	}
+
+	fn _set_allowance_unchecked(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::AccountId,
+		token: TokenId,
+		spender: Option<&T::AccountId>,
+	) {
+		match spender {
+			Some(spender) => {
+				<Allowance<T>>::insert((collection.id, token), spender);
+				<PalletCommon<T>>::deposit_event(CommonEvent::Approved(
+					collection.id,
+					token,
+					sender.clone(),
+					spender.clone(),
+					1,
+				));
+			}
+			None => {
+				if let Some(old_spender) = <Allowance<T>>::take((collection.id, token)) {
+					<PalletCommon<T>>::deposit_event(CommonEvent::Approved(
+						collection.id,
+						token,
+						sender.clone(),
+						old_spender,
+						0,
+					));
+				}
+			}
+		}
+	}
+
+	/// Burn NFT token
+	///
+	/// Sender should be the owner of the token and the owner or admin of the collection.
+	fn _burn(
+		collection: &NonfungibleHandle<T>,
+		sender: &T::AccountId,
+		token: TokenId,
+	) -> DispatchResult {
+		collection.check_is_owner_or_admin(sender)?;
+
+		Self::_burn_unchecked(collection, sender, token)
+	}
+
+	/// Burn NFT token of another account.
+	///
+	/// Same as the [`burn`] but spender doesn't need to be an owner of the token.
+	/// The owner should approve the spender to burn the token.
+	///
+	/// [`burn`]: struct.Pallet.html#method.burn
+	fn _burn_from(
+		collection: &NonfungibleHandle<T>,
+		spender: &T::AccountId,
+		from: &T::AccountId,
+		token: TokenId,
+	) -> DispatchResult {
+		collection.check_is_owner_or_admin(spender)?;
+		Self::_check_allowed(collection, spender, from, token)?;
+
+		// =========
+
+		Self::_burn_unchecked(collection, from, token)
+	}
+
+	fn _burn_unchecked(
+		collection: &NonfungibleHandle<T>,
+		owner: &T::AccountId,
+		token: TokenId,
+	) -> DispatchResult {
+		let token_data =
+			<TokenData<T>>::get((collection.id, token)).ok_or(<CommonError<T>>::TokenNotFound)?;
+		ensure!(&token_data.owner == owner, <CommonError<T>>::MustBeTokenOwner);
+
+		let burnt = <TokensBurnt<T>>::get(collection.id)
+			.checked_add(1)
+			.ok_or(ArithmeticError::Overflow)?;
+		let balance = <AccountBalance<T>>::get((collection.id, owner))
+			.checked_sub(1)
+			// Should not occur
+			.ok_or(ArithmeticError::Underflow)?;
+
+		// =========
+
+		if balance == 0 {
+			<AccountBalance<T>>::remove((collection.id, owner));
+		} else {
+			<AccountBalance<T>>::insert((collection.id, owner), balance);
+		}
+		<Owned<T>>::remove((collection.id, owner, token));
+		<TokensBurnt<T>>::insert(collection.id, burnt);
+		<TokenData<T>>::remove((collection.id, token));
+		<TokenProperties<T>>::remove((collection.id, token));
+		<Allowance<T>>::remove((collection.id, token));
+		<NftTransferBasket<T>>::remove(collection.id, token);
+		<NftApproveBasket<T>>::remove(collection.id, token);
+
+		<PalletCommon<T>>::deposit_event(CommonEvent::ItemDestroyed(
+			collection.id,
+			token,
+			owner.clone(),
+			1,
+		));
+		Ok(())
+	}
+}
+
+// Support methods
+impl<T: Config> Pallet<T> {
+	/// Get number of NFT tokens in collection
+	pub fn total_supply(collection: &NonfungibleHandle<T>) -> u32 {
+		<TokensMinted<T>>::get(collection.id) - <TokensBurnt<T>>::get(collection.id)
+	}
+
+	/// Get owner of NFT token
+	pub fn token_owner(collection_id: CollectionId, token: TokenId) -> Option<T::AccountId> {
+		<TokenData<T>>::get((collection_id, token)).map(|data| data.owner)
+	}
+
+	/// Check that NFT token exists
+	///
+	/// - `token`: Token ID.
+	fn token_exists(collection: &NonfungibleHandle<T>, token: TokenId) -> bool {
+		<TokenData<T>>::contains_key((collection.id, token))
+	}
+}
+
+// RPC methods
+impl<T: Config> Pallet<T> {
+	/// Get token properties according to given keys.
+	pub fn filter_token_properties(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		keys: Option<Vec<PropertyKey>>,
+	) -> Vec<Property> {
+		<PalletCommon<T>>::filter_properties(<TokenProperties<T>>::get((collection_id, token_id)), keys)
+	}
+
+	/// Get amount of the token owned by the account, either 0 or 1.
+	pub fn token_balance(collection_id: CollectionId, token: TokenId, account: &T::AccountId) -> u128 {
+		(Self::token_owner(collection_id, token).as_ref() == Some(account)).into()
+	}
+
+	/// Get total amount of the token, either 0 or 1.
+	pub fn token_total_pieces(collection_id: CollectionId, token: TokenId) -> u128 {
+		<TokenData<T>>::contains_key((collection_id, token)).into()
+	}
+
+	/// Check whether the spender is allowed to transfer the token on behalf of the owner, either 0 or 1.
+	pub fn token_allowance(
+		collection_id: CollectionId,
+		token: TokenId,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+	) -> u128 {
+		let is_owner = Self::token_owner(collection_id, token).as_ref() == Some(owner);
+		let is_spender = <Allowance<T>>::get((collection_id, token)).as_ref() == Some(spender);
+		(is_owner && is_spender).into()
+	}
+
+	/// Get id of the last token minted in the collection.
+	///
+	/// Returns `TokenId(0)` if no tokens were minted yet.
+	pub fn last_token_id(collection_id: CollectionId) -> TokenId {
+		TokenId(<TokensMinted<T>>::get(collection_id))
+	}
+}
@@ -0,0 +1,259 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use frame_support::{
+	construct_runtime, ord_parameter_types, parameter_types,
+	traits::{ConstU32, Everything}
+};
+use frame_system::EnsureSignedBy;
+use sp_runtime::{
+	DispatchError,
+	traits::{AccountIdLookup, BlakeTwo256}
+};
+
+use pallet_whitelist::{Investor, InvestorKey};
+pub use pallet_common::{
+	Event as CommonEvent,
+	Error as CommonError,
+	collection_initializer::CollectionInitializer
+};
+
+use primitives::{
+	Index, BlockNumber, Hash, Header, BlockHashCount, Balance
+};
+pub use collection_primitives::{
+	CollectionId, CollectionMode, CollectionPropertiesVec, CreateNftExData, Property,
+	PropertyKeyPermission, PropertyPermission, TokenId
+};
+
+pub use mock_support::primitives::*;
+pub use mock_support::accounts::*;
+pub use mock_support::consts::*;
+pub use mock_support::collections::*;
+
+mod pallet_nonfungible {
+	pub use super::super::*;
+}
+
+// Mock accounts
+pub const ROLES_ROOT: AccountId = 777;
+pub const ADMIN_1: AccountId = 101;
+pub const ADMIN_2: AccountId = 102;
+
+impl frame_system::Config for MockRuntime {
+	type AccountId = AccountId;
+	type RuntimeCall = RuntimeCall;
+	type Lookup = AccountIdLookup<AccountId, ()>;
+	type Index = Index;
+	type BlockNumber = BlockNumber;
+	type Hash = Hash;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 2;
+}
+
+impl pallet_balances::Config for MockRuntime {
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<50>;
+	type MaxHolds = ConstU32<50>;
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type HoldIdentifier = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+ord_parameter_types! {
+	pub const RolesRootAccountId: AccountId = ROLES_ROOT;
+}
+
+impl pallet_whitelist::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type RolesRoot = EnsureSignedBy<RolesRootAccountId, AccountId>;
+	type OffchainSignature = sp_runtime::testing::TestSignature;
+	type OffchainPublic = sp_runtime::testing::UintAuthorityId;
+	type MaxKycAttributesLength = frame_support::traits::ConstU32<256>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const CollectionCreationPrice: Balance = 1_000 * DOLLARS;
+	pub const TreasuryAccountId: AccountId = 666;
+}
+
+impl pallet_common::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type CollectionCreationPrice = CollectionCreationPrice;
+	type TreasuryAccountId = TreasuryAccountId;
+	type Whitelist = Whitelist;
+}
+
+impl pallet_nonfungible::Config for MockRuntime {
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<MockRuntime>;
+type Block = frame_system::mocking::MockBlock<MockRuntime>;
+
+construct_runtime! {
+	pub enum MockRuntime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Whitelist: pallet_whitelist,
+		Common: pallet_common,
+		Balances: pallet_balances,
+		Nonfungible: pallet_nonfungible,
+	}
+}
+
+pub struct ExtBuilder {
+	investors: Vec<(InvestorKey, Investor<AccountId>)>,
+	admins: Vec<AccountId>,
+	balances: Vec<(AccountId, Balance)>
+}
+
+impl ExtBuilder {
+	pub fn new() -> Self {
+		Self {
+			investors: vec![],
+			admins: vec![],
+			balances: vec![]
+		}
+	}
+
+	pub fn investors(self, investors: Vec<AccountId>) -> Self {
+		let investors = investors
+			.iter()
+			.enumerate()
+			.map(|(i, account)| {
+				(
+					[i as u8; 32],
+					Investor {
+						account: account.clone(),
+						is_active: true
+					}
+				)
+			})
+			.collect();
+
+		Self {
+			investors,
+			..self
+		}
+	}
+
+	pub fn wl_admins(self, admins: Vec<AccountId>) -> Self {
+		Self {
+			admins,
+			..self
+		}
+	}
+
+	pub fn balances(self, balances: Vec<(AccountId, Balance)>) -> Self {
+		Self {
+			balances,
+			..self
+		}
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		use frame_support::traits::GenesisBuild;
+
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<MockRuntime>()
+			.unwrap();
+
+		WhitelistConfig {
+			investors: self.investors,
+			admins: self.admins,
+			..Default::default()
+		}.assimilate_storage(&mut t).unwrap();
+
+		BalancesConfig {
+			balances: self.balances
+		}.assimilate_storage(&mut t).unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+pub fn init_nft_collection(owner: AccountId) -> CollectionId {
+	CollectionInitializer::<MockRuntime>::new()
+		.mode(CollectionMode::NFT)
+		.init(owner)
+		.expect("Collection init failed")
+}
+
+pub fn create_nft_data(owner: AccountId, properties: Vec<Property>) -> CreateNftExData<AccountId> {
+	CreateNftExData {
+		properties: CollectionPropertiesVec::truncate_from(properties),
+		owner,
+	}
+}
+
+pub fn create_nft(collection_id: CollectionId, sender: AccountId, owner: AccountId) -> Result<TokenId, DispatchError> {
+	Nonfungible::create_item(RuntimeOrigin::signed(sender), collection_id, create_nft_data(owner, vec![]))?;
+	Ok(get_token_id_from_last_event())
+}
+
+pub fn get_token_id_from_last_event() -> TokenId {
+	for event_record in System::events().iter().rev() {
+		if let RuntimeEvent::Common(CommonEvent::ItemCreated(_, token_id, _, _)) = event_record.event {
+			return token_id;
+		}
+	}
+
+	panic!("ItemCreated event not found");
+}
@@ -0,0 +1,545 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+use frame_support::{
+	assert_ok, assert_noop
+};
+
+use crate::mock::*;
+use crate::{
+	Error,
+	TokenData, Owned, AccountBalance, Allowance,
+	TokensMinted, TokensBurnt, TokenProperties, ItemData
+};
+
+#[test]
+pub fn init_collection_works() {
+	ExtBuilder::new()
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let mut data = default_create_collection_data::<MockRuntime>();
+		data.mode = CollectionMode::NFT;
+
+		assert_ok!(Nonfungible::init_collection(RuntimeOrigin::signed(ADMIN_1), data));
+
+		System::assert_last_event(RuntimeEvent::Common(CommonEvent::CollectionCreated(
+			CollectionId(1),
+			CollectionMode::NFT.id(),
+			ADMIN_1
+		)));
+	});
+}
+
+#[test]
+pub fn init_collection_fails_if_not_nft_mode() {
+	ExtBuilder::new()
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let data = default_create_collection_data::<MockRuntime>();
+
+		assert_noop!(
+			Nonfungible::init_collection(RuntimeOrigin::signed(ADMIN_1), data),
+			CommonError::<MockRuntime>::UnsupportedOperation
+		);
+	});
+}
+
+#[test]
+pub fn calls_fail_for_refungible_collection() {
+	ExtBuilder::new()
+	.investors(vec![ALICE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = CollectionInitializer::<MockRuntime>::new()
+			.init(ADMIN_1)
+			.expect("Collection init failed");
+
+		assert_noop!(
+			create_nft(collection_id, ADMIN_1, ALICE),
+			CommonError::<MockRuntime>::UnsupportedOperation
+		);
+	});
+}
+
+#[test]
+pub fn destroy_collection_works() {
+	ExtBuilder::new()
+	.investors(vec![ALICE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ADMIN_1).unwrap();
+
+		assert_noop!(
+			Nonfungible::destroy_collection(RuntimeOrigin::signed(ADMIN_1), collection_id),
+			CommonError::<MockRuntime>::CantDestroyNotEmptyCollection
+		);
+
+		assert_ok!(Nonfungible::burn(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id));
+		assert_ok!(Nonfungible::destroy_collection(RuntimeOrigin::signed(ADMIN_1), collection_id));
+
+		assert_eq!(<TokensMinted<MockRuntime>>::contains_key(collection_id), false);
+		assert_eq!(<TokensBurnt<MockRuntime>>::contains_key(collection_id), false);
+		assert_eq!(<AccountBalance<MockRuntime>>::iter_prefix_values((collection_id,)).next(), None);
+	});
+}
+
+#[test]
+pub fn create_item_works() {
+	ExtBuilder::new()
+	.investors(vec![ALICE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let data = create_nft_data(ALICE, default_token_properties());
+
+		assert_ok!(Nonfungible::create_item(RuntimeOrigin::signed(ADMIN_1), collection_id, data));
+
+		let token_id = TokenId(1);
+		assert_eq!(<TokenData<MockRuntime>>::get((collection_id, token_id)), Some(ItemData { owner: ALICE }));
+		assert!(<Owned<MockRuntime>>::get((collection_id, ALICE, token_id)));
+		assert_eq!(<AccountBalance<MockRuntime>>::get((collection_id, ALICE)), 1);
+		assert_eq!(<TokensMinted<MockRuntime>>::get(collection_id), 1);
+		assert_eq!(
+			<TokenProperties<MockRuntime>>::get((collection_id, token_id)).into_iter().count(),
+			default_token_properties().len()
+		);
+
+		System::assert_has_event(RuntimeEvent::Common(CommonEvent::ItemCreated(
+			collection_id,
+			token_id,
+			ALICE,
+			1
+		)));
+	});
+}
+
+#[test]
+pub fn create_multiple_items_works() {
+	ExtBuilder::new()
+	.investors(vec![ALICE, BOB])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let data = vec![
+			create_nft_data(ALICE, vec![]),
+			create_nft_data(BOB, vec![]),
+			create_nft_data(ALICE, vec![]),
+		];
+
+		assert_ok!(Nonfungible::create_multiple_items(RuntimeOrigin::signed(ADMIN_1), collection_id, data));
+
+		assert_eq!(<TokensMinted<MockRuntime>>::get(collection_id), 3);
+		assert_eq!(<AccountBalance<MockRuntime>>::get((collection_id, ALICE)), 2);
+		assert_eq!(<AccountBalance<MockRuntime>>::get((collection_id, BOB)), 1);
+		assert_eq!(Nonfungible::token_owner(collection_id, TokenId(2)), Some(BOB));
+		assert_eq!(Nonfungible::token_owner(collection_id, TokenId(3)), Some(ALICE));
+	});
+}
+
+#[test]
+pub fn create_item_fails_if_not_owner_or_admin() {
+	ExtBuilder::new()
+	.investors(vec![ALICE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+
+		assert_noop!(
+			create_nft(collection_id, ALICE, ALICE),
+			CommonError::<MockRuntime>::NoPermission
+		);
+	});
+}
+
+#[test]
+pub fn create_item_fails_if_owner_not_whitelisted() {
+	ExtBuilder::new()
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+
+		assert_noop!(
+			create_nft(collection_id, ADMIN_1, ALICE),
+			Error::<MockRuntime>::NeitherWhitelistedNorCollectionAdmin
+		);
+	});
+}
+
+#[test]
+pub fn transfer_works() {
+	ExtBuilder::new()
+	.investors(vec![ALICE, BOB])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_ok!(Nonfungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB));
+
+		assert_eq!(Nonfungible::token_owner(collection_id, token_id), Some(BOB));
+		assert!(!<Owned<MockRuntime>>::get((collection_id, ALICE, token_id)));
+		assert!(<Owned<MockRuntime>>::get((collection_id, BOB, token_id)));
+		assert_eq!(<AccountBalance<MockRuntime>>::get((collection_id, ALICE)), 0);
+		assert_eq!(<AccountBalance<MockRuntime>>::get((collection_id, BOB)), 1);
+
+		System::assert_last_event(RuntimeEvent::Common(CommonEvent::Transfer(
+			collection_id,
+			token_id,
+			ALICE,
+			BOB,
+			1
+		)));
+	});
+}
+
+#[test]
+pub fn transfer_fails_if_not_token_owner() {
+	ExtBuilder::new()
+	.investors(vec![ALICE, BOB])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_noop!(
+			Nonfungible::transfer(RuntimeOrigin::signed(BOB), collection_id, token_id, BOB),
+			CommonError::<MockRuntime>::MustBeTokenOwner
+		);
+		assert_noop!(
+			Nonfungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, TokenId(2), BOB),
+			CommonError::<MockRuntime>::TokenNotFound
+		);
+	});
+}
+
+#[test]
+pub fn transfer_fails_if_receiver_not_whitelisted() {
+	ExtBuilder::new()
+	.investors(vec![ALICE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_noop!(
+			Nonfungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB),
+			Error::<MockRuntime>::NeitherWhitelistedNorCollectionAdmin
+		);
+	});
+}
+
+#[test]
+pub fn approve_and_transfer_from_works() {
+	ExtBuilder::new()
+	.investors(vec![ALICE, BOB, CHARLIE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_ok!(Nonfungible::approve(RuntimeOrigin::signed(ALICE), collection_id, token_id, Some(BOB)));
+		assert_eq!(<Allowance<MockRuntime>>::get((collection_id, token_id)), Some(BOB));
+		System::assert_last_event(RuntimeEvent::Common(CommonEvent::Approved(
+			collection_id,
+			token_id,
+			ALICE,
+			BOB,
+			1
+		)));
+
+		assert_ok!(Nonfungible::transfer_from(RuntimeOrigin::signed(BOB), collection_id, token_id, ALICE, CHARLIE));
+
+		assert_eq!(Nonfungible::token_owner(collection_id, token_id), Some(CHARLIE));
+		// Allowance is reset on transfer
+		assert_eq!(<Allowance<MockRuntime>>::get((collection_id, token_id)), None);
+	});
+}
+
+#[test]
+pub fn transfer_from_fails_if_not_approved() {
+	ExtBuilder::new()
+	.investors(vec![ALICE, BOB, CHARLIE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_noop!(
+			Nonfungible::transfer_from(RuntimeOrigin::signed(BOB), collection_id, token_id, ALICE, CHARLIE),
+			CommonError::<MockRuntime>::ApprovedValueTooLow
+		);
+	});
+}
+
+#[test]
+pub fn approve_fails_if_not_token_owner() {
+	ExtBuilder::new()
+	.investors(vec![ALICE, BOB])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_noop!(
+			Nonfungible::approve(RuntimeOrigin::signed(BOB), collection_id, token_id, Some(BOB)),
+			CommonError::<MockRuntime>::CantApproveMoreThanOwned
+		);
+	});
+}
+
+#[test]
+pub fn approve_none_removes_allowance() {
+	ExtBuilder::new()
+	.investors(vec![ALICE, BOB])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_ok!(Nonfungible::approve(RuntimeOrigin::signed(ALICE), collection_id, token_id, Some(BOB)));
+		assert_ok!(Nonfungible::approve(RuntimeOrigin::signed(ALICE), collection_id, token_id, None));
+
+		assert_eq!(<Allowance<MockRuntime>>::get((collection_id, token_id)), None);
+		System::assert_last_event(RuntimeEvent::Common(CommonEvent::Approved(
+			collection_id,
+			token_id,
+			ALICE,
+			BOB,
+			0
+		)));
+	});
+}
+
+#[test]
+pub fn burn_works() {
+	ExtBuilder::new()
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ADMIN_1).unwrap();
+
+		assert_ok!(Nonfungible::burn(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id));
+
+		assert_eq!(<TokenData<MockRuntime>>::get((collection_id, token_id)), None);
+		assert!(!<Owned<MockRuntime>>::get((collection_id, ADMIN_1, token_id)));
+		assert_eq!(<AccountBalance<MockRuntime>>::get((collection_id, ADMIN_1)), 0);
+		assert_eq!(<TokensBurnt<MockRuntime>>::get(collection_id), 1);
+
+		System::assert_last_event(RuntimeEvent::Common(CommonEvent::ItemDestroyed(
+			collection_id,
+			token_id,
+			ADMIN_1,
+			1
+		)));
+	});
+}
+
+#[test]
+pub fn burn_fails_if_not_token_owner() {
+	ExtBuilder::new()
+	.investors(vec![ALICE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_noop!(
+			Nonfungible::burn(RuntimeOrigin::signed(ALICE), collection_id, token_id),
+			CommonError::<MockRuntime>::NoPermission
+		);
+		assert_noop!(
+			Nonfungible::burn(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id),
+			CommonError::<MockRuntime>::MustBeTokenOwner
+		);
+	});
+}
+
+#[test]
+pub fn burn_from_works() {
+	ExtBuilder::new()
+	.investors(vec![ALICE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let collection_id = init_nft_collection(ADMIN_1);
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_ok!(Nonfungible::approve(RuntimeOrigin::signed(ALICE), collection_id, token_id, Some(ADMIN_1)));
+		assert_ok!(Nonfungible::burn_from(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id, ALICE));
+
+		assert_eq!(<TokenData<MockRuntime>>::get((collection_id, token_id)), None);
+		assert_eq!(<Allowance<MockRuntime>>::get((collection_id, token_id)), None);
+		assert_eq!(<AccountBalance<MockRuntime>>::get((collection_id, ALICE)), 0);
+	});
+}
+
+#[test]
+pub fn set_token_property_respects_permission() {
+	ExtBuilder::new()
+	.investors(vec![ALICE])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let mutable = create_property("Mutable", "Value");
+		let unmutable = create_property("Unmutable", "Value");
+
+		let collection_id = CollectionInitializer::<MockRuntime>::new()
+			.mode(CollectionMode::NFT)
+			.mutable_property_keys(vec![mutable.key.clone()])
+			.init(ADMIN_1)
+			.expect("Collection init failed");
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_ok!(Nonfungible::set_token_property(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id, mutable.clone()));
+		System::assert_last_event(RuntimeEvent::Common(CommonEvent::TokenPropertySet(
+			collection_id,
+			token_id,
+			mutable.key.clone()
+		)));
+
+		assert_noop!(
+			Nonfungible::set_token_property(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id, unmutable),
+			CommonError::<MockRuntime>::NoPermission
+		);
+		assert_noop!(
+			Nonfungible::set_token_property(RuntimeOrigin::signed(ALICE), collection_id, token_id, mutable.clone()),
+			CommonError::<MockRuntime>::NoPermission
+		);
+		assert_noop!(
+			Nonfungible::set_token_property(RuntimeOrigin::signed(ADMIN_1), collection_id, TokenId(2), mutable.clone()),
+			CommonError::<MockRuntime>::TokenNotFound
+		);
+
+		assert_ok!(Nonfungible::delete_token_property(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id, mutable.key.clone()));
+		System::assert_last_event(RuntimeEvent::Common(CommonEvent::TokenPropertyDeleted(
+			collection_id,
+			token_id,
+			mutable.key
+		)));
+	});
+}
+
+#[test]
+pub fn set_token_property_works_for_token_owner_if_permitted() {
+	ExtBuilder::new()
+	.investors(vec![ALICE, BOB])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let property = create_property("OwnerKey", "Value");
+
+		let collection_id = init_nft_collection(ADMIN_1);
+		assert_ok!(Common::set_property_permission_unchecked(collection_id, PropertyKeyPermission {
+			key: property.key.clone(),
+			permission: PropertyPermission { mutable: true, collection_admin: false, token_owner: true }
+		}));
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+
+		assert_ok!(Nonfungible::set_token_property(RuntimeOrigin::signed(ALICE), collection_id, token_id, property.clone()));
+		assert_noop!(
+			Nonfungible::set_token_property(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id, property.clone()),
+			CommonError::<MockRuntime>::NoPermission
+		);
+		assert_noop!(
+			Nonfungible::set_token_property(RuntimeOrigin::signed(BOB), collection_id, token_id, property.clone()),
+			CommonError::<MockRuntime>::NoPermission
+		);
+
+		// New owner of the token gets the permission
+		assert_ok!(Nonfungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB));
+		assert_ok!(Nonfungible::set_token_property(RuntimeOrigin::signed(BOB), collection_id, token_id, property));
+	});
+}
+
+#[test]
+pub fn rpc_methods_work() {
+	ExtBuilder::new()
+	.investors(vec![ALICE, BOB])
+	.wl_admins(vec![ADMIN_1])
+	.balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+	.build()
+	.execute_with(|| {
+		let property = create_property("Key", "Value");
+
+		let collection_id = CollectionInitializer::<MockRuntime>::new()
+			.mode(CollectionMode::NFT)
+			.mutable_property_keys(vec![property.key.clone()])
+			.init(ADMIN_1)
+			.expect("Collection init failed");
+		let token_id = create_nft(collection_id, ADMIN_1, ALICE).unwrap();
+		assert_ok!(Nonfungible::set_token_property(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id, property.clone()));
+		assert_ok!(Nonfungible::approve(RuntimeOrigin::signed(ALICE), collection_id, token_id, Some(BOB)));
+
+		assert_eq!(Nonfungible::filter_token_properties(collection_id, token_id, None), vec![property.clone()]);
+		assert_eq!(
+			Nonfungible::filter_token_properties(collection_id, token_id, Some(vec![create_property_key("Other")])),
+			vec![]
+		);
+
+		assert_eq!(Nonfungible::token_balance(collection_id, token_id, &ALICE), 1);
+		assert_eq!(Nonfungible::token_balance(collection_id, token_id, &BOB), 0);
+		assert_eq!(Nonfungible::token_total_pieces(collection_id, token_id), 1);
+		assert_eq!(Nonfungible::token_total_pieces(collection_id, TokenId(2)), 0);
+		assert_eq!(Nonfungible::token_allowance(collection_id, token_id, &ALICE, &BOB), 1);
+		assert_eq!(Nonfungible::token_allowance(collection_id, token_id, &BOB, &ALICE), 0);
+	});
+}
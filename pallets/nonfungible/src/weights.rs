@@ -0,0 +1,336 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Weights for pallet_nonfungible
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::weights::constants::RocksDbWeight;
+
+/// Weight functions needed for pallet_nonfungible.
+pub trait WeightInfo {
+	fn init_collection(i: u32, j: u32, ) -> Weight;
+	fn destroy_collection() -> Weight;
+	fn set_collection_property() -> Weight;
+	fn delete_collection_property() -> Weight;
+	fn set_collection_properties(k: u32, ) -> Weight;
+	fn delete_collection_properties(k: u32, ) -> Weight;
+	fn set_property_permission() -> Weight;
+	fn create_item(p: u32, ) -> Weight;
+	fn create_max_item() -> Weight;
+	fn set_token_property() -> Weight;
+	fn set_token_properties(i: u32, ) -> Weight;
+	fn delete_token_property() -> Weight;
+	fn delete_token_properties(i: u32, ) -> Weight;
+	fn transfer() -> Weight;
+	fn approve() -> Weight;
+	fn transfer_from() -> Weight;
+	fn burn() -> Weight;
+	fn burn_from() -> Weight;
+	fn toggle_admin() -> Weight;
+	fn set_sponsor() -> Weight;
+	fn confirm_sponsorship() -> Weight;
+	fn remove_sponsor() -> Weight;
+	fn propose_collection_owner() -> Weight;
+	fn cancel_collection_ownership_transfer() -> Weight;
+	fn accept_collection_ownership() -> Weight;
+	fn set_sponsorship_settings() -> Weight;
+	fn toggle_sponsor_allowlist() -> Weight;
+}
+
+impl WeightInfo for () {
+	// Storage: Whitelist Admins (r:1 w:0)
+	// Storage: Common CreatedCollectionCount (r:1 w:1)
+	// Storage: Common DestroyedCollectionCount (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:0 w:1)
+	// Storage: Common CollectionProperties (r:0 w:1)
+	// Storage: Common CollectionById (r:0 w:1)
+	/// The range of component `i` is `[1, 64]`.
+	/// The range of component `j` is `[1, 64]`.
+	fn init_collection(i: u32, j: u32, ) -> Weight {
+		Weight::from_parts(62_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(520_000 as u64, 0).saturating_mul(i as u64))
+			.saturating_add(Weight::from_parts(820_000 as u64, 0).saturating_mul(j as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:1)
+	// Storage: Nonfungible TokenData (r:1 w:0)
+	// Storage: Common DestroyedCollectionCount (r:1 w:1)
+	// Storage: Common AdminAmount (r:0 w:1)
+	// Storage: Common CollectionProperties (r:0 w:1)
+	// Storage: Nonfungible TokensMinted (r:0 w:1)
+	// Storage: Nonfungible TokensBurnt (r:0 w:1)
+	fn destroy_collection() -> Weight {
+		Weight::from_parts(83_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Common CollectionProperties (r:1 w:1)
+	fn set_collection_property() -> Weight {
+		Weight::from_parts(60_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Common CollectionProperties (r:1 w:1)
+	fn delete_collection_property() -> Weight {
+		Weight::from_parts(58_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Common CollectionProperties (r:1 w:1)
+	/// The range of component `k` is `[1, 64]`.
+	fn set_collection_properties(k: u32, ) -> Weight {
+		Weight::from_parts(40_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(18_000_000 as u64, 0).saturating_mul(k as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Common CollectionProperties (r:1 w:1)
+	/// The range of component `k` is `[1, 64]`.
+	fn delete_collection_properties(k: u32, ) -> Weight {
+		Weight::from_parts(40_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(15_000_000 as u64, 0).saturating_mul(k as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:1)
+	fn set_property_permission() -> Weight {
+		Weight::from_parts(55_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	// Storage: Whitelist Investors (r:1 w:0)
+	// Storage: Nonfungible TokensMinted (r:1 w:1)
+	// Storage: Nonfungible AccountBalance (r:1 w:1)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Nonfungible TokenProperties (r:1 w:1)
+	// Storage: Nonfungible TokenData (r:1 w:1)
+	// Storage: Nonfungible Owned (r:0 w:1)
+	/// The range of component `p` is `[1, 64]`.
+	fn create_item(p: u32, ) -> Weight {
+		Weight::from_parts(90_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(25_000_000 as u64, 0).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	// Storage: Whitelist Investors (r:1 w:0)
+	// Storage: Nonfungible TokensMinted (r:1 w:1)
+	// Storage: Nonfungible AccountBalance (r:1 w:1)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Nonfungible TokenProperties (r:1 w:1)
+	// Storage: Nonfungible TokenData (r:1 w:1)
+	// Storage: Nonfungible Owned (r:0 w:1)
+	fn create_max_item() -> Weight {
+		Weight::from_parts(1_690_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Nonfungible TokenData (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Nonfungible TokenProperties (r:1 w:1)
+	fn set_token_property() -> Weight {
+		Weight::from_parts(75_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Nonfungible TokenData (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Nonfungible TokenProperties (r:1 w:1)
+	/// The range of component `i` is `[1, 64]`.
+	fn set_token_properties(i: u32, ) -> Weight {
+		Weight::from_parts(55_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(25_000_000 as u64, 0).saturating_mul(i as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Nonfungible TokenData (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Nonfungible TokenProperties (r:1 w:1)
+	fn delete_token_property() -> Weight {
+		Weight::from_parts(72_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Nonfungible TokenData (r:1 w:0)
+	// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	// Storage: Nonfungible TokenProperties (r:1 w:1)
+	/// The range of component `i` is `[1, 64]`.
+	fn delete_token_properties(i: u32, ) -> Weight {
+		Weight::from_parts(55_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(20_000_000 as u64, 0).saturating_mul(i as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:2 w:0)
+	// Storage: Whitelist Investors (r:2 w:0)
+	// Storage: Nonfungible TokenData (r:1 w:1)
+	// Storage: Nonfungible AccountBalance (r:2 w:2)
+	// Storage: Nonfungible Allowance (r:0 w:1)
+	// Storage: Nonfungible Owned (r:0 w:2)
+	fn transfer() -> Weight {
+		Weight::from_parts(80_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:2 w:0)
+	// Storage: Whitelist Investors (r:2 w:0)
+	// Storage: Nonfungible TokenData (r:1 w:0)
+	// Storage: Nonfungible Allowance (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_parts(60_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:4 w:0)
+	// Storage: Whitelist Investors (r:4 w:0)
+	// Storage: Nonfungible Allowance (r:1 w:1)
+	// Storage: Nonfungible TokenData (r:1 w:1)
+	// Storage: Nonfungible AccountBalance (r:2 w:2)
+	// Storage: Nonfungible Owned (r:0 w:2)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(100_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Nonfungible TokenData (r:1 w:1)
+	// Storage: Nonfungible TokensBurnt (r:1 w:1)
+	// Storage: Nonfungible AccountBalance (r:1 w:1)
+	// Storage: Nonfungible Owned (r:0 w:1)
+	// Storage: Nonfungible TokenProperties (r:0 w:1)
+	// Storage: Nonfungible Allowance (r:0 w:1)
+	// Storage: Nonfungible NftTransferBasket (r:0 w:1)
+	// Storage: Nonfungible NftApproveBasket (r:0 w:1)
+	fn burn() -> Weight {
+		Weight::from_parts(70_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:2 w:0)
+	// Storage: Whitelist Investors (r:2 w:0)
+	// Storage: Nonfungible Allowance (r:1 w:1)
+	// Storage: Nonfungible TokenData (r:1 w:1)
+	// Storage: Nonfungible TokensBurnt (r:1 w:1)
+	// Storage: Nonfungible AccountBalance (r:1 w:1)
+	// Storage: Nonfungible Owned (r:0 w:1)
+	// Storage: Nonfungible TokenProperties (r:0 w:1)
+	// Storage: Nonfungible NftTransferBasket (r:0 w:1)
+	// Storage: Nonfungible NftApproveBasket (r:0 w:1)
+	fn burn_from() -> Weight {
+		Weight::from_parts(90_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common IsAdmin (r:1 w:1)
+	// Storage: Common AdminAmount (r:1 w:1)
+	fn toggle_admin() -> Weight {
+		Weight::from_parts(50_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:1)
+	fn set_sponsor() -> Weight {
+		Weight::from_parts(40_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:1)
+	fn confirm_sponsorship() -> Weight {
+		Weight::from_parts(38_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:1)
+	fn remove_sponsor() -> Weight {
+		Weight::from_parts(38_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist Admins (r:1 w:0)
+	// Storage: Common PendingOwner (r:0 w:1)
+	fn propose_collection_owner() -> Weight {
+		Weight::from_parts(22_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common PendingOwner (r:1 w:1)
+	fn cancel_collection_ownership_transfer() -> Weight {
+		Weight::from_parts(20_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:1)
+	// Storage: Common PendingOwner (r:1 w:1)
+	// Storage: Whitelist Admins (r:1 w:0)
+	// Storage: Common IsAdmin (r:1 w:1)
+	// Storage: Common AdminAmount (r:1 w:1)
+	fn accept_collection_ownership() -> Weight {
+		Weight::from_parts(33_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common CollectionSponsorshipSettings (r:0 w:1)
+	// Storage: Common SponsorBudgetUsage (r:0 w:1)
+	fn set_sponsorship_settings() -> Weight {
+		Weight::from_parts(20_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common SponsorAllowlist (r:0 w:1)
+	fn toggle_sponsor_allowlist() -> Weight {
+		Weight::from_parts(18_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+}
@@ -0,0 +1,509 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! # Offering pallet
+//!
+//! ## Overview
+//!
+//! Primary sale of RFT token pieces to whitelisted investors.
+//!
+//! - Collection owner or admin (`Issuer`) creates an offering for RFT token pieces
+//!   from its balance. The pieces are moved to the escrow account of the offering.
+//! - Active investors subscribe for pieces during the offering period, paying `price`
+//!   per piece in the offering currency. Payments are held in the same escrow account.
+//! - After the end block (or once all pieces are sold) anyone can settle the offering.
+//!   If at least `min_raise` was collected, proceeds and unsold pieces go to the issuer,
+//!   otherwise all pieces are returned to the issuer.
+//! - Subscribers claim their pieces from a succeeded offering or get refunded from a failed one.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::MaxEncodedLen;
+use scale_info::TypeInfo;
+
+use frame_support::{pallet_prelude::*, traits::Contains, PalletId};
+use frame_system::pallet_prelude::*;
+
+use orml_traits::MultiCurrency;
+
+use sp_runtime::{
+	traits::{AccountIdConversion, Zero},
+	ArithmeticError
+};
+
+use collection_primitives::{CollectionId, TokenId};
+use pallet_refungible::{RefungibleHandle, TokenBalance, Pallet as PalletRefungible, Balance as PiecesBalance};
+use pallet_whitelist::traits::WhitelistInterface;
+use primitives::Balance;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub type OfferingId = u32;
+
+type CurrencyIdOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::CurrencyId;
+type OfferingInfoOf<T> = OfferingInfo<
+	<T as frame_system::Config>::AccountId,
+	CurrencyIdOf<T>,
+	<T as frame_system::Config>::BlockNumber
+>;
+
+/// Offering state
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum OfferingStatus {
+	/// Offering accepts subscriptions or waits for settlement
+	Active,
+	/// Soft cap reached, subscribers can claim pieces
+	Succeeded,
+	/// Soft cap missed, subscribers can claim refunds
+	Failed,
+}
+
+/// Offering of RFT token pieces
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct OfferingInfo<AccountId, CurrencyId, BlockNumber> {
+	/// Account receiving proceeds and unsold pieces
+	pub issuer: AccountId,
+	pub collection_id: CollectionId,
+	pub token_id: TokenId,
+	/// Currency subscriptions are paid in
+	pub currency_id: CurrencyId,
+	/// Price of one piece
+	pub price: Balance,
+	/// Minimum amount to raise for the offering to succeed
+	pub min_raise: Balance,
+	/// Maximum amount to raise
+	pub max_raise: Balance,
+	/// First block subscriptions are accepted
+	pub start: BlockNumber,
+	/// Subscriptions are accepted before this block
+	pub end: BlockNumber,
+	/// Pieces held in escrow for sale
+	pub pieces: TokenBalance,
+	/// Pieces subscribed for
+	pub sold: TokenBalance,
+	/// Amount paid by subscribers
+	pub raised: Balance,
+	pub status: OfferingStatus,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_refungible::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency subscriptions are paid in
+		type MultiCurrency: MultiCurrency<Self::AccountId, Balance = Balance>;
+
+		/// Offering escrow accounts are derived from this id
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		type WeightInfo: weights::WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Id of the next offering
+	#[pallet::storage]
+	#[pallet::getter(fn next_offering_id)]
+	pub type NextOfferingId<T: Config> = StorageValue<_, OfferingId, ValueQuery>;
+
+	/// [OfferingId] mapped to [OfferingInfo]
+	#[pallet::storage]
+	#[pallet::getter(fn offering)]
+	pub type Offerings<T: Config> = StorageMap<_, Twox64Concat, OfferingId, OfferingInfoOf<T>>;
+
+	/// Pieces subscribed for by account, removed once claimed
+	#[pallet::storage]
+	#[pallet::getter(fn subscription)]
+	pub type Subscriptions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		OfferingId,
+		Blake2_128Concat,
+		T::AccountId,
+		TokenBalance,
+		ValueQuery
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New offering created
+		OfferingCreated {
+			offering_id: OfferingId,
+			issuer: T::AccountId,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			pieces: TokenBalance,
+		},
+		/// Investor subscribed for pieces
+		Subscribed {
+			offering_id: OfferingId,
+			who: T::AccountId,
+			pieces: TokenBalance,
+			amount: Balance,
+		},
+		/// Offering reached soft cap, proceeds sent to issuer
+		OfferingSucceeded {
+			offering_id: OfferingId,
+			raised: Balance,
+			sold: TokenBalance,
+		},
+		/// Offering missed soft cap, pieces returned to issuer
+		OfferingFailed {
+			offering_id: OfferingId,
+			raised: Balance,
+		},
+		/// Subscriber received pieces of succeeded offering
+		PiecesClaimed {
+			offering_id: OfferingId,
+			who: T::AccountId,
+			pieces: TokenBalance,
+		},
+		/// Subscriber refunded from failed offering
+		Refunded {
+			offering_id: OfferingId,
+			who: T::AccountId,
+			amount: Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Offering with given id doesn't exist
+		OfferingNotFound,
+		/// Price can't be zero
+		InvalidPrice,
+		/// `min_raise` is above `max_raise` or `max_raise` isn't a positive multiple of price
+		InvalidRaiseLimits,
+		/// Offering must end after it starts and in the future
+		InvalidPeriod,
+		/// Account is not an active investor
+		NotActiveInvestor,
+		/// Offering period hasn't started yet
+		OfferingNotStarted,
+		/// Offering period is over
+		OfferingEnded,
+		/// Offering period isn't over and not all pieces are sold
+		OfferingNotEnded,
+		/// Offering is already settled
+		AlreadySettled,
+		/// Offering isn't settled yet
+		NotSettled,
+		/// Subscription for zero pieces
+		ZeroPieces,
+		/// Not enough pieces left in the offering
+		MaxRaiseExceeded,
+		/// Subscribed pieces don't meet holding limits of the token
+		HoldingLimitsViolated,
+		/// Account has nothing to claim
+		NothingToClaim,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create offering of RFT token pieces.
+		///
+		/// `max_raise / price` pieces are moved from sender to the escrow account.
+		/// Sender must be owner or admin of the collection.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_offering())]
+		pub fn create_offering(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			currency_id: CurrencyIdOf<T>,
+			price: Balance,
+			min_raise: Balance,
+			max_raise: Balance,
+			start: T::BlockNumber,
+			end: T::BlockNumber,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+
+			let collection = RefungibleHandle::<T>::try_get(collection_id)?;
+			collection.check_is_owner_or_admin(&issuer)?;
+
+			ensure!(!price.is_zero(), Error::<T>::InvalidPrice);
+			ensure!(
+				min_raise <= max_raise && !max_raise.is_zero() && max_raise % price == 0,
+				Error::<T>::InvalidRaiseLimits
+			);
+			ensure!(
+				start < end && end > <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::InvalidPeriod
+			);
+
+			let offering_id = Self::next_offering_id();
+			let next_offering_id = offering_id
+				.checked_add(1)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			let pieces = max_raise / price;
+			let account = Self::offering_account(offering_id);
+			<PalletRefungible<T>>::escrow_transfer(
+				collection_id,
+				&account,
+				&issuer,
+				&account,
+				token_id,
+				pieces
+			)?;
+
+			<Offerings<T>>::insert(offering_id, OfferingInfo {
+				issuer: issuer.clone(),
+				collection_id,
+				token_id,
+				currency_id,
+				price,
+				min_raise,
+				max_raise,
+				start,
+				end,
+				pieces,
+				sold: 0,
+				raised: 0,
+				status: OfferingStatus::Active,
+			});
+			<NextOfferingId<T>>::put(next_offering_id);
+
+			Self::deposit_event(Event::OfferingCreated {
+				offering_id,
+				issuer,
+				collection_id,
+				token_id,
+				pieces
+			});
+
+			Ok(())
+		}
+
+		/// Subscribe for pieces of active offering.
+		///
+		/// `pieces * price` is moved from sender to the escrow account.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::subscribe())]
+		pub fn subscribe(
+			origin: OriginFor<T>,
+			offering_id: OfferingId,
+			pieces: TokenBalance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				<T as pallet_common::Config>::Whitelist::is_active_investor(&who),
+				Error::<T>::NotActiveInvestor
+			);
+
+			let mut offering = Self::offering(offering_id).ok_or(Error::<T>::OfferingNotFound)?;
+			ensure!(offering.status == OfferingStatus::Active, Error::<T>::AlreadySettled);
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			ensure!(now >= offering.start, Error::<T>::OfferingNotStarted);
+			ensure!(now < offering.end, Error::<T>::OfferingEnded);
+
+			ensure!(!pieces.is_zero(), Error::<T>::ZeroPieces);
+			let sold = offering.sold
+				.checked_add(pieces)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(sold <= offering.pieces, Error::<T>::MaxRaiseExceeded);
+
+			let subscribed = Self::subscription(offering_id, &who)
+				.checked_add(pieces)
+				.ok_or(ArithmeticError::Overflow)?;
+			let holding = <PiecesBalance<T>>::get((offering.collection_id, offering.token_id, &who))
+				.checked_add(subscribed)
+				.ok_or(ArithmeticError::Overflow)?;
+			let limits = <PalletRefungible<T>>::holding_limits(offering.collection_id, offering.token_id);
+			ensure!(
+				limits.is_allowed_amount(subscribed) && limits.is_allowed_holding(holding),
+				Error::<T>::HoldingLimitsViolated
+			);
+
+			let amount = offering.price
+				.checked_mul(pieces)
+				.ok_or(ArithmeticError::Overflow)?;
+			T::MultiCurrency::transfer(
+				offering.currency_id,
+				&who,
+				&Self::offering_account(offering_id),
+				amount
+			)?;
+
+			offering.sold = sold;
+			offering.raised = offering.raised
+				.checked_add(amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			<Offerings<T>>::insert(offering_id, offering);
+			<Subscriptions<T>>::insert(offering_id, &who, subscribed);
+
+			Self::deposit_event(Event::Subscribed {
+				offering_id,
+				who,
+				pieces,
+				amount
+			});
+
+			Ok(())
+		}
+
+		/// Settle offering after its end block or once all pieces are sold.
+		///
+		/// If `min_raise` is reached proceeds and unsold pieces are sent to the issuer,
+		/// otherwise all pieces are returned to the issuer.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle())]
+		pub fn settle(
+			origin: OriginFor<T>,
+			offering_id: OfferingId,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let mut offering = Self::offering(offering_id).ok_or(Error::<T>::OfferingNotFound)?;
+			ensure!(offering.status == OfferingStatus::Active, Error::<T>::AlreadySettled);
+			ensure!(
+				<frame_system::Pallet<T>>::block_number() >= offering.end || offering.sold == offering.pieces,
+				Error::<T>::OfferingNotEnded
+			);
+
+			let account = Self::offering_account(offering_id);
+
+			let unsold = if offering.raised >= offering.min_raise {
+				T::MultiCurrency::transfer(offering.currency_id, &account, &offering.issuer, offering.raised)?;
+
+				offering.status = OfferingStatus::Succeeded;
+				offering.pieces - offering.sold
+			} else {
+				offering.status = OfferingStatus::Failed;
+				offering.pieces
+			};
+
+			if !unsold.is_zero() {
+				<PalletRefungible<T>>::escrow_transfer(
+					offering.collection_id,
+					&account,
+					&account,
+					&offering.issuer,
+					offering.token_id,
+					unsold
+				)?;
+			}
+
+			let event = match offering.status {
+				OfferingStatus::Succeeded => Event::OfferingSucceeded {
+					offering_id,
+					raised: offering.raised,
+					sold: offering.sold
+				},
+				_ => Event::OfferingFailed {
+					offering_id,
+					raised: offering.raised
+				}
+			};
+			<Offerings<T>>::insert(offering_id, offering);
+
+			Self::deposit_event(event);
+
+			Ok(())
+		}
+
+		/// Claim subscribed pieces of succeeded offering or refund of failed one.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim())]
+		pub fn claim(
+			origin: OriginFor<T>,
+			offering_id: OfferingId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let offering = Self::offering(offering_id).ok_or(Error::<T>::OfferingNotFound)?;
+			ensure!(offering.status != OfferingStatus::Active, Error::<T>::NotSettled);
+
+			let pieces = <Subscriptions<T>>::take(offering_id, &who);
+			ensure!(!pieces.is_zero(), Error::<T>::NothingToClaim);
+
+			let account = Self::offering_account(offering_id);
+
+			if offering.status == OfferingStatus::Succeeded {
+				<PalletRefungible<T>>::escrow_transfer(
+					offering.collection_id,
+					&account,
+					&account,
+					&who,
+					offering.token_id,
+					pieces
+				)?;
+
+				Self::deposit_event(Event::PiecesClaimed {
+					offering_id,
+					who,
+					pieces
+				});
+			} else {
+				let amount = offering.price
+					.checked_mul(pieces)
+					.ok_or(ArithmeticError::Overflow)?;
+				T::MultiCurrency::transfer(offering.currency_id, &account, &who, amount)?;
+
+				Self::deposit_event(Event::Refunded {
+					offering_id,
+					who,
+					amount
+				});
+			}
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Escrow account holding pieces and payments of the offering
+	pub fn offering_account(offering_id: OfferingId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(offering_id)
+	}
+}
+
+/// Matches escrow accounts of offerings
+pub struct IsOfferingAccount<T>(PhantomData<T>);
+
+impl<T: Config> Contains<T::AccountId> for IsOfferingAccount<T> {
+	fn contains(who: &T::AccountId) -> bool {
+		PalletId::try_from_sub_account::<OfferingId>(who)
+			.map_or(false, |(id, _)| id == T::PalletId::get())
+	}
+}
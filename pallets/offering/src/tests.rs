@@ -0,0 +1,302 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+
+use crate::mock::*;
+use crate::{Error, Event, OfferingStatus, Offerings, Subscriptions};
+
+const PRICE: Balance = 10 * DOLLARS;
+
+fn pieces_of(who: AccountId, collection_id: CollectionId, token_id: TokenId) -> u128 {
+	pallet_refungible::Balance::<MockRuntime>::get((collection_id, token_id, who))
+}
+
+fn create_offering(
+	collection_id: CollectionId,
+	token_id: TokenId,
+	min_raise: Balance,
+	max_raise: Balance,
+) -> u32 {
+	let offering_id = Offering::next_offering_id();
+	assert_ok!(Offering::create_offering(
+		RuntimeOrigin::signed(ISSUER),
+		collection_id,
+		token_id,
+		DAI,
+		PRICE,
+		min_raise,
+		max_raise,
+		1,
+		10
+	));
+	offering_id
+}
+
+#[test]
+fn create_offering_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(100).unwrap();
+
+		let offering_id = create_offering(collection_id, token_id, 200 * DOLLARS, 500 * DOLLARS);
+
+		System::assert_last_event(RuntimeEvent::Offering(Event::OfferingCreated {
+			offering_id,
+			issuer: ISSUER,
+			collection_id,
+			token_id,
+			pieces: 50
+		}));
+
+		let escrow = Offering::offering_account(offering_id);
+		assert_eq!(pieces_of(ISSUER, collection_id, token_id), 50);
+		assert_eq!(pieces_of(escrow, collection_id, token_id), 50);
+
+		let offering = Offerings::<MockRuntime>::get(offering_id).unwrap();
+		assert_eq!(offering.pieces, 50);
+		assert_eq!(offering.status, OfferingStatus::Active);
+		assert_eq!(Offering::next_offering_id(), offering_id + 1);
+	});
+}
+
+#[test]
+fn create_offering_fails_with_invalid_params() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(100).unwrap();
+
+		assert_noop!(
+			Offering::create_offering(RuntimeOrigin::signed(ALICE), collection_id, token_id, DAI, PRICE, 0, PRICE, 1, 10),
+			pallet_common::Error::<MockRuntime>::NoPermission
+		);
+		assert_noop!(
+			Offering::create_offering(RuntimeOrigin::signed(ISSUER), collection_id, token_id, DAI, 0, 0, PRICE, 1, 10),
+			Error::<MockRuntime>::InvalidPrice
+		);
+		assert_noop!(
+			Offering::create_offering(RuntimeOrigin::signed(ISSUER), collection_id, token_id, DAI, PRICE, 2 * PRICE, PRICE, 1, 10),
+			Error::<MockRuntime>::InvalidRaiseLimits
+		);
+		assert_noop!(
+			Offering::create_offering(RuntimeOrigin::signed(ISSUER), collection_id, token_id, DAI, PRICE, 0, PRICE + 1, 1, 10),
+			Error::<MockRuntime>::InvalidRaiseLimits
+		);
+		assert_noop!(
+			Offering::create_offering(RuntimeOrigin::signed(ISSUER), collection_id, token_id, DAI, PRICE, 0, PRICE, 10, 10),
+			Error::<MockRuntime>::InvalidPeriod
+		);
+		assert_noop!(
+			Offering::create_offering(RuntimeOrigin::signed(ISSUER), collection_id, token_id, DAI, PRICE, 0, 101 * PRICE, 1, 10),
+			pallet_common::Error::<MockRuntime>::TokenValueTooLow
+		);
+	});
+}
+
+#[test]
+fn subscribe_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(100).unwrap();
+		let offering_id = create_offering(collection_id, token_id, 200 * DOLLARS, 500 * DOLLARS);
+
+		assert_ok!(Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 5));
+		assert_ok!(Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 5));
+
+		System::assert_last_event(RuntimeEvent::Offering(Event::Subscribed {
+			offering_id,
+			who: ALICE,
+			pieces: 5,
+			amount: 5 * PRICE
+		}));
+
+		let escrow = Offering::offering_account(offering_id);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 900 * DOLLARS);
+		assert_eq!(Tokens::free_balance(DAI, &escrow), 100 * DOLLARS);
+		assert_eq!(Subscriptions::<MockRuntime>::get(offering_id, ALICE), 10);
+
+		let offering = Offerings::<MockRuntime>::get(offering_id).unwrap();
+		assert_eq!(offering.sold, 10);
+		assert_eq!(offering.raised, 100 * DOLLARS);
+	});
+}
+
+#[test]
+fn subscribe_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(100).unwrap();
+		let offering_id = create_offering(collection_id, token_id, 200 * DOLLARS, 500 * DOLLARS);
+
+		assert_noop!(
+			Offering::subscribe(RuntimeOrigin::signed(CHARLIE), offering_id, 5),
+			Error::<MockRuntime>::NotActiveInvestor
+		);
+		assert_noop!(
+			Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id + 1, 5),
+			Error::<MockRuntime>::OfferingNotFound
+		);
+		assert_noop!(
+			Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 0),
+			Error::<MockRuntime>::ZeroPieces
+		);
+		assert_noop!(
+			Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 51),
+			Error::<MockRuntime>::MaxRaiseExceeded
+		);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 5),
+			Error::<MockRuntime>::OfferingEnded
+		);
+	});
+}
+
+#[test]
+fn subscribe_counts_existing_pieces_towards_min_holding() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(100).unwrap();
+		assert_ok!(Refungible::set_collection_holding_limits(
+			RuntimeOrigin::signed(ISSUER),
+			collection_id,
+			collection_primitives::HoldingLimits {
+				min_holding: Some(10),
+				..Default::default()
+			},
+			1
+		));
+		let offering_id = create_offering(collection_id, token_id, 200 * DOLLARS, 500 * DOLLARS);
+
+		assert_noop!(
+			Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 5),
+			Error::<MockRuntime>::HoldingLimitsViolated
+		);
+
+		assert_ok!(Refungible::transfer(RuntimeOrigin::signed(ISSUER), collection_id, token_id, ALICE, 10));
+		assert_ok!(Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 5));
+	});
+}
+
+#[test]
+fn succeeded_offering_settles_and_pays_out() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(100).unwrap();
+		let offering_id = create_offering(collection_id, token_id, 200 * DOLLARS, 500 * DOLLARS);
+
+		assert_ok!(Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 15));
+		assert_ok!(Offering::subscribe(RuntimeOrigin::signed(BOB), offering_id, 10));
+
+		assert_noop!(
+			Offering::settle(RuntimeOrigin::signed(CHARLIE), offering_id),
+			Error::<MockRuntime>::OfferingNotEnded
+		);
+		assert_noop!(
+			Offering::claim(RuntimeOrigin::signed(ALICE), offering_id),
+			Error::<MockRuntime>::NotSettled
+		);
+
+		System::set_block_number(10);
+		assert_ok!(Offering::settle(RuntimeOrigin::signed(CHARLIE), offering_id));
+
+		System::assert_last_event(RuntimeEvent::Offering(Event::OfferingSucceeded {
+			offering_id,
+			raised: 250 * DOLLARS,
+			sold: 25
+		}));
+
+		// proceeds and unsold pieces go to issuer
+		assert_eq!(Tokens::free_balance(DAI, &ISSUER), 250 * DOLLARS);
+		assert_eq!(pieces_of(ISSUER, collection_id, token_id), 75);
+
+		assert_noop!(
+			Offering::settle(RuntimeOrigin::signed(CHARLIE), offering_id),
+			Error::<MockRuntime>::AlreadySettled
+		);
+
+		assert_ok!(Offering::claim(RuntimeOrigin::signed(ALICE), offering_id));
+		System::assert_last_event(RuntimeEvent::Offering(Event::PiecesClaimed {
+			offering_id,
+			who: ALICE,
+			pieces: 15
+		}));
+		assert_ok!(Offering::claim(RuntimeOrigin::signed(BOB), offering_id));
+
+		assert_eq!(pieces_of(ALICE, collection_id, token_id), 15);
+		assert_eq!(pieces_of(BOB, collection_id, token_id), 10);
+		assert_eq!(pieces_of(Offering::offering_account(offering_id), collection_id, token_id), 0);
+
+		assert_noop!(
+			Offering::claim(RuntimeOrigin::signed(ALICE), offering_id),
+			Error::<MockRuntime>::NothingToClaim
+		);
+	});
+}
+
+#[test]
+fn sold_out_offering_can_be_settled_early() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(100).unwrap();
+		let offering_id = create_offering(collection_id, token_id, 200 * DOLLARS, 500 * DOLLARS);
+
+		assert_ok!(Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 50));
+		assert_ok!(Offering::settle(RuntimeOrigin::signed(ISSUER), offering_id));
+
+		assert_eq!(
+			Offerings::<MockRuntime>::get(offering_id).unwrap().status,
+			OfferingStatus::Succeeded
+		);
+		assert_eq!(pieces_of(ISSUER, collection_id, token_id), 50);
+	});
+}
+
+#[test]
+fn failed_offering_refunds_subscribers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(100).unwrap();
+		let offering_id = create_offering(collection_id, token_id, 200 * DOLLARS, 500 * DOLLARS);
+
+		assert_ok!(Offering::subscribe(RuntimeOrigin::signed(ALICE), offering_id, 5));
+
+		System::set_block_number(10);
+		assert_ok!(Offering::settle(RuntimeOrigin::signed(BOB), offering_id));
+
+		System::assert_last_event(RuntimeEvent::Offering(Event::OfferingFailed {
+			offering_id,
+			raised: 50 * DOLLARS
+		}));
+
+		// all pieces returned, proceeds stay in escrow
+		assert_eq!(pieces_of(ISSUER, collection_id, token_id), 100);
+		assert_eq!(Tokens::free_balance(DAI, &ISSUER), 0);
+
+		assert_ok!(Offering::claim(RuntimeOrigin::signed(ALICE), offering_id));
+		System::assert_last_event(RuntimeEvent::Offering(Event::Refunded {
+			offering_id,
+			who: ALICE,
+			amount: 50 * DOLLARS
+		}));
+
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 1_000 * DOLLARS);
+		assert_eq!(pieces_of(ALICE, collection_id, token_id), 0);
+	});
+}
@@ -0,0 +1,97 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Weights for pallet_offering
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::weights::constants::RocksDbWeight;
+
+/// Weight functions needed for pallet_offering.
+pub trait WeightInfo {
+	fn create_offering() -> Weight;
+	fn subscribe() -> Weight;
+	fn settle() -> Weight;
+	fn claim() -> Weight;
+}
+
+impl WeightInfo for () {
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common IsAdmin (r:1 w:0)
+	// Storage: Offering NextOfferingId (r:1 w:1)
+	// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	// Storage: Refungible Balance (r:2 w:2)
+	// Storage: Refungible TokenHolders (r:1 w:1)
+	// Storage: Refungible AccountBalance (r:1 w:1)
+	// Storage: Refungible Owned (r:0 w:1)
+	// Storage: Offering Offerings (r:0 w:1)
+	fn create_offering() -> Weight {
+		Weight::from_parts(62_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+	}
+	// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	// Storage: Whitelist Investors (r:1 w:0)
+	// Storage: Offering Offerings (r:1 w:1)
+	// Storage: Offering Subscriptions (r:1 w:1)
+	// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn subscribe() -> Weight {
+		Weight::from_parts(58_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Offering Offerings (r:1 w:1)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	// Storage: Refungible Balance (r:2 w:2)
+	// Storage: Refungible TokenHolders (r:1 w:1)
+	// Storage: Refungible AccountBalance (r:2 w:2)
+	// Storage: Refungible Owned (r:0 w:2)
+	fn settle() -> Weight {
+		Weight::from_parts(71_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	// Storage: Offering Offerings (r:1 w:0)
+	// Storage: Offering Subscriptions (r:1 w:1)
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	// Storage: Refungible Balance (r:2 w:2)
+	// Storage: Refungible TokenHolders (r:1 w:1)
+	// Storage: Refungible AccountBalance (r:2 w:2)
+	// Storage: Refungible Owned (r:0 w:2)
+	fn claim() -> Weight {
+		Weight::from_parts(60_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+}
@@ -0,0 +1,248 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! RPC interface for querying staking rewards, reward rates and collator
+//! statistics.
+
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+use parachain_staking_runtime_api::{
+	CollatorCommission, CollatorMetadata, CollatorStats, RewardProjection, StakingRates,
+};
+pub use parachain_staking_runtime_api::Staking as StakingRuntimeApi;
+
+/// Error code for failures of the runtime API call itself.
+const RUNTIME_ERROR: i32 = 1;
+/// Error code for queries the runtime API version at the block doesn't support.
+const UNSUPPORTED_ERROR: i32 = 2;
+
+#[rpc(client, server)]
+pub trait StakingApi<BlockHash, AccountId, Balance> {
+	/// Get the claimable staking rewards of the account including rewards
+	/// which have not been incremented yet.
+	#[method(name = "staking_unclaimedRewards")]
+	fn unclaimed_rewards(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<String>;
+
+	/// Get the current staking and reward rates for collators and delegators.
+	#[method(name = "staking_stakingRates")]
+	fn staking_rates(&self, at: Option<BlockHash>) -> RpcResult<StakingRates>;
+
+	/// Get active collators sorted by their suitability for a delegation with the given stake.
+	#[method(name = "staking_sortedProposedCandidates")]
+	fn sorted_proposed_candidates(&self, stake: Balance, at: Option<BlockHash>) -> RpcResult<Vec<AccountId>>;
+
+	/// Get the commission of the collator candidate.
+	#[method(name = "staking_collatorCommission")]
+	fn collator_commission(
+		&self,
+		collator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CollatorCommission>>;
+
+	/// Get the rewards credited to the account per round, oldest first.
+	#[method(name = "staking_rewardHistory")]
+	fn reward_history(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<(u32, String)>>;
+
+	/// Get the statistics and effective reward rates of the collator candidate.
+	#[method(name = "staking_collatorStats")]
+	fn collator_stats(
+		&self,
+		collator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CollatorStats<String>>>;
+
+	/// Get the expected rewards of a delegation with the given stake to the collator candidate.
+	#[method(name = "staking_delegationProjection")]
+	fn delegation_projection(
+		&self,
+		collator: AccountId,
+		stake: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<RewardProjection<String>>>;
+
+	/// Get the metadata published by the collator candidate.
+	#[method(name = "staking_collatorMetadata")]
+	fn collator_metadata(
+		&self,
+		collator: AccountId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<CollatorMetadata<String>>>;
+}
+
+/// Provides RPC methods to query staking rewards and collators.
+pub struct Staking<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> Staking<C, Block> {
+	/// Create new `Staking` with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+fn runtime_error<E: Debug>(err: E) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query staking.",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+fn unsupported_error(version: u32) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		UNSUPPORTED_ERROR,
+		"Query is not supported by the runtime.",
+		Some(format!("Staking API version {} is required.", version)),
+	))
+	.into()
+}
+
+macro_rules! query {
+	($self:ident, $at:ident, $method:ident($($arg:expr),*)) => {{
+		let api = $self.client.runtime_api();
+		let at = $at.unwrap_or_else(|| $self.client.info().best_hash);
+		api.$method(at, $($arg),*).map_err(runtime_error)
+	}};
+	// Queries added in later versions fail with a clear error on blocks of older runtimes.
+	($self:ident, $at:ident, since $version:literal, $method:ident($($arg:expr),*)) => {{
+		let api = $self.client.runtime_api();
+		let at = $at.unwrap_or_else(|| $self.client.info().best_hash);
+		let version = api
+			.api_version::<dyn StakingRuntimeApi<Block, AccountId, Balance>>(at)
+			.map_err(runtime_error)?
+			.unwrap_or_default();
+		if version < $version {
+			return Err(unsupported_error($version));
+		}
+		api.$method(at, $($arg),*).map_err(runtime_error)
+	}};
+}
+
+impl<C, Block, AccountId, Balance> StakingApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+	for Staking<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: StakingRuntimeApi<Block, AccountId, Balance>,
+	AccountId: Codec + Clone + Send + Sync + 'static,
+	AccountId: serde::Serialize + serde::de::DeserializeOwned,
+	Balance: Codec + ToString + Send + Sync + 'static,
+	Balance: serde::de::DeserializeOwned,
+{
+	fn unclaimed_rewards(&self, account: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<String> {
+		query!(self, at, get_unclaimed_staking_rewards(&account)).map(|r| r.to_string())
+	}
+
+	fn staking_rates(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<StakingRates> {
+		query!(self, at, get_staking_rates())
+	}
+
+	fn sorted_proposed_candidates(
+		&self,
+		stake: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<AccountId>> {
+		query!(self, at, get_sorted_proposed_candidates(stake))
+	}
+
+	fn collator_commission(
+		&self,
+		collator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CollatorCommission>> {
+		query!(self, at, since 2, get_collator_commission(&collator))
+	}
+
+	fn reward_history(
+		&self,
+		account: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(u32, String)>> {
+		query!(self, at, since 2, get_reward_history(&account))
+			.map(|history| history.into_iter().map(|(round, reward)| (round, reward.to_string())).collect())
+	}
+
+	fn collator_stats(
+		&self,
+		collator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CollatorStats<String>>> {
+		query!(self, at, since 2, get_collator_stats(&collator)).map(|stats| {
+			stats.map(|stats| CollatorStats {
+				blocks_authored: stats.blocks_authored,
+				stake: stats.stake.to_string(),
+				total_stake: stats.total_stake.to_string(),
+				delegators: stats.delegators,
+				commission: stats.commission,
+				is_collating: stats.is_collating,
+				collator_apy: stats.collator_apy,
+				delegator_apy: stats.delegator_apy,
+			})
+		})
+	}
+
+	fn delegation_projection(
+		&self,
+		collator: AccountId,
+		stake: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<RewardProjection<String>>> {
+		query!(self, at, since 2, get_delegation_projection(&collator, stake)).map(|projection| {
+			projection.map(|projection| RewardProjection {
+				per_round: projection.per_round.to_string(),
+				per_year: projection.per_year.to_string(),
+				apy: projection.apy,
+			})
+		})
+	}
+
+	fn collator_metadata(
+		&self,
+		collator: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<CollatorMetadata<String>>> {
+		query!(self, at, since 2, get_collator_metadata(&collator)).map(|metadata| {
+			metadata.map(|metadata| CollatorMetadata {
+				name: metadata.name,
+				url: metadata.url,
+				contact: metadata.contact,
+				region: metadata.region,
+				node_version: metadata.node_version,
+				deposit: metadata.deposit.to_string(),
+			})
+		})
+	}
+}
@@ -23,9 +23,13 @@
 
 use codec::{Codec, Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::Perquintill;
+use sp_runtime::{Perbill, Perquintill};
 use sp_std::vec::Vec;
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
 pub struct StakingRates {
 	pub collator_staking_rate: Perquintill,
@@ -34,8 +38,74 @@ pub struct StakingRates {
 	pub delegator_reward_rate: Perquintill,
 }
 
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+pub struct CollatorCommission {
+	/// The commission currently charged on the rewards of delegators.
+	pub commission: Perbill,
+	/// The maximum commission set by governance.
+	pub max_commission: Perbill,
+	/// The round from which a requested commission can be applied and the
+	/// requested commission.
+	pub scheduled_change: Option<(u32, Perbill)>,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+pub struct CollatorStats<Balance> {
+	/// The number of blocks authored since becoming a candidate.
+	pub blocks_authored: u32,
+	/// The own stake of the collator.
+	pub stake: Balance,
+	/// The own stake of the collator plus the stake of their delegators.
+	pub total_stake: Balance,
+	/// The number of delegators.
+	pub delegators: u32,
+	/// The commission currently charged on the rewards of delegators.
+	pub commission: Perbill,
+	/// Whether the candidate is part of the current collator set.
+	pub is_collating: bool,
+	/// The annual reward rate on the own stake of the collator, including
+	/// the commission on the rewards of their delegators.
+	pub collator_apy: Perquintill,
+	/// The annual reward rate of delegators after deducting the commission.
+	pub delegator_apy: Perquintill,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Decode, Encode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug)]
+pub struct RewardProjection<Balance> {
+	/// The expected rewards per round.
+	pub per_round: Balance,
+	/// The expected rewards per year.
+	pub per_year: Balance,
+	/// The annual reward rate after deducting the commission.
+	pub apy: Perquintill,
+}
+
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Decode, Encode, TypeInfo, PartialEq, Eq, Debug)]
+pub struct CollatorMetadata<Balance> {
+	/// The display name of the candidate.
+	pub name: Vec<u8>,
+	/// The website of the candidate.
+	pub url: Vec<u8>,
+	/// Contact details of the candidate.
+	pub contact: Vec<u8>,
+	/// The region in which the collator node is operated.
+	pub region: Vec<u8>,
+	/// The version of the node software the collator runs.
+	pub node_version: Vec<u8>,
+	/// The deposit reserved for the metadata.
+	pub deposit: Balance,
+}
+
 sp_api::decl_runtime_apis! {
 	/// The API to query staking and reward rates.
+	///
+	/// Version 2 adds the collator commission, reward history, collator
+	/// statistics, delegation projection and collator metadata queries.
+	#[api_version(2)]
 	pub trait Staking<AccountId, Balance>
 	where
 		AccountId: Codec,
@@ -49,5 +119,19 @@ sp_api::decl_runtime_apis! {
 		/// Returns sorted active selected collators, which can be delegated
 		/// by account with Balance: "balance"
 		fn get_sorted_proposed_candidates(balance: Balance) -> Vec<AccountId>;
+		/// Returns the commission of a collator candidate and the maximum
+		/// commission allowed by governance.
+		fn get_collator_commission(collator: &AccountId) -> Option<CollatorCommission>;
+		/// Returns the rewards credited to an account per round, oldest
+		/// first.
+		fn get_reward_history(account: &AccountId) -> Vec<(u32, Balance)>;
+		/// Returns the statistics of a collator candidate including the
+		/// effective reward rates for the collator and their delegators.
+		fn get_collator_stats(collator: &AccountId) -> Option<CollatorStats<Balance>>;
+		/// Returns the expected rewards of a delegation with the given stake
+		/// to a collator candidate.
+		fn get_delegation_projection(collator: &AccountId, stake: Balance) -> Option<RewardProjection<Balance>>;
+		/// Returns the metadata published by a collator candidate.
+		fn get_collator_metadata(collator: &AccountId) -> Option<CollatorMetadata<Balance>>;
 	}
 }
\ No newline at end of file
@@ -24,15 +24,18 @@
 
 use frame_support::traits::{Currency, Get};
 use sp_runtime::{
-	traits::{Saturating, Zero},
-	Perquintill,
+	traits::{SaturatedConversion, Saturating, Zero},
+	Perbill, Perquintill,
 };
+use sp_staking::SessionIndex;
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
 
 use crate::{
 	types::{BalanceOf, Stake},
-	BlocksAuthored, BlocksRewarded, CandidatePool, Config, DelegatorState, InflationConfig, Pallet,
-	Rewards, TotalCollatorStake,
+	AuthoredBlocks, BlocksAuthored, BlocksRewarded, BlocksWithheld, BlocksWithheldRewarded, CandidateMetadata,
+	CandidatePool, CommissionChangeRequests, Config, DelegationBlocksRewarded, DelegationBlocksWithheldRewarded,
+	DelegatorState, InflationConfig, MaxCollatorCommission, Pallet, RewardHistory, Rewards, Round, RoundRewardRatio,
+	TotalCollatorStake,
 };
 
 
@@ -41,30 +44,70 @@ impl<T: Config> Pallet<T> {
 	///
 	/// Subtracts the number of rewarded blocks from the number of authored
 	/// blocks by the collator and multiplies that with the current stake
-	/// as well as reward rate.
+	/// as well as reward rate, deducting the share withheld in the rounds
+	/// the blocks were authored in. Delegations which are not yet eligible
+	/// for rewards do not contribute.
 	///
 	/// At least used in Runtime API.
 	pub fn get_unclaimed_staking_rewards(acc: &T::AccountId) -> BalanceOf<T> {
-		let count_rewarded = BlocksRewarded::<T>::get(acc);
 		let rewards = Rewards::<T>::get(acc);
 
 		// delegators and collators need to be handled differently
 		if let Some(delegator_state) = DelegatorState::<T>::get(acc) {
-			// #blocks for unclaimed staking rewards equals
-			// #blocks_authored_by_collator - #blocks_claimed_by_delegator
-			let count_unclaimed = BlocksAuthored::<T>::get(&delegator_state.owner).saturating_sub(count_rewarded);
-			let stake = delegator_state.amount;
-			// rewards += stake * reward_count * delegator_reward_rate
-			rewards.saturating_add(Self::calc_block_rewards_delegator(stake, count_unclaimed.into()))
-		} else if Self::is_active_candidate(acc).is_some() {
+			delegator_state.delegations.into_iter().fold(rewards, |rewards, stake| {
+				if Self::is_pending_delegation(acc, &stake.owner) {
+					return rewards;
+				}
+				// #blocks for unclaimed staking rewards equals
+				// #blocks_authored_by_collator - #blocks_claimed_by_delegator
+				let count_unclaimed = BlocksAuthored::<T>::get(&stake.owner)
+					.saturating_sub(DelegationBlocksRewarded::<T>::get(acc, &stake.owner));
+				let withheld_unclaimed = BlocksWithheld::<T>::get(&stake.owner)
+					.saturating_sub(DelegationBlocksWithheldRewarded::<T>::get(acc, &stake.owner));
+				let commission = CandidatePool::<T>::get(&stake.owner)
+					.map(|state| Self::effective_commission(&state))
+					.unwrap_or_default();
+				// rewards += stake * reward_count * delegator_reward_rate - commission
+				let reward = Self::deduct_withheld(
+					Self::calc_block_rewards_delegator(stake.amount, count_unclaimed.into()),
+					count_unclaimed,
+					withheld_unclaimed,
+				);
+				rewards.saturating_add(reward.saturating_sub(commission * reward))
+			})
+		} else if let Some(state) = CandidatePool::<T>::get(acc) {
 			// #blocks for unclaimed staking rewards equals
 			// #blocks_authored_by_collator - #blocks_claimed_by_collator
-			let count_unclaimed = BlocksAuthored::<T>::get(acc).saturating_sub(count_rewarded);
-			let stake = CandidatePool::<T>::get(acc)
-				.map(|state| state.stake)
-				.unwrap_or_else(BalanceOf::<T>::zero);
+			let count_authored = BlocksAuthored::<T>::get(acc);
+			let count_unclaimed = count_authored.saturating_sub(BlocksRewarded::<T>::get(acc));
+			let withheld_authored = BlocksWithheld::<T>::get(acc);
+			let withheld_unclaimed = withheld_authored.saturating_sub(BlocksWithheldRewarded::<T>::get(acc));
 			// rewards += stake * self_count * collator_reward_rate
-			rewards.saturating_add(Self::calc_block_rewards_collator(stake, count_unclaimed.into()))
+			let rewards = rewards.saturating_add(Self::deduct_withheld(
+				Self::calc_block_rewards_collator(state.stake, count_unclaimed.into()),
+				count_unclaimed,
+				withheld_unclaimed,
+			));
+			// rewards += commission on the unclaimed rewards of each delegator
+			let commission = Self::effective_commission(&state);
+			if commission.is_zero() {
+				return rewards;
+			}
+			state.delegators.iter().fold(rewards, |rewards, stake| {
+				if Self::is_pending_delegation(&stake.owner, acc) {
+					return rewards;
+				}
+				let count_unclaimed =
+					count_authored.saturating_sub(DelegationBlocksRewarded::<T>::get(&stake.owner, acc));
+				let withheld_unclaimed =
+					withheld_authored.saturating_sub(DelegationBlocksWithheldRewarded::<T>::get(&stake.owner, acc));
+				let reward = Self::deduct_withheld(
+					Self::calc_block_rewards_delegator(stake.amount, count_unclaimed.into()),
+					count_unclaimed,
+					withheld_unclaimed,
+				);
+				rewards.saturating_add(commission * reward)
+			})
 		} else {
 			rewards
 		}
@@ -78,16 +121,19 @@ impl<T: Config> Pallet<T> {
 		let total_issuance = T::Currency::total_issuance();
 		let total_stake = TotalCollatorStake::<T>::get();
 		let inflation_config = InflationConfig::<T>::get();
+		let reward_ratio = RoundRewardRatio::<T>::get();
 		let collator_staking_rate = Perquintill::from_rational(total_stake.collators, total_issuance);
 		let delegator_staking_rate = Perquintill::from_rational(total_stake.delegators, total_issuance);
 		let collator_reward_rate = Perquintill::from_rational(
 			inflation_config.collator.max_rate.deconstruct(),
 			collator_staking_rate.deconstruct(),
-		) * inflation_config.collator.reward_rate.annual;
+		) * inflation_config.collator.reward_rate.annual
+			* reward_ratio;
 		let delegator_reward_rate = Perquintill::from_rational(
 			inflation_config.delegator.max_rate.deconstruct(),
 			delegator_staking_rate.deconstruct(),
-		) * inflation_config.delegator.reward_rate.annual;
+		) * inflation_config.delegator.reward_rate.annual
+			* reward_ratio;
 
 		parachain_staking_runtime_api::StakingRates {
 			collator_staking_rate,
@@ -97,7 +143,134 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-		/// Provides a sorted list of collators most suited for given
+	/// Provides the commission a collator charges on the rewards of their
+	/// delegators together with the maximum set by governance and a
+	/// pending change, if any.
+	///
+	/// Returns `None` if the account is not a collator candidate.
+	///
+	/// At least used in Runtime API.
+	pub fn get_collator_commission(acc: &T::AccountId) -> Option<parachain_staking_runtime_api::CollatorCommission> {
+		let state = CandidatePool::<T>::get(acc)?;
+
+		Some(parachain_staking_runtime_api::CollatorCommission {
+			commission: Self::effective_commission(&state),
+			max_commission: MaxCollatorCommission::<T>::get(),
+			scheduled_change: CommissionChangeRequests::<T>::get(acc),
+		})
+	}
+
+	/// Provides the metadata published by a collator candidate and the
+	/// deposit reserved for it.
+	///
+	/// Returns `None` if the account has not published any metadata.
+	///
+	/// At least used in Runtime API.
+	pub fn get_collator_metadata(
+		acc: &T::AccountId,
+	) -> Option<parachain_staking_runtime_api::CollatorMetadata<BalanceOf<T>>> {
+		let (metadata, deposit) = CandidateMetadata::<T>::get(acc)?;
+
+		Some(parachain_staking_runtime_api::CollatorMetadata {
+			name: metadata.name.into_inner(),
+			url: metadata.url.into_inner(),
+			contact: metadata.contact.into_inner(),
+			region: metadata.region.into_inner(),
+			node_version: metadata.node_version.into_inner(),
+			deposit,
+		})
+	}
+
+	/// Provides the rewards credited to the account per round, oldest first.
+	///
+	/// At least used in Runtime API.
+	pub fn get_reward_history(acc: &T::AccountId) -> Vec<(SessionIndex, BalanceOf<T>)> {
+		RewardHistory::<T>::get(acc).into_inner()
+	}
+
+	/// Provides statistics of a collator candidate together with the
+	/// annual reward rates of the collator and their delegators.
+	///
+	/// The reward rates include the reduction for exceeding the maximum
+	/// staking rates as well as the commission. They assume that the
+	/// candidate is collating and authors their share of blocks.
+	///
+	/// Returns `None` if the account is not a collator candidate.
+	///
+	/// At least used in Runtime API.
+	pub fn get_collator_stats(
+		acc: &T::AccountId,
+	) -> Option<parachain_staking_runtime_api::CollatorStats<BalanceOf<T>>> {
+		let state = CandidatePool::<T>::get(acc)?;
+		let commission = Self::effective_commission(&state);
+		let rates = Self::get_staking_rates();
+
+		// the collator earns the commission on the rewards for delegated stake
+		let delegated = state.total.saturating_sub(state.stake);
+		let commission_reward = commission * (rates.delegator_reward_rate * delegated);
+		let collator_reward = (rates.collator_reward_rate * state.stake).saturating_add(commission_reward);
+		let collator_apy = if state.stake.is_zero() {
+			Perquintill::zero()
+		} else {
+			Perquintill::from_rational(collator_reward, state.stake)
+		};
+
+		Some(parachain_staking_runtime_api::CollatorStats {
+			blocks_authored: AuthoredBlocks::<T>::get(acc),
+			stake: state.stake,
+			total_stake: state.total,
+			delegators: state.delegators.len().saturated_into(),
+			commission,
+			is_collating: pallet_session::Pallet::<T>::validators().contains(acc),
+			collator_apy,
+			delegator_apy: Self::deduct_commission(rates.delegator_reward_rate, commission),
+		})
+	}
+
+	/// Provides the expected rewards of a delegation with the given stake to
+	/// the collator candidate after deducting the commission.
+	///
+	/// Takes into account that the delegation increases the staking rate of
+	/// delegators which can reduce the reward rate. Assumes that the
+	/// candidate is collating and authors their share of blocks.
+	///
+	/// Returns `None` if the account is not a collator candidate.
+	///
+	/// At least used in Runtime API.
+	pub fn get_delegation_projection(
+		collator: &T::AccountId,
+		stake: BalanceOf<T>,
+	) -> Option<parachain_staking_runtime_api::RewardProjection<BalanceOf<T>>> {
+		let state = CandidatePool::<T>::get(collator)?;
+		let commission = Self::effective_commission(&state);
+
+		let total_delegators = TotalCollatorStake::<T>::get().delegators.saturating_add(stake);
+		let staking_rate = Perquintill::from_rational(total_delegators, T::Currency::total_issuance());
+		let staking_info = InflationConfig::<T>::get().delegator;
+		let reduction = Perquintill::from_rational(staking_info.max_rate.deconstruct(), staking_rate.deconstruct());
+		let reward_ratio = RoundRewardRatio::<T>::get();
+		let apy = Self::deduct_commission(reward_ratio * reduction * staking_info.reward_rate.annual, commission);
+
+		// each block of the collator is rewarded for the number of collators,
+		// thus the multiplier for a round equals its length
+		let per_round = reward_ratio
+			* staking_info.compute_reward::<T>(stake, staking_rate, Round::<T>::get().length.into());
+
+		Some(parachain_staking_runtime_api::RewardProjection {
+			per_round: per_round.saturating_sub(commission * per_round),
+			per_year: apy * stake,
+			apy,
+		})
+	}
+
+	/// Deducts the commission of a collator from the reward rate of their
+	/// delegators.
+	fn deduct_commission(rate: Perquintill, commission: Perbill) -> Perquintill {
+		let commission = Perquintill::from_rational(commission.deconstruct(), Perbill::one().deconstruct());
+		rate.saturating_sub(commission * rate)
+	}
+
+	/// Provides a sorted list of collators most suited for given
 	/// delegator's stake amount determined with some heuristic algorithm.
 	///
 	/// The algorithm takes into account the following factors:
@@ -128,7 +301,7 @@ impl<T: Config> Pallet<T> {
 			} else {
 				continue;
 			}
-			if T::MaxDelegatorsPerCollator::get() as usize > candidate.delegators.len() {
+			if Self::staking_params().max_delegators_per_collator as usize > candidate.delegators.len() {
 				suitable_top_candidates.push(Stake {
 					owner: candidate.id,
 					amount: candidate.total,
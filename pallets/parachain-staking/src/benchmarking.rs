@@ -25,23 +25,41 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 //! Benchmarking
-use crate::{types::RoundInfo, *};
+use crate::{
+	types::{CollatorMetadata, CollatorMetadataOf, Delegator, LivenessPolicy, PendingSlash, RoundInfo, Stake},
+	*,
+};
 use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, Zero};
 use frame_support::{
 	assert_ok,
-	traits::{Currency, Get, OnInitialize},
+	traits::{Currency, EnsureOrigin, Get, OnInitialize},
+	BoundedVec,
 };
 use frame_system::{Pallet as System, RawOrigin};
 use pallet_session::Pallet as Session;
 use sp_runtime::{
 	traits::{One, SaturatedConversion, StaticLookup},
-	Perquintill,
+	Perbill, Perquintill,
 };
-use sp_std::{convert::TryInto, vec::Vec};
+use sp_std::{convert::TryInto, vec, vec::Vec};
 
 const COLLATOR_ACCOUNT_SEED: u32 = 0;
 const DELEGATOR_ACCOUNT_SEED: u32 = 1;
 
+/// Returns metadata in which every field has the maximum length.
+fn max_metadata<T: Config>() -> CollatorMetadataOf<T> {
+	let field: BoundedVec<u8, T::MaxMetadataLength> = vec![b'x'; T::MaxMetadataLength::get() as usize]
+		.try_into()
+		.expect("Field has maximum length");
+	CollatorMetadata {
+		name: field.clone(),
+		url: field.clone(),
+		contact: field.clone(),
+		region: field.clone(),
+		node_version: field,
+	}
+}
+
 /// Fills the candidate pool up to `num_candidates`.
 fn setup_collator_candidates<T: Config>(
 	num_candidates: u32,
@@ -96,6 +114,17 @@ fn fill_delegators<T: Config>(num_delegators: u32, collator: T::AccountId, colla
 	delegators
 }
 
+/// Lets an existing delegator additionally delegate all of the given collators.
+fn fill_delegations<T: Config>(delegator: &T::AccountId, collators: &[T::AccountId]) {
+	for collator in collators.iter() {
+		assert_ok!(Pallet::<T>::delegate_another_candidate(
+			T::RuntimeOrigin::from(Some(delegator.clone()).into()),
+			T::Lookup::unlookup(collator.clone()),
+			T::MinDelegatorStake::get(),
+		));
+	}
+}
+
 // fills unstake BTreeMap by unstaked many entries of 1
 fn fill_unstaking<T: Config>(collator: &T::AccountId, delegator: Option<&T::AccountId>, unstaked: u64)
 where
@@ -107,6 +136,7 @@ where
 		if let Some(delegator) = delegator {
 			assert_ok!(Pallet::<T>::delegator_stake_less(
 				RawOrigin::Signed(delegator.clone()).into(),
+				T::Lookup::unlookup(collator.clone()),
 				T::CurrencyBalance::one()
 			));
 		} else {
@@ -431,23 +461,24 @@ benchmarks! {
 		// make sure delegator collated to collator
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, amount);
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount);
 
 		// increase stake so we can unstake, because current stake is minimum
 		T::Currency::make_free_balance_be(&delegator, T::CurrencyBalance::from(u128::MAX));
-		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::CurrencyBalance::from(u as u64)));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, amount + T::CurrencyBalance::from(u as u64));
+		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), T::CurrencyBalance::from(u as u64)));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount + T::CurrencyBalance::from(u as u64));
 
 		// fill unstake BTreeMap by unstaked many entries of 1
 		fill_unstaking::<T>(&collator, Some(&delegator), u as u64);
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, amount);
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount);
 
 		let origin = RawOrigin::Signed(delegator.clone());
-	}: _(origin, amount)
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+	}: _(origin, unlookup_collator, amount)
 	verify {
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, amount + amount);
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount + amount);
 		assert!(Unstaking::<T>::get(&delegator).is_empty());
 	}
 
@@ -467,30 +498,31 @@ benchmarks! {
 		// make sure delegator collated to collator
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get());
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
 
 		// increase stake so we can unstake, because current stake is minimum
 		T::Currency::make_free_balance_be(&delegator, T::CurrencyBalance::from(u128::MAX));
-		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), amount + amount));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount + amount);
+		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount + amount));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount + amount);
 
 		// decrease stake once so we have an unstaking entry for this block
-		assert_ok!(Pallet::<T>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), amount));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount);
+		assert_ok!(Pallet::<T>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount);
 		assert_eq!(Unstaking::<T>::get(&delegator).len(), 1);
 
 		let origin = RawOrigin::Signed(delegator.clone());
-	}: _(origin, amount)
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+	}: _(origin, unlookup_collator, amount)
 	verify {
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get());
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
 		assert_eq!(Unstaking::<T>::get(&delegator).len(), 2);
 	}
 
 	leave_delegators {
-		// we need at least 1 collators
-		let n in 1 .. T::MaxTopCandidates::get();
+		// we need at least 1 delegation
+		let n in 1 .. T::MaxCollatorsPerDelegator::get();
 		// we need at least 1 delegator
 		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
 
@@ -504,23 +536,29 @@ benchmarks! {
 		// make sure delegator collated to collator
 		let state = CandidatePool::<T>::get(&collator).unwrap();
 		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get());
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get());
 
 		// increase stake so we can unstake, because current stake is minimum
 		T::Currency::make_free_balance_be(&delegator, T::CurrencyBalance::from(u128::MAX));
-		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), amount + amount));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount + amount);
+		assert_ok!(Pallet::<T>::delegator_stake_more(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount + amount));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount + amount);
 
 		// decrease stake once so we have an unstaking entry for this block
-		assert_ok!(Pallet::<T>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), amount));
-		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().amount, T::MinDelegatorStake::get() + amount);
+		assert_ok!(Pallet::<T>::delegator_stake_less(RawOrigin::Signed(delegator.clone()).into(), T::Lookup::unlookup(collator.clone()), amount));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, T::MinDelegatorStake::get() + amount);
 		assert_eq!(Unstaking::<T>::get(&delegator).len(), 1);
 
+		// delegate the remaining collators
+		fill_delegations::<T>(&delegator, &candidates[1..]);
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().delegations.len().saturated_into::<u32>(), n);
+
 		let origin = RawOrigin::Signed(delegator.clone());
 	}: _(origin)
 	verify {
-		let state = CandidatePool::<T>::get(&collator).unwrap();
-		assert!(!state.delegators.into_iter().any(|x| x.owner == delegator));
+		for c in candidates.iter() {
+			let state = CandidatePool::<T>::get(c).unwrap();
+			assert!(!state.delegators.into_iter().any(|x| x.owner == delegator));
+		}
 		assert!(DelegatorState::<T>::get(&delegator).is_none());
 		assert_eq!(Unstaking::<T>::get(&delegator).len(), 2);
 	}
@@ -566,20 +604,34 @@ benchmarks! {
 	}
 
 	increment_delegator_rewards {
-		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
-		let delegator = fill_delegators::<T>(1, collator.clone(), COLLATOR_ACCOUNT_SEED)[0].clone();
+		let n in 1 .. T::MaxCollatorsPerDelegator::get();
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		let delegator = fill_delegators::<T>(1, candidates[0].clone(), COLLATOR_ACCOUNT_SEED)[0].clone();
+		T::Currency::make_free_balance_be(&delegator, T::CurrencyBalance::from(u128::MAX));
+		fill_delegations::<T>(&delegator, &candidates[1..]);
 
 		// mock high values to compensate for tiny values in unit test env
 		let stake = T::CurrencyBalance::from(1_000_000_000_000_000_000u128);
-		DelegatorState::<T>::insert(&delegator, crate::types::Delegator { owner: collator.clone(), amount: stake});
-		BlocksAuthored::<T>::insert(&collator, u64::MAX.into());
+		let mut state = Delegator::try_new(candidates[0].clone(), stake).unwrap();
+		for c in candidates.iter().skip(1) {
+			assert_ok!(state.try_add_delegation(Stake { owner: c.clone(), amount: stake }));
+		}
+		DelegatorState::<T>::insert(&delegator, state);
+		for c in candidates.iter() {
+			// make the delegations eligible for rewards
+			PendingDelegations::<T>::remove(c);
+			BlocksAuthored::<T>::insert(c, u64::MAX.into());
+		}
 
 		assert!(Rewards::<T>::get(&delegator).is_zero());
 		let origin = RawOrigin::Signed(delegator.clone());
 	}: _(origin)
 	verify {
 		assert!(!Rewards::<T>::get(&delegator).is_zero());
-		assert_eq!(BlocksRewarded::<T>::get(&delegator), u64::MAX.into());
+		for c in candidates.iter() {
+			assert_eq!(DelegationBlocksRewarded::<T>::get(&delegator, c), u64::MAX.into());
+		}
 	}
 
 	increment_collator_rewards {
@@ -633,6 +685,283 @@ benchmarks! {
 		assert!(new.delegator.reward_rate.annual < old.delegator.reward_rate.annual);
 	}
 
+	set_max_collator_commission {
+		let n in 0 .. T::MaxTopCandidates::get();
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+
+		let origin = T::ParametersOrigin::try_successful_origin().unwrap();
+		let new = Perbill::from_percent(20);
+	}: _<T::RuntimeOrigin>(origin, new)
+	verify {
+		assert_eq!(MaxCollatorCommission::<T>::get(), new);
+	}
+
+	request_commission_change {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let commission = Perbill::from_percent(10);
+		MaxCollatorCommission::<T>::put(commission);
+		let origin = RawOrigin::Signed(collator.clone());
+	}: _(origin, commission)
+	verify {
+		assert_eq!(CommissionChangeRequests::<T>::get(&collator).map(|(_, c)| c), Some(commission));
+	}
+
+	execute_commission_change {
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		fill_delegators::<T>(m, collator.clone(), COLLATOR_ACCOUNT_SEED);
+		// mock high counter to compensate for tiny amounts in unit test env
+		BlocksAuthored::<T>::insert(&collator, u64::MAX.into());
+
+		let commission = Perbill::from_percent(10);
+		MaxCollatorCommission::<T>::put(commission);
+		assert_ok!(Pallet::<T>::request_commission_change(
+			RawOrigin::Signed(collator.clone()).into(),
+			commission
+		));
+		let round = Round::<T>::get();
+		Round::<T>::put(RoundInfo {
+			current: round.current + T::CommissionChangeDelay::get(),
+			..round
+		});
+		let origin = RawOrigin::Signed(collator.clone());
+	}: _(origin, T::Lookup::unlookup(collator.clone()))
+	verify {
+		assert_eq!(CandidatePool::<T>::get(&collator).unwrap().commission, commission);
+		assert!(CommissionChangeRequests::<T>::get(&collator).is_none());
+	}
+
+	delegate_another_candidate {
+		// we need at least 2 collators
+		let n in 2 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[1].clone();
+		let delegator = account("new-delegator", 0, DELEGATOR_ACCOUNT_SEED);
+		let amount = T::MinDelegatorStake::get();
+		T::Currency::make_free_balance_be(&delegator, amount + amount + amount + amount);
+		assert_ok!(Pallet::<T>::join_delegators(
+			RawOrigin::Signed(delegator.clone()).into(),
+			T::Lookup::unlookup(candidates[0].clone()),
+			amount,
+		));
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, unlookup_collator, amount)
+	verify {
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().total, amount + amount);
+	}
+
+	revoke_delegation {
+		// we need at least 2 collators
+		let n in 2 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let collator = candidates[1].clone();
+
+		// make sure delegator collated to the first collator
+		let state = CandidatePool::<T>::get(&candidates[0]).unwrap();
+		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
+		T::Currency::make_free_balance_be(&delegator, T::CurrencyBalance::from(u128::MAX));
+		fill_delegations::<T>(&delegator, &candidates[1..2]);
+		let unlookup_collator = T::Lookup::unlookup(collator.clone());
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, unlookup_collator)
+	verify {
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		assert!(!state.delegators.into_iter().any(|x| x.owner == delegator));
+		assert_eq!(DelegatorState::<T>::get(&delegator).unwrap().delegations.len(), 1);
+		assert_eq!(Unstaking::<T>::get(&delegator).len(), 1);
+	}
+
+	redelegate {
+		// we need at least 2 collators
+		let n in 2 .. T::MaxTopCandidates::get();
+		let m in 1 .. T::MaxDelegatorsPerCollator::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+		let from = candidates[0].clone();
+		let to = candidates[1].clone();
+
+		// make sure delegator collated to the first collator
+		let state = CandidatePool::<T>::get(&from).unwrap();
+		let delegator = state.delegators.into_bounded_vec()[0].owner.clone();
+		let unlookup_from = T::Lookup::unlookup(from.clone());
+		let unlookup_to = T::Lookup::unlookup(to.clone());
+
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, unlookup_from, unlookup_to)
+	verify {
+		let state = CandidatePool::<T>::get(&from).unwrap();
+		assert!(!state.delegators.into_iter().any(|x| x.owner == delegator));
+		let state = CandidatePool::<T>::get(&to).unwrap();
+		assert!(state.delegators.into_iter().any(|x| x.owner == delegator));
+		assert!(Unstaking::<T>::get(&delegator).is_empty());
+	}
+
+	set_liveness_policy {
+		let offline_slash = Perbill::from_percent(1);
+	}: _(RawOrigin::Root, 3, offline_slash, true)
+	verify {
+		assert_eq!(LivenessConfig::<T>::get(), LivenessPolicy {
+			max_missed_rounds: 3,
+			offline_slash,
+			slash_delegators: true,
+		});
+	}
+
+	go_online {
+		let n in (T::MinCollators::get() + 1) .. T::MaxTopCandidates::get() - 1;
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		for (i, c) in candidates.iter().enumerate() {
+			fill_delegators::<T>(m, c.clone(), i.saturated_into::<u32>());
+		}
+
+		let candidate = candidates[0].clone();
+		let state = CandidatePool::<T>::get(&candidate).unwrap();
+		Pallet::<T>::demote_offline_candidate(&candidate, state, &LivenessPolicy::default());
+		assert!(CandidatePool::<T>::get(&candidate).unwrap().is_offline());
+
+		let origin = RawOrigin::Signed(candidate.clone());
+	}: _(origin)
+	verify {
+		let candidates = TopCandidates::<T>::get();
+		assert!(candidates.into_iter().any(|other| other.owner == candidate));
+	}
+
+	slash_candidate {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let slash_origin = T::SlashOrigin::try_successful_origin().unwrap();
+		let fraction = Perbill::from_percent(10);
+		let when = Round::<T>::get().current + T::SlashDeferDuration::get().max(1);
+	}: _<T::RuntimeOrigin>(slash_origin, T::Lookup::unlookup(collator.clone()), fraction, true)
+	verify {
+		assert_eq!(PendingSlashes::<T>::get(when, &collator), Some(PendingSlash {
+			fraction,
+			slash_delegators: true,
+		}));
+	}
+
+	cancel_slash {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let slash_origin = T::SlashOrigin::try_successful_origin().unwrap();
+		assert_ok!(Pallet::<T>::slash_candidate(
+			slash_origin.clone(),
+			T::Lookup::unlookup(collator.clone()),
+			Perbill::from_percent(10),
+			true,
+		));
+		let when = Round::<T>::get().current + T::SlashDeferDuration::get().max(1);
+	}: _<T::RuntimeOrigin>(slash_origin, when, T::Lookup::unlookup(collator.clone()))
+	verify {
+		assert!(PendingSlashes::<T>::get(when, &collator).is_none());
+	}
+
+	apply_slash {
+		let m in 0 .. T::MaxDelegatorsPerCollator::get();
+
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		fill_delegators::<T>(m, collator.clone(), COLLATOR_ACCOUNT_SEED);
+		// mock high counter to compensate for tiny amounts in unit test env
+		BlocksAuthored::<T>::insert(&collator, u64::MAX.into());
+
+		let stake = CandidatePool::<T>::get(&collator).unwrap().stake;
+		let slash = PendingSlash {
+			fraction: Perbill::from_percent(50),
+			slash_delegators: true,
+		};
+	}: { Pallet::<T>::do_slash(&collator, slash) }
+	verify {
+		let state = CandidatePool::<T>::get(&collator).unwrap();
+		assert!(state.stake < stake);
+		// all delegations fell below the minimum and were removed
+		assert!(state.delegators.is_empty());
+	}
+
+	check_liveness {
+		let n in (T::MinCollators::get() + 1) .. T::MaxTopCandidates::get() - 1;
+
+		let candidates = setup_collator_candidates::<T>(n, None);
+		LivenessConfig::<T>::put(LivenessPolicy {
+			max_missed_rounds: 1,
+			offline_slash: Perbill::from_percent(1),
+			slash_delegators: true,
+		});
+		let session = Session::<T>::current_index();
+		let planned: BoundedVec<T::AccountId, T::MaxTopCandidates> = candidates.clone().try_into().unwrap();
+		PlannedCollators::<T>::insert(session, planned);
+	}: { Pallet::<T>::check_liveness(session) }
+	verify {
+		assert!(PlannedCollators::<T>::get(session).is_none());
+		assert!(CandidatePool::<T>::get(&candidates[0]).unwrap().is_offline());
+	}
+
+	set_auto_compound {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let delegator = fill_delegators::<T>(1, collator.clone(), COLLATOR_ACCOUNT_SEED)[0].clone();
+		let percentage = Perbill::from_percent(50);
+		let origin = RawOrigin::Signed(delegator.clone());
+	}: _(origin, percentage)
+	verify {
+		assert_eq!(AutoCompound::<T>::get(&delegator), percentage);
+	}
+
+	// worst case: raising a minimum stake starts a grace period
+	set_staking_parameter {
+		let origin = T::ParametersOrigin::try_successful_origin().unwrap();
+		let new = StakingParams::<T>::get().min_delegator_stake + T::CurrencyBalance::from(1u128);
+	}: set_min_delegator_stake<T::RuntimeOrigin>(origin, new)
+	verify {
+		assert_eq!(StakingParams::<T>::get().min_delegator_stake, new);
+		assert!(ActiveMinStakeGrace::<T>::get().is_some());
+	}
+
+	set_candidate_metadata {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let metadata = max_metadata::<T>();
+		let deposit = Pallet::<T>::metadata_deposit(&metadata);
+		T::Currency::make_free_balance_be(&collator, T::Currency::free_balance(&collator) + deposit);
+		let origin = RawOrigin::Signed(collator.clone());
+	}: _(origin, metadata.clone())
+	verify {
+		assert_eq!(CandidateMetadata::<T>::get(&collator), Some((metadata, deposit)));
+	}
+
+	clear_candidate_metadata {
+		let collator = setup_collator_candidates::<T>(1, None)[0].clone();
+		let metadata = max_metadata::<T>();
+		let deposit = Pallet::<T>::metadata_deposit(&metadata);
+		T::Currency::make_free_balance_be(&collator, T::Currency::free_balance(&collator) + deposit);
+		assert_ok!(Pallet::<T>::set_candidate_metadata(RawOrigin::Signed(collator.clone()).into(), metadata));
+		let origin = RawOrigin::Signed(collator.clone());
+	}: _(origin)
+	verify {
+		assert!(CandidateMetadata::<T>::get(&collator).is_none());
+	}
+
 }
 
 impl_benchmark_test_suite!(
@@ -72,10 +72,26 @@ pub trait WeightInfo {
 	fn leave_delegators(n: u32, m: u32, ) -> Weight;
 	fn unlock_unstaked(u: u32, ) -> Weight;
 	fn set_max_candidate_stake() -> Weight;
-	fn increment_delegator_rewards() -> Weight;
+	fn increment_delegator_rewards(n: u32, ) -> Weight;
 	fn increment_collator_rewards() -> Weight;
 	fn claim_rewards() -> Weight;
 	fn execute_scheduled_reward_change(n: u32, m: u32, ) -> Weight;
+	fn set_max_collator_commission(n: u32, m: u32, ) -> Weight;
+	fn request_commission_change() -> Weight;
+	fn execute_commission_change(m: u32, ) -> Weight;
+	fn delegate_another_candidate(n: u32, m: u32, ) -> Weight;
+	fn revoke_delegation(n: u32, m: u32, ) -> Weight;
+	fn redelegate(n: u32, m: u32, ) -> Weight;
+	fn set_liveness_policy() -> Weight;
+	fn go_online(n: u32, m: u32, ) -> Weight;
+	fn slash_candidate() -> Weight;
+	fn cancel_slash() -> Weight;
+	fn apply_slash(m: u32, ) -> Weight;
+	fn check_liveness(n: u32, ) -> Weight;
+	fn set_auto_compound() -> Weight;
+	fn set_staking_parameter() -> Weight;
+	fn set_candidate_metadata() -> Weight;
+	fn clear_candidate_metadata() -> Weight;
 }
 
 /// Weights for parachain_staking using the Substrate node and recommended hardware.
@@ -286,7 +302,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {// KILT Blockch
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking CandidatePool (r:2 w:1)
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
-	// Storage: ParachainStaking LastDelegation (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
 	// Storage: ParachainStaking Round (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking TopCandidates (r:1 w:1)
@@ -341,21 +357,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {// KILT Blockch
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking CandidatePool (r:1 w:1)
-	// Storage: ParachainStaking RewardCount (r:2 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
 	// Storage: ParachainStaking TopCandidates (r:1 w:1)
 	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
-	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
-	/// The range of component `n` is `[1, 75]`.
-	/// The range of component `m` is `[1, 34]`.
+	/// The range of component `n` is `[1, 5]`.
+	/// The range of component `m` is `[1, 69]`.
 	fn leave_delegators(n: u32, m: u32, ) -> Weight {
 		Weight::from_parts(0 as u64, 0)
-			// Standard Error: 153_000
-			.saturating_add(Weight::from_parts(18_270_000 as u64, 0).saturating_mul(n as u64))
-			// Standard Error: 341_000
+			// Standard Error: 721_198
+			.saturating_add(Weight::from_parts(104_119_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 1_568_203
 			.saturating_add(Weight::from_parts(39_114_000 as u64, 0).saturating_mul(m as u64))
 			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((8 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((7 as u64).saturating_mul(n as u64)))
 	}
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
@@ -373,32 +397,48 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {// KILT Blockch
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:0)
-	// Storage: ParachainStaking RewardCount (r:2 w:1)
-	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:0)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking BlocksWithheld (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksWithheldRewarded (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking OutstandingRewards (r:1 w:1)
 	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
 	// Storage: ParachainStaking InflationConfig (r:1 w:0)
-	fn increment_delegator_rewards() -> Weight {
-		Weight::from_parts(25_796_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(6 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	/// The range of component `n` is `[1, 5]`.
+	fn increment_delegator_rewards(n: u32, ) -> Weight {
+		Weight::from_parts(12_841_000 as u64, 0)
+			// Standard Error: 19_407
+			.saturating_add(Weight::from_parts(25_796_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((9 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(n as u64)))
 	}
 	// Storage: ParachainStaking CandidatePool (r:1 w:0)
 	// Storage: ParachainStaking RewardCount (r:1 w:1)
+	// Storage: ParachainStaking BlocksWithheld (r:1 w:0)
+	// Storage: ParachainStaking BlocksWithheldRewarded (r:1 w:1)
 	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking OutstandingRewards (r:1 w:1)
 	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
 	// Storage: ParachainStaking InflationConfig (r:1 w:0)
 	/// The range of component `m` is `[0, 35]`.
 	fn increment_collator_rewards() -> Weight {
 		Weight::from_parts(366_611_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(75 as u64))
-			.saturating_add(T::DbWeight::get().writes(72 as u64))
+			.saturating_add(T::DbWeight::get().reads(78 as u64))
+			.saturating_add(T::DbWeight::get().writes(74 as u64))
 	}
 	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking OutstandingRewards (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn claim_rewards() -> Weight {
 		Weight::from_parts(29_833_000 as u64, 0)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: ParachainStaking LastRewardReduction (r:1 w:1)
 	// Storage: ParachainStaking InflationConfig (r:1 w:1)
@@ -419,7 +459,232 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {// KILT Blockch
 			.saturating_add(T::DbWeight::get().reads((75 as u64).saturating_mul(m as u64)))
 			.saturating_add(T::DbWeight::get().writes((36 as u64).saturating_mul(n as u64)))
 			.saturating_add(T::DbWeight::get().writes((75 as u64).saturating_mul(m as u64)))
+	}
+	// Storage: ParachainStaking MaxCollatorCommission (r:0 w:1)
+	fn set_max_collator_commission(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(15_231_000 as u64, 0)
+			// Standard Error: 1_512_000
+			.saturating_add(Weight::from_parts(108_182_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 3_220_000
+			.saturating_add(Weight::from_parts(220_381_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().reads((37 as u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((37 as u64).saturating_mul(m as u64)))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CommissionChangeRequests (r:0 w:1)
+	fn request_commission_change() -> Weight {
+		Weight::from_parts(24_873_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CommissionChangeRequests (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking BlocksRewarded (r:35 w:35)
+	// Storage: ParachainStaking Rewards (r:36 w:36)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// The range of component `m` is `[0, 35]`.
+	fn execute_commission_change(m: u32, ) -> Weight {
+		Weight::from_parts(41_502_000 as u64, 0)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_parts(26_394_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(8 as u64))
+			.saturating_add(T::DbWeight::get().reads((2 as u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(m as u64)))
 	}	
+	// Storage: System Account (r:1 w:1)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:0 w:1)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:1)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn delegate_another_candidate(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 724_315
+			.saturating_add(Weight::from_parts(17_571_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 1_574_989
+			.saturating_add(Weight::from_parts(38_223_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(10 as u64))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn revoke_delegation(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 724_315
+			.saturating_add(Weight::from_parts(18_270_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 1_574_989
+			.saturating_add(Weight::from_parts(39_114_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:3 w:2)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:2 w:2)
+	// Storage: ParachainStaking BlocksAuthored (r:2 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:2)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn redelegate(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 724_315
+			.saturating_add(Weight::from_parts(27_418_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 1_574_989
+			.saturating_add(Weight::from_parts(58_507_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(17 as u64))
+			.saturating_add(T::DbWeight::get().writes(12 as u64))
+	}
+	// Storage: ParachainStaking LivenessConfig (r:0 w:1)
+	fn set_liveness_policy() -> Weight {
+		Weight::from_parts(14_812_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking MissedRounds (r:0 w:1)
+	fn go_online(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 181_000
+			.saturating_add(Weight::from_parts(18_204_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 294_000
+			.saturating_add(Weight::from_parts(33_507_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingSlashes (r:0 w:1)
+	fn slash_candidate() -> Weight {
+		Weight::from_parts(24_105_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking PendingSlashes (r:1 w:1)
+	fn cancel_slash() -> Weight {
+		Weight::from_parts(20_373_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking PendingSlashes (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking BlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:35 w:35)
+	// Storage: ParachainStaking DelegatorState (r:35 w:35)
+	// Storage: ParachainStaking Rewards (r:36 w:36)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: Balances Locks (r:36 w:36)
+	// Storage: System Account (r:37 w:37)
+	// Storage: ParachainStaking StakingParams (r:1 w:0)
+	// Storage: ParachainStaking ActiveMinStakeGrace (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking Unstaking (r:35 w:35)
+	// Storage: ParachainStaking DelegationStart (r:35 w:35)
+	// Storage: ParachainStaking DelegationBlocksWithheldRewarded (r:0 w:35)
+	// Storage: ParachainStaking AutoCompound (r:0 w:35)
+	fn apply_slash(m: u32, ) -> Weight {
+		Weight::from_parts(98_433_000 as u64, 0)
+			// Standard Error: 312_000
+			.saturating_add(Weight::from_parts(98_512_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(16 as u64))
+			.saturating_add(T::DbWeight::get().reads((7 as u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+			.saturating_add(T::DbWeight::get().writes((8 as u64).saturating_mul(m as u64)))
+	}
+	// Storage: ParachainStaking PlannedCollators (r:1 w:1)
+	// Storage: ParachainStaking LivenessConfig (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:16 w:16)
+	// Storage: ParachainStaking LastAuthoredRound (r:16 w:0)
+	// Storage: ParachainStaking MissedRounds (r:16 w:16)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	fn check_liveness(n: u32, ) -> Weight {
+		Weight::from_parts(11_760_000 as u64, 0)
+			// Standard Error: 143_000
+			.saturating_add(Weight::from_parts(46_918_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking AutoCompound (r:0 w:1)
+	fn set_auto_compound() -> Weight {
+		Weight::from_parts(19_377_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking MaxCollatorCandidateStake (r:1 w:0)
+	// Storage: ParachainStaking StakingParams (r:1 w:1)
+	// Storage: ParachainStaking ActiveMinStakeGrace (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	fn set_staking_parameter() -> Weight {
+		Weight::from_parts(26_418_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking CandidateMetadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_candidate_metadata() -> Weight {
+		Weight::from_parts(38_652_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: ParachainStaking CandidateMetadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_candidate_metadata() -> Weight {
+		Weight::from_parts(32_107_000 as u64, 0)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -629,7 +894,7 @@ impl WeightInfo for () {
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking CandidatePool (r:2 w:1)
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
-	// Storage: ParachainStaking LastDelegation (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
 	// Storage: ParachainStaking Round (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking TopCandidates (r:1 w:1)
@@ -684,21 +949,29 @@ impl WeightInfo for () {
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking CandidatePool (r:1 w:1)
-	// Storage: ParachainStaking RewardCount (r:2 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
 	// Storage: ParachainStaking TopCandidates (r:1 w:1)
 	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
-	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
-	/// The range of component `n` is `[1, 75]`.
-	/// The range of component `m` is `[1, 34]`.
+	/// The range of component `n` is `[1, 5]`.
+	/// The range of component `m` is `[1, 69]`.
 	fn leave_delegators(n: u32, m: u32, ) -> Weight {
 		Weight::from_parts(0 as u64, 0)
-			// Standard Error: 153_000
-			.saturating_add(Weight::from_parts(18_270_000 as u64, 0).saturating_mul(n as u64))
-			// Standard Error: 341_000
+			// Standard Error: 721_198
+			.saturating_add(Weight::from_parts(104_119_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 1_568_203
 			.saturating_add(Weight::from_parts(39_114_000 as u64, 0).saturating_mul(m as u64))
 			.saturating_add(RocksDbWeight::get().reads(6 as u64))
-			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((8 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((7 as u64).saturating_mul(n as u64)))
 	}
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
@@ -716,32 +989,48 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:0)
-	// Storage: ParachainStaking RewardCount (r:2 w:1)
-	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:0)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking BlocksWithheld (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksWithheldRewarded (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking OutstandingRewards (r:1 w:1)
 	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
 	// Storage: ParachainStaking InflationConfig (r:1 w:0)
-	fn increment_delegator_rewards() -> Weight {
-		Weight::from_parts(25_796_000 as u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(6 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	/// The range of component `n` is `[1, 5]`.
+	fn increment_delegator_rewards(n: u32, ) -> Weight {
+		Weight::from_parts(12_841_000 as u64, 0)
+			// Standard Error: 19_407
+			.saturating_add(Weight::from_parts(25_796_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((9 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes((5 as u64).saturating_mul(n as u64)))
 	}
 	// Storage: ParachainStaking CandidatePool (r:1 w:0)
 	// Storage: ParachainStaking RewardCount (r:1 w:1)
+	// Storage: ParachainStaking BlocksWithheld (r:1 w:0)
+	// Storage: ParachainStaking BlocksWithheldRewarded (r:1 w:1)
 	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking OutstandingRewards (r:1 w:1)
 	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
 	// Storage: ParachainStaking InflationConfig (r:1 w:0)
 	/// The range of component `m` is `[0, 35]`.
 	fn increment_collator_rewards() -> Weight {
 		Weight::from_parts(366_611_000 as u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(75 as u64))
-			.saturating_add(RocksDbWeight::get().writes(72 as u64))
+			.saturating_add(RocksDbWeight::get().reads(78 as u64))
+			.saturating_add(RocksDbWeight::get().writes(74 as u64))
 	}
 	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking OutstandingRewards (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn claim_rewards() -> Weight {
 		Weight::from_parts(29_833_000 as u64, 0)
-			.saturating_add(RocksDbWeight::get().reads(2 as u64))
-			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
 	// Storage: ParachainStaking LastRewardReduction (r:1 w:1)
 	// Storage: ParachainStaking InflationConfig (r:1 w:1)
@@ -763,4 +1052,229 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((36 as u64).saturating_mul(n as u64)))
 			.saturating_add(RocksDbWeight::get().writes((75 as u64).saturating_mul(m as u64)))
 	}
+	// Storage: ParachainStaking MaxCollatorCommission (r:0 w:1)
+	fn set_max_collator_commission(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(15_231_000 as u64, 0)
+			// Standard Error: 1_512_000
+			.saturating_add(Weight::from_parts(108_182_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 3_220_000
+			.saturating_add(Weight::from_parts(220_381_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads((1 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().reads((37 as u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes((37 as u64).saturating_mul(m as u64)))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CommissionChangeRequests (r:0 w:1)
+	fn request_commission_change() -> Weight {
+		Weight::from_parts(24_873_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CommissionChangeRequests (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking BlocksRewarded (r:35 w:35)
+	// Storage: ParachainStaking Rewards (r:36 w:36)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// The range of component `m` is `[0, 35]`.
+	fn execute_commission_change(m: u32, ) -> Weight {
+		Weight::from_parts(41_502_000 as u64, 0)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_parts(26_394_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(8 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(m as u64)))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:0 w:1)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:1)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn delegate_another_candidate(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 724_315
+			.saturating_add(Weight::from_parts(17_571_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 1_574_989
+			.saturating_add(Weight::from_parts(38_223_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(10 as u64))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn revoke_delegation(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 724_315
+			.saturating_add(Weight::from_parts(18_270_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 1_574_989
+			.saturating_add(Weight::from_parts(39_114_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:3 w:2)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:2 w:2)
+	// Storage: ParachainStaking BlocksAuthored (r:2 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:2)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn redelegate(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 724_315
+			.saturating_add(Weight::from_parts(27_418_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 1_574_989
+			.saturating_add(Weight::from_parts(58_507_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(17 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+	}
+	// Storage: ParachainStaking LivenessConfig (r:0 w:1)
+	fn set_liveness_policy() -> Weight {
+		Weight::from_parts(14_812_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking MissedRounds (r:0 w:1)
+	fn go_online(n: u32, m: u32, ) -> Weight {
+		Weight::from_parts(0 as u64, 0)
+			// Standard Error: 181_000
+			.saturating_add(Weight::from_parts(18_204_000 as u64, 0).saturating_mul(n as u64))
+			// Standard Error: 294_000
+			.saturating_add(Weight::from_parts(33_507_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingSlashes (r:0 w:1)
+	fn slash_candidate() -> Weight {
+		Weight::from_parts(24_105_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking PendingSlashes (r:1 w:1)
+	fn cancel_slash() -> Weight {
+		Weight::from_parts(20_373_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking PendingSlashes (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking BlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:35 w:35)
+	// Storage: ParachainStaking DelegatorState (r:35 w:35)
+	// Storage: ParachainStaking Rewards (r:36 w:36)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: Balances Locks (r:36 w:36)
+	// Storage: System Account (r:37 w:37)
+	// Storage: ParachainStaking StakingParams (r:1 w:0)
+	// Storage: ParachainStaking ActiveMinStakeGrace (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking Unstaking (r:35 w:35)
+	// Storage: ParachainStaking DelegationStart (r:35 w:35)
+	// Storage: ParachainStaking DelegationBlocksWithheldRewarded (r:0 w:35)
+	// Storage: ParachainStaking AutoCompound (r:0 w:35)
+	fn apply_slash(m: u32, ) -> Weight {
+		Weight::from_parts(98_433_000 as u64, 0)
+			// Standard Error: 312_000
+			.saturating_add(Weight::from_parts(98_512_000 as u64, 0).saturating_mul(m as u64))
+			.saturating_add(RocksDbWeight::get().reads(16 as u64))
+			.saturating_add(RocksDbWeight::get().reads((7 as u64).saturating_mul(m as u64)))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes((8 as u64).saturating_mul(m as u64)))
+	}
+	// Storage: ParachainStaking PlannedCollators (r:1 w:1)
+	// Storage: ParachainStaking LivenessConfig (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:16 w:16)
+	// Storage: ParachainStaking LastAuthoredRound (r:16 w:0)
+	// Storage: ParachainStaking MissedRounds (r:16 w:16)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	fn check_liveness(n: u32, ) -> Weight {
+		Weight::from_parts(11_760_000 as u64, 0)
+			// Standard Error: 143_000
+			.saturating_add(Weight::from_parts(46_918_000 as u64, 0).saturating_mul(n as u64))
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().reads((3 as u64).saturating_mul(n as u64)))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(n as u64)))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking AutoCompound (r:0 w:1)
+	fn set_auto_compound() -> Weight {
+		Weight::from_parts(19_377_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: ParachainStaking MaxCollatorCandidateStake (r:1 w:0)
+	// Storage: ParachainStaking StakingParams (r:1 w:1)
+	// Storage: ParachainStaking ActiveMinStakeGrace (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	fn set_staking_parameter() -> Weight {
+		Weight::from_parts(26_418_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking CandidateMetadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_candidate_metadata() -> Weight {
+		Weight::from_parts(38_652_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: ParachainStaking CandidateMetadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_candidate_metadata() -> Weight {
+		Weight::from_parts(32_107_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
 }
\ No newline at end of file
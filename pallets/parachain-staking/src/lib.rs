@@ -54,7 +54,17 @@
 //!
 //! To join the set of delegators, an account must call `join_delegators` with
 //! stake >= `MinDelegatorStake`. There are also runtime methods for delegating
-//! additional collators and revoking delegations.
+//! additional collators and revoking delegations. A delegator can back up to
+//! `MaxCollatorsPerDelegator` many collators and move a delegation to another
+//! collator via `redelegate` without unstaking.
+//!
+//! New delegations, including redelegated ones, only earn rewards for blocks
+//! authored from `DelegationWarmUp` rounds after they were made on. This
+//! prevents delegators from hopping between collators within a round to
+//! collect rewards for the same stake more than once. Withdrawing stake from
+//! a delegation within `MinDelegationDuration` rounds after it was made costs
+//! `EarlyExitPenalty` of the withdrawn stake which is paid to the other
+//! delegators of the collator or to `EarlyExitPenaltyBeneficiary`.
 //!
 //!
 //! - [`Config`]
@@ -69,9 +79,14 @@
 //!   session. That makes the set of active collators the set of block authors
 //!   by handing it over to the session and the authority pallet.
 //! - Delegating to a collator candidate by staking for them.
+//! - Delegating to multiple collator candidates and moving a delegation from
+//!   one candidate to another without unstaking.
 //! - Increasing and reducing your stake as a collator or delegator.
-//! - Revoking your delegation entirely.
+//! - Revoking a single delegation or all of your delegations entirely.
 //! - Requesting to leave the set of collator candidates.
+//! - Charging a commission on the rewards of your delegators as a collator,
+//!   bounded by a maximum set by governance and changed with a delay of
+//!   `CommissionChangeDelay` rounds.
 //! - Withdrawing your unstaked balance after waiting for a certain number of
 //!   blocks.
 //!
@@ -133,6 +148,7 @@ pub(crate) mod tests;
 
 pub mod api;
 mod inflation;
+mod rewards;
 mod set;
 mod types;
 
@@ -144,11 +160,15 @@ pub use crate::{default_weights::WeightInfo, pallet::*};
 pub mod pallet {
 	use super::*;
 	pub use crate::inflation::{InflationInfo, RewardRate, StakingInfo};
+	pub use crate::{
+		rewards::{InflationaryRewards, PotRewards, RewardSource},
+		types::{CollatorMetadata, PenaltyDestination},
+	};
 
 	use frame_support::{
 		assert_ok,
 		pallet_prelude::*,
-		storage::bounded_btree_map::BoundedBTreeMap,
+		storage::{bounded_btree_map::BoundedBTreeMap, with_storage_layer},
 		traits::{
 			Currency, EstimateNextSessionRotation, Get, Imbalance, LockIdentifier, LockableCurrency, OnUnbalanced,
 			ReservableCurrency, StorageVersion, WithdrawReasons,
@@ -160,8 +180,8 @@ pub mod pallet {
 	use pallet_session::ShouldEndSession;
 	use scale_info::TypeInfo;
 	use sp_runtime::{
-		traits::{Convert, One, SaturatedConversion, Saturating, StaticLookup, Zero},
-		Permill, Perquintill,
+		traits::{CheckedSub, Convert, One, SaturatedConversion, Saturating, StaticLookup, Zero},
+		Perbill, Permill, Perquintill,
 	};
 	use sp_staking::SessionIndex;
 	use sp_std::prelude::*;
@@ -169,8 +189,9 @@ pub mod pallet {
 	use crate::{
 		set::OrderedSet,
 		types::{
-			BalanceOf, Candidate, CandidateOf, CandidateStatus, DelegationCounter, Delegator, NegativeImbalanceOf,
-			RoundInfo, Stake, StakeOf, TotalStake,
+			BalanceOf, Candidate, CandidateOf, CandidateStatus, CollatorMetadataOf, Delegator, LivenessPolicy,
+			MinStakeGrace, NegativeImbalanceOf, PendingSlash, RoundInfo, Stake, StakeOf, StakingParameters,
+			StakingParametersOf, TotalStake,
 		},
 	};
 	use sp_std::{convert::TryInto, fmt::Debug};
@@ -179,7 +200,7 @@ pub mod pallet {
 	pub(crate) const STAKING_ID: LockIdentifier = *b"curiostk";
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(8);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(13);
 
 	/// Pallet for parachain staking.
 	#[pallet::pallet]
@@ -226,15 +247,31 @@ pub mod pallet {
 		/// genesis configuration.
 		#[pallet::constant]
 		type DefaultBlocksPerRound: Get<Self::BlockNumber>;
-		/// Number of blocks for which unstaked balance will still be locked
-		/// before it can be unlocked by actively calling the extrinsic
+		/// Initial number of blocks for which unstaked balance will still be
+		/// locked before it can be unlocked by actively calling the extrinsic
 		/// `unlock_unstaked`.
+		///
+		/// NOTE: Only used to initialise `StakingParams`, which can be changed
+		/// by `ParametersOrigin`.
 		#[pallet::constant]
 		type StakeDuration: Get<Self::BlockNumber>;
-		/// Number of rounds a collator has to stay active after submitting a
-		/// request to leave the set of collator candidates.
+		/// Upper bound for the stake duration which can be set by governance.
+		#[pallet::constant]
+		type MaxStakeDuration: Get<Self::BlockNumber>;
+		/// Initial number of rounds a collator has to stay active after
+		/// submitting a request to leave the set of collator candidates.
+		///
+		/// NOTE: Only used to initialise `StakingParams`.
 		#[pallet::constant]
 		type ExitQueueDelay: Get<u32>;
+		/// Upper bound for the exit queue delay which can be set by governance.
+		#[pallet::constant]
+		type MaxExitQueueDelay: Get<u32>;
+
+		/// Number of rounds a collator has to wait after requesting a change
+		/// of their commission before it can be applied.
+		#[pallet::constant]
+		type CommissionChangeDelay: Get<u32>;
 
 		/// Minimum number of collators selected from the set of candidates at
 		/// every validation round.
@@ -246,24 +283,56 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinRequiredCollators: Get<u32>;
 
-		/// Maximum number of delegations which can be made within the same
-		/// round.
+		/// Number of rounds a new delegation has to wait before it is
+		/// eligible for rewards. A delegation made in round `r` earns rewards
+		/// for blocks authored from round `r + DelegationWarmUp` on.
 		///
-		/// NOTE: To prevent re-delegation-reward attacks, we should keep this
-		/// to be one.
+		/// NOTE: A warm-up of at least one round prevents
+		/// re-delegation-reward attacks, i.e., collecting rewards for the
+		/// same stake more than once per round.
 		#[pallet::constant]
-		type MaxDelegationsPerRound: Get<u32>;
+		type DelegationWarmUp: Get<SessionIndex>;
+
+		/// Number of rounds a delegation has to be kept before stake can be
+		/// withdrawn from it without paying `EarlyExitPenalty`.
+		#[pallet::constant]
+		type MinDelegationDuration: Get<SessionIndex>;
+
+		/// The share of the stake withdrawn from a delegation within
+		/// `MinDelegationDuration` rounds which is taken as a penalty. Zero
+		/// disables the penalty.
+		///
+		/// NOTE: Must be less than 100%.
+		#[pallet::constant]
+		type EarlyExitPenalty: Get<Perbill>;
+
+		/// Whether early exit penalties go to the other delegators of the
+		/// collator or to `EarlyExitPenaltyBeneficiary`.
+		#[pallet::constant]
+		type EarlyExitPenaltyDestination: Get<PenaltyDestination>;
+
+		/// The beneficiary to receive early exit penalties which are not
+		/// distributed to delegators.
+		type EarlyExitPenaltyBeneficiary: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
-		/// Maximum number of delegators a single collator can have.
+		/// Upper bound for the number of delegators a single collator can
+		/// have. The limit in effect is `StakingParams` and can be lowered by
+		/// governance.
 		#[pallet::constant]
 		type MaxDelegatorsPerCollator: Get<u32> + Debug + PartialEq;
 
+		/// Maximum number of collators a single delegator can delegate.
+		#[pallet::constant]
+		type MaxCollatorsPerDelegator: Get<u32> + Debug + PartialEq;
+
 		/// Maximum size of the top candidates set.
 		#[pallet::constant]
 		type MaxTopCandidates: Get<u32> + Debug + PartialEq;
 
-		/// Minimum stake required for any account to be elected as validator
-		/// for a round.
+		/// Initial minimum stake required for any account to be elected as
+		/// validator for a round.
+		///
+		/// NOTE: Only used to initialise `StakingParams`.
 		#[pallet::constant]
 		type MinCollatorStake: Get<BalanceOf<Self>>;
 
@@ -272,10 +341,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type MinCollatorCandidateStake: Get<BalanceOf<Self>>;
 
-		/// Minimum stake required for any account to become a delegator.
+		/// Initial minimum stake required for any account to become a
+		/// delegator.
+		///
+		/// NOTE: Only used to initialise `StakingParams`.
 		#[pallet::constant]
 		type MinDelegatorStake: Get<BalanceOf<Self>>;
 
+		/// Number of rounds for which existing collators and delegations are
+		/// still measured against the previous minimum stake after governance
+		/// raised it.
+		#[pallet::constant]
+		type MinStakeGracePeriod: Get<SessionIndex>;
+
 		/// Max number of concurrent active unstaking requests before
 		/// unlocking.
 		///
@@ -295,15 +373,64 @@ pub mod pallet {
 		#[pallet::constant]
 		type NetworkRewardStart: Get<<Self as frame_system::Config>::BlockNumber>;
 
-		/// The rate in percent for the network rewards which are based on the
-		/// maximum number of collators and the maximum amount a collator can
-		/// stake.
+		/// The initial rate in percent for the network rewards which are based
+		/// on the maximum number of collators and the maximum amount a
+		/// collator can stake.
+		///
+		/// NOTE: Only used to initialise `StakingParams`.
 		#[pallet::constant]
 		type NetworkRewardRate: Get<Perquintill>;
+		/// Upper bound for the network reward rate which can be set by
+		/// governance.
+		#[pallet::constant]
+		type MaxNetworkRewardRate: Get<Perquintill>;
 
 		/// The beneficiary to receive the network rewards.
 		type NetworkRewardBeneficiary: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
+		/// The source from which staking rewards are paid out.
+		///
+		/// `InflationaryRewards` mints them, `PotRewards` pays them out of a
+		/// pre-funded account within a yearly budget. Network rewards are
+		/// only issued for inflationary sources.
+		type RewardSource: RewardSource<Self>;
+
+		/// The origin which may schedule and cancel slashes of collator
+		/// candidates.
+		type SlashOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The origin which may change the staking parameters.
+		type ParametersOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Number of rounds a scheduled slash is deferred before it is applied.
+		/// Within this window, the slash can be cancelled by `SlashOrigin`.
+		///
+		/// NOTE: Slashes are deferred by at least one round.
+		#[pallet::constant]
+		type SlashDeferDuration: Get<u32>;
+
+		/// The handler for slashed funds, e.g. the treasury.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// Maximum number of rounds for which the credited rewards of an
+		/// account are kept in its reward history.
+		#[pallet::constant]
+		type MaxRewardHistory: Get<u32>;
+
+		/// Maximum length in bytes of each field of the metadata of a
+		/// collator candidate.
+		#[pallet::constant]
+		type MaxMetadataLength: Get<u32> + Debug + PartialEq;
+
+		/// The base deposit reserved for publishing metadata as a collator
+		/// candidate.
+		#[pallet::constant]
+		type MetadataDepositBase: Get<BalanceOf<Self>>;
+
+		/// The deposit reserved per byte of published metadata.
+		#[pallet::constant]
+		type MetadataDepositPerByte: Get<BalanceOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -349,13 +476,6 @@ pub mod pallet {
 		/// The account has not delegated any collator candidate yet, hence it
 		/// is not in the set of delegators.
 		NotYetDelegating,
-		/// The delegator has exceeded the number of delegations per round which
-		/// is equal to MaxDelegatorsPerCollator.
-		///
-		/// This protects against attacks in which a delegator can re-delegate
-		/// from a collator who has already authored a block, to another one
-		/// which has not in this round.
-		DelegationsPerRoundExceeded,
 		/// The collator candidate has already reached the maximum number of
 		/// delegators.
 		///
@@ -402,6 +522,26 @@ pub mod pallet {
 		UnstakingIsEmpty,
 		/// Cannot claim rewards if empty.
 		RewardsNotFound,
+		/// The requested commission exceeds the maximum commission set by
+		/// governance.
+		CommissionAboveMax,
+		/// The collator has not requested to change their commission.
+		CommissionChangeNotFound,
+		/// The commission change was executed before waiting for
+		/// `CommissionChangeDelay` many rounds.
+		CannotChangeCommissionYet,
+		/// The collator candidate has not been demoted for being offline.
+		NotOffline,
+		/// The slash to be cancelled does not exist.
+		SlashNotFound,
+		/// The stake cannot be reduced while a slash of it is pending.
+		SlashPending,
+		/// The account is neither a collator candidate nor a delegator.
+		NotStaking,
+		/// The delegation is not below the minimum delegator stake in effect.
+		StakeNotBelowMin,
+		/// The collator candidate has not published any metadata.
+		MetadataNotFound,
 	}
 
 	#[pallet::event]
@@ -458,6 +598,11 @@ pub mod pallet {
 		/// \[account, amount of funds staked, total amount of delegators' funds
 		/// staked for the collator candidate\]
 		Delegation(T::AccountId, BalanceOf<T>, T::AccountId, BalanceOf<T>),
+		/// A delegator has moved their delegation from one collator candidate
+		/// to another without unstaking. \[delegator's account, previous
+		/// collator's account, new collator's account, amount of funds
+		/// redelegated\]
+		Redelegated(T::AccountId, T::AccountId, T::AccountId, BalanceOf<T>),
 		/// A new delegation has replaced an existing one in the set of ongoing
 		/// delegations for a collator candidate. \[new delegator's account,
 		/// amount of funds staked in the new delegation, replaced delegator's
@@ -490,6 +635,86 @@ pub mod pallet {
 		/// \[round number, first block in the current round, old value, new
 		/// value\]
 		BlocksPerRoundSet(SessionIndex, T::BlockNumber, T::BlockNumber, T::BlockNumber),
+		/// The maximum commission collators can charge has changed.
+		/// \[new max commission\]
+		MaxCollatorCommissionSet(Perbill),
+		/// A collator has requested to change their commission.
+		/// \[collator's account, requested commission, round number from
+		/// which the change can be executed\]
+		CollatorCommissionChangeRequested(T::AccountId, Perbill, SessionIndex),
+		/// The commission of a collator has changed.
+		/// \[collator's account, old commission, new commission\]
+		CollatorCommissionSet(T::AccountId, Perbill, Perbill),
+		/// The liveness policy for selected collators has changed.
+		/// \[max missed rounds, offline slash, whether delegators are slashed\]
+		LivenessPolicySet(u32, Perbill, bool),
+		/// A selected collator has not authored any block in a round.
+		/// \[collator's account, number of consecutively missed rounds\]
+		CollatorMissedRound(T::AccountId, u32),
+		/// A collator candidate was removed from the set of top candidates
+		/// for missing too many rounds in a row. \[collator's account\]
+		CollatorWentOffline(T::AccountId),
+		/// A collator candidate which was offline has come back online.
+		/// \[collator's account\]
+		CollatorBackOnline(T::AccountId),
+		/// A slash of a collator candidate has been scheduled.
+		/// \[collator's account, slashed fraction, whether delegators are
+		/// slashed, round number in which the slash is applied\]
+		SlashScheduled(T::AccountId, Perbill, bool, SessionIndex),
+		/// A scheduled slash has been cancelled.
+		/// \[round number in which the slash would have been applied,
+		/// collator's account\]
+		SlashCancelled(SessionIndex, T::AccountId),
+		/// Staked funds of a collator or a delegator have been slashed.
+		/// \[account, amount slashed\]
+		Slashed(T::AccountId, BalanceOf<T>),
+		/// The share of rewards which is staked automatically has changed.
+		/// \[account, auto-compound percentage\]
+		AutoCompoundSet(T::AccountId, Perbill),
+		/// Rewards of a collator or a delegator have been staked
+		/// automatically. \[account, collator candidate's account, amount
+		/// of rewards staked\]
+		RewardsCompounded(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// The minimum stake required for collators to be selected has
+		/// changed. \[old min stake, new min stake\]
+		MinCollatorStakeSet(BalanceOf<T>, BalanceOf<T>),
+		/// The minimum stake required for delegations has changed.
+		/// \[old min stake, new min stake\]
+		MinDelegatorStakeSet(BalanceOf<T>, BalanceOf<T>),
+		/// The maximum number of delegators per collator has changed.
+		/// \[old max number, new max number\]
+		MaxDelegatorsPerCollatorSet(u32, u32),
+		/// The number of blocks unstaked funds stay locked has changed.
+		/// \[old duration, new duration\]
+		StakeDurationSet(T::BlockNumber, T::BlockNumber),
+		/// The number of rounds collators have to wait before leaving has
+		/// changed. \[old delay, new delay\]
+		ExitQueueDelaySet(u32, u32),
+		/// The network reward rate has changed.
+		/// \[old rate, new rate\]
+		NetworkRewardRateSet(Perquintill, Perquintill),
+		/// A minimum stake has been raised. Existing collators and
+		/// delegations are measured against the previous minimum until the
+		/// grace period ends. \[round number in which the grace period ends\]
+		MinStakeGracePeriodStarted(SessionIndex),
+		/// A delegation which fell below the minimum delegator stake has been
+		/// removed. \[delegator's account, collator's account, amount
+		/// unstaked\]
+		DelegationBelowMinRemoved(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// A collator candidate has published their metadata.
+		/// \[collator's account, reserved deposit\]
+		CandidateMetadataSet(T::AccountId, BalanceOf<T>),
+		/// A collator candidate has removed their metadata.
+		/// \[collator's account\]
+		CandidateMetadataCleared(T::AccountId),
+		/// A delegator has paid a penalty for withdrawing stake from a
+		/// delegation within `MinDelegationDuration` rounds. \[delegator's
+		/// account, collator's account, penalty\]
+		EarlyExitPenalized(T::AccountId, T::AccountId, BalanceOf<T>),
+		/// A collator candidate whose stake fell below the minimum collator
+		/// stake due to a slash has been removed from the set of top
+		/// candidates. \[collator's account, remaining stake\]
+		CandidateBelowMinDemoted(T::AccountId, BalanceOf<T>),
 	}
 
 	#[pallet::hooks]
@@ -507,10 +732,20 @@ pub mod pallet {
 
 				Self::deposit_event(Event::NewRound(round.first, round.current));
 				post_weight = <T as Config>::WeightInfo::on_initialize_round_update();
+
+				// apply the slashes which were deferred until this round
+				let num_slashes = Self::apply_pending_slashes(round.current);
+				post_weight = post_weight.saturating_add(
+					<T as Config>::WeightInfo::apply_slash(T::MaxDelegatorsPerCollator::get())
+						.saturating_mul(num_slashes.into()),
+				);
 			}
+			// auto-compound the next top candidate queued at the end of the last round
+			post_weight = post_weight.saturating_add(Self::compound_next_candidate());
+
 			// check for network reward and mint
 			// on success, mint each block
-			if now > T::NetworkRewardStart::get() {
+			if T::RewardSource::is_inflationary() && now > T::NetworkRewardStart::get() {
 				T::NetworkRewardBeneficiary::on_unbalanced(Self::issue_network_reward());
 				post_weight = post_weight.saturating_add(<T as Config>::WeightInfo::on_initialize_network_rewards());
 			}
@@ -528,23 +763,29 @@ pub mod pallet {
 	#[pallet::getter(fn round)]
 	pub(crate) type Round<T: Config> = StorageValue<_, RoundInfo<T::BlockNumber>, ValueQuery>;
 
-	/// Delegation information for the latest session in which a delegator
-	/// delegated.
+	/// The round in which a delegation was made.
 	///
-	/// It maps from an account to the number of delegations in the last
-	/// session in which they (re-)delegated.
+	/// It maps from a delegator and a collator to the round of the
+	/// delegation. Withdrawing stake from the delegation before
+	/// `MinDelegationDuration` rounds have passed is penalized. Delegations
+	/// from genesis are not tracked.
 	#[pallet::storage]
-	#[pallet::getter(fn last_delegation)]
-	pub(crate) type LastDelegation<T: Config> =
-		StorageMap<_, Twox64Concat, T::AccountId, DelegationCounter, ValueQuery>;
+	#[pallet::getter(fn delegation_start)]
+	pub(crate) type DelegationStart<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, SessionIndex, OptionQuery>;
 
 	/// Delegation staking information.
 	///
 	/// It maps from an account to its delegation details.
 	#[pallet::storage]
 	#[pallet::getter(fn delegator_state)]
-	pub(crate) type DelegatorState<T: Config> =
-		StorageMap<_, Twox64Concat, T::AccountId, Delegator<T::AccountId, BalanceOf<T>>, OptionQuery>;
+	pub(crate) type DelegatorState<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Delegator<T::AccountId, BalanceOf<T>, T::MaxCollatorsPerDelegator>,
+		OptionQuery,
+	>;
 
 	/// The staking information for a candidate.
 	///
@@ -623,19 +864,77 @@ pub mod pallet {
 	#[pallet::getter(fn blocks_authored)]
 	pub(crate) type BlocksAuthored<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber, ValueQuery>;
 
-	/// The number of blocks for which rewards have been claimed by an address.
+	/// The number of blocks authored by collators since becoming a
+	/// candidate.
 	///
-	/// For collators, this can be at most BlocksAuthored. It is updated when
-	/// incrementing collator rewards, either when calling
-	/// `inc_collator_rewards` or updating the `InflationInfo`.
+	/// Unlike `BlocksAuthored`, every block is only counted once.
+	#[pallet::storage]
+	#[pallet::getter(fn authored_blocks)]
+	pub(crate) type AuthoredBlocks<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The number of blocks for which rewards have been claimed by a
+	/// collator.
 	///
-	/// For delegators, this can be at most BlocksAuthored of the collator.It is
-	/// updated when incrementing delegator rewards, either when calling
-	/// `inc_delegator_rewards` or updating the `InflationInfo`.
+	/// This can be at most BlocksAuthored. It is updated when incrementing
+	/// collator rewards, either when calling `inc_collator_rewards` or
+	/// updating the `InflationInfo`.
 	#[pallet::storage]
 	#[pallet::getter(fn blocks_rewarded)]
 	pub(crate) type BlocksRewarded<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber, ValueQuery>;
 
+	/// The number of blocks for which rewards have been claimed by a
+	/// delegator for their delegation to a collator.
+	///
+	/// It maps from a delegator and a collator to a number which can be at
+	/// most BlocksAuthored of the collator. It is updated when incrementing
+	/// delegator rewards, either when calling `inc_delegator_rewards` or
+	/// updating the `InflationInfo`.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation_blocks_rewarded)]
+	pub(crate) type DelegationBlocksRewarded<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, T::BlockNumber, ValueQuery>;
+
+	/// The share of the blocks authored by a collator which is not rewarded
+	/// because of the reward ratio of the round they were authored in.
+	///
+	/// Each block adds its weight in `BlocksAuthored` multiplied by the
+	/// complement of `RoundRewardRatio` in parts of a `Perquintill`. It only
+	/// ever increases.
+	#[pallet::storage]
+	#[pallet::getter(fn blocks_withheld)]
+	pub(crate) type BlocksWithheld<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u128, ValueQuery>;
+
+	/// The value of BlocksWithheld of a collator when their rewards were
+	/// incremented the last time.
+	#[pallet::storage]
+	#[pallet::getter(fn blocks_withheld_rewarded)]
+	pub(crate) type BlocksWithheldRewarded<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u128, ValueQuery>;
+
+	/// The value of BlocksWithheld of a collator when the rewards of a
+	/// delegator for their delegation to the collator were incremented the
+	/// last time.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation_blocks_withheld_rewarded)]
+	pub(crate) type DelegationBlocksWithheldRewarded<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::AccountId, Twox64Concat, T::AccountId, u128, ValueQuery>;
+
+	/// The delegations which are not yet eligible for rewards.
+	///
+	/// It maps from a collator to the delegating accounts and the rounds in
+	/// which the delegations were made. A delegation only earns rewards for
+	/// blocks authored from `DelegationWarmUp` rounds after it was made on,
+	/// which prevents delegators from hopping between collators to collect
+	/// rewards for the same stake more than once per round.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_delegations)]
+	pub(crate) type PendingDelegations<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(T::AccountId, SessionIndex), T::MaxDelegatorsPerCollator>,
+		OptionQuery,
+	>;
+
 	/// The accumulated rewards for collator candidates and delegators.
 	///
 	/// It maps from accounts to their total rewards since the last payout.
@@ -643,6 +942,61 @@ pub mod pallet {
 	#[pallet::getter(fn rewards)]
 	pub(crate) type Rewards<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
 
+	/// The sum of all accumulated rewards which have not been paid out yet.
+	///
+	/// Reward sources with limited funds, e.g. `PotRewards`, only credit
+	/// new rewards from funds which are not owed to anyone yet.
+	#[pallet::storage]
+	#[pallet::getter(fn outstanding_rewards)]
+	pub(crate) type OutstandingRewards<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultRewardRatio() -> Perquintill {
+		Perquintill::one()
+	}
+
+	/// The reward ratio of `RewardSource` for the current round.
+	///
+	/// It is taken when a new round starts and applies to all blocks
+	/// authored within that round.
+	#[pallet::storage]
+	#[pallet::getter(fn round_reward_ratio)]
+	pub(crate) type RoundRewardRatio<T: Config> = StorageValue<_, Perquintill, ValueQuery, DefaultRewardRatio>;
+
+	/// The share of incremented rewards which is staked automatically, at
+	/// the latest in the blocks following the end of each round.
+	///
+	/// It maps from collator candidates and delegators to their
+	/// auto-compound percentage.
+	#[pallet::storage]
+	#[pallet::getter(fn auto_compound)]
+	pub(crate) type AutoCompound<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, Perbill, ValueQuery>;
+
+	/// The top candidates at the end of the last round whose rewards and
+	/// those of their delegators are yet to be auto-compounded.
+	///
+	/// One candidate is compounded per block.
+	#[pallet::storage]
+	#[pallet::getter(fn auto_compound_queue)]
+	pub(crate) type AutoCompoundQueue<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxTopCandidates>, ValueQuery>;
+
+	/// The rewards credited to collator candidates and delegators per round,
+	/// oldest first.
+	///
+	/// Rewards are credited whenever they are incremented, hence an entry
+	/// covers all blocks rewarded since the previous one. Only the latest
+	/// `MaxRewardHistory` rounds are kept.
+	#[pallet::storage]
+	#[pallet::getter(fn reward_history)]
+	pub(crate) type RewardHistory<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<(SessionIndex, BalanceOf<T>), T::MaxRewardHistory>,
+		ValueQuery,
+	>;
+
 	pub type GenesisStaker<T> = Vec<(
 		<T as frame_system::Config>::AccountId,
 		Option<<T as frame_system::Config>::AccountId>,
@@ -653,6 +1007,82 @@ pub mod pallet {
 	#[pallet::getter(fn new_round_forced)]
 	pub(crate) type ForceNewRound<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	/// The maximum commission a collator can charge on the rewards of their
+	/// delegators.
+	#[pallet::storage]
+	#[pallet::getter(fn max_collator_commission)]
+	pub(crate) type MaxCollatorCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	/// The pending commission changes of collators.
+	///
+	/// It maps from a collator to the round from which the change can be
+	/// executed and the requested commission.
+	#[pallet::storage]
+	#[pallet::getter(fn commission_change_request)]
+	pub(crate) type CommissionChangeRequests<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (SessionIndex, Perbill), OptionQuery>;
+
+	/// The rules for penalising selected collators which do not author any
+	/// blocks.
+	#[pallet::storage]
+	#[pallet::getter(fn liveness_config)]
+	pub(crate) type LivenessConfig<T: Config> = StorageValue<_, LivenessPolicy, ValueQuery>;
+
+	/// The collators which were selected to author blocks in a round.
+	///
+	/// Only recorded while liveness tracking is enabled.
+	#[pallet::storage]
+	#[pallet::getter(fn planned_collators)]
+	pub(crate) type PlannedCollators<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, BoundedVec<T::AccountId, T::MaxTopCandidates>, OptionQuery>;
+
+	/// The last round in which a collator authored a block.
+	#[pallet::storage]
+	#[pallet::getter(fn last_authored_round)]
+	pub(crate) type LastAuthoredRound<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, SessionIndex, OptionQuery>;
+
+	/// The number of consecutive rounds in which a selected collator has not
+	/// authored any block.
+	#[pallet::storage]
+	#[pallet::getter(fn missed_rounds)]
+	pub(crate) type MissedRounds<T: Config> = StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The slashes which have been scheduled but not yet applied.
+	///
+	/// It maps from the round in which the slash is applied and the collator
+	/// to the slash.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_slashes)]
+	pub(crate) type PendingSlashes<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, SessionIndex, Twox64Concat, T::AccountId, PendingSlash, OptionQuery>;
+
+	#[pallet::type_value]
+	pub(crate) fn DefaultStakingParams<T: Config>() -> StakingParametersOf<T> {
+		Pallet::<T>::initial_staking_params()
+	}
+
+	/// The staking parameters which can be changed by `ParametersOrigin`.
+	#[pallet::storage]
+	#[pallet::getter(fn staking_params)]
+	pub(crate) type StakingParams<T: Config> =
+		StorageValue<_, StakingParametersOf<T>, ValueQuery, DefaultStakingParams<T>>;
+
+	/// The previous minimum stakes which still apply to existing collators
+	/// and delegations after governance raised them.
+	#[pallet::storage]
+	#[pallet::getter(fn min_stake_grace)]
+	pub(crate) type ActiveMinStakeGrace<T: Config> = StorageValue<_, MinStakeGrace<BalanceOf<T>>, OptionQuery>;
+
+	/// The metadata published by collator candidates together with the
+	/// deposit reserved for it.
+	///
+	/// It is removed when the candidate leaves.
+	#[pallet::storage]
+	#[pallet::getter(fn candidate_metadata)]
+	pub(crate) type CandidateMetadata<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, (CollatorMetadataOf<T>, BalanceOf<T>), OptionQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		pub stakers: GenesisStaker<T>,
@@ -681,6 +1111,7 @@ pub mod pallet {
 
 			InflationConfig::<T>::put(self.inflation_config.clone());
 			MaxCollatorCandidateStake::<T>::put(self.max_candidate_stake);
+			StakingParams::<T>::put(Pallet::<T>::initial_staking_params());
 
 			// Setup delegate & collators
 			for &(ref actor, ref opt_val, balance) in &self.stakers {
@@ -689,11 +1120,19 @@ pub mod pallet {
 					"Account does not have enough balance to stake."
 				);
 				if let Some(delegated_val) = opt_val {
-					assert_ok!(Pallet::<T>::join_delegators(
-						T::RuntimeOrigin::from(Some(actor.clone()).into()),
-						T::Lookup::unlookup(delegated_val.clone()),
-						balance,
-					));
+					if Pallet::<T>::is_delegator(actor) {
+						assert_ok!(Pallet::<T>::delegate_another_candidate(
+							T::RuntimeOrigin::from(Some(actor.clone()).into()),
+							T::Lookup::unlookup(delegated_val.clone()),
+							balance,
+						));
+					} else {
+						assert_ok!(Pallet::<T>::join_delegators(
+							T::RuntimeOrigin::from(Some(actor.clone()).into()),
+							T::Lookup::unlookup(delegated_val.clone()),
+							balance,
+						));
+					}
 				} else {
 					assert_ok!(Pallet::<T>::join_candidates(
 						T::RuntimeOrigin::from(Some(actor.clone()).into()),
@@ -701,10 +1140,14 @@ pub mod pallet {
 					));
 				}
 			}
+			// Genesis delegations are eligible for rewards right away
+			let _ = PendingDelegations::<T>::clear(u32::MAX, None);
+
 			// Set total selected candidates to minimum config
 			MaxSelectedCandidates::<T>::put(T::MinCollators::get());
 
 			Pallet::<T>::update_total_stake();
+			RoundRewardRatio::<T>::put(T::RewardSource::reward_ratio());
 
 			// Start Round 0 at Block 0
 			let round: RoundInfo<T::BlockNumber> = RoundInfo::new(0u32, 0u32.into(), T::DefaultBlocksPerRound::get());
@@ -1066,7 +1509,7 @@ pub mod pallet {
 			);
 
 			let now = Round::<T>::get().current;
-			let when = now.saturating_add(T::ExitQueueDelay::get());
+			let when = now.saturating_add(StakingParams::<T>::get().exit_queue_delay);
 			state.leave_candidates(when);
 
 			// *** No Fail beyond this point ***
@@ -1127,6 +1570,7 @@ pub mod pallet {
 			let state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
 			ensure!(state.is_leaving(), Error::<T>::NotLeaving);
 			ensure!(state.can_exit(Round::<T>::get().current), Error::<T>::CannotLeaveYet);
+			ensure!(Self::pending_slash_of(&collator).is_none(), Error::<T>::SlashPending);
 
 			let num_delegators = state.delegators.len().saturated_into::<u32>();
 			let total_amount = state.total;
@@ -1282,6 +1726,7 @@ pub mod pallet {
 
 			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
 			ensure!(!state.is_leaving(), Error::<T>::CannotStakeIfLeaving);
+			ensure!(Self::pending_slash_of(&collator).is_none(), Error::<T>::SlashPending);
 
 			let CandidateOf::<T, _> {
 				stake: before_stake,
@@ -1329,8 +1774,8 @@ pub mod pallet {
 		/// The account that wants to delegate cannot be part of the collator
 		/// candidates set as well.
 		///
-		/// The caller must _not_ have a delegation. If that is the case, they
-		/// are required to first remove the delegation.
+		/// The caller must _not_ be a delegator yet. Further collator
+		/// candidates can be delegated via `delegate_another_candidate`.
 		///
 		/// The amount staked must be larger than the minimum required to become
 		/// a delegator as set in the pallet's configuration.
@@ -1342,6 +1787,9 @@ pub mod pallet {
 		/// The collator's total stake as well as the pallet's total stake are
 		/// increased accordingly.
 		///
+		/// The delegation is eligible for rewards `DelegationWarmUp` rounds
+		/// after it was made.
+		///
 		/// Emits `Delegation`.
 		/// Emits `DelegationReplaced` if the candidate has
 		/// `MaxDelegatorsPerCollator` many delegations but this delegator
@@ -1367,7 +1815,10 @@ pub mod pallet {
 
 			// first delegation
 			ensure!(DelegatorState::<T>::get(&acc).is_none(), Error::<T>::AlreadyDelegating);
-			ensure!(amount >= T::MinDelegatorStake::get(), Error::<T>::DelegationBelowMin);
+			ensure!(
+				amount >= StakingParams::<T>::get().min_delegator_stake,
+				Error::<T>::DelegationBelowMin
+			);
 
 			// cannot be a collator candidate and delegator with same AccountId
 			ensure!(Self::is_active_candidate(&acc).is_none(), Error::<T>::CandidateExists);
@@ -1375,78 +1826,20 @@ pub mod pallet {
 				Unstaking::<T>::get(&acc).len().saturated_into::<u32>() < T::MaxUnstakeRequests::get(),
 				Error::<T>::CannotJoinBeforeUnlocking
 			);
-			// cannot delegate if number of delegations in this round exceeds
-			// MaxDelegationsPerRound
-			let delegation_counter = Self::get_delegation_counter(&acc)?;
-
-			// prepare update of collator state
-			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
-			let num_delegations_pre_insertion: u32 = state.delegators.len().saturated_into();
-
-			ensure!(!state.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
-			let delegation = Stake {
-				owner: acc.clone(),
-				amount,
-			};
-
-			// attempt to insert delegator and check for uniqueness
-			// NOTE: excess is handled below because we support replacing a delegator with
-			// fewer stake
-			let insert_delegator = state
-				.delegators
-				// we handle TooManyDelegators error below in do_update_delegator
-				.try_insert(delegation.clone())
-				.unwrap_or(true);
-			// should never fail but let's be safe
-			ensure!(insert_delegator, Error::<T>::DelegatorExists);
-
-			let delegator_state = Delegator {
-				amount,
-				owner: collator.clone(),
-			};
-			let CandidateOf::<T, _> {
-				stake: old_stake,
-				total: old_total,
-				..
-			} = state;
+			let delegator_state = Delegator::try_new(collator.clone(), amount)
+				.map_err(|_| Error::<T>::MaxCollatorsPerDelegatorExceeded)?;
 
-			// update state and potentially prepare kicking a delegator with less staked
+			// update collator state and potentially kick a delegator with less staked
 			// amount (includes setting rewards for kicked delegator)
-			let state = if num_delegations_pre_insertion == T::MaxDelegatorsPerCollator::get() {
-				Self::do_update_delegator(delegation, state)?
-			} else {
-				state.total = state.total.saturating_add(amount);
-				state
-			};
-			let new_total = state.total;
+			let (n, new_total) = Self::delegator_joins_collator(&acc, &collator, amount)?;
 
 			// *** No Fail except during increase_lock beyond this point ***
 
 			// lock stake
 			Self::increase_lock(&acc, amount, BalanceOf::<T>::zero())?;
 
-			// update top candidates and total amount at stake
-			let n = if state.is_active() {
-				Self::update_top_candidates(
-					collator.clone(),
-					old_stake,
-					// safe because total >= stake
-					old_total - old_stake,
-					state.stake,
-					state.total - state.stake,
-				)
-			} else {
-				0u32
-			};
-
 			// update states
-			CandidatePool::<T>::insert(&collator, state);
 			DelegatorState::<T>::insert(&acc, delegator_state);
-			LastDelegation::<T>::insert(&acc, delegation_counter);
-
-			// initiate rewarded counter to match the current authored counter of the
-			// candidate
-			BlocksRewarded::<T>::insert(&acc, BlocksAuthored::<T>::get(&collator));
 
 			Self::deposit_event(Event::Delegation(acc, amount, collator, new_total));
 			Ok(Some(<T as pallet::Config>::WeightInfo::join_delegators(
@@ -1456,8 +1849,8 @@ pub mod pallet {
 			.into())
 		}
 
-		/// Leave the set of delegators and, by implication, revoke the ongoing
-		/// delegation.
+		/// Leave the set of delegators and, by implication, revoke all ongoing
+		/// delegations.
 		///
 		/// All staked funds are not unlocked immediately, but they are added to
 		/// the queue of pending unstaking, and will effectively be released
@@ -1468,28 +1861,53 @@ pub mod pallet {
 		/// their chances to be included in the set of candidates in the next
 		/// rounds.
 		///
-		/// Automatically increments the accumulated rewards of the origin of
-		/// the current delegation.
+		/// Automatically increments the accumulated rewards of the origin for
+		/// all of their delegations.
+		///
+		/// For each delegation made less than `MinDelegationDuration` rounds
+		/// ago, `EarlyExitPenalty` of its stake is taken as a penalty.
 		///
 		/// Emits `DelegatorLeft`.
+		/// Emits `EarlyExitPenalized` for each penalized delegation.
 		#[pallet::call_index(13)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::leave_delegators(
-			T::MaxTopCandidates::get(),
+			T::MaxCollatorsPerDelegator::get(),
 			T::MaxDelegatorsPerCollator::get()
 		))]
 		pub fn leave_delegators(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let acc = ensure_signed(origin)?;
 			let delegator = DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
-			let collator = delegator.owner;
-			Self::delegator_leaves_collator(acc.clone(), collator)?;
+			let num_delegations: u32 = delegator.delegations.len().saturated_into();
+			let penalties: Vec<(T::AccountId, BalanceOf<T>)> = delegator
+				.delegations
+				.iter()
+				.map(|stake| {
+					let penalty = Self::early_exit_penalty(&acc, &stake.owner, stake.amount);
+					(stake.owner.clone(), penalty)
+				})
+				.collect();
+			let total_penalty = penalties
+				.iter()
+				.fold(BalanceOf::<T>::zero(), |total, (_, penalty)| total.saturating_add(*penalty));
 
-			// *** No Fail beyond this point ***
+			// *** No Fail except during delegator_leaves_collator and prep_unstake beyond
+			// this point ***
+
+			for stake in delegator.delegations.into_iter() {
+				Self::delegator_leaves_collator(acc.clone(), stake.owner)?;
+			}
 
+			// we don't unlock immediately
+			Self::prep_unstake(&acc, delegator.total.saturating_sub(total_penalty), false)?;
 			DelegatorState::<T>::remove(&acc);
+			AutoCompound::<T>::remove(&acc);
+			for (collator, penalty) in penalties {
+				Self::take_early_exit_penalty(&acc, &collator, penalty);
+			}
 
-			Self::deposit_event(Event::DelegatorLeft(acc, delegator.amount));
+			Self::deposit_event(Event::DelegatorLeft(acc, delegator.total));
 			Ok(Some(<T as pallet::Config>::WeightInfo::leave_delegators(
-				1,
+				num_delegations,
 				T::MaxDelegatorsPerCollator::get(),
 			))
 			.into())
@@ -1507,12 +1925,16 @@ pub mod pallet {
 			T::MaxDelegatorsPerCollator::get(),
 			T::MaxUnstakeRequests::get().saturated_into::<u32>())
 		)]
-		pub fn delegator_stake_more(origin: OriginFor<T>, more: BalanceOf<T>) -> DispatchResultWithPostInfo {
+		pub fn delegator_stake_more(
+			origin: OriginFor<T>,
+			candidate: <T::Lookup as StaticLookup>::Source,
+			more: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
+			let candidate = T::Lookup::lookup(candidate)?;
 			ensure!(!more.is_zero(), Error::<T>::ValStakeZero);
 
 			let mut delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			let candidate = delegation.owner.clone();
 			let mut collator = CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
 			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
 			let stake_after = delegation
@@ -1522,7 +1944,7 @@ pub mod pallet {
 			// *** No Fail except during increase_lock beyond this point ***
 
 			// update lock
-			let unstaking_len = Self::increase_lock(&delegator, stake_after, more)?;
+			let unstaking_len = Self::increase_lock(&delegator, delegation.total, more)?;
 
 			let CandidateOf::<T, _> {
 				stake: before_stake,
@@ -1547,7 +1969,12 @@ pub mod pallet {
 			};
 
 			// increment rewards and update number of rewarded blocks
-			Self::do_inc_delegator_reward(&delegator, stake_after.saturating_sub(more), &candidate);
+			Self::do_inc_delegator_reward(
+				&delegator,
+				stake_after.saturating_sub(more),
+				&candidate,
+				Self::effective_commission(&collator),
+			);
 
 			CandidatePool::<T>::insert(&candidate, collator);
 			DelegatorState::<T>::insert(&delegator, delegation);
@@ -1576,33 +2003,47 @@ pub mod pallet {
 		/// The resulting total amount of funds staked must be within the
 		/// allowed range as set in the pallet's configuration.
 		///
+		/// If the delegation was made less than `MinDelegationDuration`
+		/// rounds ago, `EarlyExitPenalty` of the unstaked funds is taken as a
+		/// penalty.
+		///
 		/// Emits `DelegatorStakedLess`.
+		/// Emits `EarlyExitPenalized` if a penalty is taken.
 		#[pallet::call_index(15)]
 		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegator_stake_less(
 			T::MaxTopCandidates::get(),
 			T::MaxDelegatorsPerCollator::get()
 		))]
-		pub fn delegator_stake_less(origin: OriginFor<T>, less: BalanceOf<T>) -> DispatchResultWithPostInfo {
+		pub fn delegator_stake_less(
+			origin: OriginFor<T>,
+			candidate: <T::Lookup as StaticLookup>::Source,
+			less: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
+			let candidate = T::Lookup::lookup(candidate)?;
 			ensure!(!less.is_zero(), Error::<T>::ValStakeZero);
 
 			let mut delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			let candidate = delegation.owner.clone();
 			let mut collator = CandidatePool::<T>::get(&candidate).ok_or(Error::<T>::CandidateNotFound)?;
 			ensure!(!collator.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+			ensure!(
+				!Self::is_delegator_slash_pending(&candidate),
+				Error::<T>::SlashPending
+			);
 			let stake_after = delegation
 				.try_decrement(candidate.clone(), less)
 				.map_err(|_| Error::<T>::DelegationNotFound)?
 				.ok_or(Error::<T>::Underflow)?;
 
 			ensure!(
-				stake_after >= T::MinDelegatorStake::get(),
+				stake_after >= StakingParams::<T>::get().min_delegator_stake,
 				Error::<T>::DelegationBelowMin
 			);
+			let penalty = Self::early_exit_penalty(&delegator, &candidate, less);
 
 			// *** No Fail except during prep_unstake beyond this point ***
 
-			Self::prep_unstake(&delegator, less, false)?;
+			Self::prep_unstake(&delegator, less.saturating_sub(penalty), false)?;
 
 			let CandidateOf::<T, _> {
 				stake: before_stake,
@@ -1627,10 +2068,16 @@ pub mod pallet {
 			};
 
 			// increment rewards and update number of rewarded blocks
-			Self::do_inc_delegator_reward(&delegator, stake_after.saturating_add(less), &candidate);
+			Self::do_inc_delegator_reward(
+				&delegator,
+				stake_after.saturating_add(less),
+				&candidate,
+				Self::effective_commission(&collator),
+			);
 
 			CandidatePool::<T>::insert(&candidate, collator);
 			DelegatorState::<T>::insert(&delegator, delegation);
+			Self::take_early_exit_penalty(&delegator, &candidate, penalty);
 
 			Self::deposit_event(Event::DelegatorStakedLess(delegator, candidate, before_total, after));
 			Ok(Some(<T as pallet::Config>::WeightInfo::delegator_stake_less(
@@ -1689,11 +2136,12 @@ pub mod pallet {
 			// reset rewards
 			let rewards = Rewards::<T>::take(&target);
 			ensure!(!rewards.is_zero(), Error::<T>::RewardsNotFound);
+			OutstandingRewards::<T>::mutate(|outstanding| *outstanding = outstanding.saturating_sub(rewards));
 
-			// mint into target
-			let rewards = T::Currency::deposit_into_existing(&target, rewards)?;
+			// mint into target or pay out of the reward pot
+			T::RewardSource::payout(&target, rewards)?;
 
-			Self::deposit_event(Event::Rewarded(target, rewards.peek()));
+			Self::deposit_event(Event::Rewarded(target, rewards));
 
 			Ok(())
 		}
@@ -1703,36 +2151,65 @@ pub mod pallet {
 		/// The same effect is triggered by changing the stake or leaving the
 		/// network.
 		///
+		/// If the collator has set an auto-compound percentage, that share of
+		/// the incremented rewards is staked right away. Top candidates are
+		/// compounded automatically after the end of each round as well.
+		///
 		/// The dispatch origin must be a collator.
 		#[pallet::call_index(18)]
-		#[pallet::weight(<T as Config>::WeightInfo::increment_collator_rewards())]
-		pub fn increment_collator_rewards(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(<T as Config>::WeightInfo::increment_collator_rewards().saturating_add(
+			<T as Config>::WeightInfo::candidate_stake_more(
+				T::MaxTopCandidates::get(),
+				T::MaxDelegatorsPerCollator::get(),
+				T::MaxUnstakeRequests::get().saturating_sub(1),
+			)
+		))]
+		pub fn increment_collator_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let collator = ensure_signed(origin)?;
 			let state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
 
-			// increment rewards and update number of rewarded blocks
-			Self::do_inc_collator_reward(&collator, state.stake);
-
-			Ok(())
+			let weight = Self::do_inc_and_compound_collator_reward(&collator, state.stake)?;
+			Ok(Some(weight).into())
 		}
 
-		/// Actively increment the rewards of a delegator.
+		/// Actively increment the rewards of a delegator for all of their
+		/// delegations.
 		///
 		/// The same effect is triggered by changing the stake or revoking
 		/// delegations.
 		///
+		/// If the delegator has set an auto-compound percentage, that share of
+		/// the rewards incremented for each delegation is staked on the same
+		/// delegation right away. Delegations to top candidates are
+		/// compounded automatically after the end of each round as well.
+		///
 		/// The dispatch origin must be a delegator.
 		#[pallet::call_index(19)]
-		#[pallet::weight(<T as Config>::WeightInfo::increment_delegator_rewards())]
-		pub fn increment_delegator_rewards(origin: OriginFor<T>) -> DispatchResult {
+		#[pallet::weight(<T as Config>::WeightInfo::increment_delegator_rewards(T::MaxCollatorsPerDelegator::get())
+			.saturating_add(<T as Config>::WeightInfo::delegator_stake_more(
+				T::MaxTopCandidates::get(),
+				T::MaxDelegatorsPerCollator::get(),
+				T::MaxUnstakeRequests::get().saturating_sub(1),
+			).saturating_mul(T::MaxCollatorsPerDelegator::get().into()))
+		)]
+		pub fn increment_delegator_rewards(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
 			let delegator = ensure_signed(origin)?;
 			let delegation = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
-			let collator = delegation.owner;
+			let num_delegations: u32 = delegation.delegations.len().saturated_into();
 
-			// increment rewards and update number of rewarded blocks
-			Self::do_inc_delegator_reward(&delegator, delegation.amount, &collator);
+			let mut weight = <T as Config>::WeightInfo::increment_delegator_rewards(num_delegations);
+			for stake in delegation.delegations.into_iter() {
+				let state = CandidatePool::<T>::get(&stake.owner).ok_or(Error::<T>::CandidateNotFound)?;
 
-			Ok(())
+				weight = weight.saturating_add(Self::do_inc_and_compound_delegator_reward(
+					&delegator,
+					stake.amount,
+					&stake.owner,
+					Self::effective_commission(&state),
+				)?);
+			}
+
+			Ok(Some(weight).into())
 		}
 
 		/// Executes the annual reduction of the reward rates for collators and
@@ -1787,32 +2264,863 @@ pub mod pallet {
 
 			Ok(Some(<T as pallet::Config>::WeightInfo::set_inflation(num_col, num_del)).into())
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
-		/// Check whether an account is currently delegating.
-		pub fn is_delegator(acc: &T::AccountId) -> bool {
-			DelegatorState::<T>::get(acc).is_some()
+		/// Set the maximum commission collator candidates can charge on the
+		/// rewards of their delegators.
+		///
+		/// Existing commissions are not changed but capped by the new maximum
+		/// when incrementing rewards. The rewards of all delegators are
+		/// incremented beforehand, so the new maximum only applies to rewards
+		/// accumulating from now on.
+		///
+		/// The dispatch origin must be `ParametersOrigin`.
+		///
+		/// Emits `MaxCollatorCommissionSet`.
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_max_collator_commission(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn set_max_collator_commission(origin: OriginFor<T>, new: Perbill) -> DispatchResultWithPostInfo {
+			T::ParametersOrigin::ensure_origin(origin)?;
+
+			// *** No Fail beyond this point ***
+
+			// increment rewards of delegators which accumulated with the old maximum
+			let mut num_delegators = 0u32;
+			CandidatePool::<T>::iter().for_each(|(id, state)| {
+				let commission = Self::effective_commission(&state);
+				state.delegators.into_iter().for_each(|delegator_state| {
+					Self::do_inc_delegator_reward(&delegator_state.owner, delegator_state.amount, &id, commission);
+					num_delegators = num_delegators.saturating_add(1u32);
+				});
+			});
+
+			MaxCollatorCommission::<T>::put(new);
+
+			Self::deposit_event(Event::MaxCollatorCommissionSet(new));
+			Ok(Some(<T as Config>::WeightInfo::set_max_collator_commission(
+				CandidatePool::<T>::count(),
+				num_delegators
+			))
+			.into())
 		}
 
-		/// Check whether an account is currently a collator candidate and
-		/// whether their state is CollatorStatus::Active.
+		/// Request to change the commission the collator candidate charges on
+		/// the rewards of their delegators.
 		///
-		/// Returns Some(is_active) if the account is a candidate, else None.
-		pub fn is_active_candidate(acc: &T::AccountId) -> Option<bool> {
-			if let Some(state) = CandidatePool::<T>::get(acc) {
-				Some(state.status == CandidateStatus::Active)
-			} else {
-				None
-			}
-		}
-		/// Set the annual inflation rate to derive per-round inflation.
+		/// The change can be executed via `execute_commission_change` after
+		/// waiting for `CommissionChangeDelay` many rounds. A new request
+		/// replaces a pending one.
 		///
-		/// The inflation details are considered valid if the annual reward rate
-		/// is approximately the per-block reward rate multiplied by the
-		/// estimated* total number of blocks per year.
+		/// The requested commission must not exceed the maximum set by
+		/// governance.
 		///
-		/// The estimated average block time is twelve seconds.
+		/// The dispatch origin must be a collator candidate.
+		///
+		/// Emits `CollatorCommissionChangeRequested`.
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::request_commission_change())]
+		pub fn request_commission_change(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+			let collator = ensure_signed(origin)?;
+			ensure!(
+				CandidatePool::<T>::contains_key(&collator),
+				Error::<T>::CandidateNotFound
+			);
+			ensure!(
+				commission <= MaxCollatorCommission::<T>::get(),
+				Error::<T>::CommissionAboveMax
+			);
+
+			let when = Round::<T>::get().current.saturating_add(T::CommissionChangeDelay::get());
+
+			// *** No Fail beyond this point ***
+
+			CommissionChangeRequests::<T>::insert(&collator, (when, commission));
+
+			Self::deposit_event(Event::CollatorCommissionChangeRequested(collator, commission, when));
+			Ok(())
+		}
+
+		/// Execute the commission change of a collator candidate who requested
+		/// it at least `CommissionChangeDelay` rounds ago.
+		///
+		/// Increments the rewards of the collator's delegators with the old
+		/// commission before applying the new one. The new commission is
+		/// capped by the current maximum set by governance.
+		///
+		/// The dispatch origin can be any signed one.
+		///
+		/// Emits `CollatorCommissionSet`.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_commission_change(T::MaxDelegatorsPerCollator::get()))]
+		pub fn execute_commission_change(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+			let (when, commission) =
+				CommissionChangeRequests::<T>::get(&collator).ok_or(Error::<T>::CommissionChangeNotFound)?;
+			ensure!(
+				when <= Round::<T>::get().current,
+				Error::<T>::CannotChangeCommissionYet
+			);
+
+			// *** No Fail beyond this point ***
+
+			// increment rewards of delegators which accumulated with the old commission
+			let effective_commission = Self::effective_commission(&state);
+			for stake in &state.delegators[..] {
+				Self::do_inc_delegator_reward(&stake.owner, stake.amount, &collator, effective_commission);
+			}
+			let num_delegators = state.delegators.len().saturated_into::<u32>();
+
+			let old = state.commission;
+			state.commission = commission.min(MaxCollatorCommission::<T>::get());
+			let new = state.commission;
+			CandidatePool::<T>::insert(&collator, state);
+			CommissionChangeRequests::<T>::remove(&collator);
+
+			Self::deposit_event(Event::CollatorCommissionSet(collator, old, new));
+			Ok(Some(<T as Config>::WeightInfo::execute_commission_change(num_delegators)).into())
+		}
+
+		/// Delegate another collator candidate as an existing delegator.
+		///
+		/// The delegator can back at most `MaxCollatorsPerDelegator` many
+		/// collator candidates at the same time and must not delegate the
+		/// given candidate already.
+		///
+		/// The amount staked must be larger than the minimum required to
+		/// delegate as set in the pallet's configuration.
+		///
+		/// The delegation is eligible for rewards `DelegationWarmUp` rounds
+		/// after it was made.
+		///
+		/// Emits `Delegation`.
+		/// Emits `DelegationReplaced` if the candidate has
+		/// `MaxDelegatorsPerCollator` many delegations but this delegator
+		/// staked more than one of the other delegators of this candidate.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::delegate_another_candidate(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn delegate_another_candidate(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let mut delegator = DelegatorState::<T>::get(&acc).ok_or(Error::<T>::NotYetDelegating)?;
+
+			ensure!(
+				amount >= StakingParams::<T>::get().min_delegator_stake,
+				Error::<T>::DelegationBelowMin
+			);
+			ensure!(
+				Unstaking::<T>::get(&acc).len().saturated_into::<u32>() < T::MaxUnstakeRequests::get(),
+				Error::<T>::CannotJoinBeforeUnlocking
+			);
+			let is_new_delegation = delegator
+				.try_add_delegation(Stake {
+					owner: collator.clone(),
+					amount,
+				})
+				.map_err(|_| Error::<T>::MaxCollatorsPerDelegatorExceeded)?;
+			ensure!(is_new_delegation, Error::<T>::AlreadyDelegatedCollator);
+
+			// update collator state and potentially kick a delegator with less staked
+			// amount (includes setting rewards for kicked delegator)
+			let (n, new_total) = Self::delegator_joins_collator(&acc, &collator, amount)?;
+
+			// *** No Fail except during increase_lock beyond this point ***
+
+			// lock the total stake of the delegator
+			Self::increase_lock(&acc, delegator.total, amount)?;
+
+			// update states
+			DelegatorState::<T>::insert(&acc, delegator);
+
+			Self::deposit_event(Event::Delegation(acc, amount, collator, new_total));
+			Ok(Some(<T as pallet::Config>::WeightInfo::delegate_another_candidate(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
+		}
+
+		/// Revoke the delegation of a single collator candidate.
+		///
+		/// The staked funds are not unlocked immediately, but they are added to
+		/// the queue of pending unstaking, and will effectively be released
+		/// after `StakeDuration` blocks. Revoking the last delegation removes
+		/// the origin from the set of delegators.
+		///
+		/// Automatically increments the accumulated rewards of the origin for
+		/// the revoked delegation.
+		///
+		/// If the delegation was made less than `MinDelegationDuration`
+		/// rounds ago, `EarlyExitPenalty` of its stake is taken as a penalty.
+		///
+		/// Emits `DelegatorLeftCollator`.
+		/// Emits `EarlyExitPenalized` if a penalty is taken.
+		/// Emits `DelegatorLeft` if no delegation is left.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_delegation(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn revoke_delegation(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let acc = ensure_signed(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let mut delegator = DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
+			let amount = delegator
+				.rm_delegation(collator.clone())
+				.ok_or(Error::<T>::DelegationNotFound)?;
+			let penalty = Self::early_exit_penalty(&acc, &collator, amount);
+
+			// *** No Fail except during delegator_leaves_collator and prep_unstake beyond
+			// this point ***
+
+			Self::delegator_leaves_collator(acc.clone(), collator.clone())?;
+
+			// we don't unlock immediately
+			Self::prep_unstake(&acc, amount.saturating_sub(penalty), false)?;
+			Self::take_early_exit_penalty(&acc, &collator, penalty);
+
+			if delegator.delegations.is_empty() {
+				DelegatorState::<T>::remove(&acc);
+				AutoCompound::<T>::remove(&acc);
+				Self::deposit_event(Event::DelegatorLeft(acc, amount));
+			} else {
+				DelegatorState::<T>::insert(&acc, delegator);
+			}
+			Ok(())
+		}
+
+		/// Move the delegation from one collator candidate to another one
+		/// without unstaking the delegated funds.
+		///
+		/// The entire stake of the delegation is moved. The new delegation is
+		/// eligible for rewards `DelegationWarmUp` rounds later. Moving a
+		/// delegation is not penalized, but the new delegation counts as made
+		/// in the current round with regard to `MinDelegationDuration`.
+		///
+		/// Automatically increments the accumulated rewards of the origin for
+		/// the previous delegation.
+		///
+		/// Emits `Redelegated`.
+		/// Emits `DelegationReplaced` if the new candidate has
+		/// `MaxDelegatorsPerCollator` many delegations but this delegator
+		/// staked more than one of the other delegators of this candidate.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::redelegate(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn redelegate(
+			origin: OriginFor<T>,
+			from: <T::Lookup as StaticLookup>::Source,
+			to: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResultWithPostInfo {
+			let acc = ensure_signed(origin)?;
+			let from = T::Lookup::lookup(from)?;
+			let to = T::Lookup::lookup(to)?;
+			ensure!(from != to, Error::<T>::AlreadyDelegatedCollator);
+
+			let mut delegator = DelegatorState::<T>::get(&acc).ok_or(Error::<T>::DelegatorNotFound)?;
+			let amount = delegator
+				.rm_delegation(from.clone())
+				.ok_or(Error::<T>::DelegationNotFound)?;
+			let is_new_delegation = delegator
+				.try_add_delegation(Stake {
+					owner: to.clone(),
+					amount,
+				})
+				.map_err(|_| Error::<T>::MaxCollatorsPerDelegatorExceeded)?;
+			ensure!(is_new_delegation, Error::<T>::AlreadyDelegatedCollator);
+
+			// move the stake without unstaking, the lock stays untouched
+			Self::delegator_leaves_collator(acc.clone(), from.clone())?;
+			let (n, _) = Self::delegator_joins_collator(&acc, &to, amount)?;
+
+			// *** No Fail beyond this point ***
+
+			DelegatorState::<T>::insert(&acc, delegator);
+
+			Self::deposit_event(Event::Redelegated(acc, from, to, amount));
+			Ok(Some(<T as pallet::Config>::WeightInfo::redelegate(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
+		}
+
+		/// Set the liveness policy for selected collators.
+		///
+		/// A selected collator which does not author any block for
+		/// `max_missed_rounds` many rounds in a row is removed from the set of
+		/// top candidates until they call `go_online`. Upon removal, a slash
+		/// of `offline_slash` of their stake, and optionally of their
+		/// delegators' stake, is scheduled. A `max_missed_rounds` of zero
+		/// disables liveness tracking.
+		///
+		/// The dispatch origin must be Root.
+		///
+		/// Emits `LivenessPolicySet`.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_liveness_policy())]
+		pub fn set_liveness_policy(
+			origin: OriginFor<T>,
+			max_missed_rounds: u32,
+			offline_slash: Perbill,
+			slash_delegators: bool,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			// *** No Fail beyond this point ***
+
+			LivenessConfig::<T>::put(LivenessPolicy {
+				max_missed_rounds,
+				offline_slash,
+				slash_delegators,
+			});
+
+			Self::deposit_event(Event::LivenessPolicySet(
+				max_missed_rounds,
+				offline_slash,
+				slash_delegators,
+			));
+			Ok(())
+		}
+
+		/// Come back online after having been removed from the set of top
+		/// candidates for missing too many rounds or for falling below the
+		/// minimum collator stake due to a slash.
+		///
+		/// The collator candidate must still have staked at least
+		/// `MinCollatorCandidateStake`, e.g. after having been slashed.
+		///
+		/// The dispatch origin must be a collator candidate which is offline.
+		///
+		/// Emits `CollatorBackOnline`.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as Config>::WeightInfo::go_online(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get(),
+		))]
+		pub fn go_online(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let collator = ensure_signed(origin)?;
+			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(state.is_offline(), Error::<T>::NotOffline);
+			ensure!(
+				state.stake >= T::MinCollatorCandidateStake::get(),
+				Error::<T>::ValStakeBelowMin
+			);
+
+			state.go_online();
+
+			// *** No Fail beyond this point ***
+
+			let n = Self::update_top_candidates(
+				collator.clone(),
+				state.stake,
+				// safe because total >= stake
+				state.total - state.stake,
+				state.stake,
+				state.total - state.stake,
+			);
+			CandidatePool::<T>::insert(&collator, state);
+			MissedRounds::<T>::remove(&collator);
+
+			Self::deposit_event(Event::CollatorBackOnline(collator));
+			Ok(Some(<T as Config>::WeightInfo::go_online(
+				n,
+				T::MaxDelegatorsPerCollator::get(),
+			))
+			.into())
+		}
+
+		/// Schedule a slash of the stake of a collator candidate, e.g. for
+		/// equivocation.
+		///
+		/// The slash is applied at the start of the round which begins
+		/// `SlashDeferDuration` rounds from now. Until then, it can be
+		/// cancelled via `cancel_slash` and neither the candidate nor, if
+		/// `slash_delegators` is set, their delegators can reduce their
+		/// stake. The slashed funds are handed to `T::Slash`.
+		///
+		/// The dispatch origin must be `SlashOrigin`.
+		///
+		/// Emits `SlashScheduled`.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::slash_candidate())]
+		pub fn slash_candidate(
+			origin: OriginFor<T>,
+			collator: <T::Lookup as StaticLookup>::Source,
+			fraction: Perbill,
+			slash_delegators: bool,
+		) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			ensure!(
+				CandidatePool::<T>::contains_key(&collator),
+				Error::<T>::CandidateNotFound
+			);
+
+			// *** No Fail beyond this point ***
+
+			Self::schedule_slash(
+				&collator,
+				PendingSlash {
+					fraction,
+					slash_delegators,
+				},
+			);
+			Ok(())
+		}
+
+		/// Cancel a slash which has been scheduled to be applied at the start
+		/// of the given round.
+		///
+		/// The dispatch origin must be `SlashOrigin`.
+		///
+		/// Emits `SlashCancelled`.
+		#[pallet::call_index(30)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_slash())]
+		pub fn cancel_slash(
+			origin: OriginFor<T>,
+			round: SessionIndex,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+			let collator = T::Lookup::lookup(collator)?;
+			ensure!(
+				PendingSlashes::<T>::contains_key(round, &collator),
+				Error::<T>::SlashNotFound
+			);
+
+			// *** No Fail beyond this point ***
+
+			PendingSlashes::<T>::remove(round, &collator);
+
+			Self::deposit_event(Event::SlashCancelled(round, collator));
+			Ok(())
+		}
+
+		/// Set the share of rewards which is staked automatically whenever
+		/// the rewards of the dispatch origin are incremented via
+		/// `increment_collator_rewards` or `increment_delegator_rewards` as
+		/// well as at the end of each round for top candidates and their
+		/// delegators. The remainder stays claimable via `claim_rewards`.
+		///
+		/// Collators compound onto their own stake up to
+		/// `MaxCollatorCandidateStake`. Delegators compound onto the
+		/// delegation for which the rewards were earned. Rewards for leaving
+		/// candidates are not compounded. A percentage of zero disables
+		/// auto-compounding.
+		///
+		/// The dispatch origin must be a collator candidate or a delegator.
+		///
+		/// Emits `AutoCompoundSet`.
+		#[pallet::call_index(31)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_auto_compound())]
+		pub fn set_auto_compound(origin: OriginFor<T>, percentage: Perbill) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				CandidatePool::<T>::contains_key(&who) || DelegatorState::<T>::contains_key(&who),
+				Error::<T>::NotStaking
+			);
+
+			// *** No Fail beyond this point ***
+
+			if percentage.is_zero() {
+				AutoCompound::<T>::remove(&who);
+			} else {
+				AutoCompound::<T>::insert(&who, percentage);
+			}
+
+			Self::deposit_event(Event::AutoCompoundSet(who, percentage));
+			Ok(())
+		}
+
+		/// Set the minimum stake a collator candidate requires to be selected
+		/// as block author.
+		///
+		/// The new value must be between `MinCollatorCandidateStake` and
+		/// `MaxCollatorCandidateStake`. Raising it starts a grace period of
+		/// `MinStakeGracePeriod` rounds in which the previous minimum still
+		/// applies.
+		///
+		/// The dispatch origin must be `ParametersOrigin`.
+		///
+		/// Emits `MinCollatorStakeSet`.
+		/// Emits `MinStakeGracePeriodStarted` if the minimum was raised.
+		#[pallet::call_index(32)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_staking_parameter())]
+		pub fn set_min_collator_stake(origin: OriginFor<T>, new: BalanceOf<T>) -> DispatchResult {
+			T::ParametersOrigin::ensure_origin(origin)?;
+			ensure!(
+				new >= T::MinCollatorCandidateStake::get(),
+				Error::<T>::CannotSetBelowMin
+			);
+			ensure!(
+				new <= MaxCollatorCandidateStake::<T>::get(),
+				Error::<T>::CannotSetAboveMax
+			);
+
+			// *** No Fail beyond this point ***
+
+			let mut params = StakingParams::<T>::get();
+			let old = params.min_collator_stake;
+			if new > old {
+				Self::start_min_stake_grace();
+			}
+			params.min_collator_stake = new;
+			StakingParams::<T>::put(params);
+
+			Self::deposit_event(Event::MinCollatorStakeSet(old, new));
+			Ok(())
+		}
+
+		/// Set the minimum stake required for a delegation.
+		///
+		/// New delegations and reductions of existing ones have to respect the
+		/// new value immediately. Raising it starts a grace period of
+		/// `MinStakeGracePeriod` rounds after which delegations below the new
+		/// minimum can be removed via `remove_delegation_below_min`.
+		///
+		/// The dispatch origin must be `ParametersOrigin`.
+		///
+		/// Emits `MinDelegatorStakeSet`.
+		/// Emits `MinStakeGracePeriodStarted` if the minimum was raised.
+		#[pallet::call_index(33)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_staking_parameter())]
+		pub fn set_min_delegator_stake(origin: OriginFor<T>, new: BalanceOf<T>) -> DispatchResult {
+			T::ParametersOrigin::ensure_origin(origin)?;
+			ensure!(!new.is_zero(), Error::<T>::CannotSetBelowMin);
+			ensure!(
+				new <= MaxCollatorCandidateStake::<T>::get(),
+				Error::<T>::CannotSetAboveMax
+			);
+
+			// *** No Fail beyond this point ***
+
+			let mut params = StakingParams::<T>::get();
+			let old = params.min_delegator_stake;
+			if new > old {
+				Self::start_min_stake_grace();
+			}
+			params.min_delegator_stake = new;
+			StakingParams::<T>::put(params);
+
+			Self::deposit_event(Event::MinDelegatorStakeSet(old, new));
+			Ok(())
+		}
+
+		/// Set the maximum number of delegators a single collator can have.
+		///
+		/// The new value must be between one and `MaxDelegatorsPerCollator`.
+		/// Lowering it does not kick existing delegators, but a collator
+		/// exceeding the new limit only accepts a delegation by replacing
+		/// their lowest one.
+		///
+		/// The dispatch origin must be `ParametersOrigin`.
+		///
+		/// Emits `MaxDelegatorsPerCollatorSet`.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_staking_parameter())]
+		pub fn set_max_delegators_per_collator(origin: OriginFor<T>, new: u32) -> DispatchResult {
+			T::ParametersOrigin::ensure_origin(origin)?;
+			ensure!(!new.is_zero(), Error::<T>::CannotSetBelowMin);
+			ensure!(new <= T::MaxDelegatorsPerCollator::get(), Error::<T>::CannotSetAboveMax);
+
+			// *** No Fail beyond this point ***
+
+			let old = StakingParams::<T>::mutate(|params| {
+				sp_std::mem::replace(&mut params.max_delegators_per_collator, new)
+			});
+
+			Self::deposit_event(Event::MaxDelegatorsPerCollatorSet(old, new));
+			Ok(())
+		}
+
+		/// Set the number of blocks for which unstaked funds stay locked.
+		///
+		/// The new value must be between `MinBlocksPerRound` and
+		/// `MaxStakeDuration`. Pending unstaking requests are not changed.
+		///
+		/// The dispatch origin must be `ParametersOrigin`.
+		///
+		/// Emits `StakeDurationSet`.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_staking_parameter())]
+		pub fn set_stake_duration(origin: OriginFor<T>, new: T::BlockNumber) -> DispatchResult {
+			T::ParametersOrigin::ensure_origin(origin)?;
+			ensure!(new >= T::MinBlocksPerRound::get(), Error::<T>::CannotSetBelowMin);
+			ensure!(new <= T::MaxStakeDuration::get(), Error::<T>::CannotSetAboveMax);
+
+			// *** No Fail beyond this point ***
+
+			let old = StakingParams::<T>::mutate(|params| sp_std::mem::replace(&mut params.stake_duration, new));
+
+			Self::deposit_event(Event::StakeDurationSet(old, new));
+			Ok(())
+		}
+
+		/// Set the number of rounds a collator has to wait after requesting
+		/// to leave the set of candidates.
+		///
+		/// The new value must be between one and `MaxExitQueueDelay`. Pending
+		/// exit requests are not changed.
+		///
+		/// The dispatch origin must be `ParametersOrigin`.
+		///
+		/// Emits `ExitQueueDelaySet`.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_staking_parameter())]
+		pub fn set_exit_queue_delay(origin: OriginFor<T>, new: u32) -> DispatchResult {
+			T::ParametersOrigin::ensure_origin(origin)?;
+			ensure!(!new.is_zero(), Error::<T>::CannotSetBelowMin);
+			ensure!(new <= T::MaxExitQueueDelay::get(), Error::<T>::CannotSetAboveMax);
+
+			// *** No Fail beyond this point ***
+
+			let old = StakingParams::<T>::mutate(|params| sp_std::mem::replace(&mut params.exit_queue_delay, new));
+
+			Self::deposit_event(Event::ExitQueueDelaySet(old, new));
+			Ok(())
+		}
+
+		/// Set the rate for the network rewards.
+		///
+		/// The new value must not exceed `MaxNetworkRewardRate`.
+		///
+		/// The dispatch origin must be `ParametersOrigin`.
+		///
+		/// Emits `NetworkRewardRateSet`.
+		#[pallet::call_index(37)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_staking_parameter())]
+		pub fn set_network_reward_rate(origin: OriginFor<T>, new: Perquintill) -> DispatchResult {
+			T::ParametersOrigin::ensure_origin(origin)?;
+			ensure!(new <= T::MaxNetworkRewardRate::get(), Error::<T>::CannotSetAboveMax);
+
+			// *** No Fail beyond this point ***
+
+			let old =
+				StakingParams::<T>::mutate(|params| sp_std::mem::replace(&mut params.network_reward_rate, new));
+
+			Self::deposit_event(Event::NetworkRewardRateSet(old, new));
+			Ok(())
+		}
+
+		/// Remove a delegation which is below the minimum delegator stake.
+		///
+		/// After the minimum was raised, delegations can only be removed once
+		/// the grace period has ended. The staked funds are added to the
+		/// queue of pending unstaking of the delegator.
+		///
+		/// The dispatch origin can be any signed account.
+		///
+		/// Emits `DelegationBelowMinRemoved`.
+		/// Emits `DelegatorLeft` if no delegation is left.
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::revoke_delegation(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		))]
+		pub fn remove_delegation_below_min(
+			origin: OriginFor<T>,
+			delegator: <T::Lookup as StaticLookup>::Source,
+			collator: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let delegator = T::Lookup::lookup(delegator)?;
+			let collator = T::Lookup::lookup(collator)?;
+			let mut state = DelegatorState::<T>::get(&delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			let amount = state.delegation(&collator).ok_or(Error::<T>::DelegationNotFound)?;
+			ensure!(amount < Self::min_stakes_in_effect().1, Error::<T>::StakeNotBelowMin);
+			state.rm_delegation(collator.clone());
+
+			// *** No Fail except during delegator_leaves_collator and prep_unstake beyond
+			// this point ***
+
+			Self::delegator_leaves_collator(delegator.clone(), collator.clone())?;
+
+			// the removal must not be blocked by the unstaking requests of the delegator
+			Self::prep_unstake(&delegator, amount, true)?;
+
+			if state.delegations.is_empty() {
+				DelegatorState::<T>::remove(&delegator);
+				AutoCompound::<T>::remove(&delegator);
+				Self::deposit_event(Event::DelegatorLeft(delegator.clone(), amount));
+			} else {
+				DelegatorState::<T>::insert(&delegator, state);
+			}
+
+			Self::deposit_event(Event::DelegationBelowMinRemoved(delegator, collator, amount));
+			Ok(())
+		}
+
+		/// Publish or update the metadata of the origin as collator
+		/// candidate.
+		///
+		/// A deposit of `MetadataDepositBase` plus `MetadataDepositPerByte`
+		/// for each byte of the metadata is reserved. Updating the metadata
+		/// reserves or releases the difference. The metadata is removed and
+		/// the deposit released when the candidate leaves.
+		///
+		/// The dispatch origin must be a collator candidate.
+		///
+		/// Emits `CandidateMetadataSet`.
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_candidate_metadata())]
+		pub fn set_candidate_metadata(origin: OriginFor<T>, metadata: CollatorMetadataOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(CandidatePool::<T>::contains_key(&who), Error::<T>::CandidateNotFound);
+
+			let deposit = Self::do_set_metadata(&who, metadata)?;
+
+			Self::deposit_event(Event::CandidateMetadataSet(who, deposit));
+			Ok(())
+		}
+
+		/// Remove the metadata of the origin and release its deposit.
+		///
+		/// The dispatch origin must have published metadata as collator
+		/// candidate.
+		///
+		/// Emits `CandidateMetadataCleared`.
+		#[pallet::call_index(40)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_candidate_metadata())]
+		pub fn clear_candidate_metadata(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(
+				CandidateMetadata::<T>::contains_key(&who),
+				Error::<T>::MetadataNotFound
+			);
+
+			// *** No Fail beyond this point ***
+
+			Self::remove_metadata(&who);
+
+			Self::deposit_event(Event::CandidateMetadataCleared(who));
+			Ok(())
+		}
+
+		/// Join the set of collator candidates and publish metadata at once.
+		///
+		/// See `join_candidates` and `set_candidate_metadata`. The free balance
+		/// of the origin must cover both the stake and the metadata deposit.
+		///
+		/// Emits `JoinedCollatorCandidates`.
+		/// Emits `CandidateMetadataSet`.
+		#[pallet::call_index(41)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::join_candidates(
+			T::MaxTopCandidates::get(),
+			T::MaxDelegatorsPerCollator::get()
+		).saturating_add(<T as Config>::WeightInfo::set_candidate_metadata()))]
+		pub fn join_candidates_with_metadata(
+			origin: OriginFor<T>,
+			stake: BalanceOf<T>,
+			metadata: CollatorMetadataOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin.clone())?;
+			// the deposit cannot be reserved from the staked funds
+			ensure!(
+				T::Currency::free_balance(&sender) >= stake.saturating_add(Self::metadata_deposit(&metadata)),
+				pallet_balances::Error::<T>::InsufficientBalance
+			);
+
+			let post_info = Self::join_candidates(origin, stake)?;
+
+			// *** No Fail except during do_set_metadata beyond this point ***
+
+			let deposit = Self::do_set_metadata(&sender, metadata)?;
+
+			Self::deposit_event(Event::CandidateMetadataSet(sender, deposit));
+			Ok(post_info
+				.actual_weight
+				.map(|weight| weight.saturating_add(<T as Config>::WeightInfo::set_candidate_metadata()))
+				.into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Check whether an account is currently delegating.
+		pub fn is_delegator(acc: &T::AccountId) -> bool {
+			DelegatorState::<T>::get(acc).is_some()
+		}
+
+		/// Check whether an account is currently a collator candidate and
+		/// whether their state is CollatorStatus::Active.
+		///
+		/// Returns Some(is_active) if the account is a candidate, else None.
+		pub fn is_active_candidate(acc: &T::AccountId) -> Option<bool> {
+			if let Some(state) = CandidatePool::<T>::get(acc) {
+				Some(state.status == CandidateStatus::Active)
+			} else {
+				None
+			}
+		}
+
+		/// The staking parameters as configured in the runtime, which are used
+		/// until governance changes them.
+		pub(crate) fn initial_staking_params() -> StakingParametersOf<T> {
+			StakingParameters {
+				min_collator_stake: T::MinCollatorStake::get(),
+				min_delegator_stake: T::MinDelegatorStake::get(),
+				max_delegators_per_collator: T::MaxDelegatorsPerCollator::get(),
+				stake_duration: T::StakeDuration::get(),
+				exit_queue_delay: T::ExitQueueDelay::get(),
+				network_reward_rate: T::NetworkRewardRate::get(),
+			}
+		}
+
+		/// Returns the minimum stakes which existing collators and delegations
+		/// have to meet as `(min collator stake, min delegator stake)`.
+		///
+		/// While a grace period is running, these are the lower of the
+		/// previous and the current minimums.
+		pub fn min_stakes_in_effect() -> (BalanceOf<T>, BalanceOf<T>) {
+			let params = StakingParams::<T>::get();
+			match ActiveMinStakeGrace::<T>::get() {
+				Some(grace) if Round::<T>::get().current < grace.ends_at => (
+					params.min_collator_stake.min(grace.min_collator_stake),
+					params.min_delegator_stake.min(grace.min_delegator_stake),
+				),
+				_ => (params.min_collator_stake, params.min_delegator_stake),
+			}
+		}
+
+		/// Keep the minimum stakes currently in effect for
+		/// `MinStakeGracePeriod` rounds before a minimum is raised.
+		///
+		/// Emits `MinStakeGracePeriodStarted`.
+		fn start_min_stake_grace() {
+			let (min_collator_stake, min_delegator_stake) = Self::min_stakes_in_effect();
+			let ends_at = Round::<T>::get().current.saturating_add(T::MinStakeGracePeriod::get());
+			ActiveMinStakeGrace::<T>::put(MinStakeGrace {
+				min_collator_stake,
+				min_delegator_stake,
+				ends_at,
+			});
+			Self::deposit_event(Event::MinStakeGracePeriodStarted(ends_at));
+		}
+		/// Set the annual inflation rate to derive per-round inflation.
+		///
+		/// The inflation details are considered valid if the annual reward rate
+		/// is approximately the per-block reward rate multiplied by the
+		/// estimated* total number of blocks per year.
+		///
+		/// The estimated average block time is twelve seconds.
 		///
 		/// NOTE: Iterates over CandidatePool and for each candidate over their
 		/// delegators to update their rewards before the reward rates change.
@@ -1846,8 +3154,9 @@ pub mod pallet {
 				// increment collator rewards
 				Self::do_inc_collator_reward(&id, state.stake);
 				// increment delegator rewards
+				let commission = Self::effective_commission(&state);
 				state.delegators.into_iter().for_each(|delegator_state| {
-					Self::do_inc_delegator_reward(&delegator_state.owner, delegator_state.amount, &id);
+					Self::do_inc_delegator_reward(&delegator_state.owner, delegator_state.amount, &id, commission);
 					num_delegators = num_delegators.saturating_add(1u32);
 				});
 			});
@@ -2017,23 +3326,109 @@ pub mod pallet {
 				delegator_stake = delegator_stake.saturating_add(amount_delegators);
 			}
 
-			TotalCollatorStake::<T>::mutate(|total| {
-				total.collators = collator_stake;
-				total.delegators = delegator_stake;
-			});
+			TotalCollatorStake::<T>::mutate(|total| {
+				total.collators = collator_stake;
+				total.delegators = delegator_stake;
+			});
+
+			// return number of selected candidates and the corresponding number of their
+			// delegators for post-weight correction
+			(collators.len().saturated_into(), num_of_delegators)
+		}
+
+		/// Update the collator's state by adding the delegator's stake.
+		///
+		/// Potentially kicks the delegator with the lowest stake if the
+		/// collator already has `MaxDelegatorsPerCollator` many delegators.
+		/// The delegation is eligible for rewards `DelegationWarmUp` rounds
+		/// after it was made.
+		///
+		/// This operation affects the pallet's total stake but neither the
+		/// lock nor the state of the joining delegator.
+		///
+		/// Returns the number of top candidates and the new total stake of
+		/// the collator.
+		fn delegator_joins_collator(
+			delegator: &T::AccountId,
+			collator: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Result<(u32, BalanceOf<T>), DispatchError> {
+			let mut state = CandidatePool::<T>::get(collator).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(!state.is_leaving(), Error::<T>::CannotDelegateIfLeaving);
+			let num_delegations_pre_insertion: u32 = state.delegators.len().saturated_into();
+
+			let delegation = Stake {
+				owner: delegator.clone(),
+				amount,
+			};
+
+			// attempt to insert delegator and check for uniqueness
+			// NOTE: excess is handled below because we support replacing a delegator with
+			// fewer stake
+			let insert_delegator = state
+				.delegators
+				// we handle TooManyDelegators error below in do_update_delegator
+				.try_insert(delegation.clone())
+				.unwrap_or(true);
+			// should never fail but let's be safe
+			ensure!(insert_delegator, Error::<T>::DelegatorExists);
+
+			let CandidateOf::<T, _> {
+				stake: old_stake,
+				total: old_total,
+				..
+			} = state;
+
+			// update state and potentially prepare kicking a delegator with less staked
+			// amount (includes setting rewards for kicked delegator)
+			let state = if num_delegations_pre_insertion >= StakingParams::<T>::get().max_delegators_per_collator {
+				Self::do_update_delegator(delegation, state)?
+			} else {
+				state.total = state.total.saturating_add(amount);
+				state
+			};
+			let new_total = state.total;
+
+			// update top candidates and total amount at stake
+			let n = if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					old_stake,
+					// safe because total >= stake
+					old_total - old_stake,
+					state.stake,
+					state.total - state.stake,
+				)
+			} else {
+				0u32
+			};
+			CandidatePool::<T>::insert(collator, state);
 
-			// return number of selected candidates and the corresponding number of their
-			// delegators for post-weight correction
-			(collators.len().saturated_into(), num_of_delegators)
+			// initiate rewarded counter to match the current authored counter of the
+			// candidate
+			DelegationBlocksRewarded::<T>::insert(delegator, collator, BlocksAuthored::<T>::get(collator));
+			DelegationBlocksWithheldRewarded::<T>::insert(delegator, collator, BlocksWithheld::<T>::get(collator));
+			DelegationStart::<T>::insert(delegator, collator, Round::<T>::get().current);
+			Self::add_pending_delegation(collator, delegator)?;
+
+			Ok((n, new_total))
 		}
 
-		/// Update the collator's state by removing the delegator's stake and
-		/// starting the process to unlock the delegator's staked funds as well
-		/// as incrementing their accumulated rewards.
+		/// Update the collator's state by removing the delegator's stake as
+		/// well as incrementing their accumulated rewards.
 		///
-		/// This operation affects the pallet's total stake.
-		fn delegator_leaves_collator(delegator: T::AccountId, collator: T::AccountId) -> DispatchResult {
+		/// This operation affects the pallet's total stake but neither the
+		/// lock nor the state of the leaving delegator. The caller has to
+		/// prepare unstaking of the returned stake, if required.
+		fn delegator_leaves_collator(
+			delegator: T::AccountId,
+			collator: T::AccountId,
+		) -> Result<BalanceOf<T>, DispatchError> {
 			let mut state = CandidatePool::<T>::get(&collator).ok_or(Error::<T>::CandidateNotFound)?;
+			ensure!(
+				!Self::is_delegator_slash_pending(&collator),
+				Error::<T>::SlashPending
+			);
 
 			let delegator_stake = state
 				.delegators
@@ -2054,11 +3449,16 @@ pub mod pallet {
 			let new_total = state.total;
 
 			// increment rewards and kill storage for number of rewarded blocks
-			Self::do_inc_delegator_reward(&delegator, delegator_stake, &collator);
-			BlocksRewarded::<T>::remove(&delegator);
-
-			// we don't unlock immediately
-			Self::prep_unstake(&delegator, delegator_stake, false)?;
+			Self::do_inc_delegator_reward(
+				&delegator,
+				delegator_stake,
+				&collator,
+				Self::effective_commission(&state),
+			);
+			DelegationBlocksRewarded::<T>::remove(&delegator, &collator);
+			DelegationBlocksWithheldRewarded::<T>::remove(&delegator, &collator);
+			DelegationStart::<T>::remove(&delegator, &collator);
+			Self::remove_pending_delegation(&collator, &delegator);
 
 			// update top candidates and total amount at stake
 			if state.is_active() {
@@ -2079,7 +3479,171 @@ pub mod pallet {
 				delegator_stake,
 				new_total,
 			));
-			Ok(())
+			Ok(delegator_stake)
+		}
+
+		/// Remove the delegation to the collator from the state of the
+		/// delegator and kill the storage for the number of rewarded blocks
+		/// and the start of the delegation.
+		///
+		/// Removes the state of the delegator if no delegation is left.
+		fn clear_delegation(delegator: &T::AccountId, collator: &T::AccountId) {
+			DelegatorState::<T>::mutate_exists(delegator, |maybe_state| {
+				if let Some(state) = maybe_state {
+					state.rm_delegation(collator.clone());
+					if state.delegations.is_empty() {
+						*maybe_state = None;
+						AutoCompound::<T>::remove(delegator);
+					}
+				}
+			});
+			DelegationBlocksRewarded::<T>::remove(delegator, collator);
+			DelegationBlocksWithheldRewarded::<T>::remove(delegator, collator);
+			DelegationStart::<T>::remove(delegator, collator);
+		}
+
+		/// Mark the delegation as not yet eligible for rewards until
+		/// `DelegationWarmUp` rounds have passed.
+		fn add_pending_delegation(collator: &T::AccountId, delegator: &T::AccountId) -> DispatchResult {
+			if T::DelegationWarmUp::get().is_zero() {
+				return Ok(());
+			}
+			Self::settle_pending_delegations(collator);
+			let round = Round::<T>::get().current;
+
+			PendingDelegations::<T>::try_mutate(collator, |maybe_pending| -> DispatchResult {
+				let delegators = maybe_pending.get_or_insert_with(BoundedVec::default);
+				delegators.retain(|(d, _)| d != delegator);
+				// should never fail since only delegators of the collator are pending
+				delegators
+					.try_push((delegator.clone(), round))
+					.map_err(|_| Error::<T>::TooManyDelegators)?;
+				Ok(())
+			})
+		}
+
+		/// Remove the delegation from the set of delegations which are not yet
+		/// eligible for rewards.
+		fn remove_pending_delegation(collator: &T::AccountId, delegator: &T::AccountId) {
+			PendingDelegations::<T>::mutate_exists(collator, |maybe_pending| {
+				if let Some(delegators) = maybe_pending {
+					delegators.retain(|(d, _)| d != delegator);
+					if delegators.is_empty() {
+						*maybe_pending = None;
+					}
+				}
+			});
+		}
+
+		/// Make the pending delegations of the collator eligible for rewards if
+		/// they were made at least `DelegationWarmUp` rounds ago.
+		///
+		/// Sets the number of rewarded blocks of these delegations to the
+		/// number of blocks authored by the collator. Since this is done at
+		/// the latest when the collator authors their first block in the
+		/// current round, it equals the number of authored blocks at the start
+		/// of the current round.
+		///
+		/// Returns the number of delegations which became eligible.
+		fn settle_pending_delegations(collator: &T::AccountId) -> u32 {
+			let mut delegators = match PendingDelegations::<T>::get(collator) {
+				Some(delegators) => delegators,
+				None => return 0u32,
+			};
+			let current = Round::<T>::get().current;
+			let warm_up = T::DelegationWarmUp::get();
+			let num_pending = delegators.len();
+			let count_authored = BlocksAuthored::<T>::get(collator);
+			let withheld_authored = BlocksWithheld::<T>::get(collator);
+
+			delegators.retain(|(delegator, round)| {
+				let is_eligible = round.saturating_add(warm_up) <= current;
+				if is_eligible {
+					DelegationBlocksRewarded::<T>::insert(delegator, collator, count_authored);
+					DelegationBlocksWithheldRewarded::<T>::insert(delegator, collator, withheld_authored);
+				}
+				!is_eligible
+			});
+			let num_settled = num_pending.saturating_sub(delegators.len());
+
+			if delegators.is_empty() {
+				PendingDelegations::<T>::remove(collator);
+			} else if num_settled > 0 {
+				PendingDelegations::<T>::insert(collator, delegators);
+			}
+			num_settled.saturated_into()
+		}
+
+		/// Check whether the delegation is not yet eligible for rewards.
+		pub(crate) fn is_pending_delegation(delegator: &T::AccountId, collator: &T::AccountId) -> bool {
+			PendingDelegations::<T>::get(collator)
+				.map(|delegators| delegators.iter().any(|(d, _)| d == delegator))
+				.unwrap_or(false)
+		}
+
+		/// The penalty for withdrawing the given amount of stake from the
+		/// delegation to the collator.
+		///
+		/// Returns zero if the delegation was made at least
+		/// `MinDelegationDuration` rounds ago or at genesis.
+		fn early_exit_penalty(
+			delegator: &T::AccountId,
+			collator: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> BalanceOf<T> {
+			let is_early = DelegationStart::<T>::get(delegator, collator)
+				.map(|start| Round::<T>::get().current < start.saturating_add(T::MinDelegationDuration::get()))
+				.unwrap_or(false);
+			if is_early {
+				T::EarlyExitPenalty::get() * amount
+			} else {
+				BalanceOf::<T>::zero()
+			}
+		}
+
+		/// Take the early exit penalty from the staked funds of the delegator
+		/// and reduce the staking lock accordingly.
+		///
+		/// Depending on `EarlyExitPenaltyDestination`, the penalty is
+		/// distributed among the other delegators of the collator which are
+		/// eligible for rewards in proportion to their stake. Any remainder,
+		/// e.g. if there are no such delegators, is handed to
+		/// `EarlyExitPenaltyBeneficiary`.
+		fn take_early_exit_penalty(delegator: &T::AccountId, collator: &T::AccountId, penalty: BalanceOf<T>) {
+			if penalty.is_zero() {
+				return;
+			}
+			let mut imbalance = Self::take_staked(delegator, penalty);
+			let penalty = imbalance.peek();
+
+			if T::EarlyExitPenaltyDestination::get() == PenaltyDestination::Delegators {
+				let recipients: Vec<StakeOf<T>> = CandidatePool::<T>::get(collator)
+					.map(|state| {
+						state
+							.delegators
+							.into_iter()
+							.filter(|stake| {
+								stake.owner != *delegator && !Self::is_pending_delegation(&stake.owner, collator)
+							})
+							.collect()
+					})
+					.unwrap_or_default();
+				let total = recipients
+					.iter()
+					.fold(BalanceOf::<T>::zero(), |total, stake| total.saturating_add(stake.amount));
+
+				if !total.is_zero() {
+					for stake in recipients {
+						let share = Perquintill::from_rational(stake.amount, total) * penalty;
+						let (part, rest) = imbalance.split(share);
+						T::Currency::resolve_creating(&stake.owner, part);
+						imbalance = rest;
+					}
+				}
+			}
+			T::EarlyExitPenaltyBeneficiary::on_unbalanced(imbalance);
+
+			Self::deposit_event(Event::EarlyExitPenalized(delegator.clone(), collator.clone(), penalty));
 		}
 
 		/// Return the best `MaxSelectedCandidates` many candidates.
@@ -2096,10 +3660,11 @@ pub mod pallet {
 			log::trace!("{} Candidates for {} Collator seats", candidates.len(), top_n);
 
 			// Choose the top MaxSelectedCandidates qualified candidates
+			let (min_collator_stake, _) = Self::min_stakes_in_effect();
 			let collators = candidates
 				.into_iter()
 				.take(top_n)
-				.filter(|x| x.amount >= T::MinCollatorStake::get())
+				.filter(|x| x.amount >= min_collator_stake)
 				.map(|x| x.owner)
 				.collect::<Vec<T::AccountId>>();
 
@@ -2107,10 +3672,10 @@ pub mod pallet {
 		}
 
 		/// Attempts to add the stake to the set of delegators of a collator
-		/// which already reached its maximum size by removing an already
-		/// existing delegator with less staked value. If the given staked
-		/// amount is at most the minimum staked value of the original delegator
-		/// set, an error is returned.
+		/// which already reached the maximum number of delegators by removing
+		/// an already existing delegator with less staked value. If the given
+		/// staked amount is at most the minimum staked value of the original
+		/// delegator set, an error is returned.
 		///
 		/// Sets rewards for the removed delegator.
 		///
@@ -2125,18 +3690,27 @@ pub mod pallet {
 			stake: Stake<T::AccountId, BalanceOf<T>>,
 			mut state: Candidate<T::AccountId, BalanceOf<T>, T::MaxDelegatorsPerCollator>,
 		) -> Result<CandidateOf<T, T::MaxDelegatorsPerCollator>, DispatchError> {
-			// attempt to replace the last element of the set
-			let stake_to_remove = state
-				.delegators
-				.try_insert_replace(stake.clone())
-				.map_err(|err_too_many| {
-					if err_too_many {
-						Error::<T>::TooManyDelegators
-					} else {
-						// should never occur because we previously check this case, but let's be sure
-						Error::<T>::AlreadyDelegating
-					}
-				})?;
+			let stake_to_remove = if state.delegators.contains(&stake) {
+				// the set still has capacity because the maximum number of delegators was
+				// lowered, thus the stake has already been inserted and we remove the last
+				// element by hand
+				let lowest = state.delegators[state.delegators.len().saturating_sub(1)].clone();
+				ensure!(lowest.owner != stake.owner, Error::<T>::TooManyDelegators);
+				state.delegators.remove(&lowest)
+			} else {
+				// attempt to replace the last element of the set
+				state
+					.delegators
+					.try_insert_replace(stake.clone())
+					.map_err(|err_too_many| {
+						if err_too_many {
+							Error::<T>::TooManyDelegators
+						} else {
+							// should never occur because we previously check this case, but let's be sure
+							Error::<T>::AlreadyDelegating
+						}
+					})?
+			};
 
 			state.total = state.total.saturating_add(stake.amount);
 
@@ -2145,11 +3719,17 @@ pub mod pallet {
 				state.total = state.total.saturating_sub(stake_to_remove.amount);
 
 				// update rewards for kicked delegator
-				Self::do_inc_delegator_reward(&stake_to_remove.owner, stake_to_remove.amount, &state.id);
+				Self::do_inc_delegator_reward(
+					&stake_to_remove.owner,
+					stake_to_remove.amount,
+					&state.id,
+					Self::effective_commission(&state),
+				);
 				// prepare unstaking for kicked delegator
 				Self::prep_unstake(&stake_to_remove.owner, stake_to_remove.amount, true)?;
-				// remove Delegator state for kicked delegator
-				DelegatorState::<T>::remove(&stake_to_remove.owner);
+				// remove delegation from the state of the kicked delegator
+				Self::clear_delegation(&stake_to_remove.owner, &state.id);
+				Self::remove_pending_delegation(&state.id, &stake_to_remove.owner);
 
 				Self::deposit_event(Event::DelegationReplaced(
 					stake.owner,
@@ -2226,7 +3806,7 @@ pub mod pallet {
 			ensure!(!amount.is_zero(), Error::<T>::StakeNotFound);
 
 			let now = frame_system::Pallet::<T>::block_number();
-			let unlock_block = now.saturating_add(T::StakeDuration::get());
+			let unlock_block = now.saturating_add(StakingParams::<T>::get().stake_duration);
 			let mut unstaking = Unstaking::<T>::get(who);
 
 			let allowed_unstakings = if is_removal {
@@ -2259,18 +3839,14 @@ pub mod pallet {
 			state: &CandidateOf<T, T::MaxDelegatorsPerCollator>,
 		) -> DispatchResult {
 			// iterate over delegators
+			let commission = Self::effective_commission(state);
 			for stake in &state.delegators[..] {
 				// increment rewards
-				Self::do_inc_delegator_reward(&stake.owner, stake.amount, collator);
+				Self::do_inc_delegator_reward(&stake.owner, stake.amount, collator, commission);
 				// prepare unstaking of delegator
 				Self::prep_unstake(&stake.owner, stake.amount, true)?;
 				// remove delegation from delegator state
-				if let Some(mut delegator) = DelegatorState::<T>::get(&stake.owner) {
-					delegator
-						.try_clear(collator.clone())
-						.map_err(|_| Error::<T>::DelegationNotFound)?;
-					DelegatorState::<T>::remove(&stake.owner);
-				}
+				Self::clear_delegation(&stake.owner, collator);
 			}
 			// prepare unstaking of collator candidate
 			Self::prep_unstake(&state.id, state.stake, true)?;
@@ -2297,11 +3873,52 @@ pub mod pallet {
 
 			// Kill storage
 			BlocksAuthored::<T>::remove(&collator);
+			AuthoredBlocks::<T>::remove(&collator);
 			BlocksRewarded::<T>::remove(&collator);
+			BlocksWithheld::<T>::remove(&collator);
+			BlocksWithheldRewarded::<T>::remove(&collator);
+			PendingDelegations::<T>::remove(&collator);
+			CommissionChangeRequests::<T>::remove(&collator);
+			LastAuthoredRound::<T>::remove(&collator);
+			MissedRounds::<T>::remove(&collator);
+			AutoCompound::<T>::remove(&collator);
+			Self::remove_metadata(collator);
 			CandidatePool::<T>::remove(&collator);
 			Ok(())
 		}
 
+		/// The deposit required for publishing the given metadata.
+		pub(crate) fn metadata_deposit(metadata: &CollatorMetadataOf<T>) -> BalanceOf<T> {
+			let bytes: u64 = metadata.total_len().saturated_into();
+			T::MetadataDepositBase::get()
+				.saturating_add(T::MetadataDepositPerByte::get().saturating_mul(BalanceOf::<T>::from(bytes)))
+		}
+
+		/// Store the metadata of a collator candidate and adjust the reserved
+		/// deposit to the size of the new metadata.
+		///
+		/// Returns the deposit which is reserved for the metadata.
+		fn do_set_metadata(who: &T::AccountId, metadata: CollatorMetadataOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+			let deposit = Self::metadata_deposit(&metadata);
+			let old_deposit = CandidateMetadata::<T>::get(who).map_or(BalanceOf::<T>::zero(), |(_, d)| d);
+
+			if deposit > old_deposit {
+				T::Currency::reserve(who, deposit.saturating_sub(old_deposit))?;
+			} else {
+				T::Currency::unreserve(who, old_deposit.saturating_sub(deposit));
+			}
+			CandidateMetadata::<T>::insert(who, (metadata, deposit));
+
+			Ok(deposit)
+		}
+
+		/// Remove the metadata of a collator candidate and release its deposit.
+		fn remove_metadata(who: &T::AccountId) {
+			if let Some((_, deposit)) = CandidateMetadata::<T>::take(who) {
+				T::Currency::unreserve(who, deposit);
+			}
+		}
+
 		/// Withdraw all staked currency which was unstaked at least
 		/// `StakeDuration` blocks ago.
 		fn do_unlock(who: &T::AccountId) -> Result<u32, DispatchError> {
@@ -2323,156 +3940,796 @@ pub mod pallet {
 					total_locked = total_locked.saturating_add(locked_balance);
 				}
 			}
-			for block_number in expired {
-				unstaking.remove(&block_number);
+			for block_number in expired {
+				unstaking.remove(&block_number);
+			}
+
+			// iterate balance locks to retrieve amount of locked balance
+			let locks = Locks::<T>::get(who);
+			total_locked = if let Some(BalanceLock { amount, .. }) = locks.iter().find(|l| l.id == STAKING_ID) {
+				amount.saturating_sub(total_unlocked.into()).into()
+			} else {
+				// should never fail to find the lock since we checked whether unstaking is not
+				// empty but let's be safe
+				Zero::zero()
+			};
+
+			if total_locked.is_zero() {
+				T::Currency::remove_lock(STAKING_ID, who);
+				Unstaking::<T>::remove(who);
+			} else {
+				T::Currency::set_lock(STAKING_ID, who, total_locked, WithdrawReasons::all());
+				Unstaking::<T>::insert(who, unstaking);
+			}
+
+			Ok(unstaking_len)
+		}
+
+		/// Calculates the network rewards per block with the current data and
+		/// issues these rewards to the network. The imbalance will be handled
+		/// in `on_initialize` by adding it to the free balance of
+		/// `NetworkRewardBeneficiary`.
+		///
+		/// Over the course of an entire year, the network rewards equal the
+		/// maximum annual collator staking rewards multiplied with the
+		/// NetworkRewardRate. E.g., assuming 10% annual collator reward rate,
+		/// 10% max staking rate, 200k KILT max collator stake and 30 collators:
+		/// NetworkRewards = NetworkRewardRate * 10% * 10% * 200_000 KILT * 30
+		///
+		/// The expected rewards are the product of
+		///  * the current total maximum collator rewards
+		///  * and the configured NetworkRewardRate
+		///
+		/// `col_reward_rate_per_block * col_max_stake * max_num_of_collators *
+		/// NetworkRewardRate`
+		fn issue_network_reward() -> NegativeImbalanceOf<T> {
+			// Multiplication with Perquintill cannot overflow
+			let max_col_rewards = InflationConfig::<T>::get().collator.reward_rate.per_block
+				* MaxCollatorCandidateStake::<T>::get()
+				* MaxSelectedCandidates::<T>::get().into();
+			let network_reward = StakingParams::<T>::get().network_reward_rate * max_col_rewards;
+
+			T::Currency::issue(network_reward)
+		}
+
+		/// Calculates the collator staking rewards for authoring `multiplier`
+		/// many blocks based on the given stake.
+		///
+		/// Depends on the current total issuance and staking reward
+		/// configuration for collators. The reward source is not accounted
+		/// for, see [Self::deduct_withheld].
+		pub(crate) fn calc_block_rewards_collator(stake: BalanceOf<T>, multiplier: BalanceOf<T>) -> BalanceOf<T> {
+			let total_issuance = T::Currency::total_issuance();
+			let TotalStake {
+				collators: total_collators,
+				..
+			} = TotalCollatorStake::<T>::get();
+			let staking_rate = Perquintill::from_rational(total_collators, total_issuance);
+
+			InflationConfig::<T>::get()
+				.collator
+				.compute_reward::<T>(stake, staking_rate, multiplier)
+		}
+
+		/// Calculates the delegator staking rewards for `multiplier` many
+		/// blocks based on the given stake.
+		///
+		/// Depends on the current total issuance and staking reward
+		/// configuration for delegators. The reward source is not accounted
+		/// for, see [Self::deduct_withheld].
+		pub(crate) fn calc_block_rewards_delegator(stake: BalanceOf<T>, multiplier: BalanceOf<T>) -> BalanceOf<T> {
+			let total_issuance = T::Currency::total_issuance();
+			let TotalStake {
+				delegators: total_delegators,
+				..
+			} = TotalCollatorStake::<T>::get();
+			let staking_rate = Perquintill::from_rational(total_delegators, total_issuance);
+
+			InflationConfig::<T>::get()
+				.delegator
+				.compute_reward::<T>(stake, staking_rate, multiplier)
+		}
+
+		/// Increment the accumulated rewards of a collator.
+		///
+		/// Updates Rewarded(col) and sets BlocksRewarded(col) to equal
+		/// BlocksAuthored(col).
+		fn do_inc_collator_reward(acc: &T::AccountId, stake: BalanceOf<T>) {
+			let count_authored = BlocksAuthored::<T>::get(acc);
+			// We can already mutate thanks to extrinsics being transactional
+			let count_rewarded = BlocksRewarded::<T>::mutate(acc, |rewarded| {
+				let old = *rewarded;
+				*rewarded = count_authored;
+				old
+			});
+			let unclaimed_blocks = count_authored.saturating_sub(count_rewarded);
+			let withheld_authored = BlocksWithheld::<T>::get(acc);
+			let withheld_rewarded =
+				BlocksWithheldRewarded::<T>::mutate(acc, |rewarded| sp_std::mem::replace(rewarded, withheld_authored));
+
+			let reward = Self::deduct_withheld(
+				Self::calc_block_rewards_collator(stake, unclaimed_blocks.into()),
+				unclaimed_blocks,
+				withheld_authored.saturating_sub(withheld_rewarded),
+			);
+			Self::credit_reward(acc, T::RewardSource::creditable(reward));
+		}
+
+		/// Increment the accumulated rewards of a delegator for their
+		/// delegation to a collator by checking the number of authored blocks
+		/// by the collator.
+		///
+		/// The collator's commission is deducted from the reward and added to
+		/// the accumulated rewards of the collator. Delegations which are not
+		/// yet eligible for rewards are skipped.
+		///
+		/// Updates Rewarded(del) and sets DelegationBlocksRewarded(del, col) to
+		/// equal BlocksAuthored(col).
+		fn do_inc_delegator_reward(
+			acc: &T::AccountId,
+			stake: BalanceOf<T>,
+			col: &T::AccountId,
+			commission: Perbill,
+		) {
+			Self::settle_pending_delegations(col);
+			if Self::is_pending_delegation(acc, col) {
+				return;
+			}
+
+			let count_authored = BlocksAuthored::<T>::get(col);
+			// We can already mutate thanks to extrinsics being transactional
+			let count_rewarded = DelegationBlocksRewarded::<T>::mutate(acc, col, |rewarded| {
+				let old = *rewarded;
+				*rewarded = count_authored;
+				old
+			});
+			let unclaimed_blocks = count_authored.saturating_sub(count_rewarded);
+			let withheld_authored = BlocksWithheld::<T>::get(col);
+			let withheld_rewarded = DelegationBlocksWithheldRewarded::<T>::mutate(acc, col, |rewarded| {
+				sp_std::mem::replace(rewarded, withheld_authored)
+			});
+
+			let reward = T::RewardSource::creditable(Self::deduct_withheld(
+				Self::calc_block_rewards_delegator(stake, unclaimed_blocks.into()),
+				unclaimed_blocks,
+				withheld_authored.saturating_sub(withheld_rewarded),
+			));
+			let collator_cut = commission * reward;
+
+			Self::credit_reward(acc, reward.saturating_sub(collator_cut));
+			if !collator_cut.is_zero() {
+				Self::credit_reward(col, collator_cut);
+			}
+		}
+
+		/// Deduct the share of the reward for `blocks` many blocks which is
+		/// withheld according to the reward ratios of the rounds the blocks
+		/// were authored in.
+		///
+		/// `withheld` is the increase of BlocksWithheld of the collator for
+		/// these blocks.
+		pub(crate) fn deduct_withheld(reward: BalanceOf<T>, blocks: T::BlockNumber, withheld: u128) -> BalanceOf<T> {
+			if withheld.is_zero() {
+				return reward;
+			}
+			let blocks = blocks
+				.saturated_into::<u128>()
+				.saturating_mul(Perquintill::one().deconstruct().into());
+			reward.saturating_sub(Perquintill::from_rational(withheld, blocks) * reward)
+		}
+
+		/// Add the reward to the accumulated rewards of the account which
+		/// have not been paid out yet.
+		fn credit_reward(acc: &T::AccountId, reward: BalanceOf<T>) {
+			if reward.is_zero() {
+				return;
+			}
+			Rewards::<T>::mutate(acc, |rewards| *rewards = rewards.saturating_add(reward));
+			OutstandingRewards::<T>::mutate(|outstanding| *outstanding = outstanding.saturating_add(reward));
+			Self::note_reward(acc, reward);
+		}
+
+		/// Add the credited rewards of the account to its entry of the
+		/// current round in the reward history, dropping the oldest entry if
+		/// the history is full.
+		fn note_reward(acc: &T::AccountId, reward: BalanceOf<T>) {
+			let max_history = T::MaxRewardHistory::get() as usize;
+			if reward.is_zero() || max_history == 0 {
+				return;
+			}
+
+			let round = Round::<T>::get().current;
+			RewardHistory::<T>::mutate(acc, |history| match history.last_mut() {
+				Some((last_round, rewards)) if *last_round == round => *rewards = rewards.saturating_add(reward),
+				_ => {
+					if history.len() >= max_history {
+						history.remove(0);
+					}
+					// cannot fail because we made room above
+					let _ = history.try_push((round, reward));
+				}
+			});
+		}
+
+		/// The commission a collator effectively charges on the rewards of
+		/// their delegators, capped by [MaxCollatorCommission].
+		pub(crate) fn effective_commission(state: &CandidateOf<T, T::MaxDelegatorsPerCollator>) -> Perbill {
+			state.commission.min(MaxCollatorCommission::<T>::get())
+		}
+
+		/// Increment the accumulated rewards of the collator and stake their
+		/// auto-compound share right away.
+		///
+		/// Returns the consumed weight.
+		fn do_inc_and_compound_collator_reward(
+			collator: &T::AccountId,
+			stake: BalanceOf<T>,
+		) -> Result<Weight, DispatchError> {
+			let rewards_before = Rewards::<T>::get(collator);
+			Self::do_inc_collator_reward(collator, stake);
+			let incremented = Rewards::<T>::get(collator).saturating_sub(rewards_before);
+
+			let weight = match Self::compound_collator_rewards(collator, incremented)? {
+				Some((n, unstaking_len)) => <T as Config>::WeightInfo::increment_collator_rewards().saturating_add(
+					<T as Config>::WeightInfo::candidate_stake_more(
+						n,
+						T::MaxDelegatorsPerCollator::get(),
+						unstaking_len,
+					),
+				),
+				None => <T as Config>::WeightInfo::increment_collator_rewards(),
+			};
+			Ok(weight)
+		}
+
+		/// Increment the accumulated rewards of the delegator for their
+		/// delegation to the collator and stake their auto-compound share on
+		/// the same delegation right away.
+		///
+		/// Returns the weight consumed by compounding.
+		fn do_inc_and_compound_delegator_reward(
+			delegator: &T::AccountId,
+			stake: BalanceOf<T>,
+			collator: &T::AccountId,
+			commission: Perbill,
+		) -> Result<Weight, DispatchError> {
+			let rewards_before = Rewards::<T>::get(delegator);
+			Self::do_inc_delegator_reward(delegator, stake, collator, commission);
+			let incremented = Rewards::<T>::get(delegator).saturating_sub(rewards_before);
+
+			let weight = match Self::compound_delegator_rewards(delegator, collator, incremented)? {
+				Some((n, unstaking_len)) => <T as Config>::WeightInfo::delegator_stake_more(
+					n,
+					T::MaxDelegatorsPerCollator::get(),
+					unstaking_len,
+				),
+				None => Weight::zero(),
+			};
+			Ok(weight)
+		}
+
+		/// Queue all top candidates to auto-compound their rewards and those
+		/// of their delegators, one candidate per block.
+		///
+		/// Candidates still queued from the previous round are replaced, their
+		/// rewards keep accumulating until they are compounded.
+		pub(crate) fn queue_auto_compound() {
+			let owners: BoundedVec<T::AccountId, T::MaxTopCandidates> = TopCandidates::<T>::get()
+				.into_iter()
+				.map(|top| top.owner)
+				.collect::<Vec<T::AccountId>>()
+				.try_into()
+				.expect("Did not extend top candidates q.e.d.");
+			AutoCompoundQueue::<T>::put(owners);
+		}
+
+		/// Auto-compound the rewards of the next queued top candidate and
+		/// their delegators.
+		///
+		/// Returns the weight of compounding a candidate with the maximum
+		/// number of delegators, which is charged regardless of the actual
+		/// work.
+		pub(crate) fn compound_next_candidate() -> Weight {
+			let mut queue = AutoCompoundQueue::<T>::get();
+			let collator = match queue.pop() {
+				Some(collator) => collator,
+				None => return T::DbWeight::get().reads(1),
+			};
+			AutoCompoundQueue::<T>::put(queue);
+			Self::compound_candidate(&collator);
+
+			Self::max_compound_candidate_weight()
+		}
+
+		/// Upper bound of the weight of [Pallet::compound_next_candidate].
+		pub(crate) fn max_compound_candidate_weight() -> Weight {
+			let max_delegators = T::MaxDelegatorsPerCollator::get();
+			let max_collator_weight = <T as Config>::WeightInfo::increment_collator_rewards().saturating_add(
+				<T as Config>::WeightInfo::candidate_stake_more(
+					T::MaxTopCandidates::get(),
+					max_delegators,
+					T::MaxUnstakeRequests::get().saturating_sub(1),
+				),
+			);
+			let max_delegator_weight = <T as Config>::WeightInfo::increment_delegator_rewards(1).saturating_add(
+				<T as Config>::WeightInfo::delegator_stake_more(
+					T::MaxTopCandidates::get(),
+					max_delegators,
+					T::MaxUnstakeRequests::get().saturating_sub(1),
+				),
+			);
+
+			// queue, candidate and auto-compound percentages of all accounts
+			T::DbWeight::get()
+				.reads_writes(max_delegators.saturating_add(3).into(), 1)
+				.saturating_add(max_collator_weight)
+				.saturating_add(max_delegator_weight.saturating_mul(max_delegators.into()))
+		}
+
+		/// Increment the rewards of the candidate and their delegators which
+		/// have set an auto-compound percentage and stake that share right
+		/// away.
+		///
+		/// Accounts for which compounding fails keep their rewards claimable.
+		fn compound_candidate(collator: &T::AccountId) {
+			let state = match CandidatePool::<T>::get(collator) {
+				Some(state) => state,
+				None => return,
+			};
+
+			if AutoCompound::<T>::contains_key(collator) {
+				let _ = with_storage_layer(|| Self::do_inc_and_compound_collator_reward(collator, state.stake));
+			}
+
+			let commission = Self::effective_commission(&state);
+			for stake in state.delegators.into_iter() {
+				if !AutoCompound::<T>::contains_key(&stake.owner) {
+					continue;
+				}
+				let _ = with_storage_layer(|| {
+					Self::do_inc_and_compound_delegator_reward(&stake.owner, stake.amount, collator, commission)
+				});
+			}
+		}
+
+		/// Stake the auto-compound share of the given rewards of the collator
+		/// candidate, capped by [MaxCollatorCandidateStake]. The remainder
+		/// stays claimable.
+		///
+		/// Returns the number of top candidates and unstaking requests if
+		/// any rewards were staked.
+		fn compound_collator_rewards(
+			collator: &T::AccountId,
+			rewards: BalanceOf<T>,
+		) -> Result<Option<(u32, u32)>, DispatchError> {
+			let mut state = match CandidatePool::<T>::get(collator) {
+				Some(state) if !state.is_leaving() => state,
+				_ => return Ok(None),
+			};
+			let amount = (AutoCompound::<T>::get(collator) * rewards)
+				.min(MaxCollatorCandidateStake::<T>::get().saturating_sub(state.stake));
+			if amount.is_zero() {
+				return Ok(None);
 			}
 
-			// iterate balance locks to retrieve amount of locked balance
-			let locks = Locks::<T>::get(who);
-			total_locked = if let Some(BalanceLock { amount, .. }) = locks.iter().find(|l| l.id == STAKING_ID) {
-				amount.saturating_sub(total_unlocked.into()).into()
+			let CandidateOf::<T, _> {
+				stake: before_stake,
+				total: before_total,
+				..
+			} = state;
+			state.stake_more(amount);
+
+			Self::take_rewards(collator, amount)?;
+			let unstaking_len = Self::increase_lock(collator, state.stake, amount)?;
+
+			let n = if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					before_stake,
+					// safe because total >= stake
+					before_total - before_stake,
+					state.stake,
+					state.total - state.stake,
+				)
 			} else {
-				// should never fail to find the lock since we checked whether unstaking is not
-				// empty but let's be safe
-				Zero::zero()
+				0u32
 			};
+			CandidatePool::<T>::insert(collator, state);
 
-			if total_locked.is_zero() {
-				T::Currency::remove_lock(STAKING_ID, who);
-				Unstaking::<T>::remove(who);
-			} else {
-				T::Currency::set_lock(STAKING_ID, who, total_locked, WithdrawReasons::all());
-				Unstaking::<T>::insert(who, unstaking);
+			Self::deposit_event(Event::RewardsCompounded(collator.clone(), collator.clone(), amount));
+			Ok(Some((n, unstaking_len)))
+		}
+
+		/// Stake the auto-compound share of the given rewards of the delegator
+		/// on their delegation to the collator. The remainder stays
+		/// claimable.
+		///
+		/// Returns the number of top candidates and unstaking requests if
+		/// any rewards were staked.
+		fn compound_delegator_rewards(
+			delegator: &T::AccountId,
+			collator: &T::AccountId,
+			rewards: BalanceOf<T>,
+		) -> Result<Option<(u32, u32)>, DispatchError> {
+			let amount = AutoCompound::<T>::get(delegator) * rewards;
+			if amount.is_zero() {
+				return Ok(None);
 			}
+			let mut state = match CandidatePool::<T>::get(collator) {
+				Some(state) if !state.is_leaving() => state,
+				_ => return Ok(None),
+			};
+			let mut delegation = DelegatorState::<T>::get(delegator).ok_or(Error::<T>::DelegatorNotFound)?;
+			delegation
+				.try_increment(collator.clone(), amount)
+				.map_err(|_| Error::<T>::DelegationNotFound)?;
 
-			Ok(unstaking_len)
-		}
+			Self::take_rewards(delegator, amount)?;
+			let unstaking_len = Self::increase_lock(delegator, delegation.total, amount)?;
 
-		/// Checks whether a delegator can still delegate in this round, e.g.,
-		/// if they have not delegated MaxDelegationsPerRound many times
-		/// already in this round.
-		fn get_delegation_counter(delegator: &T::AccountId) -> Result<DelegationCounter, DispatchError> {
-			let last_delegation = LastDelegation::<T>::get(delegator);
-			let round = Round::<T>::get();
+			let CandidateOf::<T, _> {
+				stake: before_stake,
+				total: before_total,
+				..
+			} = state;
+			state.inc_delegator(delegator.clone(), amount);
 
-			// reset counter if the round advanced since last delegation
-			let counter = if last_delegation.round < round.current {
-				0u32
+			let n = if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					before_stake,
+					// safe because total >= stake
+					before_total - before_stake,
+					state.stake,
+					state.total - state.stake,
+				)
 			} else {
-				last_delegation.counter
+				0u32
 			};
+			CandidatePool::<T>::insert(collator, state);
+			DelegatorState::<T>::insert(delegator, delegation);
 
-			ensure!(
-				counter < T::MaxDelegationsPerRound::get(),
-				Error::<T>::DelegationsPerRoundExceeded
-			);
+			Self::deposit_event(Event::RewardsCompounded(delegator.clone(), collator.clone(), amount));
+			Ok(Some((n, unstaking_len)))
+		}
 
-			Ok(DelegationCounter {
-				round: round.current,
-				counter: counter.saturating_add(1),
-			})
+		/// Deduct the given amount from the accumulated rewards of the
+		/// account and pay it out into their free balance.
+		fn take_rewards(acc: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+			Rewards::<T>::try_mutate(acc, |rewards| -> DispatchResult {
+				*rewards = rewards.checked_sub(&amount).ok_or(Error::<T>::RewardsNotFound)?;
+				Ok(())
+			})?;
+			OutstandingRewards::<T>::mutate(|outstanding| *outstanding = outstanding.saturating_sub(amount));
+			T::RewardSource::payout(acc, amount)
 		}
 
-		/// Calculates the network rewards per block with the current data and
-		/// issues these rewards to the network. The imbalance will be handled
-		/// in `on_initialize` by adding it to the free balance of
-		/// `NetworkRewardBeneficiary`.
-		///
-		/// Over the course of an entire year, the network rewards equal the
-		/// maximum annual collator staking rewards multiplied with the
-		/// NetworkRewardRate. E.g., assuming 10% annual collator reward rate,
-		/// 10% max staking rate, 200k KILT max collator stake and 30 collators:
-		/// NetworkRewards = NetworkRewardRate * 10% * 10% * 200_000 KILT * 30
+		/// Check the liveness of the collators which were selected for the
+		/// given round and demote those which have not authored any block for
+		/// `max_missed_rounds` many rounds in a row.
 		///
-		/// The expected rewards are the product of
-		///  * the current total maximum collator rewards
-		///  * and the configured NetworkRewardRate
+		/// Returns the number of checked collators.
+		pub(crate) fn check_liveness(round: SessionIndex) -> u32 {
+			let collators = match PlannedCollators::<T>::take(round) {
+				Some(collators) => collators,
+				None => return 0u32,
+			};
+			let policy = LivenessConfig::<T>::get();
+			// liveness tracking might have been disabled in the meantime
+			if policy.max_missed_rounds.is_zero() {
+				return 0u32;
+			}
+
+			for collator in collators.iter() {
+				let state = match CandidatePool::<T>::get(collator) {
+					Some(state) if state.is_active() => state,
+					// leaving and offline candidates are not tracked
+					_ => {
+						MissedRounds::<T>::remove(collator);
+						continue;
+					}
+				};
+				if LastAuthoredRound::<T>::get(collator) == Some(round) {
+					MissedRounds::<T>::remove(collator);
+					continue;
+				}
+
+				let missed = MissedRounds::<T>::mutate(collator, |missed| {
+					*missed = missed.saturating_add(1);
+					*missed
+				});
+				Self::deposit_event(Event::CollatorMissedRound(collator.clone(), missed));
+
+				if missed >= policy.max_missed_rounds {
+					Self::demote_offline_candidate(collator, state, &policy);
+				}
+			}
+
+			collators.len().saturated_into()
+		}
+
+		/// Remove the collator candidate from the set of top candidates for
+		/// being offline and schedule the offline slash, if any.
 		///
-		/// `col_reward_rate_per_block * col_max_stake * max_num_of_collators *
-		/// NetworkRewardRate`
-		fn issue_network_reward() -> NegativeImbalanceOf<T> {
-			// Multiplication with Perquintill cannot overflow
-			let max_col_rewards = InflationConfig::<T>::get().collator.reward_rate.per_block
-				* MaxCollatorCandidateStake::<T>::get()
-				* MaxSelectedCandidates::<T>::get().into();
-			let network_reward = T::NetworkRewardRate::get() * max_col_rewards;
+		/// The candidate is kept if the number of top candidates would fall
+		/// below `MinRequiredCollators`.
+		pub(crate) fn demote_offline_candidate(
+			collator: &T::AccountId,
+			mut state: CandidateOf<T, T::MaxDelegatorsPerCollator>,
+			policy: &LivenessPolicy,
+		) {
+			let mut candidates = TopCandidates::<T>::get();
+			if candidates.len().saturated_into::<u32>() <= T::MinRequiredCollators::get() {
+				return;
+			}
 
-			T::Currency::issue(network_reward)
+			state.go_offline();
+			if candidates
+				.remove(&Stake {
+					owner: collator.clone(),
+					amount: state.total,
+				})
+				.is_some()
+			{
+				TopCandidates::<T>::put(candidates);
+				Self::deposit_event(Event::LeftTopCandidates(collator.clone()));
+				// update total amount at stake from scratch
+				Self::update_total_stake();
+			}
+			CandidatePool::<T>::insert(collator, state);
+			MissedRounds::<T>::remove(collator);
+
+			Self::deposit_event(Event::CollatorWentOffline(collator.clone()));
+
+			if !policy.offline_slash.is_zero() {
+				Self::schedule_slash(
+					collator,
+					PendingSlash {
+						fraction: policy.offline_slash,
+						slash_delegators: policy.slash_delegators,
+					},
+				);
+			}
 		}
 
-		/// Calculates the collator staking rewards for authoring `multiplier`
-		/// many blocks based on the given stake.
+		/// The round in which a slash scheduled in the current round is
+		/// applied.
+		fn slash_round() -> SessionIndex {
+			Round::<T>::get()
+				.current
+				.saturating_add(T::SlashDeferDuration::get().max(1))
+		}
+
+		/// Schedule the slash of the collator candidate to be applied after
+		/// `SlashDeferDuration` many rounds.
 		///
-		/// Depends on the current total issuance and staking reward
-		/// configuration for collators.
-		pub(crate) fn calc_block_rewards_collator(stake: BalanceOf<T>, multiplier: BalanceOf<T>) -> BalanceOf<T> {
-			let total_issuance = T::Currency::total_issuance();
-			let TotalStake {
-				collators: total_collators,
-				..
-			} = TotalCollatorStake::<T>::get();
-			let staking_rate = Perquintill::from_rational(total_collators, total_issuance);
+		/// Replaces a slash which was scheduled for the same round.
+		fn schedule_slash(collator: &T::AccountId, slash: PendingSlash) {
+			let when = Self::slash_round();
+			PendingSlashes::<T>::insert(when, collator, slash);
+
+			Self::deposit_event(Event::SlashScheduled(
+				collator.clone(),
+				slash.fraction,
+				slash.slash_delegators,
+				when,
+			));
+		}
 
-			InflationConfig::<T>::get()
-				.collator
-				.compute_reward::<T>(stake, staking_rate, multiplier)
+		/// Return the first pending slash of the collator candidate, if any.
+		pub(crate) fn pending_slash_of(collator: &T::AccountId) -> Option<PendingSlash> {
+			(Round::<T>::get().current..=Self::slash_round())
+				.find_map(|round| PendingSlashes::<T>::get(round, collator))
 		}
 
-		/// Calculates the delegator staking rewards for `multiplier` many
-		/// blocks based on the given stake.
+		/// Check whether a pending slash of the collator candidate also
+		/// applies to their delegators.
+		fn is_delegator_slash_pending(collator: &T::AccountId) -> bool {
+			(Round::<T>::get().current..=Self::slash_round()).any(|round| {
+				PendingSlashes::<T>::get(round, collator)
+					.map(|slash| slash.slash_delegators)
+					.unwrap_or(false)
+			})
+		}
+
+		/// Apply all slashes which were deferred until the given round.
 		///
-		/// Depends on the current total issuance and staking reward
-		/// configuration for delegators.
-		pub(crate) fn calc_block_rewards_delegator(stake: BalanceOf<T>, multiplier: BalanceOf<T>) -> BalanceOf<T> {
-			let total_issuance = T::Currency::total_issuance();
-			let TotalStake {
-				delegators: total_delegators,
+		/// Returns the number of applied slashes.
+		fn apply_pending_slashes(round: SessionIndex) -> u32 {
+			let mut num_slashes = 0u32;
+			for (collator, slash) in PendingSlashes::<T>::drain_prefix(round) {
+				Self::do_slash(&collator, slash);
+				num_slashes = num_slashes.saturating_add(1);
+			}
+			num_slashes
+		}
+
+		/// Slash the stake of the collator candidate and, if requested, the
+		/// stake of their delegators. The slashed funds are handed to
+		/// `T::Slash`.
+		///
+		/// Increments the rewards of the affected accounts with their stake
+		/// before the slash. Delegations which fall below the minimum
+		/// delegator stake are removed and candidates which fall below the
+		/// minimum collator stake leave the set of top candidates.
+		pub(crate) fn do_slash(collator: &T::AccountId, slash: PendingSlash) {
+			// the candidate might have been removed in the meantime
+			let mut state = match CandidatePool::<T>::get(collator) {
+				Some(state) => state,
+				None => return,
+			};
+			let CandidateOf::<T, _> {
+				stake: old_stake,
+				total: old_total,
 				..
-			} = TotalCollatorStake::<T>::get();
-			let staking_rate = Perquintill::from_rational(total_delegators, total_issuance);
+			} = state;
 
-			InflationConfig::<T>::get()
-				.delegator
-				.compute_reward::<T>(stake, staking_rate, multiplier)
+			Self::do_inc_collator_reward(collator, state.stake);
+			let amount = slash.fraction * state.stake;
+			state.stake = state.stake.saturating_sub(amount);
+			state.total = state.total.saturating_sub(amount);
+			let mut imbalance = Self::slash_staked(collator, amount);
+
+			let (min_collator_stake, min_delegator_stake) = Self::min_stakes_in_effect();
+
+			if slash.slash_delegators {
+				let commission = Self::effective_commission(&state);
+				for stake in state.delegators.clone().into_iter() {
+					Self::do_inc_delegator_reward(&stake.owner, stake.amount, collator, commission);
+
+					let amount = slash.fraction * stake.amount;
+					state.dec_delegator(stake.owner.clone(), amount);
+					DelegatorState::<T>::mutate(&stake.owner, |maybe_delegator| {
+						if let Some(delegator) = maybe_delegator {
+							// cannot underflow since the slash is a fraction of the delegation
+							let _ = delegator.try_decrement(collator.clone(), amount);
+						}
+					});
+					imbalance.subsume(Self::slash_staked(&stake.owner, amount));
+
+					if stake.amount.saturating_sub(amount) < min_delegator_stake {
+						Self::remove_slashed_delegation(&stake.owner, collator, &mut state);
+					}
+				}
+			}
+
+			if state.is_active() && state.stake < min_collator_stake {
+				Self::demote_slashed_candidate(collator, &mut state, old_total);
+			}
+
+			// update top candidates and total amount at stake
+			if state.is_active() {
+				Self::update_top_candidates(
+					collator.clone(),
+					old_stake,
+					// safe because total >= stake
+					old_total - old_stake,
+					state.stake,
+					state.total - state.stake,
+				);
+			}
+			CandidatePool::<T>::insert(collator, state);
+
+			T::Slash::on_unbalanced(imbalance);
 		}
 
-		/// Increment the accumulated rewards of a collator.
+		/// Remove the delegation to the collator candidate after a slash left
+		/// it below the minimum delegator stake. The remaining stake is added
+		/// to the queue of pending unstaking of the delegator.
 		///
-		/// Updates Rewarded(col) and sets BlocksRewarded(col) to equal
-		/// BlocksAuthored(col).
-		fn do_inc_collator_reward(acc: &T::AccountId, stake: BalanceOf<T>) {
-			let count_authored = BlocksAuthored::<T>::get(acc);
-			// We can already mutate thanks to extrinsics being transactional
-			let count_rewarded = BlocksRewarded::<T>::mutate(acc, |rewarded| {
-				let old = *rewarded;
-				*rewarded = count_authored;
-				old
-			});
-			let unclaimed_blocks = count_authored.saturating_sub(count_rewarded);
+		/// The delegation is kept if the delegator cannot unstake any more.
+		fn remove_slashed_delegation(
+			delegator: &T::AccountId,
+			collator: &T::AccountId,
+			state: &mut CandidateOf<T, T::MaxDelegatorsPerCollator>,
+		) {
+			let mut delegation = match DelegatorState::<T>::get(delegator) {
+				Some(delegation) => delegation,
+				None => return,
+			};
+			let amount = match delegation.rm_delegation(collator.clone()) {
+				Some(amount) => amount,
+				None => return,
+			};
+			// the removal must not be blocked by the unstaking requests of the delegator
+			if !amount.is_zero() && Self::prep_unstake(delegator, amount, true).is_err() {
+				return;
+			}
+
+			// *** No Fail beyond this point ***
 
-			Rewards::<T>::mutate(acc, |reward| {
-				*reward = reward.saturating_add(Self::calc_block_rewards_collator(stake, unclaimed_blocks.into()));
+			state.delegators.remove(&Stake {
+				owner: delegator.clone(),
+				// amount is irrelevant for removal
+				amount: BalanceOf::<T>::one(),
 			});
+			state.total = state.total.saturating_sub(amount);
+
+			// rewards have been incremented before the slash
+			DelegationBlocksRewarded::<T>::remove(delegator, collator);
+			DelegationBlocksWithheldRewarded::<T>::remove(delegator, collator);
+			DelegationStart::<T>::remove(delegator, collator);
+			Self::remove_pending_delegation(collator, delegator);
+
+			if delegation.delegations.is_empty() {
+				DelegatorState::<T>::remove(delegator);
+				AutoCompound::<T>::remove(delegator);
+				Self::deposit_event(Event::DelegatorLeft(delegator.clone(), amount));
+			} else {
+				DelegatorState::<T>::insert(delegator, delegation);
+			}
+
+			Self::deposit_event(Event::DelegationBelowMinRemoved(
+				delegator.clone(),
+				collator.clone(),
+				amount,
+			));
 		}
 
-		/// Increment the accumulated rewards of a delegator by checking the
-		/// number of authored blocks by the collator.
+		/// Remove the collator candidate from the set of top candidates after
+		/// a slash left their stake below the minimum collator stake. They
+		/// can rejoin via `go_online` after staking more.
 		///
-		/// Updates Rewarded(del) and sets BlocksRewarded(del) to equal
-		/// BlocksAuthored(col).
-		fn do_inc_delegator_reward(acc: &T::AccountId, stake: BalanceOf<T>, col: &T::AccountId) {
-			let count_authored = BlocksAuthored::<T>::get(col);
-			// We can already mutate thanks to extrinsics being transactional
-			let count_rewarded = BlocksRewarded::<T>::mutate(acc, |rewarded| {
-				let old = *rewarded;
-				*rewarded = count_authored;
-				old
-			});
-			let unclaimed_blocks = count_authored.saturating_sub(count_rewarded);
+		/// The candidate is kept if the number of top candidates would fall
+		/// below `MinRequiredCollators`.
+		fn demote_slashed_candidate(
+			collator: &T::AccountId,
+			state: &mut CandidateOf<T, T::MaxDelegatorsPerCollator>,
+			old_total: BalanceOf<T>,
+		) {
+			let mut candidates = TopCandidates::<T>::get();
+			if candidates.len().saturated_into::<u32>() <= T::MinRequiredCollators::get() {
+				return;
+			}
 
-			Rewards::<T>::mutate(acc, |reward| {
-				*reward = reward.saturating_add(Self::calc_block_rewards_delegator(stake, unclaimed_blocks.into()))
-			});
+			state.go_offline();
+			if candidates
+				.remove(&Stake {
+					owner: collator.clone(),
+					amount: old_total,
+				})
+				.is_some()
+			{
+				TopCandidates::<T>::put(candidates);
+				Self::deposit_event(Event::LeftTopCandidates(collator.clone()));
+				// update total amount at stake from scratch
+				Self::update_total_stake();
+			}
+
+			Self::deposit_event(Event::CandidateBelowMinDemoted(collator.clone(), state.stake));
+		}
+
+		/// Slash the given amount of staked funds of the account and reduce
+		/// the staking lock accordingly.
+		fn slash_staked(who: &T::AccountId, amount: BalanceOf<T>) -> NegativeImbalanceOf<T> {
+			if amount.is_zero() {
+				return NegativeImbalanceOf::<T>::zero();
+			}
+			let imbalance = Self::take_staked(who, amount);
+			Self::deposit_event(Event::Slashed(who.clone(), imbalance.peek()));
+			imbalance
+		}
+
+		/// Take the given amount from the staked funds of the account and
+		/// reduce the staking lock accordingly.
+		fn take_staked(who: &T::AccountId, amount: BalanceOf<T>) -> NegativeImbalanceOf<T> {
+			let (imbalance, _) = T::Currency::slash(who, amount);
+			let slashed = imbalance.peek();
+
+			let locks = Locks::<T>::get(who);
+			let locked: BalanceOf<T> =
+				if let Some(BalanceLock { amount, .. }) = locks.iter().find(|l| l.id == STAKING_ID) {
+					(*amount).into()
+				} else {
+					Zero::zero()
+				};
+			let remaining = locked.saturating_sub(slashed);
+			if remaining.is_zero() {
+				T::Currency::remove_lock(STAKING_ID, who);
+			} else {
+				T::Currency::set_lock(STAKING_ID, who, remaining, WithdrawReasons::all());
+			}
+			imbalance
 		}
 	}
 
@@ -2485,16 +4742,33 @@ pub mod pallet {
 		fn note_author(author: T::AccountId) {
 			// should always include state except if the collator has been forcedly removed
 			// via `force_remove_candidate` in the current or previous round
-			if CandidatePool::<T>::get(&author).is_some() {
+			let num_settled = if CandidatePool::<T>::get(&author).is_some() {
+				// delegations from previous rounds become eligible before counting this block
+				let num_settled = Self::settle_pending_delegations(&author);
 				// necessary to compensate for a potentially fluctuating number of collators
 				let authors = pallet_session::Pallet::<T>::validators();
 				BlocksAuthored::<T>::mutate(&author, |count| {
 					*count = count.saturating_add(authors.len().saturated_into::<T::BlockNumber>());
 				});
-			}
+				// the share of this block which is not rewarded in the current round
+				let withheld = Perquintill::one().saturating_sub(RoundRewardRatio::<T>::get()).deconstruct();
+				if !withheld.is_zero() {
+					BlocksWithheld::<T>::mutate(&author, |count| {
+						*count = count.saturating_add(u128::from(withheld).saturating_mul(authors.len() as u128));
+					});
+				}
+				AuthoredBlocks::<T>::mutate(&author, |count| *count = count.saturating_add(1));
+				// the author is alive in the current round
+				LastAuthoredRound::<T>::insert(&author, pallet_session::Pallet::<T>::current_index());
+				num_settled
+			} else {
+				0u32
+			};
 
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::DbWeight::get().reads_writes(2, 1),
+				T::DbWeight::get()
+					.reads_writes(8, 5)
+					.saturating_add(T::DbWeight::get().writes(num_settled.into())),
 				DispatchClass::Mandatory,
 			);
 		}
@@ -2515,23 +4789,41 @@ pub mod pallet {
 				frame_system::Pallet::<T>::block_number(),
 			);
 
+			// the reward ratio applies to all blocks of the new round
+			RoundRewardRatio::<T>::put(T::RewardSource::reward_ratio());
+
 			frame_system::Pallet::<T>::register_extra_weight_unchecked(
-				T::DbWeight::get().reads(2),
+				T::DbWeight::get().reads_writes(9, 2),
 				DispatchClass::Mandatory,
 			);
 
-			let collators = Pallet::<T>::selected_candidates().to_vec();
+			let collators = Pallet::<T>::selected_candidates();
 			if collators.is_empty() {
 				// we never want to pass an empty set of collators. This would brick the chain.
 				log::error!("💥 keeping old session because of empty collator set!");
 				None
 			} else {
-				Some(collators)
+				// remember the collators of the new session to check their liveness once it ends
+				if !LivenessConfig::<T>::get().max_missed_rounds.is_zero() {
+					PlannedCollators::<T>::insert(new_index, collators.clone());
+				}
+				Some(collators.to_vec())
 			}
 		}
 
-		fn end_session(_end_index: SessionIndex) {
-			// we too are not caring.
+		/// Check the liveness of the collators of the ending session and
+		/// demote those which missed too many rounds in a row. Afterwards,
+		/// the top candidates are queued to auto-compound the rewards of
+		/// their own and of their delegators in the following blocks.
+		fn end_session(end_index: SessionIndex) {
+			let num_collators = Pallet::<T>::check_liveness(end_index);
+			Pallet::<T>::queue_auto_compound();
+
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				<T as Config>::WeightInfo::check_liveness(num_collators)
+					.saturating_add(T::DbWeight::get().reads_writes(1, 1)),
+				DispatchClass::Mandatory,
+			);
 		}
 
 		fn start_session(_start_index: SessionIndex) {
@@ -26,60 +26,53 @@
 
 use crate::{
 	set::OrderedSet,
-	types::{BalanceOf, Delegator, Stake},
+	types::{BalanceOf, Candidate, CandidateStatus, Delegator, Stake, StakeOf},
 };
 
 use super::*;
-use codec::{Decode, Encode, MaxEncodedLen};
+use codec::{Decode, Encode};
 use core::marker::PhantomData;
 use frame_support::{
 	dispatch::GetStorageVersion,
-	pallet_prelude::{StorageVersion, ValueQuery},
-	parameter_types, storage_alias,
-	traits::{Get, OnRuntimeUpgrade},
+	pallet_prelude::StorageVersion,
+	storage::migration::clear_storage_prefix,
+	traits::{Get, OnRuntimeUpgrade, PalletInfoAccess},
 	weights::Weight,
-	Blake2_128Concat, RuntimeDebug,
+	BoundedVec, RuntimeDebug,
 };
 use scale_info::TypeInfo;
-use sp_runtime::traits::Zero;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill,
+};
+use sp_staking::SessionIndex;
+use sp_std::{fmt::Debug, vec::Vec};
 
 #[cfg(feature = "try-runtime")]
 mod try_runtime_imports {
 	pub use sp_runtime::SaturatedConversion;
-	pub use sp_std::{
-		vec,
-		vec::Vec
-	};
 }
 #[cfg(feature = "try-runtime")]
 use try_runtime_imports::*;
 
-// Old delegator type needed for translating storage map
-#[derive(Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
-#[scale_info(skip_type_params(MaxCollatorsPerDelegator))]
-#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
-pub struct DelegatorOld<AccountId: Eq + Ord, Balance: Eq + Ord, MaxCollatorsPerDelegator: Get<u32>> {
-	pub delegations: OrderedSet<Stake<AccountId, Balance>, MaxCollatorsPerDelegator>,
+// Old candidate type without a commission needed for translating storage map
+#[derive(Encode, Decode, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(MaxDelegatorsPerCandidate))]
+pub struct CandidateOld<AccountId, Balance, MaxDelegatorsPerCandidate>
+where
+	AccountId: Eq + Ord + Debug,
+	Balance: Eq + Ord + Debug,
+	MaxDelegatorsPerCandidate: Get<u32> + Debug + PartialEq,
+{
+	pub id: AccountId,
+	pub stake: Balance,
+	pub delegators: OrderedSet<Stake<AccountId, Balance>, MaxDelegatorsPerCandidate>,
 	pub total: Balance,
+	pub status: CandidateStatus,
 }
-parameter_types! {
-	const MaxCollatorsPerDelegator: u32 = 1;
-}
-// Old delegator state map required for pre checks
-#[storage_alias]
-type DelegatorStateOld<T: Config> = StorageMap<
-	Pallet<T>,
-	Blake2_128Concat,
-	<T as frame_system::Config>::AccountId,
-	DelegatorOld<<T as frame_system::Config>::AccountId, BalanceOf<T>, MaxCollatorsPerDelegator>,
->;
-
-/// Number of delegators post migration
-#[storage_alias]
-type CounterForDelegators<T: Config> = StorageValue<Pallet<T>, u32, ValueQuery>;
-
-pub struct StakingPayoutRefactor<T>(PhantomData<T>);
-impl<T: Config> OnRuntimeUpgrade for StakingPayoutRefactor<T> {
+
+pub struct AddCollatorCommission<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for AddCollatorCommission<T> {
 	fn on_runtime_upgrade() -> Weight {
 		let current = Pallet::<T>::current_storage_version();
 		let onchain = Pallet::<T>::on_chain_storage_version();
@@ -90,13 +83,13 @@ impl<T: Config> OnRuntimeUpgrade for StakingPayoutRefactor<T> {
 			onchain
 		);
 
-		if current == 8 && onchain == 7 {
-			let num_delegators = migrate_delegators::<T>();
-			log::info!("💰 Migrated {:?} delegator states", num_delegators);
-			StorageVersion::new(8).put::<Pallet<T>>();
-			T::DbWeight::get().reads_writes(num_delegators, num_delegators)
+		if onchain == 8 {
+			let num_candidates = migrate_candidates::<T>();
+			log::info!("💰 Migrated {:?} candidate states", num_candidates);
+			StorageVersion::new(9).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(num_candidates, num_candidates)
 		} else {
-			log::info!("💰 StakingPayoutRefactor did not execute. This probably should be removed");
+			log::info!("💰 AddCollatorCommission did not execute. This probably should be removed");
 			T::DbWeight::get().reads(1)
 		}
 	}
@@ -106,96 +99,411 @@ impl<T: Config> OnRuntimeUpgrade for StakingPayoutRefactor<T> {
 		let current = Pallet::<T>::on_chain_storage_version();
 
 		assert_eq!(
-			current, 7,
-			"ParachainStaking on-chain StorageVersion is {:?} instead of 7",
+			current, 8,
+			"ParachainStaking on-chain StorageVersion is {:?} instead of 8",
 			current
 		);
 
-		// sanity check each old entry
-		for delegator in DelegatorStateOld::<T>::iter_values() {
-			assert!(
-				delegator.delegations.is_empty(),
-				"There exists a delegator without a collator in pre migration!"
-			);
+		let num_candidates: u32 = CandidatePool::<T>::count();
+		log::info!("💰 Staking migration pre check: {:?} candidates", num_candidates);
+
+		Ok(num_candidates.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		// new version must be set.
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 9,
+			"ParachainStaking StorageVersion post-migration is not 9, but {:?} instead.",
+			onchain
+		);
+
+		let old_num_candidates: u32 =
+			Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode number of candidates")?;
+		let new_num_candidates: u32 = CandidatePool::<T>::iter_keys().count().saturated_into::<u32>();
+		assert_eq!(
+			old_num_candidates, new_num_candidates,
+			"Number of candidates changed during migration! Before {:?} vs. now {:?}",
+			old_num_candidates, new_num_candidates
+		);
+
+		// sanity check each new entry
+		for candidate in CandidatePool::<T>::iter_values() {
 			assert!(
-				!delegator.total.is_zero(),
-				"There exists a delegator without any self stake in pre migration!",
+				candidate.commission.is_zero(),
+				"There exists a candidate with a commission in post migration!",
 			)
 		}
+
+		log::info!("💰 Post collator commission upgrade checks match up.");
+		Ok(())
+	}
+}
+
+/// Translate all values from the CandidatePool StorageMap from old to new
+fn migrate_candidates<T: Config>() -> u64 {
+	let mut num_translations = 0;
+	CandidatePool::<T>::translate::<CandidateOld<T::AccountId, BalanceOf<T>, T::MaxDelegatorsPerCollator>, _>(
+		|_, old| {
+			num_translations += 1;
+
+			Some(Candidate {
+				id: old.id,
+				stake: old.stake,
+				delegators: old.delegators,
+				total: old.total,
+				status: old.status,
+				commission: Perbill::zero(),
+			})
+		},
+	);
+
+	num_translations
+}
+
+pub struct MultipleDelegations<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MultipleDelegations<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current = Pallet::<T>::current_storage_version();
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
 		log::info!(
-			"💰 Staking migration pre check: {:?} delegators",
-			DelegatorStateOld::<T>::iter().count()
+			"💰 Running migration with current storage version {:?} / onchain {:?}",
+			current,
+			onchain
 		);
 
-		assert!(
-			CounterForDelegators::<T>::get().is_zero(),
-			"CounterForDelegators already set."
+		if onchain == 9 {
+			let num_delegators = migrate_delegators::<T>();
+			log::info!("💰 Migrated {:?} delegator states", num_delegators);
+			StorageVersion::new(10).put::<Pallet<T>>();
+			// read and write the delegator state as well as the number of rewarded blocks
+			T::DbWeight::get().reads_writes(num_delegators.saturating_mul(2), num_delegators.saturating_mul(3))
+		} else {
+			log::info!("💰 MultipleDelegations did not execute. This probably should be removed");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let current = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			current, 9,
+			"ParachainStaking on-chain StorageVersion is {:?} instead of 9",
+			current
 		);
-		// store number of delegators before migration to check against in post
-		// migration
-		CounterForDelegators::<T>::put(DelegatorState::<T>::iter_keys().count().saturated_into::<u32>());
 
-		Ok(vec![])
+		let num_delegators: u32 = DelegatorState::<T>::iter_keys().count().saturated_into::<u32>();
+		log::info!("💰 Staking migration pre check: {:?} delegators", num_delegators);
+
+		Ok(num_delegators.encode())
 	}
 
 	#[cfg(feature = "try-runtime")]
-	fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
 		// new version must be set.
 		let onchain = Pallet::<T>::on_chain_storage_version();
 
 		assert_eq!(
-			onchain, 8,
-			"ParachainStaking StorageVersion post-migration is not 8, but {:?} instead.",
+			onchain, 10,
+			"ParachainStaking StorageVersion post-migration is not 10, but {:?} instead.",
 			onchain
 		);
 
-		let old_num_delegators: u32 = CounterForDelegators::<T>::get();
+		let old_num_delegators: u32 =
+			Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode number of delegators")?;
 		let new_num_delegators: u32 = DelegatorState::<T>::iter_keys().count().saturated_into::<u32>();
 		assert_eq!(
 			old_num_delegators, new_num_delegators,
 			"Number of delegators changed during migration! Before {:?} vs. now {:?}",
 			old_num_delegators, new_num_delegators
 		);
-		log::info!(
-			"💰 Number of delegators: Before {:?} vs. after {:?}",
-			old_num_delegators,
-			new_num_delegators
-		);
 
 		// sanity check each new entry
-		for delegator in DelegatorState::<T>::iter_values() {
+		for (delegator, state) in DelegatorState::<T>::iter() {
+			assert_eq!(
+				state.delegations.len(),
+				1,
+				"There exists a delegator without exactly one delegation in post migration!",
+			);
 			assert!(
-				!delegator.amount.is_zero(),
+				!state.total.is_zero(),
 				"There exists a delegator without any self stake in post migration!",
-			)
+			);
+			assert!(
+				BlocksRewarded::<T>::get(&delegator).is_zero(),
+				"There exists a delegator with rewarded blocks which were not migrated!",
+			);
 		}
 
-		log::info!("💰 Post staking payout refactor upgrade checks match up.");
+		log::info!("💰 Post multiple delegations upgrade checks match up.");
 		Ok(())
 	}
 }
 
-/// Translate all values from the DelegatorState StorageMap from old to new
+/// Translate all values from the DelegatorState StorageMap from a single
+/// delegation to a set of delegations and move the number of rewarded
+/// blocks of each delegator to their delegation.
 fn migrate_delegators<T: Config>() -> u64 {
 	let mut num_translations = 0;
-	DelegatorState::<T>::translate_values(
-		|old: DelegatorOld<T::AccountId, BalanceOf<T>, MaxCollatorsPerDelegator>| {
+	DelegatorState::<T>::translate::<StakeOf<T>, _>(|delegator, old| {
+		num_translations += 1;
+
+		let count_rewarded = BlocksRewarded::<T>::take(&delegator);
+		DelegationBlocksRewarded::<T>::insert(&delegator, &old.owner, count_rewarded);
+
+		let state = Delegator::try_new(old.owner, old.amount);
+		// Should never occur since MaxCollatorsPerDelegator is at least one but let's
+		// be safe
+		if state.is_err() {
+			log::debug!("Failed to translate delegator state")
+		}
+		state.ok()
+	});
+
+	num_translations
+}
+
+pub struct MigrateStakingParams<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateStakingParams<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current = Pallet::<T>::current_storage_version();
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		log::info!(
+			"💰 Running migration with current storage version {:?} / onchain {:?}",
+			current,
+			onchain
+		);
+
+		if onchain == 10 {
+			// the parameters as configured in the runtime become the initial values
+			StakingParams::<T>::put(Pallet::<T>::initial_staking_params());
+			log::info!("💰 Moved staking parameters into storage");
+			StorageVersion::new(11).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(1, 2)
+		} else {
+			log::info!("💰 MigrateStakingParams did not execute. This probably should be removed");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let current = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			current, 10,
+			"ParachainStaking on-chain StorageVersion is {:?} instead of 10",
+			current
+		);
+		assert!(
+			!StakingParams::<T>::exists(),
+			"Staking parameters are already in storage in pre migration!"
+		);
+
+		Ok(Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: Vec<u8>) -> Result<(), &'static str> {
+		// new version must be set.
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 11,
+			"ParachainStaking StorageVersion post-migration is not 11, but {:?} instead.",
+			onchain
+		);
+		assert!(
+			StakingParams::<T>::get() == Pallet::<T>::initial_staking_params(),
+			"Staking parameters do not match the runtime configuration in post migration!"
+		);
+
+		log::info!("💰 Post staking parameters upgrade checks match up.");
+		Ok(())
+	}
+}
+
+pub struct MigrateDelegationWarmUp<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateDelegationWarmUp<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current = Pallet::<T>::current_storage_version();
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		log::info!(
+			"💰 Running migration with current storage version {:?} / onchain {:?}",
+			current,
+			onchain
+		);
+
+		if onchain == 11 {
+			let num_collators = migrate_pending_delegations::<T>();
+			log::info!("💰 Migrated pending delegations of {:?} collators", num_collators);
+			// the per round delegation counters are replaced by the warm-up period
+			let pallet_name = Pallet::<T>::name().as_bytes();
+			let num_counters: u64 = clear_storage_prefix(pallet_name, b"LastDelegation", b"", None, None)
+				.unique
+				.into();
+			log::info!("💰 Removed {:?} delegation counters", num_counters);
+			StorageVersion::new(12).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(num_collators, num_collators.saturating_add(num_counters).saturating_add(1))
+		} else {
+			log::info!("💰 MigrateDelegationWarmUp did not execute. This probably should be removed");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let current = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			current, 11,
+			"ParachainStaking on-chain StorageVersion is {:?} instead of 11",
+			current
+		);
+
+		let num_collators: u32 = PendingDelegations::<T>::iter_keys().count().saturated_into::<u32>();
+		log::info!("💰 Staking migration pre check: {:?} collators with pending delegations", num_collators);
+
+		Ok(num_collators.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		// new version must be set.
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 12,
+			"ParachainStaking StorageVersion post-migration is not 12, but {:?} instead.",
+			onchain
+		);
+
+		let old_num_collators: u32 =
+			Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode number of collators")?;
+		let new_num_collators: u32 = PendingDelegations::<T>::iter().count().saturated_into::<u32>();
+		assert_eq!(
+			old_num_collators, new_num_collators,
+			"Number of collators with pending delegations changed during migration! Before {:?} vs. now {:?}",
+			old_num_collators, new_num_collators
+		);
+
+		log::info!("💰 Post delegation warm-up upgrade checks match up.");
+		Ok(())
+	}
+}
+
+pub struct MigrateOutstandingRewards<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for MigrateOutstandingRewards<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current = Pallet::<T>::current_storage_version();
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		log::info!(
+			"💰 Running migration with current storage version {:?} / onchain {:?}",
+			current,
+			onchain
+		);
+
+		if onchain == 12 {
+			let (num_accounts, outstanding) = sum_rewards::<T>();
+			OutstandingRewards::<T>::put(outstanding);
+			log::info!(
+				"💰 Set outstanding rewards to {:?} owed to {:?} accounts",
+				outstanding,
+				num_accounts
+			);
+			// depends on the outstanding rewards in case of a limited reward source
+			RoundRewardRatio::<T>::put(T::RewardSource::reward_ratio());
+			StorageVersion::new(13).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(num_accounts.saturating_add(5), 3)
+		} else {
+			log::info!("💰 MigrateOutstandingRewards did not execute. This probably should be removed");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let current = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			current, 12,
+			"ParachainStaking on-chain StorageVersion is {:?} instead of 12",
+			current
+		);
+		assert!(
+			!OutstandingRewards::<T>::exists(),
+			"Outstanding rewards are already in storage in pre migration!"
+		);
+
+		let (_, outstanding) = sum_rewards::<T>();
+		log::info!("💰 Staking migration pre check: {:?} rewards outstanding", outstanding);
+
+		Ok(outstanding.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		// new version must be set.
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 13,
+			"ParachainStaking StorageVersion post-migration is not 13, but {:?} instead.",
+			onchain
+		);
+
+		let old_outstanding: BalanceOf<T> =
+			Decode::decode(&mut &state[..]).map_err(|_| "Failed to decode outstanding rewards")?;
+		let new_outstanding = OutstandingRewards::<T>::get();
+		assert_eq!(
+			old_outstanding, new_outstanding,
+			"Outstanding rewards do not match the accumulated rewards! Before {:?} vs. now {:?}",
+			old_outstanding, new_outstanding
+		);
+
+		log::info!("💰 Post outstanding rewards upgrade checks match up.");
+		Ok(())
+	}
+}
+
+/// Sum up the accumulated rewards of all accounts which have not been paid
+/// out yet.
+fn sum_rewards<T: Config>() -> (u64, BalanceOf<T>) {
+	Rewards::<T>::iter_values().fold((0, BalanceOf::<T>::zero()), |(num_accounts, sum), rewards| {
+		(num_accounts.saturating_add(1), sum.saturating_add(rewards))
+	})
+}
+
+/// Translate all values from the PendingDelegations StorageMap from a set of
+/// delegators sharing the round of their delegation to a set of delegators
+/// each with their own round.
+fn migrate_pending_delegations<T: Config>() -> u64 {
+	let mut num_translations = 0;
+	PendingDelegations::<T>::translate::<(SessionIndex, BoundedVec<T::AccountId, T::MaxDelegatorsPerCollator>), _>(
+		|_, (round, delegators)| {
 			num_translations += 1;
 
-			// Should never occur because of pre checks but let's be save
-			if old.total.is_zero() {
-				log::debug!("Translating delegator with 0 stake amount")
-			}
-			if old.delegations.get(0).is_none() {
-				log::debug!("Translating delegator without collator")
+			let delegators: Result<BoundedVec<_, _>, _> = delegators
+				.into_iter()
+				.map(|delegator| (delegator, round))
+				.collect::<Vec<_>>()
+				.try_into();
+			// Should never occur since the bound did not change but let's be safe
+			if delegators.is_err() {
+				log::debug!("Failed to translate pending delegations")
 			}
-
-			old.delegations.get(0).map(|stake| Delegator {
-				amount: old.total,
-				owner: stake.owner.clone(),
-			})
+			delegators.ok()
 		},
 	);
 
 	num_translations
-}
\ No newline at end of file
+}
@@ -41,7 +41,7 @@ use sp_runtime::{
 	impl_opaque_keys,
 	testing::{Header, UintAuthorityId},
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup, OpaqueKeys},
-	Perbill, Perquintill,
+	DispatchResult, Perbill, Perquintill,
 };
 use sp_std::fmt::Debug;
 
@@ -56,6 +56,7 @@ pub(crate) const MAX_COLLATOR_STAKE: Balance = 200_000 * 1000 * MILLI_KILT;
 pub(crate) const BLOCKS_PER_ROUND: BlockNumber = 5;
 pub(crate) const DECIMALS: Balance = 1000 * MILLI_KILT;
 pub(crate) const TREASURY_ACC: AccountId = u64::MAX;
+pub(crate) const REWARD_POT_ACC: AccountId = u64::MAX - 1;
 
 // Configure a mock runtime to test the pallet.
 construct_runtime!(
@@ -151,18 +152,37 @@ parameter_types! {
 	pub const MinBlocksPerRound: BlockNumber = 3;
 	pub const StakeDuration: u32 = 2;
 	pub const ExitQueueDelay: u32 = 2;
+	pub const MaxStakeDuration: u32 = 20;
+	pub const MaxExitQueueDelay: u32 = 10;
+	pub const MinStakeGracePeriod: u32 = 2;
+	pub const CommissionChangeDelay: u32 = 2;
+	pub const SlashDeferDuration: u32 = 2;
+	pub const MaxRewardHistory: u32 = 3;
 	pub const DefaultBlocksPerRound: BlockNumber = BLOCKS_PER_ROUND;
 	pub const MinCollators: u32 = 2;
-	pub const MaxDelegationsPerRound: u32 = 2;
+	pub static DelegationWarmUp: u32 = 1;
+	pub const MinDelegationDuration: u32 = 2;
+	pub static EarlyExitPenalty: Perbill = Perbill::zero();
+	pub static EarlyExitPenaltyDestination: PenaltyDestination = PenaltyDestination::Delegators;
 	#[derive(Debug, Eq, PartialEq)]
 	pub const MaxDelegatorsPerCollator: u32 = 4;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxCollatorsPerDelegator: u32 = 2;
 	pub const MinCollatorStake: Balance = 10;
 	#[derive(Debug, Eq, PartialEq)]
 	pub const MaxCollatorCandidates: u32 = 10;
 	pub const MinDelegatorStake: Balance = 5;
 	pub const MaxUnstakeRequests: u32 = 6;
 	pub const NetworkRewardRate: Perquintill = Perquintill::from_percent(10);
+	pub const MaxNetworkRewardRate: Perquintill = Perquintill::from_percent(20);
 	pub const NetworkRewardStart: BlockNumber = 5 * 5 * 60 * 24 * 36525 / 100;
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxMetadataLength: u32 = 16;
+	pub const MetadataDepositBase: Balance = 10;
+	pub const MetadataDepositPerByte: Balance = 1;
+	pub const RewardPot: AccountId = REWARD_POT_ACC;
+	pub static RewardPotEnabled: bool = false;
+	pub static YearlyRewardBudget: Balance = 0;
 }
 
 pub struct ToBeneficiary();
@@ -173,6 +193,39 @@ impl OnUnbalanced<NegativeImbalanceOf<Test>> for ToBeneficiary {
 	}
 }
 
+/// Pays rewards out of `RewardPot` if `RewardPotEnabled` is set, otherwise
+/// mints them.
+pub struct MockRewardSource;
+impl RewardSource<Test> for MockRewardSource {
+	fn is_inflationary() -> bool {
+		!RewardPotEnabled::get()
+	}
+
+	fn reward_ratio() -> Perquintill {
+		if RewardPotEnabled::get() {
+			<PotRewards<RewardPot, YearlyRewardBudget> as RewardSource<Test>>::reward_ratio()
+		} else {
+			<InflationaryRewards as RewardSource<Test>>::reward_ratio()
+		}
+	}
+
+	fn creditable(amount: Balance) -> Balance {
+		if RewardPotEnabled::get() {
+			<PotRewards<RewardPot, YearlyRewardBudget> as RewardSource<Test>>::creditable(amount)
+		} else {
+			<InflationaryRewards as RewardSource<Test>>::creditable(amount)
+		}
+	}
+
+	fn payout(who: &AccountId, amount: Balance) -> DispatchResult {
+		if RewardPotEnabled::get() {
+			<PotRewards<RewardPot, YearlyRewardBudget> as RewardSource<Test>>::payout(who, amount)
+		} else {
+			<InflationaryRewards as RewardSource<Test>>::payout(who, amount)
+		}
+	}
+}
+
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
@@ -180,19 +233,38 @@ impl Config for Test {
 	type MinBlocksPerRound = MinBlocksPerRound;
 	type DefaultBlocksPerRound = DefaultBlocksPerRound;
 	type StakeDuration = StakeDuration;
+	type MaxStakeDuration = MaxStakeDuration;
 	type ExitQueueDelay = ExitQueueDelay;
+	type MaxExitQueueDelay = MaxExitQueueDelay;
+	type CommissionChangeDelay = CommissionChangeDelay;
 	type MinCollators = MinCollators;
 	type MinRequiredCollators = MinCollators;
-	type MaxDelegationsPerRound = MaxDelegationsPerRound;
+	type DelegationWarmUp = DelegationWarmUp;
+	type MinDelegationDuration = MinDelegationDuration;
+	type EarlyExitPenalty = EarlyExitPenalty;
+	type EarlyExitPenaltyDestination = EarlyExitPenaltyDestination;
+	type EarlyExitPenaltyBeneficiary = ToBeneficiary;
 	type MaxDelegatorsPerCollator = MaxDelegatorsPerCollator;
+	type MaxCollatorsPerDelegator = MaxCollatorsPerDelegator;
 	type MinCollatorStake = MinCollatorStake;
 	type MinCollatorCandidateStake = MinCollatorStake;
 	type MaxTopCandidates = MaxCollatorCandidates;
 	type MinDelegatorStake = MinDelegatorStake;
+	type MinStakeGracePeriod = MinStakeGracePeriod;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type NetworkRewardRate = NetworkRewardRate;
+	type MaxNetworkRewardRate = MaxNetworkRewardRate;
 	type NetworkRewardStart = NetworkRewardStart;
 	type NetworkRewardBeneficiary = ToBeneficiary;
+	type RewardSource = MockRewardSource;
+	type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type ParametersOrigin = frame_system::EnsureRoot<AccountId>;
+	type SlashDeferDuration = SlashDeferDuration;
+	type Slash = ToBeneficiary;
+	type MaxRewardHistory = MaxRewardHistory;
+	type MaxMetadataLength = MaxMetadataLength;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type WeightInfo = ();
 	const BLOCKS_PER_YEAR: Self::BlockNumber = 5 * 60 * 24 * 36525 / 100;
 }
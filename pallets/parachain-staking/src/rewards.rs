@@ -0,0 +1,141 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+//! Sources from which staking rewards are paid out
+use crate::{
+	pallet::{Config, InflationConfig, OutstandingRewards, TotalCollatorStake},
+	types::{BalanceOf, TotalStake},
+};
+use frame_support::traits::{Currency, ExistenceRequirement, Get};
+use sp_runtime::{traits::Saturating, DispatchResult, Perquintill};
+use sp_std::marker::PhantomData;
+
+/// The source from which staking rewards are paid out.
+pub trait RewardSource<T: Config> {
+	/// Whether rewards are newly minted. Network rewards are only issued
+	/// for inflationary sources.
+	fn is_inflationary() -> bool;
+
+	/// The share of the rewards derived from `InflationConfig` which is
+	/// currently paid out.
+	///
+	/// It is taken at the start of each round and applies to all blocks
+	/// authored within that round.
+	fn reward_ratio() -> Perquintill;
+
+	/// The part of the given rewards which can be credited to stakers on top
+	/// of the rewards which have been credited but not paid out yet.
+	fn creditable(amount: BalanceOf<T>) -> BalanceOf<T>;
+
+	/// Pay out the rewards of the account.
+	fn payout(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult;
+}
+
+/// Mints the rewards, thus inflating the total issuance.
+pub struct InflationaryRewards;
+
+impl<T: Config> RewardSource<T> for InflationaryRewards {
+	fn is_inflationary() -> bool {
+		true
+	}
+
+	fn reward_ratio() -> Perquintill {
+		Perquintill::one()
+	}
+
+	fn creditable(amount: BalanceOf<T>) -> BalanceOf<T> {
+		amount
+	}
+
+	fn payout(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		T::Currency::deposit_into_existing(who, amount)?;
+		Ok(())
+	}
+}
+
+/// Pays the rewards out of the pre-funded account `Pot`, e.g. the treasury,
+/// without changing the total issuance.
+///
+/// The rewards are scaled down if the expected annual rewards exceed
+/// `YearlyBudget`. Once the funds of the pot which are not owed to stakers
+/// yet drop below one yearly budget, they are reduced further in proportion
+/// to these funds. Rewards are only credited as long as the pot can cover
+/// them, so claims never compete for the same funds.
+pub struct PotRewards<Pot, YearlyBudget>(PhantomData<(Pot, YearlyBudget)>);
+
+impl<T, Pot, YearlyBudget> RewardSource<T> for PotRewards<Pot, YearlyBudget>
+where
+	T: Config,
+	Pot: Get<T::AccountId>,
+	YearlyBudget: Get<BalanceOf<T>>,
+{
+	fn is_inflationary() -> bool {
+		false
+	}
+
+	fn reward_ratio() -> Perquintill {
+		let budget = YearlyBudget::get();
+		let expected = expected_annual_rewards::<T>();
+		let within_budget = if expected > budget {
+			Perquintill::from_rational(budget, expected)
+		} else {
+			Perquintill::one()
+		};
+
+		let funds = T::Currency::free_balance(&Pot::get()).saturating_sub(OutstandingRewards::<T>::get());
+		let funded = if funds < budget {
+			Perquintill::from_rational(funds, budget)
+		} else {
+			Perquintill::one()
+		};
+
+		within_budget * funded
+	}
+
+	fn creditable(amount: BalanceOf<T>) -> BalanceOf<T> {
+		// the pot has to stay alive to pay out the credited rewards
+		let available = T::Currency::free_balance(&Pot::get())
+			.saturating_sub(T::Currency::minimum_balance())
+			.saturating_sub(OutstandingRewards::<T>::get());
+		amount.min(available)
+	}
+
+	fn payout(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+		T::Currency::transfer(&Pot::get(), who, amount, ExistenceRequirement::KeepAlive)
+	}
+}
+
+/// The rewards paid to all collators and delegators within a year at the
+/// current stake and `InflationConfig`, not accounting for the reward
+/// source.
+///
+/// Stake exceeding the maximum staking rate does not increase the rewards.
+pub(crate) fn expected_annual_rewards<T: Config>() -> BalanceOf<T> {
+	let total_issuance = T::Currency::total_issuance();
+	let inflation_config = InflationConfig::<T>::get();
+	let TotalStake { collators, delegators } = TotalCollatorStake::<T>::get();
+
+	let collator_stake = collators.min(inflation_config.collator.max_rate * total_issuance);
+	let delegator_stake = delegators.min(inflation_config.delegator.max_rate * total_issuance);
+
+	(inflation_config.collator.reward_rate.annual * collator_stake)
+		.saturating_add(inflation_config.delegator.reward_rate.annual * delegator_stake)
+}
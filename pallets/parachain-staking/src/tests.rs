@@ -26,11 +26,14 @@
 
 use std::{convert::TryInto, iter};
 
+use codec::Encode;
 use frame_support::{
-	assert_noop, assert_ok, storage::bounded_btree_map::BoundedBTreeMap, traits::EstimateNextSessionRotation,
+	assert_noop, assert_ok,
+	storage::{bounded_btree_map::BoundedBTreeMap, storage_prefix, unhashed},
+	traits::{EstimateNextSessionRotation, GetStorageVersion, OnRuntimeUpgrade, PalletInfoAccess, StorageVersion},
 	BoundedVec,
 };
-use parachain_staking_runtime_api::StakingRates;
+use parachain_staking_runtime_api::{CollatorCommission, StakingRates};
 use pallet_authorship::EventHandler;
 use pallet_balances::{BalanceLock, Error as BalancesError, Reasons};
 use pallet_session::{SessionManager, ShouldEndSession};
@@ -39,14 +42,23 @@ use sp_runtime::{traits::Zero, Perbill, Permill, Perquintill, SaturatedConversio
 use crate::{
 	mock::{
 		almost_equal, events, last_event, roll_to, roll_to_claim_rewards, AccountId, Balance, Balances, BlockNumber,
-		RuntimeEvent as MetaEvent, ExtBuilder, RuntimeOrigin, Session, StakePallet, System, Test, BLOCKS_PER_ROUND, DECIMALS,
-		TREASURY_ACC,
+		DelegationWarmUp, EarlyExitPenalty, EarlyExitPenaltyDestination, RuntimeEvent as MetaEvent, ExtBuilder,
+		RewardPotEnabled, RuntimeOrigin, Session, StakePallet, System, Test, YearlyRewardBudget, BLOCKS_PER_ROUND,
+		DECIMALS, REWARD_POT_ACC, TREASURY_ACC,
 	},
+	migration::{
+		AddCollatorCommission, CandidateOld, MigrateDelegationWarmUp, MigrateOutstandingRewards, MigrateStakingParams,
+		MultipleDelegations,
+	},
+	rewards::{expected_annual_rewards, RewardSource},
 	set::OrderedSet,
 	types::{
-		BalanceOf, Candidate, CandidateStatus, DelegationCounter, Delegator, RoundInfo, Stake, StakeOf, TotalStake,
+		BalanceOf, Candidate, CandidateStatus, CollatorMetadataOf, Delegator, LivenessPolicy, PendingSlash, RoundInfo,
+		Stake, StakeOf, TotalStake,
 	},
-	CandidatePool, Config, Error, Event, InflationInfo, RewardRate, StakingInfo, STAKING_ID,
+	BlocksRewarded, CandidatePool, CollatorMetadata, Config, DelegationBlocksRewarded, DelegatorState, Error, Event,
+	InflationInfo, OutstandingRewards, PenaltyDestination, PendingDelegations, RewardRate, Rewards, StakingInfo,
+	StakingParams, STAKING_ID,
 };
 
 #[test]
@@ -141,6 +153,7 @@ fn genesis() {
 						),
 						total: 700,
 						status: CandidateStatus::Active,
+						commission: Perbill::zero(),
 					}
 				)
 			);
@@ -164,6 +177,7 @@ fn genesis() {
 						),
 						total: 400,
 						status: CandidateStatus::Active,
+						commission: Perbill::zero(),
 					}
 				)
 			);
@@ -680,7 +694,8 @@ fn execute_leave_candidates_with_delay() {
 							.unwrap()
 						),
 						total: 240,
-						status: CandidateStatus::Leaving(3)
+						status: CandidateStatus::Leaving(3),
+						commission: Perbill::zero(),
 					}
 				)
 			);
@@ -699,7 +714,8 @@ fn execute_leave_candidates_with_delay() {
 							.unwrap()
 						),
 						total: 290,
-						status: CandidateStatus::Leaving(3)
+						status: CandidateStatus::Leaving(3),
+						commission: Perbill::zero(),
 					}
 				)
 			);
@@ -712,7 +728,8 @@ fn execute_leave_candidates_with_delay() {
 							stake: collator as u128 * 10u128,
 							delegators: OrderedSet::from(BoundedVec::default()),
 							total: collator as u128 * 10u128,
-							status: CandidateStatus::Leaving(3)
+							status: CandidateStatus::Leaving(3),
+							commission: Perbill::zero(),
 						}
 					)
 				);
@@ -721,19 +738,19 @@ fn execute_leave_candidates_with_delay() {
 			}
 			assert_eq!(
 				StakePallet::delegator_state(11),
-				Some(Delegator::<AccountId, Balance> { owner: 1, amount: 110 })
+				Some(Delegator::try_new(1, 110).unwrap())
 			);
 			assert_eq!(
 				StakePallet::delegator_state(12),
-				Some(Delegator::<AccountId, Balance> { owner: 1, amount: 120 })
+				Some(Delegator::try_new(1, 120).unwrap())
 			);
 			assert_eq!(
 				StakePallet::delegator_state(13),
-				Some(Delegator::<AccountId, Balance> { owner: 2, amount: 130 })
+				Some(Delegator::try_new(2, 130).unwrap())
 			);
 			assert_eq!(
 				StakePallet::delegator_state(14),
-				Some(Delegator::<AccountId, Balance> { owner: 2, amount: 140 })
+				Some(Delegator::try_new(2, 140).unwrap())
 			);
 			for delegator in 11u64..=14u64 {
 				assert!(StakePallet::is_delegator(&delegator));
@@ -769,7 +786,8 @@ fn execute_leave_candidates_with_delay() {
 							.unwrap()
 						),
 						total: 240,
-						status: CandidateStatus::Leaving(3)
+						status: CandidateStatus::Leaving(3),
+						commission: Perbill::zero(),
 					}
 				)
 			);
@@ -788,7 +806,8 @@ fn execute_leave_candidates_with_delay() {
 							.unwrap()
 						),
 						total: 290,
-						status: CandidateStatus::Leaving(3)
+						status: CandidateStatus::Leaving(3),
+						commission: Perbill::zero(),
 					}
 				)
 			);
@@ -801,7 +820,8 @@ fn execute_leave_candidates_with_delay() {
 							stake: collator as u128 * 10u128,
 							delegators: OrderedSet::from(BoundedVec::default()),
 							total: collator as u128 * 10u128,
-							status: CandidateStatus::Leaving(3)
+							status: CandidateStatus::Leaving(3),
+							commission: Perbill::zero(),
 						}
 					)
 				);
@@ -810,19 +830,19 @@ fn execute_leave_candidates_with_delay() {
 			}
 			assert_eq!(
 				StakePallet::delegator_state(11),
-				Some(Delegator::<AccountId, Balance> { owner: 1, amount: 110 })
+				Some(Delegator::try_new(1, 110).unwrap())
 			);
 			assert_eq!(
 				StakePallet::delegator_state(12),
-				Some(Delegator::<AccountId, Balance> { owner: 1, amount: 120 })
+				Some(Delegator::try_new(1, 120).unwrap())
 			);
 			assert_eq!(
 				StakePallet::delegator_state(13),
-				Some(Delegator::<AccountId, Balance> { owner: 2, amount: 130 })
+				Some(Delegator::try_new(2, 130).unwrap())
 			);
 			assert_eq!(
 				StakePallet::delegator_state(14),
-				Some(Delegator::<AccountId, Balance> { owner: 2, amount: 140 })
+				Some(Delegator::try_new(2, 140).unwrap())
 			);
 			for delegator in 11u64..=14u64 {
 				assert!(StakePallet::is_delegator(&delegator));
@@ -954,7 +974,10 @@ fn multiple_delegations() {
 			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(11), 2, 11));
 			// 11 should be initiated with the same rewarded counter as the authored counter
 			// by their collator 2
-			assert_eq!(StakePallet::blocks_rewarded(2), StakePallet::blocks_authored(11));
+			assert_eq!(
+				StakePallet::delegation_blocks_rewarded(11, 2),
+				StakePallet::blocks_authored(2)
+			);
 
 			assert!(StakePallet::delegator_state(9).is_none());
 			assert_eq!(StakePallet::unstaking(9).get(&23), Some(&10u128));
@@ -1009,8 +1032,8 @@ fn multiple_delegations() {
 				.unwrap()
 				.delegators
 				.contains(&StakeOf::<Test> { owner: 17, amount: 11 }));
-			assert_eq!(StakePallet::delegator_state(8).unwrap().amount, 10);
-			assert_eq!(StakePallet::delegator_state(17).unwrap().amount, 11);
+			assert_eq!(StakePallet::delegator_state(8).unwrap().total, 10);
+			assert_eq!(StakePallet::delegator_state(17).unwrap().total, 11);
 			assert_eq!(Balances::usable_balance(&8), 90);
 			assert_eq!(Balances::usable_balance(&17), 89);
 			assert_eq!(Balances::free_balance(&8), 100);
@@ -1092,13 +1115,13 @@ fn should_update_total_stake() {
 			);
 
 			old_stake = StakePallet::total_collator_stake();
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 50));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 1, 50));
 			assert_noop!(
-				StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 0),
+				StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 1, 0),
 				Error::<Test>::ValStakeZero
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 0),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 1, 0),
 				Error::<Test>::ValStakeZero
 			);
 			assert_eq!(
@@ -1110,7 +1133,7 @@ fn should_update_total_stake() {
 			);
 
 			old_stake = StakePallet::total_collator_stake();
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 50));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 1, 50));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -1130,7 +1153,7 @@ fn should_update_total_stake() {
 			);
 
 			old_stake = StakePallet::total_collator_stake();
-			assert_eq!(StakePallet::delegator_state(11).unwrap().amount, 200);
+			assert_eq!(StakePallet::delegator_state(11).unwrap().total, 200);
 			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(11)));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
@@ -1141,7 +1164,7 @@ fn should_update_total_stake() {
 			);
 
 			let old_stake = StakePallet::total_collator_stake();
-			assert_eq!(StakePallet::delegator_state(8).unwrap().amount, 10);
+			assert_eq!(StakePallet::delegator_state(8).unwrap().total, 10);
 			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(8)));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
@@ -1293,24 +1316,24 @@ fn delegators_bond() {
 				Error::<Test>::AlreadyDelegating
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_more(RuntimeOrigin::signed(1), 50),
+				StakePallet::delegator_stake_more(RuntimeOrigin::signed(1), 2, 50),
 				Error::<Test>::DelegatorNotFound
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(1), 50),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(1), 2, 50),
 				Error::<Test>::DelegatorNotFound
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 11),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 1, 11),
 				Error::<Test>::Underflow
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 8),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 1, 8),
 				Error::<Test>::DelegationBelowMin
 			);
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 1, 10));
 			assert_noop!(
-				StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 81),
+				StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 1, 81),
 				BalancesError::<Test>::InsufficientBalance
 			);
 			assert_noop!(
@@ -2352,12 +2375,12 @@ fn unlock_unstaked() {
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10),);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10),);
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10),);
 			let mut unstaking: BoundedBTreeMap<BlockNumber, BalanceOf<Test>, <Test as Config>::MaxUnstakeRequests> =
 				BoundedBTreeMap::new();
 			assert_ok!(unstaking.try_insert(3, 60));
@@ -2380,7 +2403,7 @@ fn unlock_unstaked() {
 
 			roll_to(2, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10),);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10),);
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10),);
 			assert_ok!(unstaking.try_insert(4, 10));
 			assert_eq!(Balances::locks(1), vec![lock.clone()]);
 			assert_eq!(Balances::locks(2), vec![lock.clone()]);
@@ -2396,7 +2419,7 @@ fn unlock_unstaked() {
 
 			roll_to(3, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10),);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10),);
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10),);
 			assert_ok!(unstaking.try_insert(5, 10));
 			assert_ok!(unstaking.try_insert(5, 10));
 			assert_eq!(Balances::locks(1), vec![lock.clone()]);
@@ -2416,13 +2439,13 @@ fn unlock_unstaked() {
 			// reach MaxUnstakeRequests
 			roll_to(4, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
 			roll_to(5, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
 			roll_to(6, vec![]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 10));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10));
 			assert_ok!(unstaking.try_insert(6, 10));
 			assert_ok!(unstaking.try_insert(7, 10));
 			assert_ok!(unstaking.try_insert(8, 10));
@@ -2437,7 +2460,7 @@ fn unlock_unstaked() {
 				Error::<Test>::NoMoreUnstaking
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 10),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 10),
 				Error::<Test>::NoMoreUnstaking
 			);
 			assert_ok!(StakePallet::unlock_unstaked(RuntimeOrigin::signed(1), 1));
@@ -2452,10 +2475,10 @@ fn unlock_unstaked() {
 			assert_eq!(Balances::locks(1), vec![lock.clone()]);
 			assert_eq!(Balances::locks(2), vec![lock.clone()]);
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), 40));
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 40));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(2), 1, 40));
 			assert_ok!(unstaking.try_insert(9, 40));
 			assert_ok!(StakePallet::candidate_stake_more(RuntimeOrigin::signed(1), 30));
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(2), 30));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(2), 1, 30));
 			unstaking.remove(&8);
 			assert_ok!(unstaking.try_insert(9, 20));
 			assert_eq!(StakePallet::unstaking(1), unstaking);
@@ -2516,14 +2539,14 @@ fn kick_delegator_with_full_unstaking() {
 			// Fill unstake requests
 			for block in 1u64..1u64.saturating_add(max_unstake_reqs as u64) {
 				System::set_block_number(block);
-				assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 1));
+				assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 1, 1));
 			}
 			assert_eq!(StakePallet::unstaking(5).into_inner().len(), max_unstake_reqs);
 
 			// Additional unstake should fail
 			System::set_block_number(100);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 1),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 1, 1),
 				Error::<Test>::NoMoreUnstaking
 			);
 
@@ -2591,11 +2614,11 @@ fn candidate_leaves() {
 				Error::<Test>::CannotDelegateIfLeaving
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_more(RuntimeOrigin::signed(12), 1),
+				StakePallet::delegator_stake_more(RuntimeOrigin::signed(12), 1, 1),
 				Error::<Test>::CannotDelegateIfLeaving
 			);
 			assert_noop!(
-				StakePallet::delegator_stake_less(RuntimeOrigin::signed(12), 1),
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(12), 1, 1),
 				Error::<Test>::CannotDelegateIfLeaving
 			);
 			assert_noop!(
@@ -2874,51 +2897,30 @@ fn decrease_max_candidate_stake() {
 }
 
 #[test]
-fn exceed_delegations_per_round() {
+fn delegation_start() {
 	ExtBuilder::default()
 		.with_balances(vec![(1, 100), (2, 100)])
 		.with_collators(vec![(1, 100)])
 		.with_delegators(vec![(2, 1, 100)])
 		.build()
 		.execute_with(|| {
-			// leave and re-join to set counter to 2 (= MaxDelegationsPerRound)
-			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(2)));
-			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(2), 1, 100));
-			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(2)));
-			// reached max delegations in this round
-			assert_noop!(
-				StakePallet::join_delegators(RuntimeOrigin::signed(2), 1, 100),
-				Error::<Test>::DelegationsPerRoundExceeded
-			);
+			// genesis delegations are not tracked
+			assert!(StakePallet::delegation_start(2, 1).is_none());
+
+			// delegations are not limited per round
+			for _ in 0..3 {
+				assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(2)));
+				assert!(StakePallet::delegation_start(2, 1).is_none());
+				assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(2), 1, 100));
+				assert_eq!(StakePallet::delegation_start(2, 1), Some(0));
+			}
 
-			// roll to next round to clear DelegationCounter
+			// the start is reset when delegating again in a later round
 			roll_to(5, vec![]);
-			assert_eq!(
-				StakePallet::last_delegation(2),
-				DelegationCounter { round: 0, counter: 2 }
-			);
-			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(2), 1, 100));
-			// counter should be reset because the round changed
-			assert_eq!(
-				StakePallet::last_delegation(2),
-				DelegationCounter { round: 1, counter: 1 }
-			);
-			// leave and re-join to set counter to 2 (= MaxDelegationsPerRound))
-			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(2)));
+			assert_ok!(StakePallet::revoke_delegation(RuntimeOrigin::signed(2), 1));
+			assert!(StakePallet::delegation_start(2, 1).is_none());
 			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(2), 1, 100));
-			assert_noop!(
-				StakePallet::join_delegators(RuntimeOrigin::signed(2), 1, 100),
-				Error::<Test>::AlreadyDelegating
-			);
-			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(2)));
-			assert_noop!(
-				StakePallet::join_delegators(RuntimeOrigin::signed(2), 1, 100),
-				Error::<Test>::DelegationsPerRoundExceeded
-			);
-			assert_eq!(
-				StakePallet::last_delegation(2),
-				DelegationCounter { round: 1, counter: 2 }
-			);
+			assert_eq!(StakePallet::delegation_start(2, 1), Some(1));
 		});
 }
 
@@ -3139,7 +3141,7 @@ fn prioritize_collators() {
 			);
 
 			// 7 decreases delegation
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 5, 10));
 			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![5, 3]);
 			assert_eq!(
 				StakePallet::top_candidates(),
@@ -3221,7 +3223,7 @@ fn prioritize_delegators() {
 			);
 
 			// delegate_less
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(5), 2, 10));
 			assert_eq!(
 				StakePallet::candidate_pool(2).unwrap().delegators,
 				OrderedSet::from_sorted_set(
@@ -3237,7 +3239,7 @@ fn prioritize_delegators() {
 			);
 
 			// delegate_more
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(6), 2, 10));
 			assert_eq!(
 				StakePallet::candidate_pool(2).unwrap().delegators,
 				OrderedSet::from_sorted_set(
@@ -3251,7 +3253,7 @@ fn prioritize_delegators() {
 					.unwrap()
 				)
 			);
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(7), 2, 10));
 			assert_eq!(
 				StakePallet::candidate_pool(2).unwrap().delegators,
 				OrderedSet::from_sorted_set(
@@ -3625,7 +3627,7 @@ fn update_total_stake_collators_stay() {
 					delegators: 150
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 1, 10));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -3633,7 +3635,7 @@ fn update_total_stake_collators_stay() {
 					delegators: 160
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(4), 5));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(4), 2, 5));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -3679,10 +3681,10 @@ fn update_total_stake_displace_collators() {
 					delegators: 105
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(8), 45));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(8), 4, 45));
 
 			// 3 is pushed out by delegator staking less
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 45));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(7), 3, 45));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -3778,7 +3780,7 @@ fn update_total_stake_no_collator_changes() {
 					delegators: 110
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(5), 10));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(5), 1, 10));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -3794,7 +3796,7 @@ fn update_total_stake_no_collator_changes() {
 					delegators: 110
 				}
 			);
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 10));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(6), 2, 10));
 			assert_eq!(
 				StakePallet::total_collator_stake(),
 				TotalStake {
@@ -3835,7 +3837,7 @@ fn rewards_candidate_stake_more() {
 					id
 				);
 				assert!(
-					StakePallet::blocks_rewarded(id).is_zero(),
+					StakePallet::delegation_blocks_rewarded(id, 1).is_zero(),
 					"BlocksRewaeded not zero for acc_id {:?}",
 					id
 				);
@@ -3873,7 +3875,7 @@ fn rewards_candidate_stake_less() {
 					id
 				);
 				assert!(
-					StakePallet::blocks_rewarded(id).is_zero(),
+					StakePallet::delegation_blocks_rewarded(id, 1).is_zero(),
 					"BlocksRewaeded not zero for acc_id {:?}",
 					id
 				);
@@ -3919,8 +3921,8 @@ fn rewards_candidate_leave_network() {
 			assert!(StakePallet::blocks_rewarded(1).is_zero());
 
 			// count for delegators should not be incremented
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 
 			// rewards should not be incremented
 			(1..=3).for_each(|id| {
@@ -3936,15 +3938,15 @@ fn rewards_candidate_leave_network() {
 			assert!(StakePallet::blocks_rewarded(1).is_zero());
 			assert!(!StakePallet::rewards(1).is_zero());
 
-			// reward counting storages should NOT be killed for delegators
+			// reward counting storages should be killed together with the delegations
 			(2..=3).for_each(|id| {
 				assert!(!StakePallet::rewards(id).is_zero(), "Zero rewards acc_id {:?}", id);
-				assert_eq!(
-					StakePallet::blocks_rewarded(id),
-					4 * 2,
-					"Rewarded blocks Delegator {:?} do not match up with exited collator",
+				assert!(
+					StakePallet::delegation_blocks_rewarded(id, 1).is_zero(),
+					"Rewarded blocks of delegator {:?} not killed with exited collator",
 					id
 				);
+				assert!(StakePallet::delegator_state(id).is_none());
 			});
 		});
 }
@@ -3983,10 +3985,9 @@ fn rewards_force_remove_candidate() {
 					StakePallet::rewards(id)
 				);
 				if id > 1 {
-					assert_eq!(
-						StakePallet::blocks_rewarded(id),
-						2,
-						"Rewarded counter does not match for delegator {:?}",
+					assert!(
+						StakePallet::delegation_blocks_rewarded(id, 1).is_zero(),
+						"Rewarded counter not killed for delegator {:?}",
 						id
 					);
 				}
@@ -4014,7 +4015,10 @@ fn blocks_rewarded_join_delegators() {
 			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(2), 1, 100));
 			// delegator's rewarded counter should equal of collator's authored counter upon
 			// joining
-			assert_eq!(StakePallet::blocks_rewarded(2), StakePallet::blocks_authored(1));
+			assert_eq!(
+				StakePallet::delegation_blocks_rewarded(2, 1),
+				StakePallet::blocks_authored(1)
+			);
 		});
 }
 
@@ -4029,23 +4033,23 @@ fn rewards_delegator_stake_more() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::blocks_authored(1), 1);
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
 			});
 
 			// stake less to trigger reward incrementing just for 3
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), DECIMALS));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 1, DECIMALS));
 			// 1 should still have counter 1 but no rewards
 			assert_eq!(StakePallet::blocks_authored(1), 1);
 			assert!(StakePallet::blocks_rewarded(1).is_zero());
 			assert!(StakePallet::rewards(1).is_zero());
 			// 2 should still have neither rewards nor counter
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			// 3 should have rewards and the same counter as 1
-			assert_eq!(StakePallet::blocks_rewarded(3), 1);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(3, 1), 1);
 			assert!(!StakePallet::rewards(3).is_zero());
 		});
 }
@@ -4061,23 +4065,23 @@ fn rewards_delegator_stake_less() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::blocks_authored(1), 1);
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
 			});
 
 			// stake less to trigger reward incrementing just for 3
-			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), DECIMALS));
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(3), 1, DECIMALS));
 			// 1 should still have counter 1 but no rewards
 			assert_eq!(StakePallet::blocks_authored(1), 1);
 			assert!(StakePallet::blocks_rewarded(1).is_zero());
 			assert!(StakePallet::rewards(1).is_zero());
 			// 2 should still have neither rewards nor counter
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			// 3 should have rewards and the same counter as 1
-			assert_eq!(StakePallet::blocks_rewarded(3), 1);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(3, 1), 1);
 			assert!(!StakePallet::rewards(3).is_zero());
 		});
 }
@@ -4108,12 +4112,13 @@ fn rewards_delegator_replaced() {
 
 			// 6 kicks 5
 			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(6), 1, 2 * DECIMALS));
-			// 5 should have rewards and counter updated
+			// 5 should have rewards and the counter of the kicked delegation removed
 			assert!(!StakePallet::rewards(5).is_zero());
-			assert_eq!(StakePallet::blocks_rewarded(5), 1);
+			assert!(StakePallet::delegation_blocks_rewarded(5, 1).is_zero());
+			assert!(StakePallet::delegator_state(5).is_none());
 			// 6 should not have rewards but same counter as former collator
 			assert!(StakePallet::rewards(6).is_zero());
-			assert_eq!(StakePallet::blocks_rewarded(6), 1);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(6, 1), 1);
 		});
 }
 
@@ -4128,8 +4133,8 @@ fn rewards_delegator_leaves() {
 			// note collator once to set their counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::blocks_authored(1), 1);
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 			(1..=3).for_each(|id| {
 				assert!(StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
 			});
@@ -4138,12 +4143,12 @@ fn rewards_delegator_leaves() {
 			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(3)));
 			assert!(StakePallet::blocks_rewarded(1).is_zero());
 			assert!(StakePallet::rewards(1).is_zero());
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
 			assert!(StakePallet::rewards(2).is_zero());
 			assert!(!StakePallet::rewards(3).is_zero());
 			assert_eq!(StakePallet::get_unclaimed_staking_rewards(&3), StakePallet::rewards(3));
 			// counter should be reset due to leaving
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 		});
 }
 
@@ -4176,8 +4181,17 @@ fn rewards_set_inflation() {
 				hundred
 			));
 			// rewards and counters should be set
-			(1..=5).for_each(|id| {
+			(1..=2).for_each(|id| {
 				assert!(!StakePallet::blocks_rewarded(id).is_zero(), "acc_id {:?}", id);
+			});
+			[(3, 1), (4, 1), (5, 2)].into_iter().for_each(|(id, collator)| {
+				assert!(
+					!StakePallet::delegation_blocks_rewarded(id, collator).is_zero(),
+					"acc_id {:?}",
+					id
+				);
+			});
+			(1..=5).for_each(|id| {
 				assert!(!StakePallet::rewards(id).is_zero(), "acc_id {:?}", id);
 			});
 		});
@@ -4212,12 +4226,21 @@ fn rewards_yearly_inflation_adjustment() {
 
 			// execute to trigger reward increment
 			assert_ok!(StakePallet::execute_scheduled_reward_change(RuntimeOrigin::signed(1)));
-			(1..=5).for_each(|id| {
+			(1..=2).for_each(|id| {
 				assert!(
 					!StakePallet::blocks_rewarded(id).is_zero(),
 					"Zero rewarded blocks for acc_id {:?}",
 					id
 				);
+			});
+			[(3, 1), (4, 1), (5, 2)].into_iter().for_each(|(id, collator)| {
+				assert!(
+					!StakePallet::delegation_blocks_rewarded(id, collator).is_zero(),
+					"Zero rewarded blocks for acc_id {:?}",
+					id
+				);
+			});
+			(1..=5).for_each(|id| {
 				assert!(!StakePallet::rewards(id).is_zero(), "Zero rewards for acc_id {:?}", id);
 			});
 		});
@@ -4242,7 +4265,7 @@ fn rewards_incrementing_and_claiming() {
 			// note once to set counter to 1
 			StakePallet::note_author(1);
 			assert_eq!(StakePallet::blocks_authored(1), 1);
-			assert!(StakePallet::blocks_rewarded(2).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(2, 1).is_zero());
 
 			// claiming should not be possible before incrementing rewards
 			(1..=3).for_each(|id| {
@@ -4254,10 +4277,10 @@ fn rewards_incrementing_and_claiming() {
 
 			// increment rewards for 2 and match counter to collator
 			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
-			assert_eq!(StakePallet::blocks_rewarded(2), 1);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(2, 1), 1);
 			let rewards_2 = StakePallet::rewards(2);
 			assert!(!rewards_2.is_zero());
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 			assert!(StakePallet::rewards(3).is_zero());
 
 			// should only update rewards for collator as well
@@ -4267,7 +4290,7 @@ fn rewards_incrementing_and_claiming() {
 			// rewards of 2 should not be changed
 			assert_eq!(StakePallet::rewards(2), rewards_2);
 			// 3 should still not have blocks rewarded bumped
-			assert!(StakePallet::blocks_rewarded(3).is_zero());
+			assert!(StakePallet::delegation_blocks_rewarded(3, 1).is_zero());
 
 			// claim for 1 to move rewards into balance
 			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(1)));
@@ -4280,7 +4303,7 @@ fn rewards_incrementing_and_claiming() {
 			// incrementing again should not change anything because collator has not
 			// authored blocks since last inc
 			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
-			assert_eq!(StakePallet::blocks_rewarded(2), 1);
+			assert_eq!(StakePallet::delegation_blocks_rewarded(2, 1), 1);
 			// claim for 2 to move rewards into balance
 			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(2)));
 			assert!(Balances::free_balance(&2) > DECIMALS);
@@ -4372,7 +4395,7 @@ fn api_get_staking_rates() {
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(1), stake / 2));
 			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(2), stake / 2));
 			// delegator stakes more to exceed
-			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), stake));
+			assert_ok!(StakePallet::delegator_stake_more(RuntimeOrigin::signed(3), 1, stake));
 			rates.collator_staking_rate = Perquintill::from_percent(25);
 			rates.collator_reward_rate = Perquintill::from_percent(10);
 			rates.delegator_staking_rate = Perquintill::from_percent(50);
@@ -4504,4 +4527,1743 @@ fn one_of_collators_full_but_our_balance_greater() {
 				vec![1, 2]
 			);
 		});
-}
\ No newline at end of file
+}
+
+#[test]
+fn collator_commission_change() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 100), (2, 100)])
+		.with_delegators(vec![(3, 1, 100)])
+		.build()
+		.execute_with(|| {
+			let commission = Perbill::from_percent(10);
+			assert_noop!(
+				StakePallet::set_max_collator_commission(RuntimeOrigin::signed(1), commission),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			// collators cannot charge a commission by default
+			assert_noop!(
+				StakePallet::request_commission_change(RuntimeOrigin::signed(1), commission),
+				Error::<Test>::CommissionAboveMax
+			);
+
+			assert_ok!(StakePallet::set_max_collator_commission(RuntimeOrigin::root(), commission));
+			assert_eq!(StakePallet::max_collator_commission(), commission);
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::MaxCollatorCommissionSet(commission))
+			);
+
+			assert_noop!(
+				StakePallet::request_commission_change(RuntimeOrigin::signed(1), Perbill::from_percent(11)),
+				Error::<Test>::CommissionAboveMax
+			);
+			assert_noop!(
+				StakePallet::request_commission_change(RuntimeOrigin::signed(3), commission),
+				Error::<Test>::CandidateNotFound
+			);
+			assert_noop!(
+				StakePallet::execute_commission_change(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::CommissionChangeNotFound
+			);
+
+			assert_ok!(StakePallet::request_commission_change(RuntimeOrigin::signed(1), commission));
+			assert_eq!(StakePallet::commission_change_request(1), Some((2, commission)));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::CollatorCommissionChangeRequested(1, commission, 2))
+			);
+			assert_noop!(
+				StakePallet::execute_commission_change(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::CannotChangeCommissionYet
+			);
+
+			// the change can be executed after CommissionChangeDelay many rounds
+			roll_to(2 * BLOCKS_PER_ROUND, vec![]);
+			assert_ok!(StakePallet::execute_commission_change(RuntimeOrigin::signed(3), 1));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().commission, commission);
+			assert!(StakePallet::commission_change_request(1).is_none());
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::CollatorCommissionSet(1, Perbill::zero(), commission))
+			);
+
+			// lowering the maximum caps the commission
+			assert_ok!(StakePallet::set_max_collator_commission(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(5)
+			));
+			assert_eq!(
+				StakePallet::get_collator_commission(&1),
+				Some(CollatorCommission {
+					commission: Perbill::from_percent(5),
+					max_commission: Perbill::from_percent(5),
+					scheduled_change: None,
+				})
+			);
+			assert!(StakePallet::get_collator_commission(&3).is_none());
+		});
+}
+
+#[test]
+fn rewards_collator_commission() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake), (4, stake)])
+		.with_collators(vec![(1, stake), (4, stake)])
+		.with_delegators(vec![(2, 1, stake), (3, 1, stake)])
+		.build()
+		.execute_with(|| {
+			let commission = Perbill::from_percent(20);
+			assert_ok!(StakePallet::set_max_collator_commission(RuntimeOrigin::root(), commission));
+			CandidatePool::<Test>::mutate(1, |state| {
+				if let Some(state) = state {
+					state.commission = commission;
+				}
+			});
+
+			// 1 authors a block which counts for both collators
+			roll_to(2, vec![None, Some(1)]);
+			let unclaimed_1 = StakePallet::get_unclaimed_staking_rewards(&1);
+			let unclaimed_2 = StakePallet::get_unclaimed_staking_rewards(&2);
+			let reward = StakePallet::calc_block_rewards_delegator(stake, 2);
+			let cut = commission * reward;
+			assert!(!cut.is_zero());
+
+			// the commission is deducted from the delegator's reward and credited to the
+			// collator
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
+			assert_eq!(StakePallet::rewards(2), reward - cut);
+			assert_eq!(StakePallet::rewards(2), unclaimed_2);
+			assert_eq!(StakePallet::rewards(1), cut);
+
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(3)));
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(
+				StakePallet::rewards(1),
+				StakePallet::calc_block_rewards_collator(stake, 2) + 2 * cut
+			);
+			assert_eq!(StakePallet::rewards(1), unclaimed_1);
+		});
+}
+
+#[test]
+fn set_max_collator_commission_increments_rewards() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (3, stake)])
+		.with_delegators(vec![(2, 1, stake)])
+		.build()
+		.execute_with(|| {
+			let commission = Perbill::from_percent(20);
+			assert_ok!(StakePallet::set_max_collator_commission(RuntimeOrigin::root(), commission));
+			CandidatePool::<Test>::mutate(1, |state| {
+				if let Some(state) = state {
+					state.commission = commission;
+				}
+			});
+
+			roll_to(2, vec![None, Some(1)]);
+			let reward = StakePallet::calc_block_rewards_delegator(stake, 2);
+			let cut = commission * reward;
+			assert!(!cut.is_zero());
+
+			// rewards accumulated so far are charged the old maximum
+			assert_ok!(StakePallet::set_max_collator_commission(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(5)
+			));
+			assert_eq!(StakePallet::rewards(2), reward - cut);
+			assert_eq!(StakePallet::rewards(1), cut);
+
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
+			assert_eq!(StakePallet::rewards(2), reward - cut);
+		});
+}
+
+#[test]
+fn delegate_another_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 200), (5, 100)])
+		.with_collators(vec![(1, 100), (2, 100), (3, 100)])
+		.with_delegators(vec![(4, 1, 50)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(5), 2, 50),
+				Error::<Test>::NotYetDelegating
+			);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(4), 1, 50),
+				Error::<Test>::AlreadyDelegatedCollator
+			);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(4), 2, 4),
+				Error::<Test>::DelegationBelowMin
+			);
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(4), 5, 50),
+				Error::<Test>::CandidateNotFound
+			);
+
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(4), 2, 50));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::Delegation(4, 50, 2, 150))
+			);
+			let state = StakePallet::delegator_state(4).unwrap();
+			assert_eq!(state.total, 100);
+			assert_eq!(state.delegation(&1), Some(50));
+			assert_eq!(state.delegation(&2), Some(50));
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 150);
+			// the lock covers the stake of all delegations
+			assert_eq!(
+				Balances::locks(4),
+				vec![BalanceLock {
+					id: STAKING_ID,
+					amount: 100,
+					reasons: Reasons::All,
+				}]
+			);
+
+			assert_noop!(
+				StakePallet::delegate_another_candidate(RuntimeOrigin::signed(4), 3, 50),
+				Error::<Test>::MaxCollatorsPerDelegatorExceeded
+			);
+		});
+}
+
+#[test]
+fn revoke_delegation() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 100), (2, 100)])
+		.with_delegators(vec![(3, 1, 50)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(3), 2, 30));
+
+			assert_ok!(StakePallet::revoke_delegation(RuntimeOrigin::signed(3), 1));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::DelegatorLeftCollator(3, 1, 50, 100))
+			);
+			let state = StakePallet::delegator_state(3).unwrap();
+			assert_eq!(state.total, 30);
+			assert!(state.delegation(&1).is_none());
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 100);
+			assert_eq!(StakePallet::unstaking(3).get(&3), Some(&50));
+			assert_noop!(
+				StakePallet::revoke_delegation(RuntimeOrigin::signed(3), 1),
+				Error::<Test>::DelegationNotFound
+			);
+
+			// revoking the last delegation removes the delegator
+			assert_ok!(StakePallet::revoke_delegation(RuntimeOrigin::signed(3), 2));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::DelegatorLeft(3, 30)));
+			assert!(StakePallet::delegator_state(3).is_none());
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 100);
+			assert_eq!(StakePallet::unstaking(3).get(&3), Some(&80));
+			assert_noop!(
+				StakePallet::revoke_delegation(RuntimeOrigin::signed(3), 2),
+				Error::<Test>::DelegatorNotFound
+			);
+		});
+}
+
+#[test]
+fn leave_delegators_with_multiple_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_collators(vec![(1, 100), (2, 100)])
+		.with_delegators(vec![(3, 1, 50)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::delegate_another_candidate(RuntimeOrigin::signed(3), 2, 30));
+			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(3)));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::DelegatorLeft(3, 80)));
+			assert!(StakePallet::delegator_state(3).is_none());
+			assert!(StakePallet::candidate_pool(1).unwrap().delegators.is_empty());
+			assert!(StakePallet::candidate_pool(2).unwrap().delegators.is_empty());
+			assert_eq!(
+				StakePallet::total_collator_stake(),
+				TotalStake {
+					collators: 200,
+					delegators: 0,
+				}
+			);
+			assert_eq!(StakePallet::unstaking(3).get(&3), Some(&80));
+		});
+}
+
+#[test]
+fn redelegate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_collators(vec![(1, 100), (2, 100), (3, 100)])
+		.with_delegators(vec![(4, 1, 50)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StakePallet::redelegate(RuntimeOrigin::signed(4), 1, 1),
+				Error::<Test>::AlreadyDelegatedCollator
+			);
+			assert_noop!(
+				StakePallet::redelegate(RuntimeOrigin::signed(4), 2, 3),
+				Error::<Test>::DelegationNotFound
+			);
+			assert_noop!(
+				StakePallet::redelegate(RuntimeOrigin::signed(1), 1, 2),
+				Error::<Test>::DelegatorNotFound
+			);
+
+			assert_ok!(StakePallet::redelegate(RuntimeOrigin::signed(4), 1, 2));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::Redelegated(4, 1, 2, 50))
+			);
+			let state = StakePallet::delegator_state(4).unwrap();
+			assert_eq!(state.total, 50);
+			assert!(state.delegation(&1).is_none());
+			assert_eq!(state.delegation(&2), Some(50));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 100);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().total, 150);
+			// funds are neither unstaked nor unlocked
+			assert!(StakePallet::unstaking(4).is_empty());
+			assert_eq!(
+				Balances::locks(4),
+				vec![BalanceLock {
+					id: STAKING_ID,
+					amount: 50,
+					reasons: Reasons::All,
+				}]
+			);
+
+			// the moved delegation counts as made in the current round
+			assert_eq!(StakePallet::delegation_start(4, 2), Some(0));
+			assert!(StakePallet::delegation_start(4, 1).is_none());
+			roll_to(BLOCKS_PER_ROUND + 1, vec![]);
+			assert_ok!(StakePallet::redelegate(RuntimeOrigin::signed(4), 2, 3));
+			assert_eq!(StakePallet::candidate_pool(3).unwrap().total, 150);
+			assert_eq!(StakePallet::delegation_start(4, 3), Some(1));
+		});
+}
+
+#[test]
+fn new_delegations_rewarded_from_next_round() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake), (4, stake)])
+		.with_collators(vec![(1, stake), (4, stake)])
+		.with_delegators(vec![(2, 1, stake)])
+		.build()
+		.execute_with(|| {
+			// genesis delegations are eligible right away
+			assert!(!StakePallet::is_pending_delegation(&2, &1));
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(3), 1, stake));
+			assert!(StakePallet::is_pending_delegation(&3, &1));
+
+			// 1 authors a block in the round of joining
+			let authors = vec![None, Some(1), None, None, None, None, Some(1)];
+			roll_to(2, authors.clone());
+			assert!(!StakePallet::get_unclaimed_staking_rewards(&2).is_zero());
+			assert!(StakePallet::get_unclaimed_staking_rewards(&3).is_zero());
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(3)));
+			assert!(StakePallet::rewards(3).is_zero());
+
+			// 1 authors a block in the next round
+			roll_to(7, authors);
+			assert!(!StakePallet::is_pending_delegation(&3, &1));
+			let reward = StakePallet::calc_block_rewards_delegator(stake, 2);
+			assert_eq!(StakePallet::get_unclaimed_staking_rewards(&3), reward);
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(3)));
+			assert_eq!(StakePallet::rewards(3), reward);
+			assert_eq!(
+				StakePallet::delegation_blocks_rewarded(3, 1),
+				StakePallet::blocks_authored(1)
+			);
+		});
+}
+
+#[test]
+fn liveness_tracking_disabled_by_default() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 80)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(StakePallet::liveness_config(), LivenessPolicy::default());
+
+			// 2 never authors a block
+			roll_to(31, vec![Some(1); 31]);
+			assert!(StakePallet::candidate_pool(2).unwrap().is_active());
+			assert!(StakePallet::missed_rounds(2).is_zero());
+			assert!(StakePallet::planned_collators(Session::current_index()).is_none());
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+		});
+}
+
+#[test]
+fn offline_collator_is_demoted() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 80)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StakePallet::set_liveness_policy(RuntimeOrigin::signed(1), 2, Perbill::zero(), false),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(StakePallet::set_liveness_policy(
+				RuntimeOrigin::root(),
+				2,
+				Perbill::zero(),
+				false
+			));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::LivenessPolicySet(2, Perbill::zero(), false))
+			);
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+
+			// collators of round 2 are the first ones to be tracked
+			let authors = vec![Some(1); 21];
+			roll_to(6, authors.clone());
+			assert_eq!(StakePallet::planned_collators(2).unwrap().into_inner(), vec![1, 2]);
+
+			// 2 misses round 2
+			roll_to(16, authors.clone());
+			assert!(StakePallet::planned_collators(2).is_none());
+			assert_eq!(StakePallet::missed_rounds(2), 1);
+			assert!(StakePallet::missed_rounds(1).is_zero());
+			assert!(events().contains(&Event::CollatorMissedRound(2, 1)));
+			assert!(StakePallet::candidate_pool(2).unwrap().is_active());
+
+			// 2 misses round 3 and is demoted
+			roll_to(21, authors);
+			let state = StakePallet::candidate_pool(2).unwrap();
+			assert!(state.is_offline());
+			assert_eq!(state.stake, 90);
+			assert!(StakePallet::missed_rounds(2).is_zero());
+			assert!(events().contains(&Event::CollatorWentOffline(2)));
+			assert!(!StakePallet::top_candidates().into_iter().any(|c| c.owner == 2));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 3]);
+			assert_eq!(
+				StakePallet::total_collator_stake(),
+				TotalStake {
+					collators: 180,
+					delegators: 0,
+				}
+			);
+			// no slash was scheduled
+			assert!(StakePallet::pending_slash_of(&2).is_none());
+
+			// 2 can still increase their stake without re-entering the top candidates
+			assert_ok!(StakePallet::candidate_stake_more(RuntimeOrigin::signed(2), 10));
+			assert!(!StakePallet::top_candidates().into_iter().any(|c| c.owner == 2));
+		});
+}
+
+#[test]
+fn offline_collator_is_not_demoted_below_min_required() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000)])
+		.with_collators(vec![(1, 100), (2, 90)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::set_liveness_policy(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::from_percent(10),
+				false
+			));
+
+			roll_to(31, vec![Some(1); 31]);
+			assert!(StakePallet::candidate_pool(2).unwrap().is_active());
+			assert!(StakePallet::missed_rounds(2) > 1);
+			assert!(StakePallet::pending_slash_of(&2).is_none());
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+		});
+}
+
+#[test]
+fn go_online() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 80)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StakePallet::go_online(RuntimeOrigin::signed(4)),
+				Error::<Test>::CandidateNotFound
+			);
+			assert_noop!(
+				StakePallet::go_online(RuntimeOrigin::signed(2)),
+				Error::<Test>::NotOffline
+			);
+
+			assert_ok!(StakePallet::set_liveness_policy(
+				RuntimeOrigin::root(),
+				1,
+				Perbill::zero(),
+				false
+			));
+			roll_to(16, vec![Some(1); 16]);
+			assert!(StakePallet::candidate_pool(2).unwrap().is_offline());
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 3]);
+
+			assert_ok!(StakePallet::go_online(RuntimeOrigin::signed(2)));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::CollatorBackOnline(2)));
+			assert!(StakePallet::candidate_pool(2).unwrap().is_active());
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+			assert_eq!(
+				StakePallet::total_collator_stake(),
+				TotalStake {
+					collators: 190,
+					delegators: 0,
+				}
+			);
+		});
+}
+
+#[test]
+fn offline_collator_is_slashed_after_deferral() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 80)])
+		.with_delegators(vec![(4, 2, 50)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::set_liveness_policy(
+				RuntimeOrigin::root(),
+				2,
+				Perbill::from_percent(10),
+				true
+			));
+
+			// 2 is demoted at the end of round 3
+			roll_to(21, vec![Some(1); 21]);
+			assert!(StakePallet::candidate_pool(2).unwrap().is_offline());
+			let slash = PendingSlash {
+				fraction: Perbill::from_percent(10),
+				slash_delegators: true,
+			};
+			let when = 3 + <Test as Config>::SlashDeferDuration::get();
+			assert_eq!(StakePallet::pending_slashes(when, 2), Some(slash));
+			assert!(events().contains(&Event::SlashScheduled(2, Perbill::from_percent(10), true, when)));
+
+			// neither the collator nor their delegators can reduce their stake
+			assert_noop!(
+				StakePallet::candidate_stake_less(RuntimeOrigin::signed(2), 10),
+				Error::<Test>::SlashPending
+			);
+			assert_noop!(
+				StakePallet::delegator_stake_less(RuntimeOrigin::signed(4), 2, 10),
+				Error::<Test>::SlashPending
+			);
+			assert_noop!(
+				StakePallet::leave_delegators(RuntimeOrigin::signed(4)),
+				Error::<Test>::SlashPending
+			);
+
+			// slash is applied at the start of the round
+			roll_to(when as BlockNumber * BLOCKS_PER_ROUND - 1, vec![]);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().stake, 90);
+			roll_to(when as BlockNumber * BLOCKS_PER_ROUND, vec![]);
+			assert!(StakePallet::pending_slashes(when, 2).is_none());
+			assert!(StakePallet::pending_slash_of(&2).is_none());
+
+			let state = StakePallet::candidate_pool(2).unwrap();
+			assert_eq!(state.stake, 81);
+			assert_eq!(state.total, 126);
+			assert_eq!(state.delegators.into_bounded_vec()[0].amount, 45);
+			let delegator = StakePallet::delegator_state(4).unwrap();
+			assert_eq!(delegator.total, 45);
+			assert_eq!(delegator.delegation(&2), Some(45));
+			assert!(events().contains(&Event::Slashed(2, 9)));
+			assert!(events().contains(&Event::Slashed(4, 5)));
+
+			// funds went to the treasury and locks were reduced
+			assert_eq!(Balances::free_balance(2), 991);
+			assert_eq!(Balances::free_balance(4), 995);
+			assert_eq!(Balances::free_balance(TREASURY_ACC), 14);
+			assert_eq!(
+				Balances::locks(2),
+				vec![BalanceLock {
+					id: STAKING_ID,
+					amount: 81,
+					reasons: Reasons::All,
+				}]
+			);
+			assert_eq!(
+				Balances::locks(4),
+				vec![BalanceLock {
+					id: STAKING_ID,
+					amount: 45,
+					reasons: Reasons::All,
+				}]
+			);
+
+			// stake can be reduced again
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(4), 2, 10));
+		});
+}
+
+#[test]
+fn slash_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 80)])
+		.with_delegators(vec![(4, 2, 50)])
+		.build()
+		.execute_with(|| {
+			let fraction = Perbill::from_percent(20);
+			assert_noop!(
+				StakePallet::slash_candidate(RuntimeOrigin::signed(1), 2, fraction, false),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				StakePallet::slash_candidate(RuntimeOrigin::root(), 4, fraction, false),
+				Error::<Test>::CandidateNotFound
+			);
+
+			// the collator requests to leave before the slash is scheduled
+			assert_ok!(StakePallet::init_leave_candidates(RuntimeOrigin::signed(2)));
+			roll_to(BLOCKS_PER_ROUND + 1, vec![]);
+			assert_ok!(StakePallet::slash_candidate(RuntimeOrigin::root(), 2, fraction, false));
+			let when = 1 + <Test as Config>::SlashDeferDuration::get();
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::SlashScheduled(2, fraction, false, when))
+			);
+			assert!(StakePallet::pending_slash_of(&2).is_some());
+
+			// the collator cannot exit while the slash is pending
+			roll_to(2 * BLOCKS_PER_ROUND + 1, vec![]);
+			assert_noop!(
+				StakePallet::execute_leave_candidates(RuntimeOrigin::signed(2), 2),
+				Error::<Test>::SlashPending
+			);
+
+			// the slash is applied to the leaving collator but not to their delegators
+			roll_to(when as BlockNumber * BLOCKS_PER_ROUND + 1, vec![]);
+			let state = StakePallet::candidate_pool(2).unwrap();
+			assert!(state.is_leaving());
+			assert_eq!(state.stake, 72);
+			assert_eq!(state.total, 122);
+			assert_eq!(StakePallet::delegator_state(4).unwrap().total, 50);
+			assert_eq!(Balances::free_balance(4), 1000);
+			assert_eq!(Balances::free_balance(TREASURY_ACC), 18);
+
+			// the exit can be executed after the slash
+			assert_ok!(StakePallet::execute_leave_candidates(RuntimeOrigin::signed(2), 2));
+			assert_eq!(StakePallet::unstaking(2).into_inner().values().sum::<Balance>(), 72);
+		});
+}
+
+#[test]
+fn cancel_slash() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 80)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::slash_candidate(
+				RuntimeOrigin::root(),
+				3,
+				Perbill::from_percent(50),
+				true
+			));
+			let when = <Test as Config>::SlashDeferDuration::get();
+			assert_noop!(
+				StakePallet::cancel_slash(RuntimeOrigin::signed(1), when, 3),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				StakePallet::cancel_slash(RuntimeOrigin::root(), when + 1, 3),
+				Error::<Test>::SlashNotFound
+			);
+			assert_noop!(
+				StakePallet::cancel_slash(RuntimeOrigin::root(), when, 2),
+				Error::<Test>::SlashNotFound
+			);
+			assert_noop!(
+				StakePallet::candidate_stake_less(RuntimeOrigin::signed(3), 10),
+				Error::<Test>::SlashPending
+			);
+
+			assert_ok!(StakePallet::cancel_slash(RuntimeOrigin::root(), when, 3));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::SlashCancelled(when, 3)));
+			assert!(StakePallet::pending_slash_of(&3).is_none());
+			assert_ok!(StakePallet::candidate_stake_less(RuntimeOrigin::signed(3), 10));
+
+			roll_to(when as BlockNumber * BLOCKS_PER_ROUND + 1, vec![]);
+			assert_eq!(StakePallet::candidate_pool(3).unwrap().stake, 70);
+			assert_eq!(Balances::free_balance(3), 1000);
+			assert!(Balances::free_balance(TREASURY_ACC).is_zero());
+		});
+}
+
+#[test]
+fn slashed_delegation_below_min_is_removed() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000), (5, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 80)])
+		.with_delegators(vec![(4, 2, 8), (5, 2, 50)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::slash_candidate(
+				RuntimeOrigin::root(),
+				2,
+				Perbill::from_percent(50),
+				true
+			));
+			let when = <Test as Config>::SlashDeferDuration::get();
+			roll_to(when as BlockNumber * BLOCKS_PER_ROUND + 1, vec![]);
+
+			// the delegation of 4 fell below the minimum and is removed
+			assert!(events().contains(&Event::Slashed(4, 4)));
+			assert!(events().contains(&Event::DelegationBelowMinRemoved(4, 2, 4)));
+			assert!(events().contains(&Event::DelegatorLeft(4, 4)));
+			assert!(StakePallet::delegator_state(4).is_none());
+			assert!(StakePallet::delegation_start(4, 2).is_none());
+
+			// the remaining stake is unstaked as usual
+			assert_eq!(StakePallet::unstaking(4).into_inner().values().sum::<Balance>(), 4);
+			assert_eq!(Balances::free_balance(4), 996);
+			assert_eq!(
+				Balances::locks(4),
+				vec![BalanceLock {
+					id: STAKING_ID,
+					amount: 4,
+					reasons: Reasons::All,
+				}]
+			);
+
+			// the delegation of 5 is kept
+			let state = StakePallet::candidate_pool(2).unwrap();
+			assert_eq!(state.stake, 45);
+			assert_eq!(state.total, 70);
+			assert_eq!(state.delegators.len(), 1);
+			assert_eq!(StakePallet::delegator_state(5).unwrap().delegation(&2), Some(25));
+			assert!(StakePallet::top_candidates()
+				.into_bounded_vec()
+				.iter()
+				.any(|stake| stake.owner == 2 && stake.amount == 70));
+		});
+}
+
+#[test]
+fn slashed_candidate_below_min_is_demoted() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (3, 16)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::slash_candidate(
+				RuntimeOrigin::root(),
+				3,
+				Perbill::from_percent(50),
+				false
+			));
+			let when = <Test as Config>::SlashDeferDuration::get();
+			roll_to(when as BlockNumber * BLOCKS_PER_ROUND + 1, vec![]);
+
+			// the candidate fell below the minimum and left the top candidates
+			let state = StakePallet::candidate_pool(3).unwrap();
+			assert_eq!(state.stake, 8);
+			assert!(state.is_offline());
+			assert!(!StakePallet::top_candidates()
+				.into_bounded_vec()
+				.iter()
+				.any(|stake| stake.owner == 3));
+			assert!(events().contains(&Event::LeftTopCandidates(3)));
+			assert!(events().contains(&Event::CandidateBelowMinDemoted(3, 8)));
+
+			// the minimum number of top candidates is kept
+			assert_ok!(StakePallet::slash_candidate(
+				RuntimeOrigin::root(),
+				2,
+				Perbill::from_percent(95),
+				false
+			));
+			let when = when + <Test as Config>::SlashDeferDuration::get();
+			roll_to(when as BlockNumber * BLOCKS_PER_ROUND + 1, vec![]);
+			let state = StakePallet::candidate_pool(2).unwrap();
+			assert!(state.stake < 10);
+			assert!(state.is_active());
+
+			// the candidate has to stake more before coming back online
+			assert_noop!(
+				StakePallet::go_online(RuntimeOrigin::signed(3)),
+				Error::<Test>::ValStakeBelowMin
+			);
+			assert_ok!(StakePallet::candidate_stake_more(RuntimeOrigin::signed(3), 2));
+			assert_ok!(StakePallet::go_online(RuntimeOrigin::signed(3)));
+			assert!(StakePallet::top_candidates()
+				.into_bounded_vec()
+				.iter()
+				.any(|stake| stake.owner == 3));
+		});
+}
+
+#[test]
+fn set_auto_compound() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 100), (2, 90)])
+		.with_delegators(vec![(3, 1, 50)])
+		.build()
+		.execute_with(|| {
+			let percentage = Perbill::from_percent(30);
+			assert_noop!(
+				StakePallet::set_auto_compound(RuntimeOrigin::signed(4), percentage),
+				Error::<Test>::NotStaking
+			);
+
+			assert_ok!(StakePallet::set_auto_compound(RuntimeOrigin::signed(1), percentage));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::AutoCompoundSet(1, percentage))
+			);
+			assert_eq!(StakePallet::auto_compound(1), percentage);
+			assert_ok!(StakePallet::set_auto_compound(RuntimeOrigin::signed(3), percentage));
+			assert_eq!(StakePallet::auto_compound(3), percentage);
+
+			// zero disables auto-compounding
+			assert_ok!(StakePallet::set_auto_compound(RuntimeOrigin::signed(1), Perbill::zero()));
+			assert!(!crate::AutoCompound::<Test>::contains_key(1));
+
+			// leaving delegators reset their setting
+			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(3)));
+			assert!(!crate::AutoCompound::<Test>::contains_key(3));
+		});
+}
+
+#[test]
+fn collator_auto_compound() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.build()
+		.execute_with(|| {
+			let percentage = Perbill::from_percent(40);
+			assert_ok!(StakePallet::set_auto_compound(RuntimeOrigin::signed(1), percentage));
+
+			roll_to(4, vec![None, Some(1), Some(1), Some(1)]);
+			let reward = StakePallet::get_unclaimed_staking_rewards(&1);
+			assert!(!reward.is_zero());
+			let compounded = percentage * reward;
+
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert!(events().contains(&Event::RewardsCompounded(1, 1, compounded)));
+			assert_eq!(StakePallet::rewards(1), reward - compounded);
+			let state = StakePallet::candidate_pool(1).unwrap();
+			assert_eq!(state.stake, stake + compounded);
+			assert_eq!(state.total, stake + compounded);
+			assert_eq!(StakePallet::top_candidates().into_bounded_vec()[0].amount, stake + compounded);
+			assert_eq!(
+				StakePallet::total_collator_stake(),
+				TotalStake {
+					collators: 2 * stake + compounded,
+					delegators: 0,
+				}
+			);
+			assert_eq!(Balances::free_balance(1), stake + compounded);
+			assert_eq!(
+				Balances::locks(1),
+				vec![BalanceLock {
+					id: STAKING_ID,
+					amount: stake + compounded,
+					reasons: Reasons::All,
+				}]
+			);
+
+			// the remainder can be claimed
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(Balances::free_balance(1), stake + reward);
+			assert!(StakePallet::rewards(1).is_zero());
+
+			// collators without auto-compounding keep all rewards claimable
+			let reward = StakePallet::get_unclaimed_staking_rewards(&2);
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(2)));
+			assert_eq!(StakePallet::rewards(2), reward);
+			assert_eq!(StakePallet::candidate_pool(2).unwrap().stake, stake);
+		});
+}
+
+#[test]
+fn collator_auto_compound_respects_max_candidate_stake() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::set_max_candidate_stake(RuntimeOrigin::root(), stake + 1));
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(1),
+				Perbill::one()
+			));
+
+			roll_to(4, vec![None, Some(1), Some(1), Some(1)]);
+			let reward = StakePallet::get_unclaimed_staking_rewards(&1);
+			assert!(reward > 1);
+
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, stake + 1);
+			assert_eq!(StakePallet::rewards(1), reward - 1);
+
+			// nothing is compounded once the maximum is reached
+			roll_to(6, vec![None, None, None, None, Some(1), Some(1)]);
+			let reward = StakePallet::get_unclaimed_staking_rewards(&1);
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, stake + 1);
+			assert_eq!(StakePallet::rewards(1), reward);
+		});
+}
+
+#[test]
+fn delegator_auto_compound() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake), (4, stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.with_delegators(vec![(3, 1, stake), (4, 2, stake)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(3),
+				Perbill::one()
+			));
+
+			roll_to(4, vec![None, Some(1), Some(1), Some(1)]);
+			let reward = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert!(!reward.is_zero());
+
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(3)));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::RewardsCompounded(3, 1, reward))
+			);
+			assert!(StakePallet::rewards(3).is_zero());
+			let delegator = StakePallet::delegator_state(3).unwrap();
+			assert_eq!(delegator.total, stake + reward);
+			assert_eq!(delegator.delegation(&1), Some(stake + reward));
+			let state = StakePallet::candidate_pool(1).unwrap();
+			assert_eq!(state.total, 2 * stake + reward);
+			assert_eq!(
+				state.delegators.into_bounded_vec()[0],
+				StakeOf::<Test> {
+					owner: 3,
+					amount: stake + reward,
+				}
+			);
+			assert_eq!(
+				StakePallet::top_candidates().into_bounded_vec()[0],
+				StakeOf::<Test> {
+					owner: 1,
+					amount: 2 * stake + reward,
+				}
+			);
+			assert_eq!(
+				StakePallet::total_collator_stake(),
+				TotalStake {
+					collators: 2 * stake,
+					delegators: 2 * stake + reward,
+				}
+			);
+			assert_eq!(
+				Balances::locks(3),
+				vec![BalanceLock {
+					id: STAKING_ID,
+					amount: stake + reward,
+					reasons: Reasons::All,
+				}]
+			);
+		});
+}
+
+#[test]
+fn auto_compound_at_round_end() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake), (4, stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.with_delegators(vec![(3, 1, stake), (4, 1, stake)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(1),
+				Perbill::one()
+			));
+			assert_ok!(StakePallet::set_auto_compound(
+				RuntimeOrigin::signed(3),
+				Perbill::one()
+			));
+
+			roll_to(4, vec![None, Some(1), Some(1), Some(1)]);
+			let collator_reward = StakePallet::get_unclaimed_staking_rewards(&1);
+			let delegator_reward = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert!(!collator_reward.is_zero());
+			assert!(!delegator_reward.is_zero());
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, stake);
+
+			// the top candidates are queued once the round ends
+			roll_to(BLOCKS_PER_ROUND, vec![]);
+			assert!(!StakePallet::auto_compound_queue().is_empty());
+
+			// one candidate is compounded per block without any interaction
+			roll_to(BLOCKS_PER_ROUND + 2, vec![]);
+			assert!(StakePallet::auto_compound_queue().is_empty());
+			assert!(events().contains(&Event::RewardsCompounded(1, 1, collator_reward)));
+			assert!(StakePallet::rewards(1).is_zero());
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().stake, stake + collator_reward);
+
+			let compounded = StakePallet::delegator_state(3).unwrap().total - stake;
+			assert!(events().contains(&Event::RewardsCompounded(3, 1, compounded)));
+			assert!(almost_equal(compounded, delegator_reward, Perbill::from_perthousand(1)));
+			assert!(StakePallet::rewards(3).is_zero());
+			assert_eq!(
+				StakePallet::candidate_pool(1).unwrap().total,
+				3 * stake + collator_reward + compounded
+			);
+
+			// accounts without auto-compounding are left untouched
+			assert!(StakePallet::rewards(4).is_zero());
+			assert!(!StakePallet::get_unclaimed_staking_rewards(&4).is_zero());
+			assert_eq!(StakePallet::delegator_state(4).unwrap().total, stake);
+		});
+}
+
+#[test]
+fn reward_history() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.with_delegators(vec![(3, 1, stake)])
+		.build()
+		.execute_with(|| {
+			assert!(StakePallet::get_reward_history(&1).is_empty());
+			let authors: Vec<Option<AccountId>> = (0..25).map(|_| Some(1)).collect();
+
+			// rewards incremented within the same round are added up
+			roll_to(2, authors.clone());
+			let first = StakePallet::get_unclaimed_staking_rewards(&1);
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			roll_to(4, authors.clone());
+			let second = StakePallet::get_unclaimed_staking_rewards(&1) - StakePallet::rewards(1);
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert!(!first.is_zero() && !second.is_zero());
+			assert_eq!(StakePallet::get_reward_history(&1), vec![(0, first + second)]);
+
+			// only the latest MaxRewardHistory rounds are kept
+			let mut expected = vec![];
+			for round in 1..=3 {
+				roll_to(round * BLOCKS_PER_ROUND + 4, authors.clone());
+				let reward = StakePallet::get_unclaimed_staking_rewards(&1) - StakePallet::rewards(1);
+				assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+				expected.push((round as u32, reward));
+			}
+			assert_eq!(StakePallet::get_reward_history(&1), expected);
+
+			// delegators get their own history
+			let reward = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(3)));
+			assert_eq!(StakePallet::get_reward_history(&3), vec![(3, reward)]);
+
+			// claiming does not affect the history
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(3)));
+			assert_eq!(StakePallet::get_reward_history(&3), vec![(3, reward)]);
+		});
+}
+
+#[test]
+fn collator_stats() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake), (4, stake), (5, stake)])
+		.with_collators(vec![(1, stake), (2, stake), (5, stake / 2)])
+		.with_delegators(vec![(3, 1, stake), (4, 1, stake)])
+		.build()
+		.execute_with(|| {
+			assert!(StakePallet::get_collator_stats(&6).is_none());
+
+			roll_to(4, vec![None, Some(1), Some(2), Some(1)]);
+			let rates = StakePallet::get_staking_rates();
+			let stats = StakePallet::get_collator_stats(&1).unwrap();
+			assert_eq!(stats.blocks_authored, 2);
+			assert_eq!(stats.stake, stake);
+			assert_eq!(stats.total_stake, 3 * stake);
+			assert_eq!(stats.delegators, 2);
+			assert!(stats.commission.is_zero());
+			assert!(stats.is_collating);
+			assert!(rates.collator_reward_rate.deconstruct() - stats.collator_apy.deconstruct() <= 1);
+			assert_eq!(stats.delegator_apy, rates.delegator_reward_rate);
+			assert_eq!(StakePallet::get_collator_stats(&2).unwrap().blocks_authored, 1);
+
+			// candidates outside of the collator set are reported as such
+			let stats = StakePallet::get_collator_stats(&5).unwrap();
+			assert!(!stats.is_collating);
+			assert_eq!(stats.blocks_authored, 0);
+
+			// the commission is added to the collator's and deducted from the
+			// delegators' reward rate
+			assert_ok!(StakePallet::set_max_collator_commission(
+				RuntimeOrigin::root(),
+				Perbill::from_percent(20)
+			));
+			assert_ok!(StakePallet::request_commission_change(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(10)
+			));
+			roll_to(15, vec![]);
+			assert_ok!(StakePallet::execute_commission_change(RuntimeOrigin::signed(1), 1));
+			let rates = StakePallet::get_staking_rates();
+			let stats = StakePallet::get_collator_stats(&1).unwrap();
+			assert_eq!(stats.commission, Perbill::from_percent(10));
+			assert_eq!(
+				stats.delegator_apy,
+				rates.delegator_reward_rate - Perquintill::from_percent(10) * rates.delegator_reward_rate
+			);
+			assert!(stats.collator_apy > rates.collator_reward_rate);
+		});
+}
+
+#[test]
+fn delegation_projection() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.build()
+		.execute_with(|| {
+			assert!(StakePallet::get_delegation_projection(&3, stake).is_none());
+
+			let projection = StakePallet::get_delegation_projection(&1, stake).unwrap();
+			assert!(!projection.apy.is_zero());
+			assert_eq!(projection.per_year, projection.apy * stake);
+
+			// the projection matches the rewards of an actual delegation
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(3), 1, stake));
+			let authors: Vec<Option<AccountId>> = (0..20).map(|i| if i % 2 == 0 { Some(1) } else { Some(2) }).collect();
+			// the delegation earns rewards from the next round on, in which
+			// collator 1 authors every other block
+			roll_to(3 * BLOCKS_PER_ROUND, authors);
+			let rewards = StakePallet::get_unclaimed_staking_rewards(&3);
+			assert!(almost_equal(rewards, 2 * projection.per_round, Perbill::from_perthousand(1)));
+		});
+}
+
+#[test]
+fn set_staking_parameters() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000)])
+		.with_collators(vec![(1, 100), (2, 90), (4, 80)])
+		.with_delegators(vec![(3, 1, 50)])
+		.build()
+		.execute_with(|| {
+			let initial = StakePallet::staking_params();
+			assert_eq!(initial.min_collator_stake, <Test as Config>::MinCollatorStake::get());
+			assert_eq!(initial.min_delegator_stake, <Test as Config>::MinDelegatorStake::get());
+			assert_eq!(
+				initial.max_delegators_per_collator,
+				<Test as Config>::MaxDelegatorsPerCollator::get()
+			);
+			assert_eq!(initial.stake_duration, <Test as Config>::StakeDuration::get());
+			assert_eq!(initial.exit_queue_delay, <Test as Config>::ExitQueueDelay::get());
+			assert_eq!(initial.network_reward_rate, <Test as Config>::NetworkRewardRate::get());
+
+			assert_noop!(
+				StakePallet::set_stake_duration(RuntimeOrigin::signed(1), 10),
+				sp_runtime::DispatchError::BadOrigin
+			);
+
+			// min collator stake
+			assert_noop!(
+				StakePallet::set_min_collator_stake(RuntimeOrigin::root(), 9),
+				Error::<Test>::CannotSetBelowMin
+			);
+			assert_noop!(
+				StakePallet::set_min_collator_stake(RuntimeOrigin::root(), StakePallet::max_candidate_stake() + 1),
+				Error::<Test>::CannotSetAboveMax
+			);
+			assert_ok!(StakePallet::set_min_collator_stake(RuntimeOrigin::root(), 20));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::MinCollatorStakeSet(10, 20))
+			);
+			assert_eq!(StakePallet::staking_params().min_collator_stake, 20);
+
+			// min delegator stake
+			assert_noop!(
+				StakePallet::set_min_delegator_stake(RuntimeOrigin::root(), 0),
+				Error::<Test>::CannotSetBelowMin
+			);
+			assert_ok!(StakePallet::set_min_delegator_stake(RuntimeOrigin::root(), 3));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::MinDelegatorStakeSet(5, 3))
+			);
+			assert_eq!(StakePallet::staking_params().min_delegator_stake, 3);
+
+			// max delegators per collator
+			assert_noop!(
+				StakePallet::set_max_delegators_per_collator(RuntimeOrigin::root(), 0),
+				Error::<Test>::CannotSetBelowMin
+			);
+			assert_noop!(
+				StakePallet::set_max_delegators_per_collator(
+					RuntimeOrigin::root(),
+					<Test as Config>::MaxDelegatorsPerCollator::get() + 1
+				),
+				Error::<Test>::CannotSetAboveMax
+			);
+			assert_ok!(StakePallet::set_max_delegators_per_collator(RuntimeOrigin::root(), 2));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::MaxDelegatorsPerCollatorSet(4, 2))
+			);
+
+			// stake duration
+			assert_noop!(
+				StakePallet::set_stake_duration(RuntimeOrigin::root(), 2),
+				Error::<Test>::CannotSetBelowMin
+			);
+			assert_noop!(
+				StakePallet::set_stake_duration(RuntimeOrigin::root(), 21),
+				Error::<Test>::CannotSetAboveMax
+			);
+			assert_ok!(StakePallet::set_stake_duration(RuntimeOrigin::root(), 10));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::StakeDurationSet(2, 10)));
+
+			// exit queue delay
+			assert_noop!(
+				StakePallet::set_exit_queue_delay(RuntimeOrigin::root(), 0),
+				Error::<Test>::CannotSetBelowMin
+			);
+			assert_noop!(
+				StakePallet::set_exit_queue_delay(RuntimeOrigin::root(), 11),
+				Error::<Test>::CannotSetAboveMax
+			);
+			assert_ok!(StakePallet::set_exit_queue_delay(RuntimeOrigin::root(), 4));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::ExitQueueDelaySet(2, 4)));
+
+			// network reward rate
+			assert_noop!(
+				StakePallet::set_network_reward_rate(RuntimeOrigin::root(), Perquintill::from_percent(21)),
+				Error::<Test>::CannotSetAboveMax
+			);
+			let rate = Perquintill::from_percent(5);
+			assert_ok!(StakePallet::set_network_reward_rate(RuntimeOrigin::root(), rate));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::NetworkRewardRateSet(
+					<Test as Config>::NetworkRewardRate::get(),
+					rate
+				))
+			);
+
+			// the new values apply without a runtime upgrade
+			assert_ok!(StakePallet::revoke_delegation(RuntimeOrigin::signed(3), 1));
+			let mut unstaking: BoundedBTreeMap<BlockNumber, BalanceOf<Test>, <Test as Config>::MaxUnstakeRequests> =
+				BoundedBTreeMap::new();
+			assert_ok!(unstaking.try_insert(11, 50));
+			assert_eq!(StakePallet::unstaking(3), unstaking);
+
+			assert_ok!(StakePallet::init_leave_candidates(RuntimeOrigin::signed(2)));
+			assert_eq!(
+				StakePallet::candidate_pool(2).unwrap().status,
+				CandidateStatus::Leaving(4)
+			);
+		});
+}
+
+#[test]
+fn min_stake_grace_period() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000), (5, 1000)])
+		.with_collators(vec![(1, 100), (2, 20)])
+		.with_delegators(vec![(3, 1, 10), (4, 1, 20)])
+		.build()
+		.execute_with(|| {
+			// lowering a minimum does not start a grace period
+			assert_ok!(StakePallet::set_min_delegator_stake(RuntimeOrigin::root(), 4));
+			assert!(StakePallet::min_stake_grace().is_none());
+
+			assert_ok!(StakePallet::set_min_collator_stake(RuntimeOrigin::root(), 50));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::MinCollatorStakeSet(10, 50))
+			);
+			assert!(events().contains(&Event::MinStakeGracePeriodStarted(2)));
+			assert_ok!(StakePallet::set_min_delegator_stake(RuntimeOrigin::root(), 15));
+			assert_eq!(StakePallet::min_stakes_in_effect(), (10, 4));
+
+			// new delegations have to meet the new minimum right away
+			assert_noop!(
+				StakePallet::join_delegators(RuntimeOrigin::signed(5), 1, 10),
+				Error::<Test>::DelegationBelowMin
+			);
+
+			// existing stakes are measured against the previous minimums
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1, 2]);
+			assert_noop!(
+				StakePallet::remove_delegation_below_min(RuntimeOrigin::signed(5), 3, 1),
+				Error::<Test>::StakeNotBelowMin
+			);
+
+			roll_to(11, vec![]);
+			assert_eq!(StakePallet::round().current, 2);
+			assert_eq!(StakePallet::min_stakes_in_effect(), (50, 15));
+			assert_eq!(StakePallet::selected_candidates().into_inner(), vec![1]);
+
+			assert_noop!(
+				StakePallet::remove_delegation_below_min(RuntimeOrigin::signed(5), 4, 1),
+				Error::<Test>::StakeNotBelowMin
+			);
+			assert_noop!(
+				StakePallet::remove_delegation_below_min(RuntimeOrigin::signed(5), 3, 2),
+				Error::<Test>::DelegationNotFound
+			);
+			assert_ok!(StakePallet::remove_delegation_below_min(RuntimeOrigin::signed(5), 3, 1));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::DelegationBelowMinRemoved(3, 1, 10))
+			);
+			assert!(StakePallet::delegator_state(3).is_none());
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().total, 120);
+			let mut unstaking: BoundedBTreeMap<BlockNumber, BalanceOf<Test>, <Test as Config>::MaxUnstakeRequests> =
+				BoundedBTreeMap::new();
+			assert_ok!(unstaking.try_insert(13, 10));
+			assert_eq!(StakePallet::unstaking(3), unstaking);
+		});
+}
+
+#[test]
+fn lowered_max_delegators_per_collator() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000), (4, 1000), (5, 1000), (6, 1000)])
+		.with_collators(vec![(1, 100)])
+		.with_delegators(vec![(2, 1, 10), (3, 1, 20), (4, 1, 30)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(StakePallet::set_max_delegators_per_collator(RuntimeOrigin::root(), 2));
+
+			// existing delegations are kept
+			assert_eq!(StakePallet::candidate_pool(1).unwrap().delegators.len(), 3);
+
+			// a delegation lower than all existing ones is rejected
+			assert_noop!(
+				StakePallet::join_delegators(RuntimeOrigin::signed(5), 1, 5),
+				Error::<Test>::TooManyDelegators
+			);
+
+			// a higher delegation replaces the lowest one
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(6), 1, 15));
+			assert!(StakePallet::delegator_state(2).is_none());
+			let state = StakePallet::candidate_pool(1).unwrap();
+			assert_eq!(state.delegators.len(), 3);
+			assert_eq!(state.total, 165);
+			assert_eq!(StakePallet::unstaking(2).get(&3), Some(&10));
+			assert!(events().contains(&Event::DelegationReplaced(6, 15, 2, 10, 1, 165)));
+		});
+}
+
+fn collator_metadata(name: &[u8], node_version: &[u8]) -> CollatorMetadataOf<Test> {
+	CollatorMetadata {
+		name: name.to_vec().try_into().unwrap(),
+		url: b"curio.io".to_vec().try_into().unwrap(),
+		contact: b"@curio".to_vec().try_into().unwrap(),
+		region: b"EU".to_vec().try_into().unwrap(),
+		node_version: node_version.to_vec().try_into().unwrap(),
+	}
+}
+
+#[test]
+fn set_candidate_metadata() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 100), (2, 90)])
+		.build()
+		.execute_with(|| {
+			let metadata = collator_metadata(b"collator", b"1.9.0");
+			// 8 + 8 + 6 + 2 + 5 bytes
+			let deposit = 10 + 29;
+			assert_eq!(StakePallet::metadata_deposit(&metadata), deposit);
+
+			assert_noop!(
+				StakePallet::set_candidate_metadata(RuntimeOrigin::signed(3), metadata.clone()),
+				Error::<Test>::CandidateNotFound
+			);
+
+			assert_ok!(StakePallet::set_candidate_metadata(RuntimeOrigin::signed(1), metadata.clone()));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::CandidateMetadataSet(1, deposit))
+			);
+			assert_eq!(StakePallet::candidate_metadata(1), Some((metadata, deposit)));
+			assert_eq!(Balances::reserved_balance(1), deposit);
+
+			let api_metadata = StakePallet::get_collator_metadata(&1).unwrap();
+			assert_eq!(api_metadata.name, b"collator".to_vec());
+			assert_eq!(api_metadata.node_version, b"1.9.0".to_vec());
+			assert_eq!(api_metadata.deposit, deposit);
+			assert!(StakePallet::get_collator_metadata(&2).is_none());
+
+			// updating the metadata adjusts the deposit
+			let metadata = collator_metadata(b"c", b"1.10.0");
+			assert_ok!(StakePallet::set_candidate_metadata(RuntimeOrigin::signed(1), metadata.clone()));
+			assert_eq!(Balances::reserved_balance(1), 33);
+			assert_eq!(StakePallet::candidate_metadata(1), Some((metadata, 33)));
+
+			assert_noop!(
+				StakePallet::clear_candidate_metadata(RuntimeOrigin::signed(2)),
+				Error::<Test>::MetadataNotFound
+			);
+			assert_ok!(StakePallet::clear_candidate_metadata(RuntimeOrigin::signed(1)));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::CandidateMetadataCleared(1))
+			);
+			assert!(StakePallet::candidate_metadata(1).is_none());
+			assert!(Balances::reserved_balance(1).is_zero());
+		});
+}
+
+#[test]
+fn join_candidates_with_metadata() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 1000), (2, 1000), (3, 1000)])
+		.with_collators(vec![(1, 100), (2, 90)])
+		.build()
+		.execute_with(|| {
+			let metadata = collator_metadata(b"collator", b"1.9.0");
+			let deposit = StakePallet::metadata_deposit(&metadata);
+
+			// the deposit cannot be reserved from the stake
+			assert_noop!(
+				StakePallet::join_candidates_with_metadata(RuntimeOrigin::signed(3), 1000, metadata.clone()),
+				BalancesError::<Test>::InsufficientBalance
+			);
+
+			assert_ok!(StakePallet::join_candidates_with_metadata(
+				RuntimeOrigin::signed(3),
+				900,
+				metadata.clone()
+			));
+			assert!(events().contains(&Event::JoinedCollatorCandidates(3, 900)));
+			assert_eq!(
+				last_event(),
+				MetaEvent::StakePallet(Event::CandidateMetadataSet(3, deposit))
+			);
+			assert_eq!(StakePallet::candidate_pool(3).unwrap().stake, 900);
+			assert_eq!(StakePallet::candidate_metadata(3), Some((metadata, deposit)));
+			assert_eq!(Balances::reserved_balance(3), deposit);
+
+			// leaving candidates get their deposit back
+			assert_ok!(StakePallet::init_leave_candidates(RuntimeOrigin::signed(3)));
+			roll_to(11, vec![]);
+			assert_ok!(StakePallet::execute_leave_candidates(RuntimeOrigin::signed(3), 3));
+			assert!(StakePallet::candidate_metadata(3).is_none());
+			assert!(Balances::reserved_balance(3).is_zero());
+		});
+}
+
+#[test]
+fn pot_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, DECIMALS), (2, DECIMALS), (REWARD_POT_ACC, 1000 * DECIMALS)])
+		.with_collators(vec![(1, DECIMALS)])
+		.with_delegators(vec![(2, 1, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			let expected = expected_annual_rewards::<Test>();
+			assert!(!expected.is_zero());
+
+			// a budget covering the expected rewards pays out the full rewards
+			RewardPotEnabled::set(true);
+			YearlyRewardBudget::set(expected);
+			assert_eq!(<Test as Config>::RewardSource::reward_ratio(), Perquintill::one());
+
+			// claiming transfers out of the pot without minting
+			let total_issuance = <Test as Config>::Currency::total_issuance();
+			StakePallet::note_author(1);
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			let rewards = StakePallet::rewards(1);
+			assert_eq!(rewards, StakePallet::calc_block_rewards_collator(DECIMALS, 1));
+			assert_eq!(StakePallet::outstanding_rewards(), rewards);
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(last_event(), MetaEvent::StakePallet(Event::Rewarded(1, rewards)));
+			assert_eq!(Balances::free_balance(&1), DECIMALS + rewards);
+			assert_eq!(Balances::free_balance(&REWARD_POT_ACC), 1000 * DECIMALS - rewards);
+			assert_eq!(<Test as Config>::Currency::total_issuance(), total_issuance);
+			assert!(StakePallet::outstanding_rewards().is_zero());
+
+			// rewards are scaled down to the budget
+			let budget = expected / 2;
+			YearlyRewardBudget::set(budget);
+			let within_budget = Perquintill::from_rational(budget, expected);
+			assert_eq!(<Test as Config>::RewardSource::reward_ratio(), within_budget);
+
+			// but only from the next round on, regardless of when the rewards
+			// are incremented
+			roll_to(
+				BLOCKS_PER_ROUND + 1,
+				vec![None, Some(1), Some(1), Some(1), Some(1), Some(1)],
+			);
+			assert_eq!(StakePallet::round_reward_ratio(), within_budget);
+			let full_reward = StakePallet::calc_block_rewards_collator(DECIMALS, 4);
+			let reduced_reward = within_budget * StakePallet::calc_block_rewards_collator(DECIMALS, 1);
+			assert!(almost_equal(
+				StakePallet::get_unclaimed_staking_rewards(&1),
+				full_reward + reduced_reward,
+				Perbill::from_perthousand(1)
+			));
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert!(almost_equal(
+				StakePallet::rewards(1),
+				full_reward + reduced_reward,
+				Perbill::from_perthousand(1)
+			));
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(1)));
+
+			// and further in proportion to the funds once the pot runs low
+			Balances::make_free_balance_be(&REWARD_POT_ACC, budget / 4);
+			let expected = expected_annual_rewards::<Test>();
+			let ratio = Perquintill::from_rational(budget, expected) * Perquintill::from_rational(budget / 4, budget);
+			assert_eq!(<Test as Config>::RewardSource::reward_ratio(), ratio);
+
+			// an empty pot does not pay out anything
+			Balances::make_free_balance_be(&REWARD_POT_ACC, 0);
+			assert!(<Test as Config>::RewardSource::reward_ratio().is_zero());
+			roll_to(2 * BLOCKS_PER_ROUND, vec![]);
+			assert!(StakePallet::round_reward_ratio().is_zero());
+			StakePallet::note_author(1);
+			assert!(StakePallet::get_unclaimed_staking_rewards(&1).is_zero());
+
+			// no network rewards are minted
+			let total_issuance = <Test as Config>::Currency::total_issuance();
+			let network_reward_start = <Test as Config>::NetworkRewardStart::get();
+			System::set_block_number(network_reward_start);
+			roll_to(network_reward_start + 2, vec![None]);
+			assert!(Balances::free_balance(&TREASURY_ACC).is_zero());
+			assert_eq!(<Test as Config>::Currency::total_issuance(), total_issuance);
+		});
+}
+
+#[test]
+fn pot_rewards_cover_outstanding_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, DECIMALS), (2, DECIMALS), (REWARD_POT_ACC, DECIMALS)])
+		.with_collators(vec![(1, DECIMALS)])
+		.with_delegators(vec![(2, 1, DECIMALS)])
+		.build()
+		.execute_with(|| {
+			RewardPotEnabled::set(true);
+			let budget = expected_annual_rewards::<Test>();
+			YearlyRewardBudget::set(budget);
+			let authors: Vec<Option<AccountId>> = (0..20).map(|_| Some(1)).collect();
+			roll_to(20, authors);
+
+			// the pot cannot cover all rewards of the past rounds
+			let unclaimed =
+				StakePallet::get_unclaimed_staking_rewards(&1) + StakePallet::get_unclaimed_staking_rewards(&2);
+			let pot = unclaimed / 2;
+			Balances::make_free_balance_be(&REWARD_POT_ACC, pot);
+
+			// only what the pot can cover is credited
+			assert_ok!(StakePallet::increment_delegator_rewards(RuntimeOrigin::signed(2)));
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			let outstanding = StakePallet::rewards(1) + StakePallet::rewards(2);
+			assert_eq!(StakePallet::outstanding_rewards(), outstanding);
+			assert_eq!(outstanding, pot - 1);
+
+			// the outstanding rewards are not available for new rewards
+			assert_eq!(
+				<Test as Config>::RewardSource::reward_ratio(),
+				Perquintill::from_rational(1, budget)
+			);
+			StakePallet::note_author(1);
+			assert_ok!(StakePallet::increment_collator_rewards(RuntimeOrigin::signed(1)));
+			assert_eq!(StakePallet::outstanding_rewards(), outstanding);
+
+			// hence the first claimer cannot drain the pot
+			let rewards_2 = StakePallet::rewards(2);
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(1)));
+			assert_ok!(StakePallet::claim_rewards(RuntimeOrigin::signed(2)));
+			assert_eq!(Balances::free_balance(&2), DECIMALS + rewards_2);
+			assert_eq!(Balances::free_balance(&REWARD_POT_ACC), 1);
+			assert!(StakePallet::outstanding_rewards().is_zero());
+		});
+}
+
+#[test]
+fn delegation_warm_up() {
+	let stake = 100_000 * DECIMALS;
+	ExtBuilder::default()
+		.with_balances(vec![(1, stake), (2, stake), (3, stake), (4, stake)])
+		.with_collators(vec![(1, stake), (2, stake)])
+		.build()
+		.execute_with(|| {
+			DelegationWarmUp::set(2);
+			// 1 authors the second block of each round
+			let authors: Vec<Option<AccountId>> = (0..20).map(|n| if n % 5 == 1 { Some(1) } else { None }).collect();
+
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(3), 1, stake));
+			roll_to(5, authors.clone());
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(4), 1, stake));
+			assert_eq!(
+				StakePallet::pending_delegations(1).unwrap().into_inner(),
+				vec![(3, 0), (4, 1)]
+			);
+
+			// 3 is still warming up in the round after joining
+			roll_to(10, authors.clone());
+			assert!(StakePallet::is_pending_delegation(&3, &1));
+			assert!(StakePallet::get_unclaimed_staking_rewards(&3).is_zero());
+
+			// 3 earns rewards from the second round after joining on
+			roll_to(12, authors.clone());
+			assert!(!StakePallet::is_pending_delegation(&3, &1));
+			assert!(StakePallet::is_pending_delegation(&4, &1));
+			let reward = StakePallet::calc_block_rewards_delegator(stake, 2);
+			assert_eq!(StakePallet::get_unclaimed_staking_rewards(&3), reward);
+			assert!(StakePallet::get_unclaimed_staking_rewards(&4).is_zero());
+
+			roll_to(17, authors);
+			assert!(StakePallet::pending_delegations(1).is_none());
+			assert_eq!(StakePallet::get_unclaimed_staking_rewards(&3), 2 * reward);
+			assert_eq!(StakePallet::get_unclaimed_staking_rewards(&4), reward);
+		});
+}
+
+#[test]
+fn early_exit_penalty() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 300), (4, 200), (5, 100), (6, 100)])
+		.with_collators(vec![(1, 100)])
+		.with_delegators(vec![(2, 1, 100), (3, 1, 300)])
+		.build()
+		.execute_with(|| {
+			EarlyExitPenalty::set(Perbill::from_percent(10));
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(4), 1, 200));
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(5), 1, 100));
+
+			// the penalty is distributed among the other eligible delegators
+			assert_ok!(StakePallet::delegator_stake_less(RuntimeOrigin::signed(4), 1, 40));
+			assert!(events().contains(&Event::EarlyExitPenalized(4, 1, 4)));
+			assert_eq!(Balances::free_balance(2), 101);
+			assert_eq!(Balances::free_balance(3), 303);
+			// 5 is not yet eligible for rewards
+			assert_eq!(Balances::free_balance(5), 100);
+			assert_ok!(StakePallet::revoke_delegation(RuntimeOrigin::signed(4), 1));
+			assert!(events().contains(&Event::EarlyExitPenalized(4, 1, 16)));
+			assert_eq!(Balances::free_balance(2), 105);
+			assert_eq!(Balances::free_balance(3), 315);
+			assert!(Balances::free_balance(TREASURY_ACC).is_zero());
+
+			// the penalty is taken from the staked funds
+			assert_eq!(Balances::free_balance(4), 180);
+			assert_eq!(StakePallet::unstaking(4).get(&3), Some(&180));
+			assert_eq!(
+				Balances::locks(4),
+				vec![BalanceLock {
+					id: STAKING_ID,
+					amount: 180,
+					reasons: Reasons::All,
+				}]
+			);
+
+			// delegations older than MinDelegationDuration are not penalized
+			roll_to(10, vec![]);
+			assert_ok!(StakePallet::revoke_delegation(RuntimeOrigin::signed(5), 1));
+			assert_eq!(Balances::free_balance(5), 100);
+			assert_eq!(Balances::free_balance(2), 105);
+
+			// the penalty can be handed to the treasury instead
+			EarlyExitPenaltyDestination::set(PenaltyDestination::Treasury);
+			assert_ok!(StakePallet::join_delegators(RuntimeOrigin::signed(6), 1, 100));
+			assert_ok!(StakePallet::leave_delegators(RuntimeOrigin::signed(6)));
+			assert!(events().contains(&Event::EarlyExitPenalized(6, 1, 10)));
+			assert_eq!(Balances::free_balance(6), 90);
+			assert_eq!(Balances::free_balance(TREASURY_ACC), 10);
+			assert_eq!(Balances::free_balance(2), 105);
+		});
+}
+
+#[test]
+fn migrations_from_v8_to_v13() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+		.with_collators(vec![(1, 100), (2, 100)])
+		.with_delegators(vec![(3, 1, 100), (4, 1, 100), (5, 2, 100)])
+		.build()
+		.execute_with(|| {
+			// rewind the storage to version 8
+			let candidates: Vec<_> = CandidatePool::<Test>::iter_values().collect();
+			for candidate in candidates.iter() {
+				let old = CandidateOld::<AccountId, Balance, <Test as Config>::MaxDelegatorsPerCollator> {
+					id: candidate.id,
+					stake: candidate.stake,
+					delegators: candidate.delegators.clone(),
+					total: candidate.total,
+					status: candidate.status,
+				};
+				unhashed::put(&CandidatePool::<Test>::hashed_key_for(candidate.id), &old);
+			}
+			for (delegator, collator) in [(3, 1), (4, 1), (5, 2)] {
+				let old: StakeOf<Test> = Stake {
+					owner: collator,
+					amount: 100,
+				};
+				unhashed::put(&DelegatorState::<Test>::hashed_key_for(delegator), &old);
+				BlocksRewarded::<Test>::insert(delegator, delegator);
+			}
+			StakingParams::<Test>::kill();
+			let old_pending: BoundedVec<AccountId, <Test as Config>::MaxDelegatorsPerCollator> =
+				vec![3, 4].try_into().unwrap();
+			unhashed::put(&PendingDelegations::<Test>::hashed_key_for(1), &(2u32, old_pending));
+			let last_delegation_key = [
+				&storage_prefix(StakePallet::name().as_bytes(), b"LastDelegation")[..],
+				&1u64.encode()[..],
+			]
+			.concat();
+			unhashed::put(&last_delegation_key, &(2u32, 2u32));
+			Rewards::<Test>::insert(1, 10);
+			Rewards::<Test>::insert(3, 5);
+			OutstandingRewards::<Test>::kill();
+			StorageVersion::new(8).put::<StakePallet>();
+
+			<(
+				AddCollatorCommission<Test>,
+				MultipleDelegations<Test>,
+				MigrateStakingParams<Test>,
+				MigrateDelegationWarmUp<Test>,
+				MigrateOutstandingRewards<Test>,
+			) as OnRuntimeUpgrade>::on_runtime_upgrade();
+
+			assert_eq!(StakePallet::on_chain_storage_version(), StorageVersion::new(13));
+			assert_eq!(CandidatePool::<Test>::iter_values().collect::<Vec<_>>(), candidates);
+			for (delegator, collator) in [(3, 1), (4, 1), (5, 2)] {
+				assert_eq!(
+					StakePallet::delegator_state(delegator),
+					Some(Delegator::try_new(collator, 100).unwrap())
+				);
+				assert!(BlocksRewarded::<Test>::get(delegator).is_zero());
+				assert_eq!(DelegationBlocksRewarded::<Test>::get(delegator, collator), delegator);
+			}
+			assert!(StakingParams::<Test>::exists());
+			assert_eq!(StakePallet::staking_params(), StakePallet::initial_staking_params());
+			assert_eq!(
+				StakePallet::pending_delegations(1).unwrap().into_inner(),
+				vec![(3, 2), (4, 2)]
+			);
+			assert!(unhashed::get_raw(&last_delegation_key).is_none());
+			assert_eq!(StakePallet::outstanding_rewards(), 15);
+		});
+}
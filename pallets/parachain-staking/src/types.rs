@@ -23,11 +23,14 @@
 // Subject to the GPL-3.0 license.
 
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::traits::{Currency, Get};
+use frame_support::{
+	traits::{Currency, Get},
+	BoundedVec,
+};
 use scale_info::TypeInfo;
 use sp_runtime::{
 	traits::{AtLeast32BitUnsigned, CheckedSub, Saturating, Zero},
-	RuntimeDebug,
+	Perbill, Perquintill, RuntimeDebug,
 };
 use sp_staking::SessionIndex;
 use sp_std::{
@@ -99,6 +102,8 @@ pub enum CandidateStatus {
 	Active,
 	/// Staked until the inner round
 	Leaving(SessionIndex),
+	/// Demoted from the top candidates after missing too many rounds in a row
+	Offline,
 }
 
 impl Default for CandidateStatus {
@@ -134,6 +139,10 @@ where
 	/// The current status of the candidate. Indicates whether a candidate is
 	/// active or leaving the candidate pool
 	pub status: CandidateStatus,
+
+	/// The share of their delegators' rewards the candidate keeps for
+	/// themselves.
+	pub commission: Perbill,
 }
 
 impl<A, B, S> Candidate<A, B, S>
@@ -150,6 +159,7 @@ where
 			delegators: OrderedSet::new(),
 			total,
 			status: CandidateStatus::default(), // default active
+			commission: Perbill::zero(),
 		}
 	}
 
@@ -169,6 +179,18 @@ where
 		self.status = CandidateStatus::Active;
 	}
 
+	pub fn is_offline(&self) -> bool {
+		self.status == CandidateStatus::Offline
+	}
+
+	pub fn go_offline(&mut self) {
+		self.status = CandidateStatus::Offline;
+	}
+
+	pub fn go_online(&mut self) {
+		self.status = CandidateStatus::Active;
+	}
+
 	pub fn stake_more(&mut self, more: B) {
 		self.stake = self.stake.saturating_add(more);
 		self.total = self.total.saturating_add(more);
@@ -215,45 +237,115 @@ where
 	}
 }
 
-pub type Delegator<AccountId, Balance> = Stake<AccountId, Balance>;
-impl<AccountId, Balance> Delegator<AccountId, Balance>
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxCollatorsPerDelegator))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, Balance: MaxEncodedLen))]
+/// Global delegator state with the delegated collators and the total stake
+pub struct Delegator<AccountId, Balance, MaxCollatorsPerDelegator>
+where
+	AccountId: Eq + Ord + Debug,
+	Balance: Eq + Ord + Debug,
+	MaxCollatorsPerDelegator: Get<u32> + Debug + PartialEq,
+{
+	/// The collators the delegator backs together with the delegated amount.
+	pub delegations: OrderedSet<Stake<AccountId, Balance>, MaxCollatorsPerDelegator>,
+
+	/// The total amount the delegator staked.
+	///
+	/// Should equal the sum of all delegations.
+	pub total: Balance,
+}
+
+impl<AccountId, Balance, MaxCollatorsPerDelegator> Delegator<AccountId, Balance, MaxCollatorsPerDelegator>
 where
 	AccountId: Eq + Ord + Clone + Debug,
 	Balance: Copy + Add<Output = Balance> + Saturating + PartialOrd + Eq + Ord + Debug + Zero + Default + CheckedSub,
+	MaxCollatorsPerDelegator: Get<u32> + Debug + PartialEq,
 {
-	/// Returns Ok if the delegation for the
-	/// collator exists and `Err` otherwise.
-	pub fn try_clear(&mut self, collator: AccountId) -> Result<(), ()> {
-		if self.owner == collator {
-			self.amount = Balance::zero();
-			Ok(())
+	/// Returns Ok(delegator) with a single delegation and `Err` if the
+	/// maximum number of delegations is zero.
+	pub fn try_new(collator: AccountId, amount: Balance) -> Result<Self, ()> {
+		let mut delegator = Delegator {
+			delegations: OrderedSet::new(),
+			total: Balance::zero(),
+		};
+		delegator.try_add_delegation(Stake {
+			owner: collator,
+			amount,
+		})?;
+		Ok(delegator)
+	}
+
+	/// Returns the delegated amount for the collator if the delegation
+	/// exists.
+	pub fn delegation(&self, collator: &AccountId) -> Option<Balance> {
+		self.delegations
+			.linear_search(&Stake {
+				owner: collator.clone(),
+				amount: Balance::zero(),
+			})
+			.ok()
+			.map(|i| self.delegations[i].amount)
+	}
+
+	/// Returns Ok(true) if the delegation was added, Ok(false) if the
+	/// collator is already delegated and `Err` if the maximum number of
+	/// delegations would be exceeded.
+	pub fn try_add_delegation(&mut self, stake: Stake<AccountId, Balance>) -> Result<bool, ()> {
+		let amount = stake.amount;
+		if self.delegations.try_insert(stake).map_err(|_| ())? {
+			self.total = self.total.saturating_add(amount);
+			Ok(true)
 		} else {
-			Err(())
+			Ok(false)
 		}
 	}
 
+	/// Returns Some(delegated_amount) if the delegation for the collator was
+	/// removed and `None` if it was not found.
+	pub fn rm_delegation(&mut self, collator: AccountId) -> Option<Balance> {
+		let amount = self
+			.delegations
+			.remove(&Stake {
+				owner: collator,
+				amount: Balance::zero(),
+			})?
+			.amount;
+		self.total = self.total.saturating_sub(amount);
+		Some(amount)
+	}
+
 	/// Returns Ok(delegated_amount) if successful, `Err` if delegation was
 	/// not found.
 	pub fn try_increment(&mut self, collator: AccountId, more: Balance) -> Result<Balance, ()> {
-		if self.owner == collator {
-			self.amount = self.amount.saturating_add(more);
-			Ok(self.amount)
-		} else {
-			Err(())
-		}
+		let i = self
+			.delegations
+			.linear_search(&Stake {
+				owner: collator,
+				amount: Balance::zero(),
+			})
+			.map_err(|_| ())?;
+		let amount = self.delegations[i].amount.saturating_add(more);
+		self.delegations.mutate(|vec| vec[i].amount = amount);
+		self.total = self.total.saturating_add(more);
+		Ok(amount)
 	}
 
 	/// Returns Ok(Some(delegated_amount)) if successful, `Err` if delegation
 	/// was not found and Ok(None) if delegated stake would underflow.
 	pub fn try_decrement(&mut self, collator: AccountId, less: Balance) -> Result<Option<Balance>, ()> {
-		if self.owner == collator {
-			Ok(self.amount.checked_sub(&less).map(|new| {
-				self.amount = new;
-				self.amount
-			}))
-		} else {
-			Err(())
-		}
+		let i = self
+			.delegations
+			.linear_search(&Stake {
+				owner: collator,
+				amount: Balance::zero(),
+			})
+			.map_err(|_| ())?;
+		Ok(self.delegations[i].amount.checked_sub(&less).map(|amount| {
+			self.delegations.mutate(|vec| vec[i].amount = amount);
+			self.total = self.total.saturating_sub(less);
+			amount
+		}))
 	}
 }
 
@@ -310,18 +402,109 @@ pub struct TotalStake<Balance: Default> {
 	pub delegators: Balance,
 }
 
-/// The number of delegations a delegator has done within the last session in
-/// which they delegated.
-#[derive(Default, Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
-pub struct DelegationCounter {
-	/// The index of the last delegation.
-	pub round: SessionIndex,
-	/// The number of delegations made within round.
-	pub counter: u32,
+/// The recipient of the penalty for withdrawing stake from a delegation
+/// within `MinDelegationDuration` rounds after it was made.
+#[derive(Clone, Copy, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum PenaltyDestination {
+	/// The other delegators of the collator which are eligible for rewards,
+	/// in proportion to their stake.
+	Delegators,
+	/// `EarlyExitPenaltyBeneficiary`, e.g. the treasury.
+	Treasury,
+}
+
+/// The rules for penalising selected collators which do not author blocks.
+///
+/// A `max_missed_rounds` of zero disables liveness tracking.
+#[derive(Default, Clone, Copy, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct LivenessPolicy {
+	/// The number of consecutive rounds without an authored block after
+	/// which a selected collator is demoted.
+	pub max_missed_rounds: u32,
+	/// The fraction of stake which is slashed upon demotion.
+	pub offline_slash: Perbill,
+	/// Whether the offline slash also applies to the delegators' stake.
+	pub slash_delegators: bool,
+}
+
+/// A slash which is applied to a collator once its deferral window has
+/// passed.
+#[derive(Clone, Copy, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PendingSlash {
+	/// The fraction of the stake which is slashed.
+	pub fraction: Perbill,
+	/// Whether the delegators' stake is slashed as well.
+	pub slash_delegators: bool,
+}
+
+/// The metadata a collator candidate publishes about themselves.
+#[derive(Encode, Decode, Clone, RuntimeDebug, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(MaxLength))]
+#[codec(mel_bound())]
+pub struct CollatorMetadata<MaxLength>
+where
+	MaxLength: Get<u32> + Debug + PartialEq,
+{
+	/// The display name of the candidate.
+	pub name: BoundedVec<u8, MaxLength>,
+	/// The website of the candidate.
+	pub url: BoundedVec<u8, MaxLength>,
+	/// Contact details, e.g. an email address or a chat handle.
+	pub contact: BoundedVec<u8, MaxLength>,
+	/// The region in which the collator node is operated.
+	pub region: BoundedVec<u8, MaxLength>,
+	/// The version of the node software the collator runs.
+	pub node_version: BoundedVec<u8, MaxLength>,
+}
+
+impl<MaxLength> CollatorMetadata<MaxLength>
+where
+	MaxLength: Get<u32> + Debug + PartialEq,
+{
+	/// Returns the number of bytes of all fields together.
+	pub fn total_len(&self) -> usize {
+		self.name.len() + self.url.len() + self.contact.len() + self.region.len() + self.node_version.len()
+	}
+}
+
+/// The staking parameters which can be changed by governance without a
+/// runtime upgrade.
+#[derive(Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct StakingParameters<Balance, BlockNumber> {
+	/// Minimum stake required for any account to be elected as validator
+	/// for a round.
+	pub min_collator_stake: Balance,
+	/// Minimum stake required for a delegation.
+	pub min_delegator_stake: Balance,
+	/// Maximum number of delegators a single collator can have. Bounded by
+	/// `Config::MaxDelegatorsPerCollator`.
+	pub max_delegators_per_collator: u32,
+	/// Number of blocks for which unstaked balance remains locked.
+	pub stake_duration: BlockNumber,
+	/// Number of rounds a collator has to stay active after requesting to
+	/// leave the set of candidates.
+	pub exit_queue_delay: u32,
+	/// The rate for the network rewards.
+	pub network_reward_rate: Perquintill,
+}
+
+/// The minimum stakes which remain in effect for existing collators and
+/// delegations after governance raised them.
+#[derive(Clone, Encode, Decode, Eq, MaxEncodedLen, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MinStakeGrace<Balance> {
+	/// The minimum collator stake before it was raised.
+	pub min_collator_stake: Balance,
+	/// The minimum delegator stake before it was raised.
+	pub min_delegator_stake: Balance,
+	/// The round from which the raised minimums are enforced.
+	pub ends_at: SessionIndex,
 }
 
 pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
 pub type CandidateOf<T, S> = Candidate<AccountIdOf<T>, BalanceOf<T>, S>;
 pub type StakeOf<T> = Stake<AccountIdOf<T>, BalanceOf<T>>;
-pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
\ No newline at end of file
+pub type DelegatorOf<T> = Delegator<AccountIdOf<T>, BalanceOf<T>, <T as Config>::MaxCollatorsPerDelegator>;
+pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::NegativeImbalance;
+pub type StakingParametersOf<T> = StakingParameters<BalanceOf<T>, <T as frame_system::Config>::BlockNumber>;
+pub type CollatorMetadataOf<T> = CollatorMetadata<<T as Config>::MaxMetadataLength>;
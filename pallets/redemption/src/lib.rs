@@ -0,0 +1,361 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! # Redemption pallet
+//!
+//! ## Overview
+//!
+//! Buyback of all pieces of a RFT token and retirement of the token.
+//!
+//! - Collection owner or admin (`Issuer`) starts redemption of a token, escrowing
+//!   `price_per_piece * TotalSupply` in the chosen currency.
+//! - The token is frozen: its pieces can't be transferred, repartitioned or moved into escrow accounts.
+//! - Pieces of every holder are burned and paid out at `price_per_piece`, either
+//!   by the holder claiming the payout or by anyone redeeming holders in bounded batches.
+//! - Pieces held in escrow on behalf of others (e.g. wrapped or offered pieces) are not redeemed
+//!   from the escrow account; they have to be moved out to their owners first, who redeem them as usual.
+//! - Once the last piece is burned the token is marked retired and the rest of
+//!   the escrow (if any) is returned to the issuer.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::MaxEncodedLen;
+use scale_info::TypeInfo;
+
+use frame_support::{pallet_prelude::*, traits::Contains, PalletId};
+use frame_system::pallet_prelude::*;
+
+use orml_traits::MultiCurrency;
+
+use sp_runtime::{
+	traits::{AccountIdConversion, Zero},
+	ArithmeticError
+};
+use sp_std::prelude::*;
+
+use collection_primitives::{CollectionId, TokenId};
+use pallet_common::Error as CommonError;
+use pallet_refungible::{
+	RefungibleHandle, TokenBalance, Pallet as PalletRefungible,
+	Balance as PiecesBalance, TotalSupply
+};
+use primitives::Balance;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+type CurrencyIdOf<T> = <<T as Config>::MultiCurrency as MultiCurrency<<T as frame_system::Config>::AccountId>>::CurrencyId;
+type RedemptionInfoOf<T> = RedemptionInfo<<T as frame_system::Config>::AccountId, CurrencyIdOf<T>>;
+
+/// Redemption state
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen, Debug)]
+pub enum RedemptionStatus {
+	/// Holders are being paid out
+	Active,
+	/// All pieces are burned, token is retired
+	Retired,
+}
+
+/// Redemption of RFT token
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct RedemptionInfo<AccountId, CurrencyId> {
+	/// Account funding the redemption
+	pub issuer: AccountId,
+	/// Currency holders are paid in
+	pub currency_id: CurrencyId,
+	/// Payout for one piece
+	pub price_per_piece: Balance,
+	pub status: RedemptionStatus,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_refungible::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Currency holders are paid in
+		type MultiCurrency: MultiCurrency<Self::AccountId, Balance = Balance>;
+
+		/// Redemption escrow accounts are derived from this id
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Maximum number of holders redeemed by one `redeem_batch` call
+		#[pallet::constant]
+		type MaxRedemptionBatch: Get<u32>;
+
+		/// Accounts holding pieces in escrow on behalf of others, skipped by `redeem_batch`
+		type EscrowAccounts: Contains<Self::AccountId>;
+
+		type WeightInfo: weights::WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// ([CollectionId], [TokenId]) mapped to [RedemptionInfo]
+	#[pallet::storage]
+	#[pallet::getter(fn redemption)]
+	pub type Redemptions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectionId,
+		Twox64Concat,
+		TokenId,
+		RedemptionInfoOf<T>
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Redemption started, payout for all pieces escrowed
+		RedemptionStarted {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			issuer: T::AccountId,
+			currency_id: CurrencyIdOf<T>,
+			price_per_piece: Balance,
+			amount: Balance,
+		},
+		/// Holder pieces burned and paid out
+		Redeemed {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			who: T::AccountId,
+			pieces: TokenBalance,
+			amount: Balance,
+		},
+		/// All pieces redeemed, token retired
+		TokenRetired {
+			collection_id: CollectionId,
+			token_id: TokenId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Price can't be zero
+		InvalidPrice,
+		/// Token is already redeemed or retired
+		RedemptionExists,
+		/// Token isn't redeemed
+		RedemptionNotFound,
+		/// Token is already retired
+		TokenRetired,
+		/// Account holds no pieces of the token
+		NothingToRedeem,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Start redemption of RFT token.
+		///
+		/// `price_per_piece * TotalSupply` is moved from sender to the escrow account and the token is frozen.
+		/// Sender must be owner or admin of the collection.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::start_redemption())]
+		pub fn start_redemption(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			currency_id: CurrencyIdOf<T>,
+			price_per_piece: Balance,
+		) -> DispatchResult {
+			let issuer = ensure_signed(origin)?;
+
+			let collection = RefungibleHandle::<T>::try_get(collection_id)?;
+			collection.check_is_owner_or_admin(&issuer)?;
+
+			ensure!(!price_per_piece.is_zero(), Error::<T>::InvalidPrice);
+			ensure!(
+				!<Redemptions<T>>::contains_key(collection_id, token_id),
+				Error::<T>::RedemptionExists
+			);
+
+			let total_supply = <TotalSupply<T>>::try_get((collection_id, token_id))
+				.map_err(|_| <CommonError<T>>::TokenNotFound)?;
+			let amount = price_per_piece
+				.checked_mul(total_supply)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			T::MultiCurrency::transfer(
+				currency_id,
+				&issuer,
+				&Self::redemption_account(collection_id, token_id),
+				amount
+			)?;
+			<PalletRefungible<T>>::set_token_frozen(collection_id, token_id, true);
+
+			<Redemptions<T>>::insert(collection_id, token_id, RedemptionInfo {
+				issuer: issuer.clone(),
+				currency_id,
+				price_per_piece,
+				status: RedemptionStatus::Active,
+			});
+
+			Self::deposit_event(Event::RedemptionStarted {
+				collection_id,
+				token_id,
+				issuer,
+				currency_id,
+				price_per_piece,
+				amount
+			});
+
+			Ok(())
+		}
+
+		/// Burn all pieces of the sender and pay them out.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_redemption())]
+		pub fn claim_redemption(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut redemption = Self::active_redemption(collection_id, token_id)?;
+
+			ensure!(
+				!<PiecesBalance<T>>::get((collection_id, token_id, &who)).is_zero(),
+				Error::<T>::NothingToRedeem
+			);
+			Self::redeem(collection_id, token_id, &redemption, &who)?;
+
+			Self::try_retire(collection_id, token_id, &mut redemption)
+		}
+
+		/// Burn and pay out pieces of up to `MaxRedemptionBatch` holders.
+		///
+		/// Escrow accounts and empty balances are skipped.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeem_batch(T::MaxRedemptionBatch::get()))]
+		pub fn redeem_batch(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let mut redemption = Self::active_redemption(collection_id, token_id)?;
+
+			let holders: Vec<T::AccountId> = <PiecesBalance<T>>::iter_prefix((collection_id, token_id))
+				.filter(|(holder, balance)| !balance.is_zero() && !T::EscrowAccounts::contains(holder))
+				.take(T::MaxRedemptionBatch::get() as usize)
+				.map(|(holder, _)| holder)
+				.collect();
+
+			for holder in holders.iter() {
+				Self::redeem(collection_id, token_id, &redemption, holder)?;
+			}
+
+			Self::try_retire(collection_id, token_id, &mut redemption)?;
+
+			Ok(Some(<T as Config>::WeightInfo::redeem_batch(holders.len() as u32)).into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Escrow account holding payout of the token redemption
+	pub fn redemption_account(collection_id: CollectionId, token_id: TokenId) -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating((collection_id, token_id))
+	}
+
+	fn active_redemption(collection_id: CollectionId, token_id: TokenId) -> Result<RedemptionInfoOf<T>, DispatchError> {
+		let redemption = Self::redemption(collection_id, token_id).ok_or(Error::<T>::RedemptionNotFound)?;
+		ensure!(redemption.status == RedemptionStatus::Active, Error::<T>::TokenRetired);
+
+		Ok(redemption)
+	}
+
+	// Burn all pieces of the holder and pay them out from the escrow
+	fn redeem(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		redemption: &RedemptionInfoOf<T>,
+		who: &T::AccountId,
+	) -> DispatchResult {
+		let pieces = <PalletRefungible<T>>::burn_all(collection_id, who, token_id)?;
+		let amount = redemption.price_per_piece
+			.checked_mul(pieces)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		T::MultiCurrency::transfer(
+			redemption.currency_id,
+			&Self::redemption_account(collection_id, token_id),
+			who,
+			amount
+		)?;
+
+		Self::deposit_event(Event::Redeemed {
+			collection_id,
+			token_id,
+			who: who.clone(),
+			pieces,
+			amount
+		});
+
+		Ok(())
+	}
+
+	// Retire the token once its last piece is burned, returning what is left in the escrow to the issuer
+	fn try_retire(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		redemption: &mut RedemptionInfoOf<T>,
+	) -> DispatchResult {
+		if <TotalSupply<T>>::contains_key((collection_id, token_id)) {
+			return Ok(());
+		}
+
+		let account = Self::redemption_account(collection_id, token_id);
+		let rest = T::MultiCurrency::free_balance(redemption.currency_id, &account);
+		if !rest.is_zero() {
+			T::MultiCurrency::transfer(redemption.currency_id, &account, &redemption.issuer, rest)?;
+		}
+
+		redemption.status = RedemptionStatus::Retired;
+		<Redemptions<T>>::insert(collection_id, token_id, redemption.clone());
+		<PalletRefungible<T>>::set_token_frozen(collection_id, token_id, false);
+
+		Self::deposit_event(Event::TokenRetired {
+			collection_id,
+			token_id
+		});
+
+		Ok(())
+	}
+}
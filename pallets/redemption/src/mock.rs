@@ -0,0 +1,263 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use codec::{Encode, Decode, MaxEncodedLen};
+use frame_support::{
+	construct_runtime, ord_parameter_types, parameter_types, PalletId,
+	traits::{ConstU32, Everything, IsInVec, Nothing}
+};
+use frame_system::EnsureSignedBy;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	DispatchError, RuntimeDebug,
+	traits::{AccountIdLookup, BlakeTwo256}
+};
+
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+use pallet_whitelist::{Investor, InvestorKey};
+pub use pallet_common::{
+	Event as CommonEvent,
+	collection_initializer::CollectionInitializer
+};
+use pallet_refungible::{CreateItemData, TokenBalance};
+
+use primitives::{
+	Amount, Index, BlockNumber, Hash, Header, BlockHashCount
+};
+pub use collection_primitives::{CollectionId, TokenId};
+
+pub use mock_support::primitives::*;
+pub use mock_support::accounts::*;
+pub use mock_support::consts::*;
+
+mod pallet_redemption {
+	pub use super::super::*;
+}
+
+pub const ROLES_ROOT: AccountId = 777;
+pub const ADMIN_1: AccountId = 101;
+pub const ISSUER: AccountId = ADMIN_1;
+pub const ESCROW: AccountId = 555;
+
+#[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum CurrencyId {
+	CGT,
+	DAI,
+}
+
+pub const DAI: CurrencyId = CurrencyId::DAI;
+
+impl frame_system::Config for MockRuntime {
+	type AccountId = AccountId;
+	type RuntimeCall = RuntimeCall;
+	type Lookup = AccountIdLookup<AccountId, ()>;
+	type Index = Index;
+	type BlockNumber = BlockNumber;
+	type Hash = Hash;
+	type Hashing = BlakeTwo256;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<Balance>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type DbWeight = ();
+	type BaseCallFilter = Everything;
+	type SystemWeightInfo = ();
+	type BlockWeights = ();
+	type BlockLength = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: Balance = 2;
+}
+
+impl pallet_balances::Config for MockRuntime {
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type MaxHolds = ();
+	type Balance = Balance;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type HoldIdentifier = ();
+	type ReserveIdentifier = [u8; 8];
+}
+
+orml_traits::parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type DustRemovalWhitelist = Nothing;
+}
+
+ord_parameter_types! {
+	pub const RolesRootAccountId: AccountId = ROLES_ROOT;
+}
+
+impl pallet_whitelist::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type RolesRoot = EnsureSignedBy<RolesRootAccountId, AccountId>;
+	type OffchainSignature = sp_runtime::testing::TestSignature;
+	type OffchainPublic = sp_runtime::testing::UintAuthorityId;
+	type MaxKycAttributesLength = frame_support::traits::ConstU32<256>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const CollectionCreationPrice: Balance = 0;
+	pub const TreasuryAccountId: AccountId = 666;
+}
+
+impl pallet_common::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type CollectionCreationPrice = CollectionCreationPrice;
+	type TreasuryAccountId = TreasuryAccountId;
+	type Whitelist = Whitelist;
+}
+
+impl pallet_refungible::Config for MockRuntime {
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const RedemptionPalletId: PalletId = PalletId(*b"cur/rdmp");
+	pub const MaxRedemptionBatch: u32 = 2;
+	pub EscrowAccounts: Vec<AccountId> = vec![ESCROW];
+}
+
+impl pallet_redemption::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type MultiCurrency = Tokens;
+	type PalletId = RedemptionPalletId;
+	type MaxRedemptionBatch = MaxRedemptionBatch;
+	type EscrowAccounts = IsInVec<EscrowAccounts>;
+	type WeightInfo = ();
+}
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<MockRuntime>;
+type Block = frame_system::mocking::MockBlock<MockRuntime>;
+
+construct_runtime! {
+	pub enum MockRuntime where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Whitelist: pallet_whitelist,
+		Common: pallet_common,
+		Balances: pallet_balances,
+		Tokens: orml_tokens,
+		Refungible: pallet_refungible,
+		Redemption: pallet_redemption,
+	}
+}
+
+pub struct ExtBuilder {
+	investors: Vec<AccountId>,
+	tokens_balances: Vec<(AccountId, CurrencyId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		Self {
+			investors: vec![ALICE, BOB, CHARLIE],
+			tokens_balances: vec![
+				(ISSUER, DAI, 1_000 * DOLLARS),
+			],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn build(self) -> sp_io::TestExternalities {
+		use frame_support::traits::GenesisBuild;
+
+		let mut t = frame_system::GenesisConfig::default()
+			.build_storage::<MockRuntime>()
+			.unwrap();
+
+		let investors: Vec<(InvestorKey, Investor<AccountId>)> = self.investors
+			.iter()
+			.enumerate()
+			.map(|(i, account)| ([i as u8; 32], Investor { account: *account, is_active: true }))
+			.collect();
+
+		WhitelistConfig {
+			investors,
+			admins: vec![ADMIN_1],
+			..Default::default()
+		}.assimilate_storage(&mut t).unwrap();
+
+		orml_tokens::GenesisConfig::<MockRuntime> {
+			balances: self.tokens_balances
+		}.assimilate_storage(&mut t).unwrap();
+
+		let mut ext = sp_io::TestExternalities::new(t);
+		ext.execute_with(|| System::set_block_number(1));
+		ext
+	}
+}
+
+/// Create collection owned by [ISSUER] with token of given balances
+pub fn create_token(balances: Vec<(AccountId, TokenBalance)>) -> Result<(CollectionId, TokenId), DispatchError> {
+	let collection_id = CollectionInitializer::<MockRuntime>::new().init_default(ISSUER)?;
+
+	let data = CreateItemData::<AccountId> {
+		balances,
+		properties: vec![]
+	};
+	Refungible::create_item(RuntimeOrigin::signed(ISSUER), collection_id, data)?;
+
+	Ok((collection_id, TokenId(1)))
+}
@@ -0,0 +1,234 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+
+use crate::mock::*;
+use crate::{Error, Event, RedemptionStatus, Redemptions};
+
+const PRICE: Balance = 2 * DOLLARS;
+
+fn start_redemption(collection_id: CollectionId, token_id: TokenId) {
+	assert_ok!(Redemption::start_redemption(
+		RuntimeOrigin::signed(ISSUER),
+		collection_id,
+		token_id,
+		DAI,
+		PRICE
+	));
+}
+
+fn token_exists(collection_id: CollectionId, token_id: TokenId) -> bool {
+	pallet_refungible::TotalSupply::<MockRuntime>::contains_key((collection_id, token_id))
+}
+
+#[test]
+fn start_redemption_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10), (BOB, 20)]).unwrap();
+
+		start_redemption(collection_id, token_id);
+
+		System::assert_last_event(RuntimeEvent::Redemption(Event::RedemptionStarted {
+			collection_id,
+			token_id,
+			issuer: ISSUER,
+			currency_id: DAI,
+			price_per_piece: PRICE,
+			amount: 60 * DOLLARS
+		}));
+
+		let escrow = Redemption::redemption_account(collection_id, token_id);
+		assert_eq!(Tokens::free_balance(DAI, &escrow), 60 * DOLLARS);
+		assert_eq!(Tokens::free_balance(DAI, &ISSUER), 940 * DOLLARS);
+		assert_eq!(
+			Redemptions::<MockRuntime>::get(collection_id, token_id).unwrap().status,
+			RedemptionStatus::Active
+		);
+	});
+}
+
+#[test]
+fn start_redemption_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10), (BOB, 20)]).unwrap();
+
+		assert_noop!(
+			Redemption::start_redemption(RuntimeOrigin::signed(ALICE), collection_id, token_id, DAI, PRICE),
+			pallet_common::Error::<MockRuntime>::NoPermission
+		);
+		assert_noop!(
+			Redemption::start_redemption(RuntimeOrigin::signed(ISSUER), collection_id, token_id, DAI, 0),
+			Error::<MockRuntime>::InvalidPrice
+		);
+		assert_noop!(
+			Redemption::start_redemption(RuntimeOrigin::signed(ISSUER), collection_id, TokenId(2), DAI, PRICE),
+			pallet_common::Error::<MockRuntime>::TokenNotFound
+		);
+
+		start_redemption(collection_id, token_id);
+
+		assert_noop!(
+			Redemption::start_redemption(RuntimeOrigin::signed(ISSUER), collection_id, token_id, DAI, PRICE),
+			Error::<MockRuntime>::RedemptionExists
+		);
+	});
+}
+
+#[test]
+fn claim_redemption_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10), (BOB, 20)]).unwrap();
+
+		assert_noop!(
+			Redemption::claim_redemption(RuntimeOrigin::signed(ALICE), collection_id, token_id),
+			Error::<MockRuntime>::RedemptionNotFound
+		);
+
+		start_redemption(collection_id, token_id);
+
+		assert_noop!(
+			Redemption::claim_redemption(RuntimeOrigin::signed(CHARLIE), collection_id, token_id),
+			Error::<MockRuntime>::NothingToRedeem
+		);
+
+		assert_ok!(Redemption::claim_redemption(RuntimeOrigin::signed(ALICE), collection_id, token_id));
+		System::assert_last_event(RuntimeEvent::Redemption(Event::Redeemed {
+			collection_id,
+			token_id,
+			who: ALICE,
+			pieces: 10,
+			amount: 20 * DOLLARS
+		}));
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 20 * DOLLARS);
+		assert!(token_exists(collection_id, token_id));
+
+		assert_ok!(Redemption::claim_redemption(RuntimeOrigin::signed(BOB), collection_id, token_id));
+		System::assert_last_event(RuntimeEvent::Redemption(Event::TokenRetired {
+			collection_id,
+			token_id
+		}));
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 40 * DOLLARS);
+		assert!(!token_exists(collection_id, token_id));
+		assert_eq!(
+			Redemptions::<MockRuntime>::get(collection_id, token_id).unwrap().status,
+			RedemptionStatus::Retired
+		);
+
+		assert_noop!(
+			Redemption::claim_redemption(RuntimeOrigin::signed(BOB), collection_id, token_id),
+			Error::<MockRuntime>::TokenRetired
+		);
+	});
+}
+
+#[test]
+fn redeem_batch_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10), (BOB, 20), (CHARLIE, 30)]).unwrap();
+		start_redemption(collection_id, token_id);
+
+		// MaxRedemptionBatch holders at a time
+		assert_ok!(Redemption::redeem_batch(RuntimeOrigin::signed(EVE), collection_id, token_id));
+		assert!(token_exists(collection_id, token_id));
+		assert_eq!(pallet_refungible::TokenHolders::<MockRuntime>::get((collection_id, token_id)), 1);
+
+		assert_ok!(Redemption::redeem_batch(RuntimeOrigin::signed(EVE), collection_id, token_id));
+		assert!(!token_exists(collection_id, token_id));
+
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 20 * DOLLARS);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 40 * DOLLARS);
+		assert_eq!(Tokens::free_balance(DAI, &CHARLIE), 60 * DOLLARS);
+		assert_eq!(Tokens::free_balance(DAI, &ISSUER), 880 * DOLLARS);
+
+		assert_noop!(
+			Redemption::redeem_batch(RuntimeOrigin::signed(EVE), collection_id, token_id),
+			Error::<MockRuntime>::TokenRetired
+		);
+	});
+}
+
+#[test]
+fn rest_of_escrow_returned_on_retirement() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ISSUER, 10)]).unwrap();
+		start_redemption(collection_id, token_id);
+
+		assert_ok!(Refungible::burn(RuntimeOrigin::signed(ISSUER), collection_id, token_id, 5));
+		assert_ok!(Redemption::claim_redemption(RuntimeOrigin::signed(ISSUER), collection_id, token_id));
+
+		let escrow = Redemption::redemption_account(collection_id, token_id);
+		assert_eq!(Tokens::free_balance(DAI, &escrow), 0);
+		assert_eq!(Tokens::free_balance(DAI, &ISSUER), 1_000 * DOLLARS);
+	});
+}
+
+#[test]
+fn token_is_frozen_during_redemption() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ISSUER, 10), (ALICE, 10)]).unwrap();
+		start_redemption(collection_id, token_id);
+
+		assert!(Refungible::is_frozen((collection_id, token_id)));
+		assert_noop!(
+			Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB, 5),
+			pallet_refungible::Error::<MockRuntime>::TokenFrozen
+		);
+		assert_noop!(
+			Refungible::repartition(RuntimeOrigin::signed(ISSUER), collection_id, token_id, 20),
+			pallet_refungible::Error::<MockRuntime>::TokenFrozen
+		);
+		assert_noop!(
+			Refungible::escrow_transfer(collection_id, &ESCROW, &ALICE, &ESCROW, token_id, 5),
+			pallet_refungible::Error::<MockRuntime>::TokenFrozen
+		);
+
+		assert_ok!(Redemption::redeem_batch(RuntimeOrigin::signed(EVE), collection_id, token_id));
+		assert!(!token_exists(collection_id, token_id));
+		assert!(!Refungible::is_frozen((collection_id, token_id)));
+	});
+}
+
+#[test]
+fn redeem_batch_skips_escrow_accounts() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10), (BOB, 20)]).unwrap();
+		assert_ok!(Refungible::escrow_transfer(collection_id, &ESCROW, &ALICE, &ESCROW, token_id, 4));
+		start_redemption(collection_id, token_id);
+
+		assert_ok!(Redemption::redeem_batch(RuntimeOrigin::signed(EVE), collection_id, token_id));
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 12 * DOLLARS);
+		assert_eq!(Tokens::free_balance(DAI, &BOB), 40 * DOLLARS);
+		assert_eq!(Tokens::free_balance(DAI, &ESCROW), 0);
+		assert!(token_exists(collection_id, token_id));
+
+		// escrowed pieces are returned to the owner and redeemed by them
+		assert_ok!(Refungible::escrow_transfer(collection_id, &ESCROW, &ESCROW, &ALICE, token_id, 4));
+		assert_ok!(Redemption::claim_redemption(RuntimeOrigin::signed(ALICE), collection_id, token_id));
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 20 * DOLLARS);
+		assert!(!token_exists(collection_id, token_id));
+	});
+}
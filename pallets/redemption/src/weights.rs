@@ -0,0 +1,87 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Weights for pallet_redemption
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::weights::constants::RocksDbWeight;
+
+/// Weight functions needed for pallet_redemption.
+pub trait WeightInfo {
+	fn start_redemption() -> Weight;
+	fn claim_redemption() -> Weight;
+	fn redeem_batch(i: u32, ) -> Weight;
+}
+
+impl WeightInfo for () {
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common IsAdmin (r:1 w:0)
+	// Storage: Redemption Redemptions (r:1 w:1)
+	// Storage: Refungible TotalSupply (r:1 w:0)
+	// Storage: Tokens Accounts (r:2 w:2)
+	fn start_redemption() -> Weight {
+		Weight::from_parts(45_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	// Storage: Redemption Redemptions (r:1 w:1)
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Refungible Balance (r:1 w:1)
+	// Storage: Refungible TotalSupply (r:2 w:1)
+	// Storage: Refungible AccountBalance (r:1 w:1)
+	// Storage: Refungible TokenHolders (r:1 w:1)
+	// Storage: Refungible TokensBurnt (r:1 w:1)
+	// Storage: Refungible Owned (r:0 w:1)
+	// Storage: Refungible TokenProperties (r:0 w:1)
+	// Storage: Refungible TokenHoldingLimits (r:0 w:1)
+	// Storage: Tokens Accounts (r:3 w:3)
+	fn claim_redemption() -> Weight {
+		Weight::from_parts(78_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().writes(12 as u64))
+	}
+	// Storage: Redemption Redemptions (r:1 w:1)
+	// Storage: Refungible Balance (r:1 w:1)
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Refungible TotalSupply (r:2 w:1)
+	// Storage: Refungible AccountBalance (r:1 w:1)
+	// Storage: Refungible TokenHolders (r:1 w:1)
+	// Storage: Refungible TokensBurnt (r:1 w:1)
+	// Storage: Refungible Owned (r:0 w:1)
+	// Storage: Refungible TokenProperties (r:0 w:1)
+	// Storage: Refungible TokenHoldingLimits (r:0 w:1)
+	// Storage: Tokens Accounts (r:3 w:3)
+	/// The range of component `i` is `[1, 100]`.
+	fn redeem_batch(i: u32, ) -> Weight {
+		Weight::from_parts(40_000_000 as u64, 0)
+			.saturating_add(Weight::from_parts(36_000_000 as u64, 0).saturating_mul(i as u64))
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().reads((5 as u64).saturating_mul(i as u64)))
+			.saturating_add(RocksDbWeight::get().writes(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes((5 as u64).saturating_mul(i as u64)))
+	}
+}
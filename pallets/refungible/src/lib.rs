@@ -100,16 +100,16 @@ use frame_support::{
 	dispatch::{DispatchResult}
 };
 use pallet_common::{
-	Error as CommonError,
+	BalanceOf, Error as CommonError,
 	Event as CommonEvent, Pallet as PalletCommon,
 };
 use sp_runtime::{ArithmeticError, DispatchError, TransactionOutcome};
 use sp_std::{vec::Vec, vec, collections::{btree_map::BTreeMap}, fmt::Debug};
 use collection_primitives::{
-	CollectionId, CollectionPropertiesVec,
-	CreateCollectionData, MAX_ITEMS_PER_BATCH, MAX_PROPERTIES_PER_ITEM,
-	MAX_REFUNGIBLE_PIECES, Property, PropertyKey, PropertyKeyPermission, PropertyPermission,
-	PropertyScope, PropertyValue, TokenId, TrySetProperty,
+	CollectionId, CollectionMode, CollectionPropertiesVec,
+	CreateCollectionData, HoldingLimits, MAX_ITEMS_PER_BATCH, MAX_PROPERTIES_PER_ITEM,
+	MAX_REFUNGIBLE_PIECES, Property, PropertyKey, PropertyKeyPermission,
+	PropertyScope, PropertyValue, SponsorshipSettings, TokenId, TrySetProperty,
 };
 
 use pallet_whitelist::{
@@ -120,11 +120,15 @@ pub use weights::WeightInfo;
 
 pub use pallet::*;
 pub mod weights;
+pub mod migration;
 pub mod mock;
 pub mod tests;
 
 pub type TokenBalance = u128;
 
+/// Maximum amount of entries returned by a single page of enumeration queries.
+pub const MAX_PAGE_SIZE: u32 = 1000;
+
 #[derive(Encode, Decode, Debug, Clone, PartialEq, TypeInfo)]
 pub struct CreateItemData<AccountId> {
 	pub balances: Vec<(AccountId, TokenBalance)>,
@@ -186,6 +190,10 @@ impl<T: Config> RefungibleHandle<T> {
 	
 	pub fn try_get(collection_id: CollectionId) -> Result<Self, DispatchError> {
 		let common_collection = pallet_common::CollectionHandle::<T>::try_get(collection_id)?;
+		ensure!(
+			common_collection.mode == CollectionMode::ReFungible,
+			<CommonError<T>>::UnsupportedOperation
+		);
 
 		Ok(RefungibleHandle::cast(common_collection))
 	}
@@ -230,6 +238,18 @@ pub mod pallet {
 		RefungibleDisallowsNesting,
 		/// Setting item properties is not allowed.
 		SettingPropertiesNotAllowed,
+		/// Holding limits can't be zero.
+		InvalidHoldingLimits,
+		/// Token already has the maximum amount of holders.
+		TokenHoldersLimitExceeded,
+		/// Account would hold less pieces than allowed.
+		HoldingBelowMinimum,
+		/// Amount of pieces is not a multiple of the transfer increment.
+		AmountNotMultipleOfIncrement,
+		/// Token is frozen, its pieces can't be moved or repartitioned.
+		TokenFrozen,
+		/// Given number of tokens is lower than the number of tokens in the collection.
+		TokenCountUnderestimated,
 	}
 
 	// LOG: pallet_structure::Config unpluged
@@ -240,7 +260,7 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -287,6 +307,20 @@ pub mod pallet {
 		QueryKind = ValueQuery,
 	>;
 
+	/// Used to enumerate tokens partially owned by account across all collections.
+	///
+	/// Mirrors [`Owned`] with the account moved to the front of the key.
+	#[pallet::storage]
+	pub type AccountTokens<T: Config> = StorageNMap<
+		Key = (
+			Key<Blake2_128Concat, T::AccountId>,
+			Key<Twox64Concat, CollectionId>,
+			Key<Twox64Concat, TokenId>,
+		),
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
 	/// Amount of tokens (not pieces) partially owned by an account within a collection.
 	// LOG: CrossAccountId -> AccountId
 	#[pallet::storage]
@@ -330,6 +364,42 @@ pub mod pallet {
 		QueryKind = ValueQuery,
 	>;
 
+	/// Amount of accounts holding pieces of a token.
+	#[pallet::storage]
+	pub type TokenHolders<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = u32,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Holding limits applied to all tokens of a collection.
+	#[pallet::storage]
+	#[pallet::getter(fn collection_holding_limits)]
+	pub type CollectionHoldingLimits<T: Config> = StorageMap<
+		Hasher = Twox64Concat,
+		Key = CollectionId,
+		Value = HoldingLimits,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Holding limits of a token. Set fields override collection holding limits.
+	#[pallet::storage]
+	#[pallet::getter(fn token_holding_limits)]
+	pub type TokenHoldingLimits<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = HoldingLimits,
+		QueryKind = ValueQuery,
+	>;
+
+	/// Tokens frozen by another pallet, e.g. during redemption.
+	#[pallet::storage]
+	#[pallet::getter(fn is_frozen)]
+	pub type FrozenTokens<T: Config> = StorageNMap<
+		Key = (Key<Twox64Concat, CollectionId>, Key<Twox64Concat, TokenId>),
+		Value = bool,
+		QueryKind = ValueQuery,
+	>;
+
 	/// Collection id (controlled?2), token id (controlled?2)
 	#[pallet::storage]
 	#[pallet::getter(fn refungible_transfer_basket)]
@@ -685,6 +755,134 @@ pub mod pallet {
 
 			Self::_remove_sponsor(collection_id, &sender)
 		}
+
+		/// Set holding limits for all tokens of the collection.
+		///
+		/// Only collection owner can set collection holding limits. Fails if a token which does
+		/// not override the holders limit already has more holders than allowed.
+		/// Other limits are checked on further balance changes only: existing holdings which violate
+		/// them are kept, but can only be increased to an allowed amount or removed entirely.
+		///
+		/// - `limits`: New holding limits. Fields which are not set are not restricted.
+		/// - `token_count`: Number of tokens in the collection, used to weigh the call.
+		///   Must not be lower than the actual number of tokens.
+		#[pallet::call_index(23)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_collection_holding_limits(*token_count))]
+		pub fn set_collection_holding_limits(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			limits: HoldingLimits,
+			token_count: u32
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection = RefungibleHandle::try_get(collection_id)?;
+
+			Self::_set_collection_holding_limits(&collection, &sender, limits, token_count)
+		}
+
+		/// Set holding limits for the token.
+		///
+		/// Only collection owner can set token holding limits. Set fields override collection
+		/// holding limits. Fails if the token already has more holders than allowed.
+		///
+		/// - `limits`: New holding limits of the token.
+		#[pallet::call_index(24)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_token_holding_limits())]
+		pub fn set_token_holding_limits(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			limits: HoldingLimits
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let collection = RefungibleHandle::try_get(collection_id)?;
+
+			Self::_set_token_holding_limits(&collection, &sender, token_id, limits)
+		}
+
+		/// Propose a new owner of the collection.
+		///
+		/// Only collection owner can propose a new owner. Ownership is changed
+		/// once `new_owner` accepts it with `accept_collection_ownership`.
+		///
+		/// - `new_owner`: Whitelist admin who will own the collection.
+		#[pallet::call_index(25)]
+		#[pallet::weight(<T as Config>::WeightInfo::propose_collection_owner())]
+		pub fn propose_collection_owner(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			new_owner: T::AccountId
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::_propose_collection_owner(collection_id, &sender, new_owner)
+		}
+
+		/// Cancel a pending ownership transfer of the collection.
+		///
+		/// Only collection owner can cancel the transfer.
+		#[pallet::call_index(26)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_collection_ownership_transfer())]
+		pub fn cancel_collection_ownership_transfer(
+			origin: OriginFor<T>,
+			collection_id: CollectionId
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::_cancel_collection_ownership_transfer(collection_id, &sender)
+		}
+
+		/// Accept ownership of the collection.
+		///
+		/// Only the account proposed by the collection owner can accept ownership.
+		#[pallet::call_index(27)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_collection_ownership())]
+		pub fn accept_collection_ownership(
+			origin: OriginFor<T>,
+			collection_id: CollectionId
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::_accept_collection_ownership(collection_id, &sender)
+		}
+
+		/// Set restrictions on sponsored transactions of the collection.
+		///
+		/// Only collection owner or confirmed sponsor can change sponsorship settings.
+		/// Budget usage is reset.
+		///
+		/// - `settings`: Sponsorship period, budget, per-account quota and sponsored accounts.
+		///   `None` removes all restrictions.
+		#[pallet::call_index(28)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_sponsorship_settings())]
+		pub fn set_sponsorship_settings(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			settings: Option<SponsorshipSettings<BalanceOf<T>, T::BlockNumber>>
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::_set_sponsorship_settings(collection_id, &sender, settings)
+		}
+
+		/// Add an account to or remove it from the sponsor allowlist of the collection.
+		///
+		/// Only collection owner or confirmed sponsor can change the allowlist.
+		///
+		/// - `account`: Account which status is changed.
+		/// - `value`: Whether transactions of the account should be sponsored.
+		#[pallet::call_index(29)]
+		#[pallet::weight(<T as Config>::WeightInfo::toggle_sponsor_allowlist())]
+		pub fn toggle_sponsor_allowlist(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			account: T::AccountId,
+			value: bool
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			Self::_toggle_sponsor_allowlist(collection_id, &sender, account, value)
+		}
 	}
 }
 
@@ -701,6 +899,11 @@ impl<T: Config> Pallet<T> {
 		payer: T::AccountId,
 		data: CreateCollectionData<T::AccountId>,
 	) -> Result<CollectionId, DispatchError> {
+		ensure!(
+			data.mode == CollectionMode::ReFungible,
+			<CommonError<T>>::UnsupportedOperation
+		);
+
 		<PalletCommon<T>>::init_collection(owner, payer, data)
 	}
 
@@ -730,6 +933,10 @@ impl<T: Config> Pallet<T> {
 		let _ = <Allowance<T>>::clear_prefix((id,), u32::MAX, None);
 		let _ = <Owned<T>>::clear_prefix((id,), u32::MAX, None);
 		let _ = <AccountBalance<T>>::clear_prefix((id,), u32::MAX, None);
+		let _ = <TokenHolders<T>>::clear_prefix((id,), u32::MAX, None);
+		let _ = <TokenHoldingLimits<T>>::clear_prefix((id,), u32::MAX, None);
+		let _ = <FrozenTokens<T>>::clear_prefix((id,), u32::MAX, None);
+		<CollectionHoldingLimits<T>>::remove(id);
 		Ok(())
 	}
 
@@ -813,6 +1020,86 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		<PalletCommon<T>>::remove_sponsor(collection_id, sender)
 	}
+
+	fn _propose_collection_owner(
+		collection_id: CollectionId,
+		sender: &T::AccountId,
+		new_owner: T::AccountId
+	) -> DispatchResult {
+		RefungibleHandle::<T>::try_get(collection_id)?;
+
+		<PalletCommon<T>>::propose_collection_owner(collection_id, sender, new_owner)
+	}
+
+	fn _cancel_collection_ownership_transfer(
+		collection_id: CollectionId,
+		sender: &T::AccountId
+	) -> DispatchResult {
+		RefungibleHandle::<T>::try_get(collection_id)?;
+
+		<PalletCommon<T>>::cancel_collection_ownership_transfer(collection_id, sender)
+	}
+
+	fn _accept_collection_ownership(
+		collection_id: CollectionId,
+		sender: &T::AccountId
+	) -> DispatchResult {
+		RefungibleHandle::<T>::try_get(collection_id)?;
+
+		<PalletCommon<T>>::accept_collection_ownership(collection_id, sender)
+	}
+
+	fn _set_sponsorship_settings(
+		collection_id: CollectionId,
+		sender: &T::AccountId,
+		settings: Option<SponsorshipSettings<BalanceOf<T>, T::BlockNumber>>
+	) -> DispatchResult {
+		RefungibleHandle::<T>::try_get(collection_id)?;
+
+		<PalletCommon<T>>::set_sponsorship_settings(collection_id, sender, settings)
+	}
+
+	fn _toggle_sponsor_allowlist(
+		collection_id: CollectionId,
+		sender: &T::AccountId,
+		account: T::AccountId,
+		value: bool
+	) -> DispatchResult {
+		RefungibleHandle::<T>::try_get(collection_id)?;
+
+		<PalletCommon<T>>::toggle_sponsor_allowlist(collection_id, sender, account, value)
+	}
+
+	fn _set_collection_holding_limits(
+		collection: &RefungibleHandle<T>,
+		sender: &T::AccountId,
+		limits: HoldingLimits,
+		token_count: u32,
+	) -> DispatchResult {
+		collection.check_is_owner(sender)?;
+		ensure!(limits.is_valid(), <Error<T>>::InvalidHoldingLimits);
+		ensure!(
+			Self::total_supply(collection) <= token_count,
+			<Error<T>>::TokenCountUnderestimated
+		);
+
+		// same check as for token holding limits, for every token which doesn't override it
+		if let Some(max_token_holders) = limits.max_token_holders {
+			for (token, holders) in <TokenHolders<T>>::iter_prefix((collection.id,)) {
+				ensure!(
+					holders <= max_token_holders
+						|| <TokenHoldingLimits<T>>::get((collection.id, token)).max_token_holders.is_some(),
+					<Error<T>>::TokenHoldersLimitExceeded
+				);
+			}
+		}
+
+		<CollectionHoldingLimits<T>>::insert(collection.id, limits);
+
+		<PalletCommon<T>>::deposit_event(CommonEvent::CollectionHoldingLimitsSet(collection.id));
+
+		Ok(())
+	}
 }
 
 // Token related methods
@@ -884,6 +1171,16 @@ impl<T: Config> Pallet<T> {
 			<CommonError<T>>::CollectionTokenLimitExceeded
 		);
 
+		// Holders per token
+		let holders = data
+			.iter()
+			.enumerate()
+			.map(|(i, data)| {
+				let token_id = TokenId(first_token_id + i as u32 + 1);
+				Self::_check_create_item_holding_limits(collection.id, token_id, data)
+			})
+			.collect::<Result<Vec<_>, DispatchError>>()?;
+
 		let mut balances = BTreeMap::new();
 		for data in &data {
 			for owner in data.balances.keys() {
@@ -905,13 +1202,14 @@ impl<T: Config> Pallet<T> {
 			for (i, data) in data.iter().enumerate() {
 				let token_id = first_token_id + i as u32 + 1;
 				<TotalSupply<T>>::insert((collection.id, token_id), totals[i]);
+				<TokenHolders<T>>::insert((collection.id, token_id), holders[i]);
 
 				for (user, amount) in data.balances.iter() {
 					if *amount == 0 {
 						continue;
 					}
 					<Balance<T>>::insert((collection.id, token_id, &user), amount);
-					<Owned<T>>::insert((collection.id, &user, TokenId(token_id)), true);
+					Self::_insert_owned(collection.id, user, TokenId(token_id));
 				}
 
 				if let Err(e) = Self::_set_token_properties(
@@ -954,6 +1252,36 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	// Check initial balances of a token against its holding limits.
+	// Returns amount of token holders.
+	fn _check_create_item_holding_limits(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		data: &ValidatedCreateItemData<T>,
+	) -> Result<u32, DispatchError> {
+		let limits = Self::holding_limits(collection_id, token_id);
+
+		let mut holders = 0u32;
+		for amount in data.balances.values().filter(|amount| **amount > 0) {
+			ensure!(
+				limits.is_allowed_amount(*amount),
+				<Error<T>>::AmountNotMultipleOfIncrement
+			);
+			ensure!(
+				limits.is_allowed_holding(*amount),
+				<Error<T>>::HoldingBelowMinimum
+			);
+			holders += 1;
+		}
+
+		ensure!(
+			holders <= limits.max_token_holders(),
+			<Error<T>>::TokenHoldersLimitExceeded
+		);
+
+		Ok(holders)
+	}
+
 	// Check that all given users are either whitelisted or collection admins
 	fn _verify_create_multiple_items_data(collection: &RefungibleHandle<T>, data: &Vec<ValidatedCreateItemData<T>>) -> DispatchResult {
 		for item in data.iter() {
@@ -1052,12 +1380,11 @@ impl<T: Config> Pallet<T> {
 	// 		all the token pieces.
 	// LOG: Balance getting in `is_token_owner` closure fixed
 	// LOG: nesting_budget argument deleted
-	// LOG: token_owner permission ignored
-	// LOG: Access restricted to owner or admin
-	// LOG: PropertyPermission check simplified.
-	//		Now property can be modified only by owner or admins
-	//		if mutable = true. By default mutable = false and 
-	//		property can be set only while token creation.
+	// LOG: On token creation access restricted to owner or admin.
+	//		After that property can be modified only if mutable = true,
+	//		by owner or admins if collection_admin = true and by the
+	//		token owner if token_owner = true. By default all flags are
+	//		false and property can be set only while token creation.
 	#[transactional]
 	fn _modify_token_properties(
 		collection: &RefungibleHandle<T>,
@@ -1066,18 +1393,18 @@ impl<T: Config> Pallet<T> {
 		properties: impl Iterator<Item = (PropertyKey, Option<PropertyValue>)>,
 		is_token_create: bool,
 	) -> DispatchResult {
-		collection.check_is_owner_or_admin(sender)?;
+		if is_token_create {
+			collection.check_is_owner_or_admin(sender)?;
+		}
 
 		for (key, value) in properties {
-			let permission = <PalletCommon<T>>::property_permissions(collection.id)
-				.get(&key)
-				.cloned()
-				.unwrap_or_else(PropertyPermission::unmutable);
-
 			if !is_token_create {
-				if let PropertyPermission { mutable: false, .. } = permission {
-					fail!(<CommonError<T>>::NoPermission);
-				}
+				<PalletCommon<T>>::check_token_property_permission(
+					collection,
+					sender,
+					&key,
+					|| Self::owns_whole_token(collection, token_id, sender),
+				)?;
 			}
 
 			match value {
@@ -1111,6 +1438,37 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Set holding limits of the RFT token.
+	///
+	/// Sender should be the owner of the collection.
+	///
+	/// - `limits`: Holding limits which override collection holding limits.
+	fn _set_token_holding_limits(
+		collection: &RefungibleHandle<T>,
+		sender: &T::AccountId,
+		token: TokenId,
+		limits: HoldingLimits,
+	) -> DispatchResult {
+		collection.check_is_owner(sender)?;
+		ensure!(
+			Self::token_exists(collection, token),
+			<CommonError<T>>::TokenNotFound
+		);
+		ensure!(limits.is_valid(), <Error<T>>::InvalidHoldingLimits);
+
+		let effective = limits.clone().or(<CollectionHoldingLimits<T>>::get(collection.id));
+		ensure!(
+			<TokenHolders<T>>::get((collection.id, token)) <= effective.max_token_holders(),
+			<Error<T>>::TokenHoldersLimitExceeded
+		);
+
+		<TokenHoldingLimits<T>>::insert((collection.id, token), limits);
+
+		<PalletCommon<T>>::deposit_event(CommonEvent::TokenHoldingLimitsSet(collection.id, token));
+
+		Ok(())
+	}
+
 	/// Transfer RFT token pieces from one account to another.
 	///
 	/// If the sender is no longer owns any pieces after the `transfer` than she stops being an owner of the token.
@@ -1133,6 +1491,25 @@ impl<T: Config> Pallet<T> {
 		amount: TokenBalance,
 	) -> DispatchResult {
 		Self::_check_transfer_allowed(collection, from, to)?;
+		ensure!(!Self::is_frozen((collection.id, token)), <Error<T>>::TokenFrozen);
+
+		Self::_transfer_unchecked(collection, from, to, token, amount, true)
+	}
+
+	// Moves pieces without whitelist checks. Holding limits are enforced only if `check_limits` is set.
+	fn _transfer_unchecked(
+		collection: &RefungibleHandle<T>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token: TokenId,
+		amount: TokenBalance,
+		check_limits: bool,
+	) -> DispatchResult {
+		let limits = Self::holding_limits(collection.id, token);
+		ensure!(
+			!check_limits || limits.is_allowed_amount(amount),
+			<Error<T>>::AmountNotMultipleOfIncrement
+		);
 
 		let initial_balance_from = <Balance<T>>::get((collection.id, token, from));
 		let updated_balance_from = initial_balance_from
@@ -1154,6 +1531,35 @@ impl<T: Config> Pallet<T> {
 			None
 		};
 
+		let token_holders = if let Some(updated_balance_to) = updated_balance_to {
+			ensure!(
+				!check_limits
+					|| (limits.is_allowed_holding(updated_balance_from)
+						&& limits.is_allowed_holding(updated_balance_to)),
+				<Error<T>>::HoldingBelowMinimum
+			);
+
+			let joined = create_target && updated_balance_to > 0;
+			let left = initial_balance_from > 0 && updated_balance_from == 0;
+			let holders = <TokenHolders<T>>::get((collection.id, token));
+			match (joined, left) {
+				(true, false) => {
+					let holders = holders
+						.checked_add(1)
+						.ok_or(ArithmeticError::Overflow)?;
+					ensure!(
+						!check_limits || holders <= limits.max_token_holders(),
+						<Error<T>>::TokenHoldersLimitExceeded
+					);
+					Some(holders)
+				}
+				(false, true) => Some(holders.saturating_sub(1)),
+				_ => None,
+			}
+		} else {
+			None
+		};
+
 		let account_balance_from = if updated_balance_from == 0 {
 			Some(
 				<AccountBalance<T>>::get((collection.id, from))
@@ -1192,11 +1598,14 @@ impl<T: Config> Pallet<T> {
 			<Balance<T>>::insert((collection.id, token, to), updated_balance_to);
 			if let Some(account_balance_from) = account_balance_from {
 				<AccountBalance<T>>::insert((collection.id, from), account_balance_from);
-				<Owned<T>>::remove((collection.id, from, token));
+				Self::_remove_owned(collection.id, from, token);
 			}
 			if let Some(account_balance_to) = account_balance_to {
 				<AccountBalance<T>>::insert((collection.id, to), account_balance_to);
-				<Owned<T>>::insert((collection.id, to, token), true);
+				Self::_insert_owned(collection.id, to, token);
+			}
+			if let Some(token_holders) = token_holders {
+				<TokenHolders<T>>::insert((collection.id, token), token_holders);
 			}
 		}
 
@@ -1351,9 +1760,34 @@ impl<T: Config> Pallet<T> {
 		amount: TokenBalance,
 	) -> DispatchResult {
 		collection.check_is_owner_or_admin(owner)?;
+		Self::_check_burn_holding_limits(collection, owner, token, amount)?;
 
 		Self::_burn_permissionless(collection, owner, token, amount)
 	}
+
+	// Check that burn of `amount` pieces from `owner` respects holding limits of the token.
+	fn _check_burn_holding_limits(
+		collection: &RefungibleHandle<T>,
+		owner: &T::AccountId,
+		token: TokenId,
+		amount: TokenBalance,
+	) -> DispatchResult {
+		let limits = Self::holding_limits(collection.id, token);
+		ensure!(
+			limits.is_allowed_amount(amount),
+			<Error<T>>::AmountNotMultipleOfIncrement
+		);
+
+		let balance = <Balance<T>>::get((collection.id, token, owner))
+			.checked_sub(amount)
+			.ok_or(<CommonError<T>>::TokenValueTooLow)?;
+		ensure!(
+			limits.is_allowed_holding(balance),
+			<Error<T>>::HoldingBelowMinimum
+		);
+
+		Ok(())
+	}
 	
 	/// Burn RFT token pieces from the account.
 	///
@@ -1374,6 +1808,7 @@ impl<T: Config> Pallet<T> {
 
 		let allowance =
 			Self::_check_allowed(collection, spender, from, token, amount)?;
+		Self::_check_burn_holding_limits(collection, from, token, amount)?;
 
 		// =========
 
@@ -1423,7 +1858,7 @@ impl<T: Config> Pallet<T> {
 
 			// =========
 
-			<Owned<T>>::remove((collection.id, owner, token));
+			Self::_remove_owned(collection.id, owner, token);
 			<AccountBalance<T>>::insert((collection.id, owner), account_balance);
 			Self::_destroy_item_unchecked(collection, token)?;
 			<PalletCommon<T>>::deposit_event(CommonEvent::ItemDestroyed(
@@ -1450,9 +1885,12 @@ impl<T: Config> Pallet<T> {
 		// =========
 
 		if balance == 0 {
-			<Owned<T>>::remove((collection.id, owner, token));
+			Self::_remove_owned(collection.id, owner, token);
 			<Balance<T>>::remove((collection.id, token, owner));
 			<AccountBalance<T>>::insert((collection.id, owner), account_balance);
+			<TokenHolders<T>>::mutate((collection.id, token), |holders| {
+				*holders = holders.saturating_sub(1)
+			});
 		} else {
 			<Balance<T>>::insert((collection.id, token, owner), balance);
 		}
@@ -1480,6 +1918,8 @@ impl<T: Config> Pallet<T> {
 		<TokensBurnt<T>>::insert(collection.id, burnt);
 		<TokenProperties<T>>::remove((collection.id, token_id));
 		<TotalSupply<T>>::remove((collection.id, token_id));
+		<TokenHolders<T>>::remove((collection.id, token_id));
+		<TokenHoldingLimits<T>>::remove((collection.id, token_id));
 		let _ = <Balance<T>>::clear_prefix((collection.id, token_id), u32::MAX, None);
 		let _ = <Allowance<T>>::clear_prefix((collection.id, token_id), u32::MAX, None);
 		
@@ -1502,6 +1942,7 @@ impl<T: Config> Pallet<T> {
 		amount: TokenBalance,
 	) -> DispatchResult {
 		collection.check_is_owner_or_admin(owner)?;
+		ensure!(!Self::is_frozen((collection.id, token)), <Error<T>>::TokenFrozen);
 
 		ensure!(
 			amount <= MAX_REFUNGIBLE_PIECES,
@@ -1540,6 +1981,16 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn _insert_owned(collection_id: CollectionId, owner: &T::AccountId, token: TokenId) {
+		<Owned<T>>::insert((collection_id, owner, token), true);
+		<AccountTokens<T>>::insert((owner, collection_id, token), true);
+	}
+
+	fn _remove_owned(collection_id: CollectionId, owner: &T::AccountId, token: TokenId) {
+		<Owned<T>>::remove((collection_id, owner, token));
+		<AccountTokens<T>>::remove((owner, collection_id, token));
+	}
+
 	fn _total_pieces(collection_id: CollectionId, token_id: TokenId) -> Option<TokenBalance> {
 		<TotalSupply<T>>::try_get((collection_id, token_id)).ok()
 	}
@@ -1552,6 +2003,106 @@ impl<T: Config> Pallet<T> {
 		<TokensMinted<T>>::get(collection.id) - <TokensBurnt<T>>::get(collection.id)
 	}
 
+	/// Get effective holding limits of RFT token.
+	///
+	/// Limits set for the token override limits set for the collection.
+	pub fn holding_limits(collection_id: CollectionId, token: TokenId) -> HoldingLimits {
+		<TokenHoldingLimits<T>>::get((collection_id, token))
+			.or(<CollectionHoldingLimits<T>>::get(collection_id))
+	}
+
+	/// Transfer RFT token pieces on behalf of another pallet.
+	///
+	/// Used to move pieces in and out of the `escrow` account of the calling pallet.
+	/// Whitelist and holding limits are checked for the recipient unless it is the `escrow` itself.
+	/// Pieces of a frozen token can only be moved out of the escrow.
+	pub fn escrow_transfer(
+		collection_id: CollectionId,
+		escrow: &T::AccountId,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token: TokenId,
+		amount: TokenBalance,
+	) -> DispatchResult {
+		let collection = RefungibleHandle::try_get(collection_id)?;
+
+		if to == escrow {
+			ensure!(!Self::is_frozen((collection_id, token)), <Error<T>>::TokenFrozen);
+		} else {
+			ensure!(
+				collection.limits.transfers_enabled(),
+				<CommonError<T>>::TransfersDisabled
+			);
+			Self::_check_whitelisted_or_collection_admin(&collection, to)?;
+			Self::_check_receive_holding_limits(&collection, from, to, token, amount)?;
+		}
+
+		Self::_transfer_unchecked(&collection, from, to, token, amount, false)
+	}
+
+	// Check that `to` receiving `amount` pieces from `from` respects holding limits of the token.
+	fn _check_receive_holding_limits(
+		collection: &RefungibleHandle<T>,
+		from: &T::AccountId,
+		to: &T::AccountId,
+		token: TokenId,
+		amount: TokenBalance,
+	) -> DispatchResult {
+		let limits = Self::holding_limits(collection.id, token);
+		ensure!(
+			limits.is_allowed_amount(amount),
+			<Error<T>>::AmountNotMultipleOfIncrement
+		);
+
+		let balance_to = <Balance<T>>::get((collection.id, token, to));
+		let updated_balance_to = balance_to
+			.checked_add(amount)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			limits.is_allowed_holding(updated_balance_to),
+			<Error<T>>::HoldingBelowMinimum
+		);
+
+		let sender_leaves = <Balance<T>>::get((collection.id, token, from)) == amount;
+		if balance_to == 0 && amount > 0 && !sender_leaves {
+			ensure!(
+				<TokenHolders<T>>::get((collection.id, token)) < limits.max_token_holders(),
+				<Error<T>>::TokenHoldersLimitExceeded
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Freeze or unfreeze the token on behalf of another pallet.
+	///
+	/// Pieces of a frozen token can't be transferred, repartitioned or moved into escrow accounts.
+	pub fn set_token_frozen(collection_id: CollectionId, token: TokenId, frozen: bool) {
+		if frozen {
+			<FrozenTokens<T>>::insert((collection_id, token), true);
+		} else {
+			<FrozenTokens<T>>::remove((collection_id, token));
+		}
+	}
+
+	/// Burn all RFT token pieces owned by the account on behalf of another pallet.
+	///
+	/// Holding limits are not checked. Returns amount of burned pieces.
+	pub fn burn_all(
+		collection_id: CollectionId,
+		owner: &T::AccountId,
+		token: TokenId,
+	) -> Result<TokenBalance, DispatchError> {
+		let collection = RefungibleHandle::try_get(collection_id)?;
+
+		let amount = <Balance<T>>::get((collection_id, token, owner));
+		ensure!(amount > 0, <CommonError<T>>::TokenValueTooLow);
+
+		Self::_burn_permissionless(&collection, owner, token, amount)?;
+
+		Ok(amount)
+	}
+
 	/// Check that RFT token exists
 	///
 	/// - `token`: Token ID.
@@ -1559,6 +2110,12 @@ impl<T: Config> Pallet<T> {
 		<TotalSupply<T>>::contains_key((collection.id, token))
 	}
 
+	/// Token owner of RFT token is the account which owns all of its pieces.
+	fn owns_whole_token(collection: &RefungibleHandle<T>, token: TokenId, who: &T::AccountId) -> bool {
+		let balance = <Balance<T>>::get((collection.id, token, who));
+		balance > 0 && balance == <TotalSupply<T>>::get((collection.id, token))
+	}
+
 	pub fn set_scoped_token_property(
 		collection_id: CollectionId,
 		token_id: TokenId,
@@ -1586,4 +2143,85 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
-}
\ No newline at end of file
+}
+// RPC methods
+impl<T: Config> Pallet<T> {
+	/// Get token properties according to given keys.
+	pub fn filter_token_properties(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		keys: Option<Vec<PropertyKey>>,
+	) -> Vec<Property> {
+		<PalletCommon<T>>::filter_properties(<TokenProperties<T>>::get((collection_id, token_id)), keys)
+	}
+
+	/// Get amount of token pieces owned by the account.
+	pub fn token_balance(collection_id: CollectionId, token: TokenId, account: &T::AccountId) -> u128 {
+		<Balance<T>>::get((collection_id, token, account))
+	}
+
+	/// Get total amount of pieces of the token.
+	pub fn token_total_pieces(collection_id: CollectionId, token: TokenId) -> u128 {
+		<TotalSupply<T>>::get((collection_id, token))
+	}
+
+	/// Get amount of token pieces the spender is allowed to use on behalf of the owner.
+	pub fn token_allowance(
+		collection_id: CollectionId,
+		token: TokenId,
+		owner: &T::AccountId,
+		spender: &T::AccountId,
+	) -> u128 {
+		<Allowance<T>>::get((collection_id, token, owner, spender))
+	}
+
+	/// Get a page of tokens partially owned by the account across all collections.
+	///
+	/// Tokens are returned in storage order, starting after `start_after` if it is set.
+	/// At most `limit` entries are returned, capped by [`MAX_PAGE_SIZE`].
+	pub fn account_tokens(
+		account: &T::AccountId,
+		start_after: Option<(CollectionId, TokenId)>,
+		limit: u32,
+	) -> Vec<(CollectionId, TokenId)> {
+		let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		match start_after {
+			Some((collection_id, token)) => <AccountTokens<T>>::iter_key_prefix_from(
+				(account,),
+				<AccountTokens<T>>::hashed_key_for((account, collection_id, token)),
+			)
+			.take(limit)
+			.collect(),
+			None => <AccountTokens<T>>::iter_key_prefix((account,)).take(limit).collect(),
+		}
+	}
+
+	/// Get a page of holders of the token with their amounts of pieces.
+	///
+	/// Holders are returned in storage order, starting after `start_after` if it is set.
+	/// At most `limit` entries are returned, capped by [`MAX_PAGE_SIZE`].
+	pub fn token_holders(
+		collection_id: CollectionId,
+		token: TokenId,
+		start_after: Option<T::AccountId>,
+		limit: u32,
+	) -> Vec<(T::AccountId, TokenBalance)> {
+		let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		match start_after {
+			Some(holder) => <Balance<T>>::iter_prefix_from(
+				(collection_id, token),
+				<Balance<T>>::hashed_key_for((collection_id, token, holder)),
+			)
+			.take(limit)
+			.collect(),
+			None => <Balance<T>>::iter_prefix((collection_id, token)).take(limit).collect(),
+		}
+	}
+
+	/// Get id of the last token minted in the collection.
+	///
+	/// Returns `TokenId(0)` if no tokens were minted yet.
+	pub fn last_token_id(collection_id: CollectionId) -> TokenId {
+		TokenId(<TokensMinted<T>>::get(collection_id))
+	}
+}
@@ -0,0 +1,177 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use core::marker::PhantomData;
+use frame_support::{
+	dispatch::GetStorageVersion,
+	pallet_prelude::StorageVersion,
+	traits::{Get, OnRuntimeUpgrade},
+	weights::Weight,
+};
+
+use crate::{Config, Pallet, Balance, TokenHolders, Owned, AccountTokens};
+
+#[cfg(feature = "try-runtime")]
+use sp_std::{vec, vec::Vec};
+
+/// Fills [`TokenHolders`] for tokens minted before holders were counted.
+pub struct PopulateTokenHolders<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for PopulateTokenHolders<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current = Pallet::<T>::current_storage_version();
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		log::info!(
+			"Running refungible migration with current storage version {:?} / onchain {:?}",
+			current,
+			onchain
+		);
+
+		if current >= 2 && onchain == 1 {
+			let mut reads = 0u64;
+			let mut writes = 0u64;
+			for ((collection_id, token_id, _), amount) in <Balance<T>>::iter() {
+				reads += 1;
+				if amount > 0 {
+					<TokenHolders<T>>::mutate((collection_id, token_id), |holders| {
+						*holders = holders.saturating_add(1)
+					});
+					writes += 1;
+				}
+			}
+
+			log::info!("Counted holders for {:?} token balances", writes);
+			StorageVersion::new(2).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(reads.saturating_add(writes), writes.saturating_add(1))
+		} else {
+			log::info!("PopulateTokenHolders did not execute. This probably should be removed");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 1,
+			"Refungible on-chain StorageVersion is {:?} instead of 1",
+			onchain
+		);
+		assert!(
+			<TokenHolders<T>>::iter().next().is_none(),
+			"TokenHolders already populated"
+		);
+
+		Ok(vec![])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 2,
+			"Refungible StorageVersion post-migration is not 2, but {:?} instead.",
+			onchain
+		);
+
+		let holdings = <Balance<T>>::iter_values().filter(|amount| *amount > 0).count() as u32;
+		let holders = <TokenHolders<T>>::iter_values().fold(0u32, |acc, v| acc.saturating_add(v));
+		assert_eq!(
+			holdings, holders,
+			"Token holders mismatch! Balances {:?} vs. holders {:?}",
+			holdings, holders
+		);
+
+		Ok(())
+	}
+}
+
+/// Fills [`AccountTokens`] from [`Owned`] for tokens owned before the index was introduced.
+pub struct PopulateAccountTokens<T>(PhantomData<T>);
+impl<T: Config> OnRuntimeUpgrade for PopulateAccountTokens<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let current = Pallet::<T>::current_storage_version();
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		log::info!(
+			"Running refungible migration with current storage version {:?} / onchain {:?}",
+			current,
+			onchain
+		);
+
+		if current == 3 && onchain == 2 {
+			let mut count = 0u64;
+			for (collection_id, owner, token_id) in <Owned<T>>::iter_keys() {
+				<AccountTokens<T>>::insert((owner, collection_id, token_id), true);
+				count += 1;
+			}
+
+			log::info!("Indexed {:?} owned tokens by account", count);
+			StorageVersion::new(3).put::<Pallet<T>>();
+			T::DbWeight::get().reads_writes(count, count.saturating_add(1))
+		} else {
+			log::info!("PopulateAccountTokens did not execute. This probably should be removed");
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 2,
+			"Refungible on-chain StorageVersion is {:?} instead of 2",
+			onchain
+		);
+		assert!(
+			<AccountTokens<T>>::iter().next().is_none(),
+			"AccountTokens already populated"
+		);
+
+		Ok(vec![])
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_: Vec<u8>) -> Result<(), &'static str> {
+		let onchain = Pallet::<T>::on_chain_storage_version();
+
+		assert_eq!(
+			onchain, 3,
+			"Refungible StorageVersion post-migration is not 3, but {:?} instead.",
+			onchain
+		);
+
+		let owned = <Owned<T>>::iter_keys().count();
+		let indexed = <AccountTokens<T>>::iter_keys().count();
+		assert_eq!(
+			owned, indexed,
+			"Account tokens mismatch! Owned {:?} vs. indexed {:?}",
+			owned, indexed
+		);
+
+		Ok(())
+	}
+}
@@ -145,6 +145,9 @@ ord_parameter_types! {
 impl pallet_whitelist::Config for MockRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type RolesRoot = EnsureSignedBy<RolesRootAccountId, AccountId>;
+	type OffchainSignature = sp_runtime::testing::TestSignature;
+	type OffchainPublic = sp_runtime::testing::UintAuthorityId;
+	type MaxKycAttributesLength = frame_support::traits::ConstU32<256>;
 	type WeightInfo = ();
 }
 
@@ -320,7 +323,9 @@ impl TokenInitializer {
 		if let Some(properties_with_mut_flag) = &self.properties {			
 			for (property, is_mutable) in properties_with_mut_flag {
 				let property_permission = PropertyPermission {
-					mutable: *is_mutable
+					mutable: *is_mutable,
+					collection_admin: true,
+					token_owner: false
 				};
 				let property_key_permission = PropertyKeyPermission {
 					key: property.key.clone(),
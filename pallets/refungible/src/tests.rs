@@ -32,8 +32,11 @@ use crate::{
     TotalSupply, Balance, Allowance,
     Owned, AccountBalance,
     TokensMinted, TokensBurnt,
-    TokenProperties, CreateItemData
+    TokenProperties, CreateItemData,
+    TokenHolders, CollectionHoldingLimits, TokenHoldingLimits,
+    AccountTokens
 };
+use collection_primitives::HoldingLimits;
 
 // Simple redirection to corresponding common pallet's method
 // So most of testing are made there
@@ -754,6 +757,115 @@ pub fn set_token_property_fails_if_not_collection_admin() {
     });
 }
 
+#[test]
+pub fn set_token_property_respects_all_permission_combinations() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB])
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .admins(vec![ADMIN_2])
+            .init(ADMIN_1)
+            .expect("Collection init failed");
+
+        // ALICE owns all pieces of the token
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        let mut i = 0;
+        for mutable in [false, true] {
+            for collection_admin in [false, true] {
+                for token_owner in [false, true] {
+                    // (sender, is owner or admin of collection, is token owner)
+                    let senders = [
+                        (ADMIN_1, true, false),
+                        (ADMIN_2, true, false),
+                        (ALICE, false, true),
+                        (BOB, false, false),
+                    ];
+                    for (sender, is_admin, is_token_owner) in senders {
+                        i += 1;
+                        let property = create_property(&format!("Key{}", i), "Value");
+                        assert_ok!(Common::set_property_permission_unchecked(collection_id, PropertyKeyPermission {
+                            key: property.key.clone(),
+                            permission: PropertyPermission { mutable, collection_admin, token_owner }
+                        }));
+
+                        let result = Refungible::set_token_property(
+                            RuntimeOrigin::signed(sender),
+                            collection_id,
+                            token_id,
+                            property.clone()
+                        );
+
+                        if mutable && ((collection_admin && is_admin) || (token_owner && is_token_owner)) {
+                            assert_ok!(result);
+                            assert_ok!(Refungible::delete_token_property(
+                                RuntimeOrigin::signed(sender),
+                                collection_id,
+                                token_id,
+                                property.key.clone()
+                            ));
+                        } else {
+                            assert_noop!(result, CommonError::<MockRuntime>::NoPermission);
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[test]
+pub fn set_token_property_fails_for_partial_token_owner() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 5), (BOB, 5)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        let property = create_property("Key", "Value");
+        assert_ok!(Common::set_property_permission_unchecked(collection_id, PropertyKeyPermission {
+            key: property.key.clone(),
+            permission: PropertyPermission { mutable: true, collection_admin: false, token_owner: true }
+        }));
+
+        assert_noop!(
+            Refungible::set_token_property(
+                RuntimeOrigin::signed(ALICE),
+                collection_id,
+                token_id,
+                property.clone()
+            ),
+            CommonError::<MockRuntime>::NoPermission
+        );
+
+        assert_ok!(Refungible::transfer(RuntimeOrigin::signed(BOB), collection_id, token_id, ALICE, 5));
+
+        assert_ok!(Refungible::set_token_property(
+            RuntimeOrigin::signed(ALICE),
+            collection_id,
+            token_id,
+            property
+        ));
+    });
+}
+
 // Tests to set_token_property also applicable to set_token_properties as
 // set_token_property is simple redirection with one property
 // So only simple check made
@@ -1820,4 +1932,789 @@ pub fn remove_sponsor_works() {
             collection_id
         ));
     });
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn collection_ownership_transfer_works() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_ok!(Refungible::propose_collection_owner(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            ADMIN_2
+        ));
+        assert_ok!(Refungible::accept_collection_ownership(
+            RuntimeOrigin::signed(ADMIN_2),
+            collection_id
+        ));
+
+        let collection = pallet_common::CollectionById::<MockRuntime>::get(collection_id)
+            .expect("Collection not found");
+        assert_eq!(collection.owner, ADMIN_2);
+
+        assert_noop!(
+            Refungible::set_sponsor(RuntimeOrigin::signed(ADMIN_1), collection_id, ALICE),
+            CommonError::<MockRuntime>::NoPermission
+        );
+    });
+}
+
+#[test]
+pub fn cancel_collection_ownership_transfer_works() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_ok!(Refungible::propose_collection_owner(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            ADMIN_2
+        ));
+        assert_ok!(Refungible::cancel_collection_ownership_transfer(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id
+        ));
+
+        assert_noop!(
+            Refungible::accept_collection_ownership(RuntimeOrigin::signed(ADMIN_2), collection_id),
+            CommonError::<MockRuntime>::NoPendingOwnershipTransfer
+        );
+    });
+}
+
+#[test]
+pub fn set_collection_holding_limits_works() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let limits = HoldingLimits {
+            max_token_holders: Some(2),
+            min_holding: Some(5),
+            transfer_increment: None,
+        };
+
+        assert_ok!(Refungible::set_collection_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            limits.clone(),
+            0
+        ));
+
+        System::assert_last_event(RuntimeEvent::Common(CommonEvent::CollectionHoldingLimitsSet(
+            collection_id
+        )));
+
+        assert_eq!(<CollectionHoldingLimits<MockRuntime>>::get(collection_id), limits);
+    });
+}
+
+#[test]
+pub fn set_collection_holding_limits_fails_if_not_owner() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .admins(vec![ADMIN_2])
+            .init(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_noop!(
+            Refungible::set_collection_holding_limits(
+                RuntimeOrigin::signed(ADMIN_2),
+                collection_id,
+                HoldingLimits::default(),
+                0
+            ),
+            CommonError::<MockRuntime>::NoPermission
+        );
+    });
+}
+
+#[test]
+pub fn set_collection_holding_limits_fails_if_zero_limit() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let limits = HoldingLimits {
+            max_token_holders: None,
+            min_holding: None,
+            transfer_increment: Some(0),
+        };
+
+        assert_noop!(
+            Refungible::set_collection_holding_limits(
+                RuntimeOrigin::signed(ADMIN_1),
+                collection_id,
+                limits,
+                0
+            ),
+            Error::<MockRuntime>::InvalidHoldingLimits
+        );
+    });
+}
+
+#[test]
+pub fn set_collection_holding_limits_fails_if_below_current_holders() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 10), (BOB, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        let limits = HoldingLimits {
+            max_token_holders: Some(1),
+            ..Default::default()
+        };
+
+        assert_noop!(
+            Refungible::set_collection_holding_limits(
+                RuntimeOrigin::signed(ADMIN_1),
+                collection_id,
+                limits.clone(),
+                1
+            ),
+            Error::<MockRuntime>::TokenHoldersLimitExceeded
+        );
+
+        // tokens which override the holders limit are not affected
+        assert_ok!(Refungible::set_token_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            token_id,
+            HoldingLimits {
+                max_token_holders: Some(2),
+                ..Default::default()
+            }
+        ));
+        assert_ok!(Refungible::set_collection_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            limits.clone(),
+            1
+        ));
+
+        assert_eq!(<CollectionHoldingLimits<MockRuntime>>::get(collection_id), limits);
+    });
+}
+
+#[test]
+pub fn set_collection_holding_limits_fails_if_token_count_underestimated() {
+    ExtBuilder::new()
+    .investors(vec![ALICE])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let _ = TokenInitializer::new()
+            .balances(vec![(ALICE, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        assert_noop!(
+            Refungible::set_collection_holding_limits(
+                RuntimeOrigin::signed(ADMIN_1),
+                collection_id,
+                HoldingLimits::default(),
+                0
+            ),
+            Error::<MockRuntime>::TokenCountUnderestimated
+        );
+    });
+}
+
+#[test]
+pub fn set_token_holding_limits_works() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ADMIN_1, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        let limits = HoldingLimits {
+            max_token_holders: Some(3),
+            min_holding: None,
+            transfer_increment: Some(2),
+        };
+
+        assert_ok!(Refungible::set_token_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            token_id,
+            limits.clone()
+        ));
+
+        System::assert_last_event(RuntimeEvent::Common(CommonEvent::TokenHoldingLimitsSet(
+            collection_id,
+            token_id
+        )));
+
+        assert_eq!(<TokenHoldingLimits<MockRuntime>>::get((collection_id, token_id)), limits);
+    });
+}
+
+#[test]
+pub fn set_token_holding_limits_fails_if_token_not_exists() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_noop!(
+            Refungible::set_token_holding_limits(
+                RuntimeOrigin::signed(ADMIN_1),
+                collection_id,
+                TokenId(1),
+                HoldingLimits::default()
+            ),
+            CommonError::<MockRuntime>::TokenNotFound
+        );
+    });
+}
+
+#[test]
+pub fn set_token_holding_limits_fails_if_not_owner() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1, ADMIN_2])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .admins(vec![ADMIN_2])
+            .init(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ADMIN_1, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        assert_noop!(
+            Refungible::set_token_holding_limits(
+                RuntimeOrigin::signed(ADMIN_2),
+                collection_id,
+                token_id,
+                HoldingLimits::default()
+            ),
+            CommonError::<MockRuntime>::NoPermission
+        );
+    });
+}
+
+#[test]
+pub fn set_token_holding_limits_fails_if_below_current_holders() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 10), (BOB, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        assert_noop!(
+            Refungible::set_token_holding_limits(
+                RuntimeOrigin::signed(ADMIN_1),
+                collection_id,
+                token_id,
+                HoldingLimits {
+                    max_token_holders: Some(1),
+                    ..Default::default()
+                }
+            ),
+            Error::<MockRuntime>::TokenHoldersLimitExceeded
+        );
+
+        assert_ok!(Refungible::set_token_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            token_id,
+            HoldingLimits {
+                max_token_holders: Some(2),
+                ..Default::default()
+            }
+        ));
+    });
+}
+
+#[test]
+pub fn token_holders_are_counted() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB, CHARLIE])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 10), (BOB, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        assert_eq!(<TokenHolders<MockRuntime>>::get((collection_id, token_id)), 2);
+
+        // new holder
+        assert_ok!(Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, CHARLIE, 5));
+        assert_eq!(<TokenHolders<MockRuntime>>::get((collection_id, token_id)), 3);
+
+        // holder leaves
+        assert_ok!(Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB, 5));
+        assert_eq!(<TokenHolders<MockRuntime>>::get((collection_id, token_id)), 2);
+
+        // holder replaced
+        assert_ok!(Refungible::transfer(RuntimeOrigin::signed(CHARLIE), collection_id, token_id, ALICE, 5));
+        assert_eq!(<TokenHolders<MockRuntime>>::get((collection_id, token_id)), 2);
+    });
+}
+
+#[test]
+pub fn transfer_fails_if_token_holders_limit_exceeded() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB, CHARLIE])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_ok!(Refungible::set_collection_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            HoldingLimits {
+                max_token_holders: Some(2),
+                ..Default::default()
+            },
+            0
+        ));
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 10), (BOB, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        assert_noop!(
+            Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, CHARLIE, 5),
+            Error::<MockRuntime>::TokenHoldersLimitExceeded
+        );
+
+        // whole balance can be moved to a new holder
+        assert_ok!(Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, CHARLIE, 10));
+    });
+}
+
+#[test]
+pub fn transfer_fails_if_holding_below_minimum() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_ok!(Refungible::set_collection_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            HoldingLimits {
+                min_holding: Some(4),
+                ..Default::default()
+            },
+            0
+        ));
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        // receiver gets too little
+        assert_noop!(
+            Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB, 3),
+            Error::<MockRuntime>::HoldingBelowMinimum
+        );
+
+        // sender keeps too little
+        assert_noop!(
+            Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB, 7),
+            Error::<MockRuntime>::HoldingBelowMinimum
+        );
+
+        assert_ok!(Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB, 6));
+    });
+}
+
+#[test]
+pub fn transfer_fails_if_amount_not_multiple_of_increment() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        // collection allows any amount, token overrides it
+        assert_ok!(Refungible::set_collection_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            HoldingLimits {
+                transfer_increment: Some(1),
+                ..Default::default()
+            },
+            1
+        ));
+        assert_ok!(Refungible::set_token_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            token_id,
+            HoldingLimits {
+                transfer_increment: Some(5),
+                ..Default::default()
+            }
+        ));
+
+        assert_noop!(
+            Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB, 3),
+            Error::<MockRuntime>::AmountNotMultipleOfIncrement
+        );
+
+        assert_ok!(Refungible::transfer(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB, 5));
+    });
+}
+
+#[test]
+pub fn create_item_fails_if_holding_limits_violated() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB, CHARLIE])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_ok!(Refungible::set_collection_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            HoldingLimits {
+                max_token_holders: Some(2),
+                min_holding: Some(10),
+                transfer_increment: Some(10),
+            },
+            0
+        ));
+
+        assert_noop!(
+            TokenInitializer::new()
+                .balances(vec![(ALICE, 10), (BOB, 10), (CHARLIE, 10)])
+                .init(collection_id, ADMIN_1),
+            Error::<MockRuntime>::TokenHoldersLimitExceeded
+        );
+
+        assert_noop!(
+            TokenInitializer::new()
+                .balances(vec![(ALICE, 15)])
+                .init(collection_id, ADMIN_1),
+            Error::<MockRuntime>::AmountNotMultipleOfIncrement
+        );
+
+        assert_ok!(
+            TokenInitializer::new()
+                .balances(vec![(ALICE, 10), (BOB, 20)])
+                .init(collection_id, ADMIN_1)
+        );
+    });
+}
+
+#[test]
+pub fn burn_fails_if_holding_below_minimum() {
+    ExtBuilder::new()
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ADMIN_1, 10)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        assert_ok!(Refungible::set_token_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            token_id,
+            HoldingLimits {
+                min_holding: Some(5),
+                ..Default::default()
+            }
+        ));
+
+        assert_noop!(
+            Refungible::burn(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id, 6),
+            Error::<MockRuntime>::HoldingBelowMinimum
+        );
+
+        assert_ok!(Refungible::burn(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id, 5));
+        assert_eq!(<TokenHolders<MockRuntime>>::get((collection_id, token_id)), 1);
+
+        assert_ok!(Refungible::burn(RuntimeOrigin::signed(ADMIN_1), collection_id, token_id, 5));
+        assert_eq!(<TokenHolders<MockRuntime>>::contains_key((collection_id, token_id)), false);
+        assert_eq!(<TokenHoldingLimits<MockRuntime>>::contains_key((collection_id, token_id)), false);
+    });
+}
+
+#[test]
+pub fn rpc_methods_work() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 7), (BOB, 3)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        assert_ok!(Refungible::set_allowance(RuntimeOrigin::signed(ALICE), collection_id, token_id, BOB, 5));
+
+        assert_eq!(Refungible::token_balance(collection_id, token_id, &ALICE), 7);
+        assert_eq!(Refungible::token_balance(collection_id, token_id, &BOB), 3);
+        assert_eq!(Refungible::token_total_pieces(collection_id, token_id), 10);
+        assert_eq!(Refungible::token_allowance(collection_id, token_id, &ALICE, &BOB), 5);
+        assert_eq!(Refungible::token_allowance(collection_id, token_id, &BOB, &ALICE), 0);
+        assert_eq!(Refungible::filter_token_properties(collection_id, token_id, None), vec![]);
+    });
+}
+
+#[test]
+pub fn account_tokens_index_is_kept_consistent() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let first_collection = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+        let second_collection = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let first_token = TokenInitializer::new()
+            .balances(vec![(ALICE, 5), (BOB, 5)])
+            .init(first_collection, ADMIN_1)
+            .unwrap();
+        let second_token = TokenInitializer::new()
+            .balances(vec![(ALICE, 10), (ADMIN_1, 10)])
+            .init(second_collection, ADMIN_1)
+            .unwrap();
+
+        let mut alice_tokens = Refungible::account_tokens(&ALICE, None, 10);
+        alice_tokens.sort();
+        assert_eq!(alice_tokens, vec![(first_collection, first_token), (second_collection, second_token)]);
+        assert_eq!(Refungible::account_tokens(&BOB, None, 10), vec![(first_collection, first_token)]);
+
+        // Transfer of all pieces moves the token to the receiver
+        assert_ok!(Refungible::transfer(RuntimeOrigin::signed(ALICE), first_collection, first_token, BOB, 5));
+        assert_eq!(Refungible::account_tokens(&ALICE, None, 10), vec![(second_collection, second_token)]);
+        assert_eq!(Refungible::account_tokens(&BOB, None, 10), vec![(first_collection, first_token)]);
+
+        // Burn of all pieces removes the token
+        assert_ok!(Refungible::burn(RuntimeOrigin::signed(ADMIN_1), second_collection, second_token, 10));
+        assert_eq!(Refungible::account_tokens(&ADMIN_1, None, 10), vec![]);
+
+        for ((collection_id, owner, token_id), _) in <Owned<MockRuntime>>::iter() {
+            assert!(<AccountTokens<MockRuntime>>::get((owner, collection_id, token_id)));
+        }
+        assert_eq!(<Owned<MockRuntime>>::iter().count(), <AccountTokens<MockRuntime>>::iter().count());
+    });
+}
+
+#[test]
+pub fn enumeration_queries_are_paginated() {
+    ExtBuilder::new()
+    .investors(vec![ALICE, BOB, CHARLIE])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        assert_eq!(Refungible::last_token_id(collection_id), TokenId(0));
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 1), (BOB, 2), (CHARLIE, 3)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+        for _ in 0..2 {
+            TokenInitializer::new()
+                .balances(vec![(ALICE, 1)])
+                .init(collection_id, ADMIN_1)
+                .unwrap();
+        }
+
+        assert_eq!(Refungible::last_token_id(collection_id), TokenId(3));
+
+        let first_page = Refungible::token_holders(collection_id, token_id, None, 2);
+        assert_eq!(first_page.len(), 2);
+        let second_page = Refungible::token_holders(collection_id, token_id, Some(first_page[1].0), 2);
+        assert_eq!(second_page.len(), 1);
+
+        let mut holders: Vec<_> = first_page.into_iter().chain(second_page).collect();
+        holders.sort();
+        assert_eq!(holders, vec![(ALICE, 1), (BOB, 2), (CHARLIE, 3)]);
+
+        let first_page = Refungible::account_tokens(&ALICE, None, 2);
+        assert_eq!(first_page.len(), 2);
+        let second_page = Refungible::account_tokens(&ALICE, Some(first_page[1]), 2);
+        assert_eq!(second_page.len(), 1);
+
+        let mut tokens: Vec<_> = first_page.into_iter().chain(second_page).collect();
+        tokens.sort();
+        assert_eq!(tokens, vec![(collection_id, TokenId(1)), (collection_id, TokenId(2)), (collection_id, TokenId(3))]);
+    });
+}
+
+#[test]
+pub fn escrow_transfer_checks_recipient_unless_escrow() {
+    ExtBuilder::new()
+    .investors(vec![ALICE])
+    .wl_admins(vec![ADMIN_1])
+    .balances(vec![(ADMIN_1, 10_000 * DOLLARS)])
+    .build()
+    .execute_with(|| {
+
+        let escrow = 999;
+
+        let collection_id = CollectionInitializer::<MockRuntime>::new()
+            .init_default(ADMIN_1)
+            .expect("Collection init failed");
+
+        let token_id = TokenInitializer::new()
+            .balances(vec![(ALICE, 30)])
+            .init(collection_id, ADMIN_1)
+            .unwrap();
+
+        assert_ok!(Refungible::set_collection_holding_limits(
+            RuntimeOrigin::signed(ADMIN_1),
+            collection_id,
+            HoldingLimits {
+                min_holding: Some(10),
+                ..Default::default()
+            },
+            1
+        ));
+
+        // escrow account is neither whitelisted nor restricted by holding limits
+        assert_ok!(Refungible::escrow_transfer(collection_id, &escrow, &ALICE, &escrow, token_id, 25));
+
+        assert_noop!(
+            Refungible::escrow_transfer(collection_id, &escrow, &escrow, &BOB, token_id, 10),
+            Error::<MockRuntime>::NeitherWhitelistedNorCollectionAdmin
+        );
+        assert_noop!(
+            Refungible::escrow_transfer(collection_id, &escrow, &escrow, &ALICE, token_id, 4),
+            Error::<MockRuntime>::HoldingBelowMinimum
+        );
+
+        assert_ok!(Refungible::escrow_transfer(collection_id, &escrow, &escrow, &ALICE, token_id, 5));
+        assert_eq!(<Balance<MockRuntime>>::get((collection_id, token_id, ALICE)), 10);
+    });
+}
@@ -77,6 +77,13 @@ pub trait WeightInfo {
 	fn set_sponsor() -> Weight;
 	fn confirm_sponsorship() -> Weight;
 	fn remove_sponsor() -> Weight;
+	fn set_collection_holding_limits(b: u32, ) -> Weight;
+	fn set_token_holding_limits() -> Weight;
+	fn propose_collection_owner() -> Weight;
+	fn cancel_collection_ownership_transfer() -> Weight;
+	fn accept_collection_ownership() -> Weight;
+	fn set_sponsorship_settings() -> Weight;
+	fn toggle_sponsor_allowlist() -> Weight;
 }
 
 /// Weight functions for `pallet_refungible`.
@@ -326,4 +333,71 @@ impl WeightInfo for () {
 	fn set_sponsor() -> Weight { todo!() }
 	fn confirm_sponsorship() -> Weight { todo!() }
 	fn remove_sponsor() -> Weight { todo!() }
-}
\ No newline at end of file
+
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Refungible TokensMinted (r:1 w:0)
+	// Storage: Refungible TokensBurnt (r:1 w:0)
+	// Storage: Refungible TokenHolders (r:201 w:0)
+	// Storage: Refungible TokenHoldingLimits (r:200 w:0)
+	// Storage: Refungible CollectionHoldingLimits (r:0 w:1)
+	/// The range of component `b` is `[0, 200]`.
+	fn set_collection_holding_limits(b: u32, ) -> Weight {
+		Weight::from_parts(27_000_000 as u64, 0)
+			// Standard Error: 4_212
+			.saturating_add(Weight::from_parts(6_540_000 as u64, 0).saturating_mul(b as u64))
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(b as u64)))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Refungible TotalSupply (r:1 w:0)
+	// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	// Storage: Refungible TokenHolders (r:1 w:0)
+	// Storage: Refungible TokenHoldingLimits (r:0 w:1)
+	fn set_token_holding_limits() -> Weight {
+		Weight::from_parts(34_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist Admins (r:1 w:0)
+	// Storage: Common PendingOwner (r:0 w:1)
+	fn propose_collection_owner() -> Weight {
+		Weight::from_parts(22_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common PendingOwner (r:1 w:1)
+	fn cancel_collection_ownership_transfer() -> Weight {
+		Weight::from_parts(20_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:1)
+	// Storage: Common PendingOwner (r:1 w:1)
+	// Storage: Whitelist Admins (r:1 w:0)
+	// Storage: Common IsAdmin (r:1 w:1)
+	// Storage: Common AdminAmount (r:1 w:1)
+	fn accept_collection_ownership() -> Weight {
+		Weight::from_parts(33_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(4 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common CollectionSponsorshipSettings (r:0 w:1)
+	// Storage: Common SponsorBudgetUsage (r:0 w:1)
+	fn set_sponsorship_settings() -> Weight {
+		Weight::from_parts(20_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common SponsorAllowlist (r:0 w:1)
+	fn toggle_sponsor_allowlist() -> Weight {
+		Weight::from_parts(18_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+}
@@ -0,0 +1,324 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! # RFT wrapper pallet
+//!
+//! ## Overview
+//!
+//! Wrapping of RFT token pieces into a fungible currency, so they can be traded on `pallet_dex`.
+//!
+//! - Collection owner or admin registers a token as wrappable. The wrapped currency id
+//!   is derived from ([CollectionId], [TokenId]) by [WrappedCurrencyId].
+//! - Holders lock pieces in the pallet account and get the same amount of wrapped currency,
+//!   or burn wrapped currency to get pieces back. Unwrapped pieces must respect holding limits of the token.
+//! - [EnsureWhitelistedTransfer] should be set as `PreTransfer` hook of the currency,
+//!   so wrapped currency only moves between whitelisted investors and exempt accounts
+//!   (e.g. DEX account).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{pallet_prelude::*, traits::Contains, PalletId};
+use frame_system::pallet_prelude::*;
+
+use orml_traits::{currency::OnTransfer, MultiCurrency};
+
+use sp_runtime::traits::{AccountIdConversion, Zero};
+use sp_std::marker::PhantomData;
+
+use collection_primitives::{CollectionId, TokenId};
+use pallet_common::Error as CommonError;
+use pallet_refungible::{RefungibleHandle, TokenBalance, Pallet as PalletRefungible, TotalSupply};
+use pallet_whitelist::traits::WhitelistInterface;
+use primitives::Balance;
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Currency id of wrapped RFT token
+pub trait WrappedCurrencyId: Sized {
+	/// Currency id RFT token is wrapped into
+	fn wrapped_currency_id(collection_id: CollectionId, token_id: TokenId) -> Self;
+	/// RFT token wrapped into the currency, if any
+	fn wrapped_token(&self) -> Option<(CollectionId, TokenId)>;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_refungible::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		type CurrencyId: Parameter + Member + Copy + MaxEncodedLen + WrappedCurrencyId;
+
+		/// Currency wrapped pieces are minted in
+		type MultiCurrency: MultiCurrency<Self::AccountId, CurrencyId = Self::CurrencyId, Balance = Balance>;
+
+		/// Pieces are locked in the account derived from this id
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Accounts allowed to hold wrapped currency without being whitelisted
+		type TransferWhitelistExempt: Contains<Self::AccountId>;
+
+		type WeightInfo: weights::WeightInfo;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// RFT tokens registered for wrapping
+	#[pallet::storage]
+	#[pallet::getter(fn is_wrappable)]
+	pub type WrappedTokens<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		CollectionId,
+		Twox64Concat,
+		TokenId,
+		bool,
+		ValueQuery
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// RFT token registered for wrapping
+		WrappedTokenRegistered {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			currency_id: T::CurrencyId,
+		},
+		/// Pieces locked and wrapped currency minted
+		Wrapped {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			who: T::AccountId,
+			amount: TokenBalance,
+		},
+		/// Wrapped currency burned and pieces unlocked
+		Unwrapped {
+			collection_id: CollectionId,
+			token_id: TokenId,
+			who: T::AccountId,
+			amount: TokenBalance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Token is already registered for wrapping
+		AlreadyRegistered,
+		/// Token isn't registered for wrapping
+		NotRegistered,
+		/// Amount can't be zero
+		ZeroAmount,
+		/// Account is neither whitelisted investor nor exempt
+		NotWhitelisted,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register RFT token for wrapping.
+		///
+		/// Sender must be owner or admin of the collection.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_wrapped_token())]
+		pub fn register_wrapped_token(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let collection = RefungibleHandle::<T>::try_get(collection_id)?;
+			collection.check_is_owner_or_admin(&who)?;
+
+			ensure!(
+				<TotalSupply<T>>::contains_key((collection_id, token_id)),
+				<CommonError<T>>::TokenNotFound
+			);
+			ensure!(!Self::is_wrappable(collection_id, token_id), Error::<T>::AlreadyRegistered);
+
+			<WrappedTokens<T>>::insert(collection_id, token_id, true);
+
+			Self::deposit_event(Event::WrappedTokenRegistered {
+				collection_id,
+				token_id,
+				currency_id: T::CurrencyId::wrapped_currency_id(collection_id, token_id)
+			});
+
+			Ok(())
+		}
+
+		/// Lock `amount` pieces of the sender and mint the same amount of wrapped currency.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::wrap())]
+		pub fn wrap(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			amount: TokenBalance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_can_wrap(collection_id, token_id, &who, amount)?;
+
+			let account = Self::account_id();
+			<PalletRefungible<T>>::escrow_transfer(
+				collection_id,
+				&account,
+				&who,
+				&account,
+				token_id,
+				amount
+			)?;
+			T::MultiCurrency::deposit(
+				T::CurrencyId::wrapped_currency_id(collection_id, token_id),
+				&who,
+				amount
+			)?;
+
+			Self::deposit_event(Event::Wrapped {
+				collection_id,
+				token_id,
+				who,
+				amount
+			});
+
+			Ok(())
+		}
+
+		/// Burn `amount` of wrapped currency of the sender and unlock the same amount of pieces.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::unwrap())]
+		pub fn unwrap(
+			origin: OriginFor<T>,
+			collection_id: CollectionId,
+			token_id: TokenId,
+			amount: TokenBalance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::ensure_can_wrap(collection_id, token_id, &who, amount)?;
+
+			T::MultiCurrency::withdraw(
+				T::CurrencyId::wrapped_currency_id(collection_id, token_id),
+				&who,
+				amount
+			)?;
+			let account = Self::account_id();
+			<PalletRefungible<T>>::escrow_transfer(
+				collection_id,
+				&account,
+				&account,
+				&who,
+				token_id,
+				amount
+			)?;
+
+			Self::deposit_event(Event::Unwrapped {
+				collection_id,
+				token_id,
+				who,
+				amount
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account holding wrapped pieces
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// Check that account can hold wrapped currency
+	pub fn is_allowed_holder(who: &T::AccountId) -> bool {
+		<T as pallet_common::Config>::Whitelist::is_active_investor(who)
+			|| T::TransferWhitelistExempt::contains(who)
+			|| *who == Self::account_id()
+	}
+
+	// Same conditions as for transfer of pieces: token is wrappable, transfers are enabled
+	// and account is whitelisted
+	fn ensure_can_wrap(
+		collection_id: CollectionId,
+		token_id: TokenId,
+		who: &T::AccountId,
+		amount: TokenBalance,
+	) -> DispatchResult {
+		ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
+		ensure!(Self::is_wrappable(collection_id, token_id), Error::<T>::NotRegistered);
+
+		let collection = RefungibleHandle::<T>::try_get(collection_id)?;
+		ensure!(
+			collection.limits.transfers_enabled(),
+			<CommonError<T>>::TransfersDisabled
+		);
+		ensure!(
+			<T as pallet_common::Config>::Whitelist::is_active_investor(who),
+			Error::<T>::NotWhitelisted
+		);
+
+		Ok(())
+	}
+}
+
+/// `PreTransfer` hook rejecting transfers of wrapped currency to or from non-whitelisted accounts
+pub struct EnsureWhitelistedTransfer<T>(PhantomData<T>);
+
+impl<T: Config> OnTransfer<T::AccountId, T::CurrencyId, Balance> for EnsureWhitelistedTransfer<T> {
+	fn on_transfer(currency_id: T::CurrencyId, from: &T::AccountId, to: &T::AccountId, _amount: Balance) -> DispatchResult {
+		if currency_id.wrapped_token().is_none() {
+			return Ok(());
+		}
+
+		ensure!(
+			Pallet::<T>::is_allowed_holder(from) && Pallet::<T>::is_allowed_holder(to),
+			Error::<T>::NotWhitelisted
+		);
+
+		Ok(())
+	}
+}
+
+/// Matches the account holding wrapped pieces
+pub struct IsWrapperAccount<T>(PhantomData<T>);
+
+impl<T: Config> Contains<T::AccountId> for IsWrapperAccount<T> {
+	fn contains(who: &T::AccountId) -> bool {
+		*who == Pallet::<T>::account_id()
+	}
+}
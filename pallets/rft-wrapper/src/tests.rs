@@ -0,0 +1,216 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+#![cfg(test)]
+
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+
+use crate::mock::*;
+use crate::{Error, Event};
+
+fn wrapped(collection_id: CollectionId, token_id: TokenId) -> CurrencyId {
+	CurrencyId::WrappedRft(collection_id, token_id)
+}
+
+fn pieces(collection_id: CollectionId, token_id: TokenId, who: AccountId) -> u128 {
+	pallet_refungible::Balance::<MockRuntime>::get((collection_id, token_id, who))
+}
+
+#[test]
+fn register_wrapped_token_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10)]).unwrap();
+
+		System::assert_last_event(RuntimeEvent::RftWrapper(Event::WrappedTokenRegistered {
+			collection_id,
+			token_id,
+			currency_id: wrapped(collection_id, token_id)
+		}));
+		assert!(RftWrapper::is_wrappable(collection_id, token_id));
+	});
+}
+
+#[test]
+fn register_wrapped_token_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10)]).unwrap();
+
+		assert_noop!(
+			RftWrapper::register_wrapped_token(RuntimeOrigin::signed(ALICE), collection_id, token_id),
+			pallet_common::Error::<MockRuntime>::NoPermission
+		);
+		assert_noop!(
+			RftWrapper::register_wrapped_token(RuntimeOrigin::signed(ISSUER), collection_id, TokenId(2)),
+			pallet_common::Error::<MockRuntime>::TokenNotFound
+		);
+		assert_noop!(
+			RftWrapper::register_wrapped_token(RuntimeOrigin::signed(ISSUER), collection_id, token_id),
+			Error::<MockRuntime>::AlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn wrap_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10)]).unwrap();
+
+		assert_ok!(RftWrapper::wrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 4));
+		System::assert_last_event(RuntimeEvent::RftWrapper(Event::Wrapped {
+			collection_id,
+			token_id,
+			who: ALICE,
+			amount: 4
+		}));
+
+		assert_eq!(pieces(collection_id, token_id, ALICE), 6);
+		assert_eq!(pieces(collection_id, token_id, RftWrapper::account_id()), 4);
+		assert_eq!(Tokens::free_balance(wrapped(collection_id, token_id), &ALICE), 4);
+		assert_eq!(Tokens::total_issuance(wrapped(collection_id, token_id)), 4);
+	});
+}
+
+#[test]
+fn wrap_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10), (ISSUER, 10)]).unwrap();
+
+		assert_noop!(
+			RftWrapper::wrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 0),
+			Error::<MockRuntime>::ZeroAmount
+		);
+		assert_noop!(
+			RftWrapper::wrap(RuntimeOrigin::signed(ALICE), collection_id, TokenId(2), 1),
+			Error::<MockRuntime>::NotRegistered
+		);
+		// Collection admin isn't whitelisted investor
+		assert_noop!(
+			RftWrapper::wrap(RuntimeOrigin::signed(ISSUER), collection_id, token_id, 1),
+			Error::<MockRuntime>::NotWhitelisted
+		);
+		assert_noop!(
+			RftWrapper::wrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 11),
+			pallet_common::Error::<MockRuntime>::TokenValueTooLow
+		);
+	});
+}
+
+#[test]
+fn unwrap_works() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10)]).unwrap();
+		assert_ok!(RftWrapper::wrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 10));
+		assert_ok!(Tokens::transfer(wrapped(collection_id, token_id), &ALICE, &BOB, 3));
+
+		assert_ok!(RftWrapper::unwrap(RuntimeOrigin::signed(BOB), collection_id, token_id, 3));
+		System::assert_last_event(RuntimeEvent::RftWrapper(Event::Unwrapped {
+			collection_id,
+			token_id,
+			who: BOB,
+			amount: 3
+		}));
+
+		assert_eq!(pieces(collection_id, token_id, BOB), 3);
+		assert_eq!(pieces(collection_id, token_id, RftWrapper::account_id()), 7);
+		assert_eq!(Tokens::free_balance(wrapped(collection_id, token_id), &BOB), 0);
+		assert_eq!(Tokens::total_issuance(wrapped(collection_id, token_id)), 7);
+	});
+}
+
+#[test]
+fn unwrap_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10)]).unwrap();
+		assert_ok!(RftWrapper::wrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 5));
+
+		assert_noop!(
+			RftWrapper::unwrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 0),
+			Error::<MockRuntime>::ZeroAmount
+		);
+		assert_noop!(
+			RftWrapper::unwrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 6),
+			orml_tokens::Error::<MockRuntime>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn unwrap_respects_holding_limits() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 20)]).unwrap();
+		let currency_id = wrapped(collection_id, token_id);
+		assert_ok!(RftWrapper::wrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 20));
+		assert_ok!(Tokens::transfer(currency_id, &ALICE, &BOB, 10));
+
+		assert_ok!(Refungible::set_token_holding_limits(
+			RuntimeOrigin::signed(ISSUER),
+			collection_id,
+			token_id,
+			collection_primitives::HoldingLimits {
+				max_token_holders: Some(2),
+				min_holding: Some(5),
+				transfer_increment: None,
+			}
+		));
+
+		assert_noop!(
+			RftWrapper::unwrap(RuntimeOrigin::signed(BOB), collection_id, token_id, 3),
+			pallet_refungible::Error::<MockRuntime>::HoldingBelowMinimum
+		);
+		assert_ok!(RftWrapper::unwrap(RuntimeOrigin::signed(BOB), collection_id, token_id, 5));
+
+		// wrapper account and BOB already hold pieces
+		assert_noop!(
+			RftWrapper::unwrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 5),
+			pallet_refungible::Error::<MockRuntime>::TokenHoldersLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn wrapped_transfers_require_whitelist() {
+	ExtBuilder::default().build().execute_with(|| {
+		let (collection_id, token_id) = create_token(vec![(ALICE, 10)]).unwrap();
+		let currency_id = wrapped(collection_id, token_id);
+		assert_ok!(RftWrapper::wrap(RuntimeOrigin::signed(ALICE), collection_id, token_id, 10));
+
+		assert_ok!(Tokens::transfer(currency_id, &ALICE, &BOB, 1));
+		assert_noop!(
+			Tokens::transfer(currency_id, &ALICE, &EVE, 1),
+			Error::<MockRuntime>::NotWhitelisted
+		);
+
+		// Exempt accounts can hold wrapped currency, but can't pass it to non-whitelisted ones
+		assert_ok!(Tokens::transfer(currency_id, &ALICE, &DEX_ACCOUNT, 1));
+		assert_ok!(Tokens::transfer(currency_id, &DEX_ACCOUNT, &CHARLIE, 1));
+		assert_ok!(Tokens::transfer(currency_id, &ALICE, &DEX_ACCOUNT, 1));
+		assert_noop!(
+			Tokens::transfer(currency_id, &DEX_ACCOUNT, &EVE, 1),
+			Error::<MockRuntime>::NotWhitelisted
+		);
+
+		// Other currencies are not affected
+		assert_ok!(Tokens::transfer(DAI, &ALICE, &EVE, DOLLARS));
+	});
+}
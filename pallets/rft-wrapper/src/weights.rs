@@ -0,0 +1,84 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+//! Weights for pallet_rft_wrapper
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::weights::constants::RocksDbWeight;
+
+/// Weight functions needed for pallet_rft_wrapper.
+pub trait WeightInfo {
+	fn register_wrapped_token() -> Weight;
+	fn wrap() -> Weight;
+	fn unwrap() -> Weight;
+}
+
+impl WeightInfo for () {
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Common IsAdmin (r:1 w:0)
+	// Storage: Refungible TotalSupply (r:1 w:0)
+	// Storage: RftWrapper WrappedTokens (r:1 w:1)
+	fn register_wrapped_token() -> Weight {
+		Weight::from_parts(23_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(4 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: RftWrapper WrappedTokens (r:1 w:0)
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	// Storage: Whitelist Investors (r:1 w:0)
+	// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	// Storage: Refungible Balance (r:2 w:2)
+	// Storage: Refungible TokenHolders (r:1 w:1)
+	// Storage: Refungible AccountBalance (r:2 w:2)
+	// Storage: Refungible Owned (r:0 w:2)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn wrap() -> Weight {
+		Weight::from_parts(62_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: RftWrapper WrappedTokens (r:1 w:0)
+	// Storage: Common CollectionById (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	// Storage: Whitelist Investors (r:1 w:0)
+	// Storage: Tokens Accounts (r:1 w:1)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	// Storage: Refungible Balance (r:2 w:2)
+	// Storage: Refungible TokenHolders (r:1 w:1)
+	// Storage: Refungible AccountBalance (r:2 w:2)
+	// Storage: Refungible Owned (r:0 w:2)
+	fn unwrap() -> Weight {
+		Weight::from_parts(62_000_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+}
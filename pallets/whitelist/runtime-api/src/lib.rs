@@ -0,0 +1,46 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+/// Investor KYC hash
+pub type InvestorKey = [u8; 32];
+
+sp_api::decl_runtime_apis! {
+	/// The API to query whitelisted investors.
+	pub trait WhitelistApi<AccountId, BlockNumber>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec
+	{
+		/// Returns investors whose verification expires within `blocks` from the current block,
+		/// including already expired ones, sorted by expiry block.
+		fn investors_expiring_within(blocks: BlockNumber) -> Vec<(AccountId, InvestorKey, BlockNumber)>;
+		/// Returns investors whose verification expires before block `before`,
+		/// including already expired ones, sorted by expiry block.
+		fn investors_expiring_before(before: BlockNumber) -> Vec<(AccountId, InvestorKey, BlockNumber)>;
+		/// Returns whether the account is an investor with active status and not expired verification.
+		fn is_active_investor(account: AccountId) -> bool;
+	}
+}
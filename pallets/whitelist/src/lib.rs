@@ -34,6 +34,15 @@
 //! - `RolesRoot` is able to assign and resign `Admin`
 //! - `Admin` is the most priveleged role with plenty of control possibilities
 //! - `Manager` is able to control investors statuses
+//! 
+//! Investor verification may expire at a given block, after which the investor
+//! is treated as not active until the verification is renewed.
+//! 
+//! Investors can also register themselves with an attestation signed by one of the
+//! KYC providers registered by `RolesRoot`. The provider signs the attestation along with
+//! [KYC_ATTESTATION_PREFIX] and the genesis hash of the chain, each attestation carries the
+//! nonce of the investor key and can be used once. Investor removed by `Admin` can't register
+//! itself again.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -48,9 +57,12 @@ use sp_std::prelude::*;
 
 use frame_support::{traits::EnsureOrigin};
 use frame_support::{pallet_prelude::*, dispatch::DispatchError, fail};
+use frame_support::sp_runtime::traits::{IdentifyAccount, Saturating, Verify, Zero};
 use frame_system::pallet_prelude::*;
 
 use module_support::is_vec_unique;
+use primitives::Balance;
+use collection_primitives::SponsorshipUsage;
 
 pub mod traits;
 use traits::WhitelistInterface;
@@ -85,6 +97,47 @@ pub type InvestorKey = [u8; 32];
 /// Maximum number of investors that can be added at a time
 pub const MAX_NEW_INVESTORS: u8 = 100;
 
+/// Prefix of the payload signed by KYC providers, keeps attestations apart from other signed data
+pub const KYC_ATTESTATION_PREFIX: &[u8] = b"curio/whitelist/kyc-attestation";
+
+/// Fee sponsorship of investors' currency transfers and DEX swaps
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen, Debug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct InvestorSponsorship<AccountId, Balance, BlockNumber> {
+	/// Account paying the fees
+	pub sponsor: AccountId,
+	/// Length of the period in blocks, limits are reset once it's over
+	pub period: BlockNumber,
+	/// Maximum amount of fees paid by the sponsor in a period, unlimited if `None`
+	pub budget: Option<Balance>,
+	/// Maximum number of sponsored transactions of an investor in a period, unlimited if `None`
+	pub account_quota: Option<u32>,
+}
+
+/// Investor verification attested by a KYC provider, see [Pallet::attestation_payload] for the signed data
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct KycAttestation<AccountId, BlockNumber, Attributes> {
+	/// Investor KYC hash
+	pub investor_key: InvestorKey,
+	/// Investor AccountId
+	pub account: AccountId,
+	/// Block at which verification expires
+	pub expires_at: BlockNumber,
+	/// Provider specific investor attributes
+	pub attributes: Attributes,
+	/// Number of attestations of the investor key used before this one
+	pub nonce: u32,
+}
+
+/// KYC provider which verified the investor and the attested attributes
+#[derive(Clone, Encode, Decode, Eq, PartialEq, TypeInfo, MaxEncodedLen, Debug)]
+pub struct KycVerification<AccountId, Attributes> {
+	/// KYC provider AccountId
+	pub provider: AccountId,
+	/// Provider specific investor attributes
+	pub attributes: Attributes,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -96,12 +149,26 @@ pub mod pallet {
 
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+		/// Signature of KYC providers over investor attestations
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// Public key of KYC providers
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// Maximum length of investor attributes attested by KYC provider
+		#[pallet::constant]
+		type MaxKycAttributesLength: Get<u32>;
+
 		type WeightInfo: weights::WeightInfo;
 	}
 
 	#[pallet::pallet]
 	pub struct Pallet<T>(_);
 
+	pub type KycAttributesOf<T> = BoundedVec<u8, <T as Config>::MaxKycAttributesLength>;
+
+	pub type KycAttestationOf<T> = KycAttestation<<T as frame_system::Config>::AccountId, <T as frame_system::Config>::BlockNumber, KycAttributesOf<T>>;
+
 	/// [InvestorKey] mappend to [Investor]
 	#[pallet::storage]
 	#[pallet::getter(fn investor)]
@@ -122,6 +189,44 @@ pub mod pallet {
 	#[pallet::getter(fn is_manager)]
 	pub type Managers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
 
+	/// Sponsorship of investors' transactions, none are sponsored if not set
+	#[pallet::storage]
+	#[pallet::getter(fn investor_sponsorship)]
+	pub type InvestorSponsorshipSettings<T: Config> = StorageValue<_, InvestorSponsorship<T::AccountId, Balance, T::BlockNumber>>;
+
+	/// Fees paid by the investors sponsor in the current period
+	#[pallet::storage]
+	pub type SponsorBudgetUsage<T: Config> = StorageValue<_, SponsorshipUsage<Balance, T::BlockNumber>>;
+
+	/// Number of sponsored transactions of an investor in the current period
+	#[pallet::storage]
+	pub type SponsoredInvestorUsage<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, SponsorshipUsage<u32, T::BlockNumber>>;
+
+	/// Block at which verification of the investor with given [InvestorKey] expires
+	#[pallet::storage]
+	#[pallet::getter(fn investor_expiry)]
+	pub type InvestorExpiry<T: Config> = StorageMap<_, Blake2_128Concat, InvestorKey, T::BlockNumber>;
+
+	/// Accounts of registered KYC providers
+	#[pallet::storage]
+	#[pallet::getter(fn is_kyc_provider)]
+	pub type KycProviders<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, bool, ValueQuery>;
+
+	/// KYC provider verification of the investor with given [InvestorKey]
+	#[pallet::storage]
+	#[pallet::getter(fn investor_verification)]
+	pub type InvestorVerifications<T: Config> = StorageMap<_, Blake2_128Concat, InvestorKey, KycVerification<T::AccountId, KycAttributesOf<T>>>;
+
+	/// Nonce the next attestation of the investor with given [InvestorKey] must carry
+	#[pallet::storage]
+	#[pallet::getter(fn attestation_nonce)]
+	pub type AttestationNonces<T: Config> = StorageMap<_, Blake2_128Concat, InvestorKey, u32, ValueQuery>;
+
+	/// Keys of investors removed by `Admin`, such investors can't register themselves again
+	#[pallet::storage]
+	#[pallet::getter(fn is_investor_key_removed)]
+	pub type RemovedInvestorKeys<T: Config> = StorageMap<_, Blake2_128Concat, InvestorKey, bool, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -161,6 +266,41 @@ pub mod pallet {
 			old_account: T::AccountId,
 			new_account: T::AccountId
 		},
+		/// 'Investor' removed from the whitelist
+		InvestorRemoved {
+			who: T::AccountId,
+			investor_key: InvestorKey,
+			investor: T::AccountId
+		},
+		/// 'Investor' verification renewed, `expires_at` is `None` if it never expires
+		InvestorVerificationRenewed {
+			who: T::AccountId,
+			investor: T::AccountId,
+			expires_at: Option<T::BlockNumber>
+		},
+		/// New KYC provider added
+		KycProviderAdded {
+			provider: T::AccountId
+		},
+		/// KYC provider removed
+		KycProviderRemoved {
+			provider: T::AccountId
+		},
+		/// Investor registered with an attestation of KYC provider
+		InvestorAttested {
+			provider: T::AccountId,
+			investor_key: InvestorKey,
+			investor: T::AccountId,
+			expires_at: T::BlockNumber
+		},
+		/// Sponsorship of investors' transactions set or removed
+		InvestorSponsorshipSet {
+			sponsorship: Option<InvestorSponsorship<T::AccountId, Balance, T::BlockNumber>>
+		},
+		/// Investor's transaction is not sponsored as the sponsor budget of the period is spent
+		InvestorSponsorshipBudgetExhausted {
+			investor: T::AccountId
+		},
 	}
 
 	#[pallet::error]
@@ -192,7 +332,21 @@ pub mod pallet {
 		/// Investor status is already not active
 		AlreadyNotActive,
 		/// Can't change investor's 
-		SameAddress
+		SameAddress,
+		/// Sponsorship period can't be zero
+		ZeroSponsorshipPeriod,
+		/// Verification expiry block is not in the future
+		ExpiryInPast,
+		/// KYC provider with this AccountId already exists
+		KycProviderExists,
+		/// Account is not KYC provider
+		NotKycProvider,
+		/// Attestation is not signed by given KYC provider or is for another account
+		InvalidAttestation,
+		/// Attestation nonce is not the next nonce of the investor key
+		AttestationUsed,
+		/// Investor with this InvestorKey was removed by `Admin`
+		InvestorKeyRemoved
 	}
 
 	#[pallet::genesis_config]
@@ -309,6 +463,8 @@ pub mod pallet {
 
 		/// Add investors
 		/// 
+		/// Investors removed by `Admin` which are added back are no longer considered removed
+		/// 
 		/// - `new_investors` - array of ([InvestorKey], [Investor]) to be added
 		/// 
 		/// Fails:
@@ -384,6 +540,135 @@ pub mod pallet {
 
 			Ok(().into())
 		}
+
+		/// Set sponsorship of investors' transactions
+		/// 
+		/// - `sponsorship` - sponsor account and limits, `None` to stop sponsoring
+		/// 
+		/// Fails:
+		/// - with [BadOrigin](DispatchError::BadOrigin) when origin is not `RolesRoot`
+		/// - with [ZeroSponsorshipPeriod](crate::pallet::Error::ZeroSponsorshipPeriod) when given period is zero
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_investor_sponsorship())]
+		pub fn set_investor_sponsorship(
+			origin: OriginFor<T>,
+			sponsorship: Option<InvestorSponsorship<T::AccountId, Balance, T::BlockNumber>>
+		) -> DispatchResult {
+			T::RolesRoot::ensure_origin(origin)?;
+
+			Self::_set_investor_sponsorship(sponsorship)?;
+
+			Ok(().into())
+		}
+
+		/// Remove investor
+		/// 
+		/// Frees investor's [InvestorKey], so it can be used again
+		/// 
+		/// - `investor_account` - investor's AccountId
+		/// 
+		/// Fails:
+		/// - with [PermissionDenied](crate::pallet::Error::PermissionDenied) when origin is not `Admin`
+		/// - with [NotInvestor](crate::pallet::Error::NotInvestor) when given AccountId is not investor
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_investor())]
+		pub fn remove_investor(origin: OriginFor<T>, investor_account: T::AccountId) -> DispatchResult {
+			let who = Self::ensure_origin_is_admin(origin)?;
+
+			Self::_remove_investor(&who, &investor_account)?;
+
+			Ok(().into())
+		}
+
+		/// Renew investor verification
+		/// 
+		/// - `investor_account` - investor's AccountId
+		/// - `expires_at` - block at which verification expires, `None` if it never expires
+		/// 
+		/// Fails:
+		/// - with [PermissionDenied](crate::pallet::Error::PermissionDenied) when origin is not `Admin` or `Manager`
+		/// - with [NotInvestor](crate::pallet::Error::NotInvestor) when given AccountId is not investor
+		/// - with [ExpiryInPast](crate::pallet::Error::ExpiryInPast) when `expires_at` is not after the current block
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::renew_investor_verification())]
+		pub fn renew_investor_verification(
+			origin: OriginFor<T>,
+			investor_account: T::AccountId,
+			expires_at: Option<T::BlockNumber>
+		) -> DispatchResult {
+			let who = Self::ensure_origin_is_admin_or_manager(origin)?;
+
+			Self::_renew_investor_verification(&who, &investor_account, expires_at)?;
+
+			Ok(().into())
+		}
+
+		/// Add KYC provider
+		/// 
+		/// - `provider` - AccountId (public key) of the provider signing attestations
+		/// 
+		/// Fails:
+		/// - with [BadOrigin](DispatchError::BadOrigin) when origin is not `RolesRoot`
+		/// - with [KycProviderExists](crate::pallet::Error::KycProviderExists) when given AccountId is already KYC provider
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_kyc_provider())]
+		pub fn add_kyc_provider(origin: OriginFor<T>, provider: T::AccountId) -> DispatchResult {
+			T::RolesRoot::ensure_origin(origin)?;
+
+			Self::_add_kyc_provider(&provider)?;
+
+			Ok(().into())
+		}
+
+		/// Remove KYC provider
+		/// 
+		/// Investors registered by the provider are kept
+		/// 
+		/// - `provider` - AccountId of KYC provider
+		/// 
+		/// Fails:
+		/// - with [BadOrigin](DispatchError::BadOrigin) when origin is not `RolesRoot`
+		/// - with [NotKycProvider](crate::pallet::Error::NotKycProvider) when given AccountId is not KYC provider
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_kyc_provider())]
+		pub fn remove_kyc_provider(origin: OriginFor<T>, provider: T::AccountId) -> DispatchResult {
+			T::RolesRoot::ensure_origin(origin)?;
+
+			Self::_remove_kyc_provider(&provider)?;
+
+			Ok(().into())
+		}
+
+		/// Register myself as active investor with an attestation of KYC provider
+		/// 
+		/// Attestation can be used once, investor removed by `Admin` can't register again
+		/// 
+		/// - `attestation` - investor key, account, verification expiry, attributes and nonce
+		/// - `provider` - AccountId of KYC provider signed the attestation
+		/// - `signature` - provider signature over [attestation_payload](Pallet::attestation_payload)
+		/// 
+		/// Fails:
+		/// - with [NotKycProvider](crate::pallet::Error::NotKycProvider) when `provider` is not KYC provider
+		/// - with [InvalidAttestation](crate::pallet::Error::InvalidAttestation) when attestation account is not RuntimeOrigin or signature is invalid
+		/// - with [ExpiryInPast](crate::pallet::Error::ExpiryInPast) when attested verification is already expired
+		/// - with [AccountAlreadyInvestor](crate::pallet::Error::AccountAlreadyInvestor) when RuntimeOrigin is already an investor
+		/// - with [InvestorKeyExists](crate::pallet::Error::InvestorKeyExists) when attested [InvestorKey] is already used
+		/// - with [AttestationUsed](crate::pallet::Error::AttestationUsed) when attestation nonce is not the next one of the investor key
+		/// - with [InvestorKeyRemoved](crate::pallet::Error::InvestorKeyRemoved) when investor was removed by `Admin`
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::register_investor())]
+		pub fn register_investor(
+			origin: OriginFor<T>,
+			attestation: KycAttestationOf<T>,
+			provider: T::AccountId,
+			signature: T::OffchainSignature
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::_register_investor(&who, attestation, &provider, &signature)?;
+
+			Ok(().into())
+		}
 	}
 }
 
@@ -422,8 +707,8 @@ impl<T: Config> WhitelistInterface<T> for Pallet<T> {
 	}
 
 	fn is_active_investor(who: &T::AccountId) -> bool {
-		if let Ok((investor, _)) = Self::_try_get_investor_and_key(who) {
-			investor.is_active
+		if let Ok((investor, key)) = Self::_try_get_investor_and_key(who) {
+			investor.is_active && !Self::is_verification_expired(&key)
 		} else {
 			false
 		}
@@ -544,6 +829,9 @@ impl<T: Config> Pallet<T> {
 			KeysOfInvestors::<T>::insert(investor.account.clone(), investor_key.clone());
 
 			Investors::<T>::insert(investor_key.clone(), investor.clone());
+
+			// Investor added back by `Admin` or `Manager` is no longer considered removed
+			RemovedInvestorKeys::<T>::remove(investor_key);
 			
 			Self::deposit_event(Event::AddInvestor {
 				who: who.clone(), 
@@ -613,4 +901,283 @@ impl<T: Config> Pallet<T> {
 
 		Ok(())
 	}
-}
\ No newline at end of file
+}
+
+// Investor verification lifecycle methods
+impl<T: Config> Pallet<T> {
+	fn _remove_investor(who: &T::AccountId, investor_account: &T::AccountId) -> DispatchResult {
+		let (_, key) = Self::_try_get_investor_and_key(investor_account)?;
+
+		Investors::<T>::remove(key);
+		KeysOfInvestors::<T>::remove(investor_account);
+		InvestorExpiry::<T>::remove(key);
+		InvestorVerifications::<T>::remove(key);
+		SponsoredInvestorUsage::<T>::remove(investor_account);
+		RemovedInvestorKeys::<T>::insert(key, true);
+
+		Self::deposit_event(Event::InvestorRemoved {
+			who: who.clone(),
+			investor_key: key,
+			investor: investor_account.clone()
+		});
+
+		Ok(())
+	}
+
+	fn _renew_investor_verification(
+		who: &T::AccountId,
+		investor_account: &T::AccountId,
+		expires_at: Option<T::BlockNumber>
+	) -> DispatchResult {
+		let (_, key) = Self::_try_get_investor_and_key(investor_account)?;
+
+		match expires_at {
+			Some(expires_at) => {
+				ensure!(
+					expires_at > <frame_system::Pallet<T>>::block_number(),
+					Error::<T>::ExpiryInPast
+				);
+
+				InvestorExpiry::<T>::insert(key, expires_at);
+			},
+			None => InvestorExpiry::<T>::remove(key),
+		}
+
+		Self::deposit_event(Event::InvestorVerificationRenewed {
+			who: who.clone(),
+			investor: investor_account.clone(),
+			expires_at
+		});
+
+		Ok(())
+	}
+
+	fn is_verification_expired(key: &InvestorKey) -> bool {
+		match Self::investor_expiry(key) {
+			Some(expires_at) => <frame_system::Pallet<T>>::block_number() >= expires_at,
+			None => false,
+		}
+	}
+
+	/// Investors whose verification expires before block `before`, including already expired ones.
+	/// 
+	/// Returns investor's AccountId, [InvestorKey] and expiry block, sorted by expiry block.
+	pub fn investors_expiring_before(before: T::BlockNumber) -> Vec<(T::AccountId, InvestorKey, T::BlockNumber)> {
+		let mut expiring: Vec<_> = InvestorExpiry::<T>::iter()
+			.filter(|(_, expires_at)| *expires_at < before)
+			.filter_map(|(key, expires_at)| {
+				Self::investor(key).map(|investor| (investor.account, key, expires_at))
+			})
+			.collect();
+
+		expiring.sort_by(|a, b| a.2.cmp(&b.2));
+
+		expiring
+	}
+
+	/// Investors whose verification expires within `blocks` from the current block.
+	pub fn investors_expiring_within(blocks: T::BlockNumber) -> Vec<(T::AccountId, InvestorKey, T::BlockNumber)> {
+		let before = <frame_system::Pallet<T>>::block_number().saturating_add(blocks);
+
+		Self::investors_expiring_before(before)
+	}
+}
+
+// KYC providers methods
+impl<T: Config> Pallet<T> {
+	fn _add_kyc_provider(provider: &T::AccountId) -> DispatchResult {
+		ensure!(
+			!Self::is_kyc_provider(provider),
+			Error::<T>::KycProviderExists
+		);
+
+		KycProviders::<T>::insert(provider, true);
+
+		Self::deposit_event(Event::KycProviderAdded { provider: provider.clone() });
+
+		Ok(())
+	}
+
+	fn _remove_kyc_provider(provider: &T::AccountId) -> DispatchResult {
+		ensure!(
+			Self::is_kyc_provider(provider),
+			Error::<T>::NotKycProvider
+		);
+
+		KycProviders::<T>::remove(provider);
+
+		Self::deposit_event(Event::KycProviderRemoved { provider: provider.clone() });
+
+		Ok(())
+	}
+
+	fn _register_investor(
+		who: &T::AccountId,
+		attestation: KycAttestationOf<T>,
+		provider: &T::AccountId,
+		signature: &T::OffchainSignature
+	) -> DispatchResult {
+		ensure!(
+			Self::is_kyc_provider(provider),
+			Error::<T>::NotKycProvider
+		);
+
+		ensure!(
+			attestation.account == *who && signature.verify(&Self::attestation_payload(&attestation)[..], provider),
+			Error::<T>::InvalidAttestation
+		);
+
+		ensure!(
+			attestation.expires_at > <frame_system::Pallet<T>>::block_number(),
+			Error::<T>::ExpiryInPast
+		);
+
+		let KycAttestation { investor_key, account, expires_at, attributes, nonce } = attestation;
+		let investor = Investor { account: account.clone(), is_active: true };
+
+		Self::_verify_add_investors(&sp_std::vec![(investor_key, investor.clone())])?;
+
+		ensure!(
+			nonce == Self::attestation_nonce(investor_key),
+			Error::<T>::AttestationUsed
+		);
+
+		ensure!(
+			!Self::is_investor_key_removed(investor_key),
+			Error::<T>::InvestorKeyRemoved
+		);
+
+		KeysOfInvestors::<T>::insert(account.clone(), investor_key);
+		Investors::<T>::insert(investor_key, investor);
+		InvestorExpiry::<T>::insert(investor_key, expires_at);
+		InvestorVerifications::<T>::insert(investor_key, KycVerification {
+			provider: provider.clone(),
+			attributes
+		});
+		AttestationNonces::<T>::insert(investor_key, nonce.saturating_add(1));
+
+		Self::deposit_event(Event::InvestorAttested {
+			provider: provider.clone(),
+			investor_key,
+			investor: account,
+			expires_at
+		});
+
+		Ok(())
+	}
+
+	/// Data signed by KYC provider for `attestation`
+	/// 
+	/// SCALE encoding of [KYC_ATTESTATION_PREFIX], the genesis hash and the attestation,
+	/// so the attestation is only valid on this chain.
+	pub fn attestation_payload(attestation: &KycAttestationOf<T>) -> Vec<u8> {
+		let genesis_hash = <frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+
+		(KYC_ATTESTATION_PREFIX, genesis_hash, attestation).encode()
+	}
+}
+
+// Investors sponsorship methods
+impl<T: Config> Pallet<T> {
+	fn _set_investor_sponsorship(
+		sponsorship: Option<InvestorSponsorship<T::AccountId, Balance, T::BlockNumber>>
+	) -> DispatchResult {
+		if let Some(sponsorship) = &sponsorship {
+			ensure!(
+				!sponsorship.period.is_zero(),
+				Error::<T>::ZeroSponsorshipPeriod
+			);
+		}
+
+		InvestorSponsorshipSettings::<T>::set(sponsorship.clone());
+		// Budget of the new sponsor isn't affected by fees paid by the previous one
+		SponsorBudgetUsage::<T>::kill();
+
+		Self::deposit_event(Event::InvestorSponsorshipSet { sponsorship });
+
+		Ok(())
+	}
+
+	/// Sponsor paying `fee` for a transaction of `who`, if any.
+	/// 
+	/// Only active investors are sponsored, within the quota and the budget of the period.
+	/// Read only, call [`charge_investor_sponsorship`](Self::charge_investor_sponsorship)
+	/// once the transaction is sponsored.
+	pub fn investor_sponsor(who: &T::AccountId, fee: Balance) -> Option<T::AccountId> {
+		let sponsorship = Self::investor_sponsorship()?;
+
+		if !<Self as WhitelistInterface<T>>::is_active_investor(who) {
+			return None;
+		}
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		if let Some(quota) = sponsorship.account_quota {
+			let used = SponsorshipUsage::current(
+				SponsoredInvestorUsage::<T>::get(who),
+				sponsorship.period,
+				block_number
+			).used;
+			if used >= quota {
+				return None;
+			}
+		}
+
+		if Self::is_investor_sponsorship_budget_exceeded(fee) {
+			return None;
+		}
+
+		Some(sponsorship.sponsor)
+	}
+
+	/// Whether `fee` exceeds the budget of the investors sponsor left in the period.
+	pub fn is_investor_sponsorship_budget_exceeded(fee: Balance) -> bool {
+		let sponsorship = match Self::investor_sponsorship() {
+			Some(sponsorship) => sponsorship,
+			None => return false,
+		};
+
+		match sponsorship.budget {
+			Some(budget) => {
+				let used = SponsorshipUsage::current(
+					SponsorBudgetUsage::<T>::get(),
+					sponsorship.period,
+					<frame_system::Pallet<T>>::block_number()
+				).used;
+				used.saturating_add(fee) > budget
+			},
+			None => false,
+		}
+	}
+
+	/// Note that a transaction of the active investor `who` isn't sponsored, as the budget is exhausted.
+	pub fn note_investor_sponsorship_budget_exhausted(who: &T::AccountId) {
+		Self::deposit_event(Event::InvestorSponsorshipBudgetExhausted { investor: who.clone() });
+	}
+
+	/// Account `fee` paid by the investors sponsor for a transaction of `who`.
+	pub fn charge_investor_sponsorship(who: &T::AccountId, fee: Balance) {
+		let sponsorship = match Self::investor_sponsorship() {
+			Some(sponsorship) => sponsorship,
+			None => return,
+		};
+
+		let block_number = <frame_system::Pallet<T>>::block_number();
+
+		if sponsorship.account_quota.is_some() {
+			SponsoredInvestorUsage::<T>::mutate(who, |usage| {
+				let mut current = SponsorshipUsage::current(usage.take(), sponsorship.period, block_number);
+				current.used = current.used.saturating_add(1);
+				*usage = Some(current);
+			});
+		}
+
+		if sponsorship.budget.is_some() {
+			SponsorBudgetUsage::<T>::mutate(|usage| {
+				let mut current = SponsorshipUsage::current(usage.take(), sponsorship.period, block_number);
+				current.used = current.used.saturating_add(fee);
+				*usage = Some(current);
+			});
+		}
+	}
+}
@@ -22,11 +22,11 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 use crate::{self as pallet_whitelist, InvestorKey, Investor, MAX_NEW_INVESTORS};
-use frame_support::{parameter_types, ord_parameter_types, traits::Everything};
+use frame_support::{parameter_types, ord_parameter_types, traits::{ConstU32, Everything}};
 use frame_system as system;
 use sp_core::H256;
 use sp_runtime::{
-	testing::Header,
+	testing::{Header, TestSignature, UintAuthorityId},
 	traits::{BlakeTwo256, IdentityLookup},
 };
 use system::{EnsureSignedBy};
@@ -212,6 +212,9 @@ parameter_types! {
 impl pallet_whitelist::Config for Test {
     type RolesRoot = EnsureSignedBy<RolesRoot, Self::AccountId>;
 	type RuntimeEvent = RuntimeEvent;
+	type OffchainSignature = TestSignature;
+	type OffchainPublic = UintAuthorityId;
+	type MaxKycAttributesLength = ConstU32<16>;
 	type WeightInfo = ();
 }
 
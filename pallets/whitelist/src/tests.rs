@@ -23,8 +23,13 @@
 
 #![cfg(test)]
 
-use crate::{mock::*, Error, Investor, traits::WhitelistInterface};
+use crate::{
+    mock::*, Error, Investor, InvestorSponsorship, KycAttestation, KycVerification, KYC_ATTESTATION_PREFIX,
+    traits::WhitelistInterface
+};
+use codec::Encode;
 use frame_support::{assert_noop, assert_ok, dispatch::DispatchError};
+use sp_runtime::testing::TestSignature;
 
 #[test]
 fn ensure_roles_root_works() {
@@ -896,4 +901,507 @@ fn change_my_address_fails_if_not_investor() {
             Error::<Test>::NotInvestor
         );
 	});
-}
\ No newline at end of file
+}
+#[test]
+fn set_investor_sponsorship_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        let sponsorship = InvestorSponsorship { sponsor: DAVE, period: 10, budget: Some(100), account_quota: Some(1) };
+
+        assert_noop!(
+            Whitelist::set_investor_sponsorship(RuntimeOrigin::signed(ALICE), Some(sponsorship.clone())),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Whitelist::set_investor_sponsorship(
+                RuntimeOrigin::signed(ROLES_ROOT),
+                Some(InvestorSponsorship { period: 0, ..sponsorship.clone() })
+            ),
+            Error::<Test>::ZeroSponsorshipPeriod
+        );
+
+        assert_ok!(Whitelist::set_investor_sponsorship(RuntimeOrigin::signed(ROLES_ROOT), Some(sponsorship.clone())));
+        assert_eq!(Whitelist::investor_sponsorship(), Some(sponsorship.clone()));
+
+        System::assert_last_event(RuntimeEvent::Whitelist(crate::Event::InvestorSponsorshipSet {
+            sponsorship: Some(sponsorship)
+        }));
+
+        assert_ok!(Whitelist::set_investor_sponsorship(RuntimeOrigin::signed(ROLES_ROOT), None));
+        assert_eq!(Whitelist::investor_sponsorship(), None);
+	});
+}
+
+#[test]
+fn investor_sponsorship_limits_are_checked() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::signed(ROLES_ROOT), ALICE));
+        assert_ok!(Whitelist::add_investors(
+            RuntimeOrigin::signed(ALICE),
+            vec![
+                ([0u8; 32], Investor {account: BOB, is_active: true}),
+                ([1u8; 32], Investor {account: CHARLIE, is_active: true}),
+                ([2u8; 32], Investor {account: EVE, is_active: false})
+            ]
+        ));
+
+        // Nothing is sponsored until the sponsorship is set
+        assert_eq!(Whitelist::investor_sponsor(&BOB, 10), None);
+
+        assert_ok!(Whitelist::set_investor_sponsorship(
+            RuntimeOrigin::signed(ROLES_ROOT),
+            Some(InvestorSponsorship { sponsor: DAVE, period: 10, budget: Some(100), account_quota: Some(1) })
+        ));
+
+        // Only active investors are sponsored
+        assert_eq!(Whitelist::investor_sponsor(&EVE, 10), None);
+        assert_eq!(Whitelist::investor_sponsor(&ALICE, 10), None);
+
+        // Lookup doesn't change the state
+        let events_before = System::events().len();
+        assert_eq!(Whitelist::investor_sponsor(&BOB, 101), None);
+        assert!(Whitelist::is_investor_sponsorship_budget_exceeded(101));
+        assert_eq!(System::events().len(), events_before);
+        assert_eq!(Whitelist::investor_sponsor(&BOB, 60), Some(DAVE));
+        assert_eq!(Whitelist::investor_sponsor(&BOB, 60), Some(DAVE));
+
+        assert_eq!(Whitelist::investor_sponsor(&BOB, 60), Some(DAVE));
+        Whitelist::charge_investor_sponsorship(&BOB, 60);
+
+        // Quota of the investor is used
+        assert_eq!(Whitelist::investor_sponsor(&BOB, 10), None);
+        // Budget left is not enough
+        assert_eq!(Whitelist::investor_sponsor(&CHARLIE, 60), None);
+        assert_eq!(Whitelist::investor_sponsor(&CHARLIE, 40), Some(DAVE));
+
+        // Limits are reset once the period is over
+        System::set_block_number(11);
+        assert_eq!(Whitelist::investor_sponsor(&BOB, 60), Some(DAVE));
+	});
+}
+
+#[test]
+fn remove_investor_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::signed(ROLES_ROOT), ALICE));
+        assert_ok!(Whitelist::add_manager(RuntimeOrigin::signed(ALICE), EVE));
+        assert_ok!(Whitelist::add_investors(
+            RuntimeOrigin::signed(ALICE),
+            vec![([0u8; 32], Investor {account: BOB, is_active: true})]
+        ));
+        assert_ok!(Whitelist::renew_investor_verification(RuntimeOrigin::signed(ALICE), BOB, Some(10)));
+
+        assert_noop!(
+            Whitelist::remove_investor(RuntimeOrigin::signed(EVE), BOB),
+            Error::<Test>::PermissionDenied
+        );
+        assert_noop!(
+            Whitelist::remove_investor(RuntimeOrigin::signed(ALICE), CHARLIE),
+            Error::<Test>::NotInvestor
+        );
+
+        assert_ok!(Whitelist::remove_investor(RuntimeOrigin::signed(ALICE), BOB));
+
+        assert_eq!(Whitelist::investor_key(BOB), None);
+        assert_eq!(Whitelist::investor([0u8; 32]), None);
+        assert_eq!(Whitelist::investor_expiry([0u8; 32]), None);
+        assert!(!Whitelist::is_active_investor(&BOB));
+
+        System::assert_last_event(RuntimeEvent::Whitelist(crate::Event::InvestorRemoved {
+            who: ALICE,
+            investor_key: [0u8; 32],
+            investor: BOB
+        }));
+
+        // Investor key is free to be used again
+        assert_ok!(Whitelist::add_investors(
+            RuntimeOrigin::signed(ALICE),
+            vec![([0u8; 32], Investor {account: CHARLIE, is_active: true})]
+        ));
+        assert_eq!(Whitelist::investor_expiry([0u8; 32]), None);
+        assert!(Whitelist::is_active_investor(&CHARLIE));
+	});
+}
+
+#[test]
+fn investor_verification_expires() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::signed(ROLES_ROOT), ALICE));
+        assert_ok!(Whitelist::add_manager(RuntimeOrigin::signed(ALICE), EVE));
+        assert_ok!(Whitelist::add_investors(
+            RuntimeOrigin::signed(ALICE),
+            vec![([0u8; 32], Investor {account: BOB, is_active: true})]
+        ));
+
+        assert_noop!(
+            Whitelist::renew_investor_verification(RuntimeOrigin::signed(DAVE), BOB, Some(10)),
+            Error::<Test>::PermissionDenied
+        );
+        assert_noop!(
+            Whitelist::renew_investor_verification(RuntimeOrigin::signed(EVE), CHARLIE, Some(10)),
+            Error::<Test>::NotInvestor
+        );
+        assert_noop!(
+            Whitelist::renew_investor_verification(RuntimeOrigin::signed(EVE), BOB, Some(1)),
+            Error::<Test>::ExpiryInPast
+        );
+
+        assert_ok!(Whitelist::renew_investor_verification(RuntimeOrigin::signed(EVE), BOB, Some(10)));
+        assert_eq!(Whitelist::investor_expiry([0u8; 32]), Some(10));
+
+        System::assert_last_event(RuntimeEvent::Whitelist(crate::Event::InvestorVerificationRenewed {
+            who: EVE,
+            investor: BOB,
+            expires_at: Some(10)
+        }));
+
+        System::set_block_number(9);
+        assert!(Whitelist::is_active_investor(&BOB));
+
+        System::set_block_number(10);
+        assert!(!Whitelist::is_active_investor(&BOB));
+        // Status itself is kept, only verification is expired
+        assert!(Whitelist::investor([0u8; 32]).unwrap().is_active);
+
+        assert_ok!(Whitelist::renew_investor_verification(RuntimeOrigin::signed(EVE), BOB, Some(20)));
+        assert!(Whitelist::is_active_investor(&BOB));
+
+        assert_ok!(Whitelist::renew_investor_verification(RuntimeOrigin::signed(EVE), BOB, None));
+        assert_eq!(Whitelist::investor_expiry([0u8; 32]), None);
+
+        System::set_block_number(100);
+        assert!(Whitelist::is_active_investor(&BOB));
+	});
+}
+
+#[test]
+fn investors_expiring_soon_are_listed() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::signed(ROLES_ROOT), ALICE));
+        assert_ok!(Whitelist::add_investors(
+            RuntimeOrigin::signed(ALICE),
+            vec![
+                ([0u8; 32], Investor {account: BOB, is_active: true}),
+                ([1u8; 32], Investor {account: CHARLIE, is_active: true}),
+                ([2u8; 32], Investor {account: EVE, is_active: true})
+            ]
+        ));
+        assert_ok!(Whitelist::renew_investor_verification(RuntimeOrigin::signed(ALICE), BOB, Some(30)));
+        assert_ok!(Whitelist::renew_investor_verification(RuntimeOrigin::signed(ALICE), CHARLIE, Some(10)));
+
+        assert_eq!(Whitelist::investors_expiring_within(5), vec![]);
+        assert_eq!(
+            Whitelist::investors_expiring_within(50),
+            vec![(CHARLIE, [1u8; 32], 10), (BOB, [0u8; 32], 30)]
+        );
+
+        // Already expired investors are listed as well
+        System::set_block_number(20);
+        assert_eq!(
+            Whitelist::investors_expiring_within(5),
+            vec![(CHARLIE, [1u8; 32], 10)]
+        );
+        assert_eq!(
+            Whitelist::investors_expiring_before(31),
+            vec![(CHARLIE, [1u8; 32], 10), (BOB, [0u8; 32], 30)]
+        );
+	});
+}
+
+#[test]
+fn add_and_remove_kyc_provider_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_noop!(
+            Whitelist::add_kyc_provider(RuntimeOrigin::signed(ALICE), DAVE),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Whitelist::add_kyc_provider(RuntimeOrigin::signed(ROLES_ROOT), DAVE));
+        assert!(Whitelist::is_kyc_provider(DAVE));
+        System::assert_last_event(RuntimeEvent::Whitelist(crate::Event::KycProviderAdded { provider: DAVE }));
+
+        assert_noop!(
+            Whitelist::add_kyc_provider(RuntimeOrigin::signed(ROLES_ROOT), DAVE),
+            Error::<Test>::KycProviderExists
+        );
+
+        assert_noop!(
+            Whitelist::remove_kyc_provider(RuntimeOrigin::signed(ALICE), DAVE),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(Whitelist::remove_kyc_provider(RuntimeOrigin::signed(ROLES_ROOT), DAVE));
+        assert!(!Whitelist::is_kyc_provider(DAVE));
+        System::assert_last_event(RuntimeEvent::Whitelist(crate::Event::KycProviderRemoved { provider: DAVE }));
+
+        assert_noop!(
+            Whitelist::remove_kyc_provider(RuntimeOrigin::signed(ROLES_ROOT), DAVE),
+            Error::<Test>::NotKycProvider
+        );
+	});
+}
+
+#[test]
+fn register_investor_works() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Whitelist::add_kyc_provider(RuntimeOrigin::signed(ROLES_ROOT), DAVE));
+
+        let attestation = KycAttestation {
+            investor_key: [0u8; 32],
+            account: BOB,
+            expires_at: 10,
+            attributes: b"country:LI".to_vec().try_into().unwrap(),
+            nonce: 0
+        };
+        let signature = TestSignature(DAVE, Whitelist::attestation_payload(&attestation));
+
+        assert_ok!(Whitelist::register_investor(
+            RuntimeOrigin::signed(BOB),
+            attestation.clone(),
+            DAVE,
+            signature.clone()
+        ));
+
+        assert_eq!(Whitelist::investor_key(BOB), Some([0u8; 32]));
+        assert_eq!(Whitelist::investor([0u8; 32]), Some(Investor {account: BOB, is_active: true}));
+        assert_eq!(Whitelist::investor_expiry([0u8; 32]), Some(10));
+        assert_eq!(
+            Whitelist::investor_verification([0u8; 32]),
+            Some(KycVerification { provider: DAVE, attributes: attestation.attributes.clone() })
+        );
+        assert!(Whitelist::is_active_investor(&BOB));
+
+        System::assert_last_event(RuntimeEvent::Whitelist(crate::Event::InvestorAttested {
+            provider: DAVE,
+            investor_key: [0u8; 32],
+            investor: BOB,
+            expires_at: 10
+        }));
+
+        assert_noop!(
+            Whitelist::register_investor(RuntimeOrigin::signed(BOB), attestation, DAVE, signature),
+            Error::<Test>::AccountAlreadyInvestor
+        );
+
+        System::set_block_number(10);
+        assert!(!Whitelist::is_active_investor(&BOB));
+	});
+}
+
+#[test]
+fn register_investor_fails_with_invalid_attestation() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(5);
+
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::signed(ROLES_ROOT), ALICE));
+        assert_ok!(Whitelist::add_investors(
+            RuntimeOrigin::signed(ALICE),
+            vec![([1u8; 32], Investor {account: CHARLIE, is_active: true})]
+        ));
+        assert_ok!(Whitelist::add_kyc_provider(RuntimeOrigin::signed(ROLES_ROOT), DAVE));
+
+        let attestation = KycAttestation {
+            investor_key: [0u8; 32],
+            account: BOB,
+            expires_at: 10,
+            attributes: Default::default(),
+            nonce: 0
+        };
+
+        // Provider is not registered
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                attestation.clone(),
+                EVE,
+                TestSignature(EVE, Whitelist::attestation_payload(&attestation))
+            ),
+            Error::<Test>::NotKycProvider
+        );
+
+        // Signed by another account
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                attestation.clone(),
+                DAVE,
+                TestSignature(EVE, Whitelist::attestation_payload(&attestation))
+            ),
+            Error::<Test>::InvalidAttestation
+        );
+
+        // Signed data differs from the attestation
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                KycAttestation { expires_at: 20, ..attestation.clone() },
+                DAVE,
+                TestSignature(DAVE, Whitelist::attestation_payload(&attestation))
+            ),
+            Error::<Test>::InvalidAttestation
+        );
+
+        // Attestation of another account
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(EVE),
+                attestation.clone(),
+                DAVE,
+                TestSignature(DAVE, Whitelist::attestation_payload(&attestation))
+            ),
+            Error::<Test>::InvalidAttestation
+        );
+
+        let expired = KycAttestation { expires_at: 5, ..attestation.clone() };
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                expired.clone(),
+                DAVE,
+                TestSignature(DAVE, Whitelist::attestation_payload(&expired))
+            ),
+            Error::<Test>::ExpiryInPast
+        );
+
+        let used_key = KycAttestation { investor_key: [1u8; 32], ..attestation.clone() };
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                used_key.clone(),
+                DAVE,
+                TestSignature(DAVE, Whitelist::attestation_payload(&used_key))
+            ),
+            Error::<Test>::InvestorKeyExists
+        );
+	});
+}
+
+#[test]
+fn register_investor_fails_with_attestation_of_another_chain_or_used_nonce() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Whitelist::add_kyc_provider(RuntimeOrigin::signed(ROLES_ROOT), DAVE));
+
+        let attestation = KycAttestation {
+            investor_key: [0u8; 32],
+            account: BOB,
+            expires_at: 10,
+            attributes: Default::default(),
+            nonce: 0
+        };
+
+        // Attestation signed without the prefix and the genesis hash
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                attestation.clone(),
+                DAVE,
+                TestSignature(DAVE, attestation.encode())
+            ),
+            Error::<Test>::InvalidAttestation
+        );
+
+        // Attestation signed for another chain
+        let other_chain_payload = (KYC_ATTESTATION_PREFIX, [1u8; 32], &attestation).encode();
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                attestation.clone(),
+                DAVE,
+                TestSignature(DAVE, other_chain_payload)
+            ),
+            Error::<Test>::InvalidAttestation
+        );
+
+        let ahead = KycAttestation { nonce: 1, ..attestation.clone() };
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                ahead.clone(),
+                DAVE,
+                TestSignature(DAVE, Whitelist::attestation_payload(&ahead))
+            ),
+            Error::<Test>::AttestationUsed
+        );
+
+        assert_ok!(Whitelist::register_investor(
+            RuntimeOrigin::signed(BOB),
+            attestation.clone(),
+            DAVE,
+            TestSignature(DAVE, Whitelist::attestation_payload(&attestation))
+        ));
+        assert_eq!(Whitelist::attestation_nonce([0u8; 32]), 1);
+
+	});
+}
+
+#[test]
+fn removed_investor_cannot_register_again() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+
+        assert_ok!(Whitelist::add_admin(RuntimeOrigin::signed(ROLES_ROOT), ALICE));
+        assert_ok!(Whitelist::add_kyc_provider(RuntimeOrigin::signed(ROLES_ROOT), DAVE));
+
+        let attestation = KycAttestation {
+            investor_key: [0u8; 32],
+            account: BOB,
+            expires_at: 10,
+            attributes: Default::default(),
+            nonce: 0
+        };
+        assert_ok!(Whitelist::register_investor(
+            RuntimeOrigin::signed(BOB),
+            attestation.clone(),
+            DAVE,
+            TestSignature(DAVE, Whitelist::attestation_payload(&attestation))
+        ));
+
+        assert_ok!(Whitelist::remove_investor(RuntimeOrigin::signed(ALICE), BOB));
+        assert!(Whitelist::is_investor_key_removed([0u8; 32]));
+
+        // Replayed attestation
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                attestation.clone(),
+                DAVE,
+                TestSignature(DAVE, Whitelist::attestation_payload(&attestation))
+            ),
+            Error::<Test>::AttestationUsed
+        );
+
+        // Fresh attestation
+        let fresh = KycAttestation { nonce: 1, ..attestation.clone() };
+        assert_noop!(
+            Whitelist::register_investor(
+                RuntimeOrigin::signed(BOB),
+                fresh.clone(),
+                DAVE,
+                TestSignature(DAVE, Whitelist::attestation_payload(&fresh))
+            ),
+            Error::<Test>::InvestorKeyRemoved
+        );
+
+        // Admin may still add the investor back
+        assert_ok!(Whitelist::add_investors(
+            RuntimeOrigin::signed(ALICE),
+            vec![([0u8; 32], Investor {account: BOB, is_active: true})]
+        ));
+        assert!(!Whitelist::is_investor_key_removed([0u8; 32]));
+	});
+}
@@ -11,6 +11,12 @@ pub trait WeightInfo {
     fn set_investor_status() -> Weight;
     fn change_investor_address() -> Weight;
     fn change_my_address() -> Weight;
+    fn set_investor_sponsorship() -> Weight;
+    fn remove_investor() -> Weight;
+    fn renew_investor_verification() -> Weight;
+    fn add_kyc_provider() -> Weight;
+    fn remove_kyc_provider() -> Weight;
+    fn register_investor() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -47,6 +53,7 @@ impl WeightInfo for () {
 	// Storage: Whitelist Admins (r:1 w:0)
 	// Storage: Whitelist KeysOfInvestors (r:1 w:1)
 	// Storage: Whitelist Investors (r:1 w:1)
+	// Storage: Whitelist RemovedInvestorKeys (r:0 w:1)
 	/// The range of component `i` is `[1, 100]`.
 	fn add_investors(i: u32, ) -> Weight {
 		// Minimum execution time: 74_009 nanoseconds.
@@ -55,7 +62,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(18_466_297 as u64, 0).saturating_mul(i as u64))
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().reads((2 as u64).saturating_mul(i as u64)))
-			.saturating_add(RocksDbWeight::get().writes((2 as u64).saturating_mul(i as u64)))
+			.saturating_add(RocksDbWeight::get().writes((3 as u64).saturating_mul(i as u64)))
 	}
 	// Storage: Whitelist Admins (r:1 w:0)
 	// Storage: Whitelist KeysOfInvestors (r:1 w:0)
@@ -83,4 +90,62 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as u64))
 			.saturating_add(RocksDbWeight::get().writes(3 as u64))
 	}
-}
\ No newline at end of file
+	// Storage: Whitelist InvestorSponsorshipSettings (r:0 w:1)
+	// Storage: Whitelist SponsorBudgetUsage (r:0 w:1)
+	fn set_investor_sponsorship() -> Weight {
+		// Minimum execution time: 21_460 nanoseconds.
+		Weight::from_parts(22_125_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Whitelist Admins (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:1 w:1)
+	// Storage: Whitelist Investors (r:1 w:1)
+	// Storage: Whitelist InvestorExpiry (r:0 w:1)
+	// Storage: Whitelist InvestorVerifications (r:0 w:1)
+	// Storage: Whitelist SponsoredInvestorUsage (r:0 w:1)
+	// Storage: Whitelist RemovedInvestorKeys (r:0 w:1)
+	fn remove_investor() -> Weight {
+		// Minimum execution time: 78_531 nanoseconds.
+		Weight::from_parts(82_906_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Whitelist Admins (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	// Storage: Whitelist Investors (r:1 w:0)
+	// Storage: Whitelist InvestorExpiry (r:0 w:1)
+	fn renew_investor_verification() -> Weight {
+		// Minimum execution time: 56_127 nanoseconds.
+		Weight::from_parts(57_310_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(3 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Whitelist KycProviders (r:1 w:1)
+	fn add_kyc_provider() -> Weight {
+		// Minimum execution time: 50_912 nanoseconds.
+		Weight::from_parts(52_144_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Whitelist KycProviders (r:1 w:1)
+	fn remove_kyc_provider() -> Weight {
+		// Minimum execution time: 52_307 nanoseconds.
+		Weight::from_parts(54_890_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Whitelist KycProviders (r:1 w:0)
+	// Storage: Whitelist KeysOfInvestors (r:1 w:1)
+	// Storage: Whitelist Investors (r:1 w:1)
+	// Storage: Whitelist InvestorExpiry (r:0 w:1)
+	// Storage: System BlockHash (r:1 w:0)
+	// Storage: Whitelist AttestationNonces (r:1 w:1)
+	// Storage: Whitelist RemovedInvestorKeys (r:1 w:0)
+	// Storage: Whitelist InvestorVerifications (r:0 w:1)
+	fn register_investor() -> Weight {
+		// Minimum execution time: 143_218 nanoseconds.
+		Weight::from_parts(147_902_000 as u64, 0)
+			.saturating_add(RocksDbWeight::get().reads(6 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+}
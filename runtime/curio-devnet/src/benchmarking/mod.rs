@@ -25,6 +25,13 @@ pub mod pallet_dex;
 pub mod pallet_currencies;
 pub mod pallet_whitelist;
 pub mod pallet_refungible;
+pub mod pallet_nonfungible;
+pub mod pallet_offering;
+pub mod pallet_redemption;
+pub mod pallet_holder_governance;
+pub mod pallet_rft_wrapper;
+pub mod pallet_liquid_staking;
+pub mod pallet_fee_currencies;
 pub mod transaction_pause;
 
 pub mod orml_tokens;
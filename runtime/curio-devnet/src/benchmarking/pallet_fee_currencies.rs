@@ -0,0 +1,106 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use frame_benchmarking::{whitelisted_caller, account};
+use frame_support::traits::Get;
+use sp_std::prelude::*;
+
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+
+use primitives::AccountId;
+
+use crate::{
+	Currencies, FeeCurrencies, Runtime, RuntimeOrigin,
+	currency_id::{CurrencyId, CGT, DAI, DOT, USDT},
+};
+
+use pallet_fee_currencies::{Event as PalletEvent, FeePayment};
+
+use super::{
+	pallet_dex::inject_liquidity,
+	utils::{assert_last_event, set_balance, token_unit},
+};
+
+const SEED: u32 = 0;
+
+/// Fee currencies along the longest swap path, ending with the native currency
+const FEE_SWAP_PATH: [CurrencyId; 3] = [DAI, DOT, CGT];
+
+runtime_benchmarks! {
+	{Runtime, pallet_fee_currencies}
+
+	set_fee_currency {
+	}: _(RuntimeOrigin::root(), USDT, vec![USDT, CGT])
+	verify {
+		assert_last_event(PalletEvent::FeeCurrencySet {
+			currency_id: USDT,
+			path: vec![USDT, CGT]
+		}.into());
+	}
+
+	remove_fee_currency {
+		FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), USDT, vec![USDT, CGT]).unwrap();
+	}: _(RuntimeOrigin::root(), USDT)
+	verify {
+		assert_last_event(PalletEvent::FeeCurrencyRemoved {
+			currency_id: USDT
+		}.into());
+	}
+
+	swap_fee {
+		let u in 2 .. <Runtime as pallet_fee_currencies::Config>::MaxSwapPathLength::get();
+
+		let maker: AccountId = account("maker", 0, SEED);
+		let caller: AccountId = whitelisted_caller();
+
+		let path = FEE_SWAP_PATH[FEE_SWAP_PATH.len() - u as usize ..].to_vec();
+		for pair in path.windows(2) {
+			inject_liquidity(maker.clone(), pair[0], pair[1], 10_000 * token_unit(pair[0]), 10_000 * token_unit(pair[1]), false)?;
+		}
+		FeeCurrencies::set_fee_currency(RuntimeOrigin::root(), path[0], path.clone())?;
+		set_balance(path[0], &caller, 10_000 * token_unit(path[0]));
+
+		let payment = FeePayment {
+			currency_id: path[0],
+			max_amount: 100 * token_unit(path[0]),
+		};
+	}: {
+		FeeCurrencies::swap_fee(&caller, &payment, token_unit(CGT)).map_err(|_| "fee swap failed")?;
+	}
+	verify {
+		let path_limit: u32 = <Runtime as pallet_fee_currencies::Config>::MaxSwapPathLength::get();
+		// would panic the benchmark anyways, must add new currencies to FEE_SWAP_PATH for benchmarking to work
+		assert!(path_limit <= FEE_SWAP_PATH.len() as u32);
+		assert!(Currencies::free_balance(CGT, &caller) >= token_unit(CGT));
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use crate::benchmarking::utils::tests::new_test_ext;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
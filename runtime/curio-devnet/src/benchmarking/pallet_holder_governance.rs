@@ -0,0 +1,188 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use sp_std::prelude::*;
+
+use frame_support::dispatch::GetDispatchInfo;
+use orml_benchmarking::runtime_benchmarks;
+use sp_runtime::Perbill;
+
+use crate::{
+	AccountId, BlockNumber, Runtime, RuntimeCall, RuntimeEvent, RuntimeOrigin, System, HolderGovernance, Refungible, Whitelist,
+	collections::{MaxSnapshotHolders, ProposalDeposit},
+};
+
+use collection_primitives::{CollectionId, TokenId};
+use mock_support::collections::default_create_collection_data;
+use pallet_common::Event as CommonEvent;
+use pallet_holder_governance::{Event as PalletEvent, GovernanceParams};
+use pallet_refungible::CreateItemData;
+use pallet_whitelist::Investor;
+
+use super::utils::{assert_last_event, set_balance, NATIVE};
+
+const PIECES: u128 = 10;
+const VOTING_PERIOD: BlockNumber = 10;
+
+fn create_issuer() -> AccountId {
+	let issuer = AccountId::new([1u8; 32]);
+	Whitelist::add_admin(RuntimeOrigin::root(), issuer.clone()).unwrap();
+	issuer
+}
+
+fn create_holders(issuer: &AccountId, n: u32) -> Vec<(AccountId, u128)> {
+	(0..n)
+		.map(|i| {
+			let i = i as u8;
+			let account = AccountId::new([100 + i; 32]);
+			Whitelist::add_investors(RuntimeOrigin::signed(issuer.clone()), vec![
+				([i; 32], Investor { account: account.clone(), is_active: true })
+			]).unwrap();
+			set_balance(NATIVE, &account, 2 * ProposalDeposit::get());
+			(account, PIECES)
+		})
+		.collect()
+}
+
+fn create_token(issuer: &AccountId, balances: Vec<(AccountId, u128)>) -> (CollectionId, TokenId) {
+	Refungible::init_collection(
+		RuntimeOrigin::signed(issuer.clone()),
+		default_create_collection_data::<Runtime>()
+	).unwrap();
+	let collection_id = match System::events().last().unwrap().event {
+		RuntimeEvent::Common(CommonEvent::CollectionCreated(id, _, _)) => id,
+		_ => panic!("Unexpected event")
+	};
+
+	Refungible::create_item(RuntimeOrigin::signed(issuer.clone()), collection_id, CreateItemData {
+		balances,
+		properties: vec![]
+	}).unwrap();
+
+	(collection_id, TokenId(1))
+}
+
+fn params() -> GovernanceParams<BlockNumber> {
+	GovernanceParams {
+		quorum: Perbill::from_percent(50),
+		threshold: Perbill::from_percent(50),
+		voting_period: VOTING_PERIOD,
+	}
+}
+
+fn remark_call() -> Box<RuntimeCall> {
+	Box::new(RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![1u8; 32] }))
+}
+
+// Governed token with `n` holders and proposal of the first one, voted aye by all holders if `vote`
+fn create_proposal(n: u32, vote: bool) -> AccountId {
+	let issuer = create_issuer();
+	let holders = create_holders(&issuer, n);
+	let proposer = holders[0].0.clone();
+	let (collection_id, token_id) = create_token(&issuer, holders.clone());
+	HolderGovernance::set_governance_params(RuntimeOrigin::signed(issuer), collection_id, token_id, params()).unwrap();
+	HolderGovernance::propose(RuntimeOrigin::signed(proposer.clone()), collection_id, token_id, remark_call()).unwrap();
+
+	if vote {
+		for (holder, _) in holders {
+			HolderGovernance::vote(RuntimeOrigin::signed(holder), 0, true).unwrap();
+		}
+	}
+
+	proposer
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_holder_governance}
+
+	set_governance_params {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let holders = create_holders(&issuer, 1);
+		let (collection_id, token_id) = create_token(&issuer, holders);
+	}: _(RuntimeOrigin::signed(issuer), collection_id, token_id, params())
+	verify {
+		assert_last_event(PalletEvent::GovernanceParamsSet {
+			collection_id,
+			token_id,
+			params: params()
+		}.into());
+	}
+
+	propose {
+		let h in 1..MaxSnapshotHolders::get();
+
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let holders = create_holders(&issuer, h);
+		let proposer = holders[0].0.clone();
+		let (collection_id, token_id) = create_token(&issuer, holders);
+		HolderGovernance::set_governance_params(RuntimeOrigin::signed(issuer), collection_id, token_id, params()).unwrap();
+	}: _(RuntimeOrigin::signed(proposer.clone()), collection_id, token_id, remark_call())
+	verify {
+		assert_last_event(PalletEvent::Proposed {
+			proposal_id: 0,
+			collection_id,
+			token_id,
+			proposer,
+			end: 1 + VOTING_PERIOD
+		}.into());
+	}
+
+	vote {
+		System::set_block_number(1);
+		let proposer = create_proposal(1, false);
+	}: _(RuntimeOrigin::signed(proposer.clone()), 0, true)
+	verify {
+		assert_last_event(PalletEvent::Voted {
+			proposal_id: 0,
+			who: proposer,
+			aye: true,
+			power: PIECES
+		}.into());
+	}
+
+	close {
+		let h in 1..MaxSnapshotHolders::get();
+
+		System::set_block_number(1);
+		let proposer = create_proposal(h, true);
+		System::set_block_number(1 + VOTING_PERIOD);
+		let call_weight = remark_call().get_dispatch_info().weight;
+	}: _(RuntimeOrigin::signed(proposer), 0, call_weight)
+	verify {
+		assert_last_event(PalletEvent::Approved {
+			proposal_id: 0,
+			result: Ok(())
+		}.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use crate::benchmarking::utils::tests::new_test_ext;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
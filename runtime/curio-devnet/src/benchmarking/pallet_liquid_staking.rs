@@ -0,0 +1,154 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use sp_std::prelude::*;
+
+use frame_support::traits::Hooks;
+use frame_system::RawOrigin;
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+use pallet_authorship::EventHandler;
+
+use crate::{
+	AccountId, BlockNumber, Currencies, LiquidStaking, ParachainStaking, Runtime, RuntimeOrigin, System,
+	currency_id::LCGT,
+	consensus::{LiquidStakingBatchPeriod, MinCollatorStake, StakeDuration},
+	DOLLARS,
+};
+
+use pallet_liquid_staking::Event as PalletEvent;
+
+use super::utils::{assert_last_event, set_balance, NATIVE};
+
+const DEPOSIT: u128 = 10_000 * DOLLARS;
+
+fn create_collator() -> AccountId {
+	let collator = AccountId::new([1u8; 32]);
+	set_balance(NATIVE, &collator, 2 * MinCollatorStake::get());
+	ParachainStaking::join_candidates(RuntimeOrigin::signed(collator.clone()), MinCollatorStake::get()).unwrap();
+	collator
+}
+
+fn create_holder() -> AccountId {
+	let holder = AccountId::new([100u8; 32]);
+	set_balance(NATIVE, &holder, 2 * DEPOSIT);
+	holder
+}
+
+fn setup_pool() -> (AccountId, AccountId) {
+	let collator = create_collator();
+	let holder = create_holder();
+	LiquidStaking::add_collator(RuntimeOrigin::root(), collator.clone()).unwrap();
+	LiquidStaking::deposit(RuntimeOrigin::signed(holder.clone()), DEPOSIT).unwrap();
+	(collator, holder)
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_liquid_staking}
+
+	add_collator {
+		System::set_block_number(1);
+		let collator = create_collator();
+	}: _(RawOrigin::Root, collator.clone())
+	verify {
+		assert_last_event(PalletEvent::CollatorAdded { collator }.into());
+	}
+
+	remove_collator {
+		System::set_block_number(1);
+		let (collator, _) = setup_pool();
+	}: _(RawOrigin::Root, collator.clone())
+	verify {
+		assert_last_event(PalletEvent::CollatorRemoved { collator }.into());
+	}
+
+	deposit {
+		System::set_block_number(1);
+		let collator = create_collator();
+		let holder = create_holder();
+		LiquidStaking::add_collator(RuntimeOrigin::root(), collator).unwrap();
+	}: _(RawOrigin::Signed(holder.clone()), DEPOSIT)
+	verify {
+		assert_eq!(Currencies::free_balance(LCGT, &holder), DEPOSIT);
+	}
+
+	redeem {
+		System::set_block_number(1);
+		let (_, holder) = setup_pool();
+	}: _(RawOrigin::Signed(holder.clone()), DEPOSIT)
+	verify {
+		assert_eq!(Currencies::free_balance(LCGT, &holder), 0);
+	}
+
+	withdraw_redeemed {
+		System::set_block_number(1);
+		let (_, holder) = setup_pool();
+		LiquidStaking::redeem(RuntimeOrigin::signed(holder.clone()), DEPOSIT).unwrap();
+
+		let batch_block: BlockNumber = LiquidStakingBatchPeriod::get();
+		System::set_block_number(batch_block);
+		LiquidStaking::on_initialize(batch_block);
+		System::set_block_number(batch_block + StakeDuration::get());
+	}: _(RawOrigin::Signed(holder.clone()))
+	verify {
+		assert_last_event(PalletEvent::Withdrawn { who: holder, amount: DEPOSIT }.into());
+	}
+
+	on_initialize_compound {
+		System::set_block_number(1);
+		let (collator, _) = setup_pool();
+		ParachainStaking::note_author(collator);
+
+		let round_start = ParachainStaking::round().first + ParachainStaking::round().length;
+		System::set_block_number(round_start);
+		ParachainStaking::on_initialize(round_start);
+	}: {
+		LiquidStaking::on_initialize(round_start);
+	}
+	verify {
+		assert_eq!(LiquidStaking::last_compounded_round(), ParachainStaking::round().current);
+	}
+
+	on_initialize_process_batch {
+		System::set_block_number(1);
+		let (_, holder) = setup_pool();
+		LiquidStaking::redeem(RuntimeOrigin::signed(holder), DEPOSIT / 2).unwrap();
+
+		let batch_block: BlockNumber = LiquidStakingBatchPeriod::get();
+		System::set_block_number(batch_block);
+	}: {
+		LiquidStaking::on_initialize(batch_block);
+	}
+	verify {
+		assert_eq!(LiquidStaking::current_batch(), 1);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use crate::benchmarking::utils::tests::new_test_ext;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
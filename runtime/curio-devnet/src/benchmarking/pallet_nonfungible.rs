@@ -0,0 +1,507 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use pallet_common::Event as PalletEvent;
+use pallet_whitelist::traits::WhitelistInterface;
+
+use crate::{AccountId, Runtime, RuntimeEvent, RuntimeOrigin, System, Nonfungible, Whitelist};
+use collection_primitives::{
+	CollectionMode, CollectionId, CreateCollectionData, CreateNftExData, PropertiesPermissionsVec,
+	CollectionPropertiesVec, PropertyKeyPermission, Property, CollectionTokenPrefix, CollectionDescription,
+	CollectionName, TokenId, MAX_PROPERTIES_PER_ITEM, PropertyValue, PropertyKey,
+	MAX_COLLECTION_NAME_LENGTH, MAX_COLLECTION_DESCRIPTION_LENGTH, MAX_TOKEN_PREFIX_LENGTH,
+	CollectionLimits, SponsoringRateLimit, MAX_PROPERTY_KEY_LENGTH, PropertyPermission,
+	SponsoredAccounts, SponsorshipSettings,
+};
+
+use codec::alloc::string::ToString;
+use core::convert::TryInto;
+use sp_std::prelude::*;
+
+use orml_benchmarking::runtime_benchmarks;
+use super::utils::assert_last_event;
+
+fn get_individual_property_key(i: usize) -> Vec<u8> {
+	let mut vec:Vec<u8> = Vec::from([97u8; MAX_PROPERTY_KEY_LENGTH as usize]);
+	let n: Vec<u8> = i.to_string().as_bytes().into();
+	for j in 0..n.len(){
+		vec[j] = n[j];
+	}
+	vec
+}
+
+fn get_individual_account_id(i: usize) -> [u8; 32] {
+	let mut vec:Vec<u8> = Vec::from([100u8; 32 as usize]);
+	let n: Vec<u8> = i.to_string().as_bytes().into();
+	for j in 0..n.len(){
+		vec[j] = n[j];
+	}
+	vec.try_into().unwrap()
+}
+
+fn property_permissions_from_size(size: usize) -> Vec<PropertyKeyPermission> {
+	(0..size)
+		.map(|i| PropertyKeyPermission {
+			key: PropertyKey::truncate_from(get_individual_property_key(i)),
+			permission: PropertyPermission {
+				mutable: true,
+				collection_admin: true,
+				token_owner: false,
+			},
+		})
+		.collect()
+}
+
+fn property_from_size(size: usize) -> Vec<Property> {
+	(0..size)
+		.map(|i| {
+			let vec = get_individual_property_key(i);
+			Property {
+				key: PropertyKey::truncate_from(vec.clone()),
+				value: PropertyValue::truncate_from(vec)
+			}
+		})
+		.collect()
+}
+
+fn property_key_from_size(size: usize) -> Vec<PropertyKey> {
+	(0..size)
+		.map(|i| PropertyKey::truncate_from(get_individual_property_key(i)))
+		.collect()
+}
+
+fn default_create_collection_data(property_premissions_size: usize, property_size: usize) -> CreateCollectionData<AccountId> {
+	let limits = CollectionLimits {
+		account_token_ownership_limit: None,
+		sponsored_data_size: Some(100),
+		sponsored_data_rate_limit: Some(SponsoringRateLimit::Blocks(100)),
+		token_limit: None,
+		sponsor_transfer_timeout: Some(100),
+		sponsor_approve_timeout: Some(100),
+		owner_can_transfer: Some(true),
+		owner_can_destroy: Some(true),
+		transfers_enabled: Some(true),
+	};
+
+	CreateCollectionData::<AccountId> {
+		mode: CollectionMode::NFT,
+		name: CollectionName::truncate_from(Vec::from([98u16; MAX_COLLECTION_NAME_LENGTH as usize])),
+		description: CollectionDescription::truncate_from(Vec::from([98u16; MAX_COLLECTION_DESCRIPTION_LENGTH as usize])),
+		token_prefix: CollectionTokenPrefix::truncate_from(Vec::from([98u8; MAX_TOKEN_PREFIX_LENGTH as usize])),
+		pending_sponsor: None,
+		limits: Some(limits),
+		property_permissions: PropertiesPermissionsVec::truncate_from(
+			property_permissions_from_size(property_premissions_size),
+		),
+		properties: CollectionPropertiesVec::truncate_from(property_from_size(property_size)),
+	}
+}
+
+fn create_item_data(owner: AccountId, properties: Vec<Property>) -> CreateNftExData<AccountId> {
+	CreateNftExData::<AccountId> {
+		properties: CollectionPropertiesVec::truncate_from(properties),
+		owner,
+	}
+}
+
+fn get_collection_id_from_last_event() -> CollectionId {
+	match System::events().last().unwrap().event {
+		RuntimeEvent::Common(PalletEvent::CollectionCreated(id, _, _)) => {
+			id
+		},
+		_ => {
+			panic!("Unexpected event");
+		}
+	}
+}
+
+fn get_token_id_from_last_event() -> TokenId {
+	for event_record in System::events().iter().rev() {
+		if let RuntimeEvent::Common(PalletEvent::ItemCreated(_, token_id, _, _)) = event_record.event {
+			return token_id;
+		}
+	}
+
+	panic!("ItemCreated event not found");
+}
+
+fn create_admin(account_number: usize) -> AccountId {
+	let account_id = AccountId::new(get_individual_account_id(account_number));
+	if !Whitelist::is_admin(account_id.clone()) {
+		Whitelist::add_admin(RuntimeOrigin::root(), account_id.clone()).unwrap();
+	}
+	account_id
+}
+
+fn default_init_collection(admin: &AccountId) -> CollectionId {
+	let data = default_create_collection_data(
+		MAX_PROPERTIES_PER_ITEM as usize,
+		MAX_PROPERTIES_PER_ITEM as usize
+	);
+
+	Nonfungible::init_collection(RuntimeOrigin::signed(admin.clone()), data).unwrap();
+	get_collection_id_from_last_event()
+}
+
+// Mint NFT token with maximum amount of properties
+fn default_create_item(admin: &AccountId, collection_id: CollectionId, owner: &AccountId) -> TokenId {
+	let data = create_item_data(owner.clone(), property_from_size(MAX_PROPERTIES_PER_ITEM as usize));
+	Nonfungible::create_item(RuntimeOrigin::signed(admin.clone()), collection_id, data).unwrap();
+	get_token_id_from_last_event()
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_nonfungible}
+
+	init_collection {
+		let i in 1..MAX_PROPERTIES_PER_ITEM.into();
+		let j in 1..MAX_PROPERTIES_PER_ITEM.into();
+		let account_id = create_admin(0);
+		let data = default_create_collection_data(i as usize, j as usize);
+	}: _(RuntimeOrigin::signed(account_id.clone()), data.clone())
+	verify {
+		let collection_id = get_collection_id_from_last_event();
+		assert_last_event(PalletEvent::CollectionCreated(collection_id, CollectionMode::NFT.id(), account_id).into());
+	}
+
+	destroy_collection {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone())
+	verify {
+		assert_last_event(PalletEvent::CollectionDestroyed(collection_id).into());
+	}
+
+	set_collection_property {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let properties = property_from_size(1);
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), properties[0].clone())
+	verify {
+		assert_last_event(PalletEvent::CollectionPropertySet(collection_id, properties[0].key.clone()).into());
+	}
+
+	delete_collection_property {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let property_keys = property_key_from_size(1);
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), property_keys[0].clone())
+	verify {
+		assert_last_event(PalletEvent::CollectionPropertyDeleted(collection_id, property_keys[0].clone()).into());
+	}
+
+	set_collection_properties {
+		let k in 1..MAX_PROPERTIES_PER_ITEM.into();
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let properties = property_from_size(k as usize);
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), properties.clone())
+	verify {
+		for i in 0..k {
+			System::assert_has_event(PalletEvent::CollectionPropertySet(collection_id, properties[i as usize].clone().key).into())
+		}
+	}
+
+	delete_collection_properties {
+		let k in 1..MAX_PROPERTIES_PER_ITEM.into();
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let property_keys = property_key_from_size(k as usize);
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), property_keys.clone())
+	verify {
+		for i in 0..k {
+			System::assert_has_event(PalletEvent::CollectionPropertyDeleted(collection_id, property_keys[i as usize].clone()).into())
+		}
+	}
+
+	set_property_permission {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let properties = property_permissions_from_size(1);
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), properties[0].clone())
+	verify {
+		assert_last_event(PalletEvent::PropertyPermissionSet(collection_id, properties[0].key.clone()).into());
+	}
+
+	create_item {
+		let p in 1..MAX_PROPERTIES_PER_ITEM; //Max amount of property
+
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+
+		let data = create_item_data(account_id.clone(), property_from_size(p as usize));
+	}: _(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), data)
+	verify {
+		let token_id = get_token_id_from_last_event();
+		System::assert_has_event(PalletEvent::ItemCreated(collection_id, token_id, account_id, 1).into())
+	}
+
+	create_max_item {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+	}: {default_create_item(&account_id, collection_id, &account_id)}
+	verify {
+		let token_id = get_token_id_from_last_event();
+		System::assert_has_event(PalletEvent::ItemCreated(collection_id, token_id, account_id, 1).into())
+	}
+
+	set_token_property {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let token_id = default_create_item(&account_id, collection_id, &account_id);
+
+		let property = Property {
+			key: PropertyKey::truncate_from(get_individual_property_key(1)),
+			value: PropertyValue::truncate_from(get_individual_property_key(1))
+		};
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), token_id.clone(), property.clone())
+	verify {
+		assert_last_event(PalletEvent::TokenPropertySet(collection_id, token_id, property.key).into());
+	}
+
+	set_token_properties {
+		let i in 1..MAX_PROPERTIES_PER_ITEM;
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let token_id = default_create_item(&account_id, collection_id, &account_id);
+
+		let property = property_from_size(i as usize);
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), token_id.clone(), property.clone())
+	verify {
+		for j in 0..i {
+			System::assert_has_event(PalletEvent::TokenPropertySet(collection_id, token_id, property[j as usize].clone().key).into())
+		}
+	}
+
+	delete_token_property {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let token_id = default_create_item(&account_id, collection_id, &account_id);
+
+		let property = PropertyKey::truncate_from(get_individual_property_key(1));
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), token_id.clone(), property.clone())
+	verify {
+		assert_last_event(PalletEvent::TokenPropertyDeleted(collection_id, token_id, property).into());
+	}
+
+	delete_token_properties {
+		let i in 1..MAX_PROPERTIES_PER_ITEM;
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let token_id = default_create_item(&account_id, collection_id, &account_id);
+
+		let property = property_key_from_size(i as usize);
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), token_id.clone(), property.clone())
+	verify {
+		for j in 0..i {
+			System::assert_has_event(PalletEvent::TokenPropertyDeleted(collection_id, token_id, property[j as usize].clone()).into())
+		}
+	}
+
+	transfer {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let token_id = default_create_item(&account_id, collection_id, &account_id);
+
+		let to = create_admin(1);
+	}: _(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), token_id.clone(), to.clone())
+	verify {
+		assert_last_event(PalletEvent::Transfer(collection_id, token_id, account_id, to, 1).into());
+	}
+
+	approve {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let token_id = default_create_item(&account_id, collection_id, &account_id);
+
+		let spender = create_admin(1);
+	}: _(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), token_id.clone(), Some(spender.clone()))
+	verify {
+		assert_last_event(PalletEvent::Approved(collection_id, token_id, account_id, spender, 1).into());
+	}
+
+	transfer_from {
+		System::set_block_number(1);
+		let from = create_admin(0);
+		let collection_id = default_init_collection(&from);
+		let token_id = default_create_item(&from, collection_id, &from);
+
+		let who = create_admin(1);
+		let to = create_admin(2);
+
+		Nonfungible::approve(RuntimeOrigin::signed(from.clone()), collection_id.clone(), token_id.clone(), Some(who.clone())).unwrap();
+	}: _(RuntimeOrigin::signed(who), collection_id.clone(), token_id.clone(), from.clone(), to.clone())
+	verify {
+		assert_last_event(PalletEvent::Transfer(collection_id, token_id, from, to, 1).into());
+	}
+
+	burn {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let token_id = default_create_item(&account_id, collection_id, &account_id);
+	}: _(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), token_id.clone())
+	verify {
+		assert_last_event(PalletEvent::ItemDestroyed(collection_id, token_id, account_id, 1).into());
+	}
+
+	burn_from {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+
+		let from = create_admin(1);
+		let token_id = default_create_item(&account_id, collection_id, &from);
+		Nonfungible::approve(RuntimeOrigin::signed(from.clone()), collection_id.clone(), token_id.clone(), Some(account_id.clone())).unwrap();
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), token_id.clone(), from.clone())
+	verify {
+		assert_last_event(PalletEvent::ItemDestroyed(collection_id, token_id, from, 1).into());
+	}
+
+	toggle_admin {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let person = create_admin(1);
+	}: _(RuntimeOrigin::signed(account_id), collection_id, person.clone(), true)
+	verify {
+		assert_last_event(PalletEvent::AdminToggled(person, true).into());
+	}
+
+	set_sponsor {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let sponsor = AccountId::new(get_individual_account_id(1));
+	}: _(RuntimeOrigin::signed(account_id), collection_id, sponsor.clone())
+	verify {
+		assert_last_event(PalletEvent::SponsorSet(collection_id, sponsor).into());
+	}
+
+	confirm_sponsorship {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let sponsor = AccountId::new(get_individual_account_id(1));
+		Nonfungible::set_sponsor(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), sponsor.clone()).unwrap();
+	}: _(RuntimeOrigin::signed(sponsor.clone()), collection_id.clone())
+	verify {
+		assert_last_event(PalletEvent::SponsorhipConfirmed(collection_id, sponsor).into());
+	}
+
+	remove_sponsor {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let sponsor = AccountId::new(get_individual_account_id(1));
+		Nonfungible::set_sponsor(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), sponsor.clone()).unwrap();
+		Nonfungible::confirm_sponsorship(RuntimeOrigin::signed(sponsor), collection_id.clone()).unwrap();
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone())
+	verify {
+		assert_last_event(PalletEvent::SponsorshipRemoved(collection_id).into());
+	}
+
+	propose_collection_owner {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let new_owner = create_admin(1);
+	}: _(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), new_owner.clone())
+	verify {
+		assert_last_event(PalletEvent::CollectionOwnershipTransferProposed(collection_id, account_id, new_owner).into());
+	}
+
+	cancel_collection_ownership_transfer {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let new_owner = create_admin(1);
+		Nonfungible::propose_collection_owner(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), new_owner).unwrap();
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone())
+	verify {
+		assert_last_event(PalletEvent::CollectionOwnershipTransferCancelled(collection_id).into());
+	}
+
+	// New owner is a collection admin and sponsor is unconfirmed, so both are cleared
+	accept_collection_ownership {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let new_owner = create_admin(1);
+		let sponsor = AccountId::new(get_individual_account_id(2));
+		Nonfungible::toggle_admin(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), new_owner.clone(), true).unwrap();
+		Nonfungible::set_sponsor(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), sponsor).unwrap();
+		Nonfungible::propose_collection_owner(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), new_owner.clone()).unwrap();
+	}: _(RuntimeOrigin::signed(new_owner.clone()), collection_id.clone())
+	verify {
+		assert_last_event(PalletEvent::CollectionOwnerChanged(collection_id, account_id, new_owner).into());
+	}
+
+	set_sponsorship_settings {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let settings = SponsorshipSettings {
+			period: 14_400,
+			budget: Some(1_000_000_000_000),
+			account_quota: Some(10),
+			sponsored_accounts: SponsoredAccounts::WhitelistedInvestors,
+		};
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), Some(settings))
+	verify {
+		assert_last_event(PalletEvent::SponsorshipSettingsSet(collection_id).into());
+	}
+
+	toggle_sponsor_allowlist {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let account = AccountId::new(get_individual_account_id(1));
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), account.clone(), true)
+	verify {
+		assert_last_event(PalletEvent::SponsorAllowlistToggled(collection_id, account, true).into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use crate::benchmarking::utils::tests::new_test_ext;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
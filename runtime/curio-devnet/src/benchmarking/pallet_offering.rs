@@ -0,0 +1,178 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use sp_std::prelude::*;
+
+use orml_benchmarking::runtime_benchmarks;
+
+use crate::{
+	AccountId, Balance, Runtime, RuntimeEvent, RuntimeOrigin, System, Offering, Refungible, Whitelist,
+	currency_id::{
+		CurrencyId,
+		TokenSymbol
+	},
+};
+
+use collection_primitives::{CollectionId, TokenId};
+use mock_support::collections::default_create_collection_data;
+use pallet_common::Event as CommonEvent;
+use pallet_offering::{Event as PalletEvent, OfferingId};
+use pallet_refungible::CreateItemData;
+use pallet_whitelist::Investor;
+
+use super::utils::{assert_last_event, set_balance, token_unit};
+
+const DAI: CurrencyId = CurrencyId::Token(TokenSymbol::DAI);
+const PIECES: u128 = 100;
+
+fn price() -> Balance {
+	token_unit(DAI)
+}
+
+fn create_issuer() -> AccountId {
+	let issuer = AccountId::new([1u8; 32]);
+	Whitelist::add_admin(RuntimeOrigin::root(), issuer.clone()).unwrap();
+	issuer
+}
+
+fn create_investor(issuer: &AccountId, i: u8) -> AccountId {
+	let account = AccountId::new([100 + i; 32]);
+	Whitelist::add_investors(RuntimeOrigin::signed(issuer.clone()), vec![
+		([i; 32], Investor { account: account.clone(), is_active: true })
+	]).unwrap();
+	set_balance(DAI, &account, PIECES * price());
+	account
+}
+
+fn create_token(issuer: &AccountId) -> (CollectionId, TokenId) {
+	Refungible::init_collection(
+		RuntimeOrigin::signed(issuer.clone()),
+		default_create_collection_data::<Runtime>()
+	).unwrap();
+	let collection_id = match System::events().last().unwrap().event {
+		RuntimeEvent::Common(CommonEvent::CollectionCreated(id, _, _)) => id,
+		_ => panic!("Unexpected event")
+	};
+
+	Refungible::create_item(RuntimeOrigin::signed(issuer.clone()), collection_id, CreateItemData {
+		balances: vec![(issuer.clone(), PIECES)],
+		properties: vec![]
+	}).unwrap();
+
+	(collection_id, TokenId(1))
+}
+
+fn create_offering(issuer: &AccountId) -> OfferingId {
+	let (collection_id, token_id) = create_token(issuer);
+	let offering_id = Offering::next_offering_id();
+
+	Offering::create_offering(
+		RuntimeOrigin::signed(issuer.clone()),
+		collection_id,
+		token_id,
+		DAI,
+		price(),
+		0,
+		PIECES * price(),
+		1,
+		10
+	).unwrap();
+
+	offering_id
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_offering}
+
+	create_offering {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let (collection_id, token_id) = create_token(&issuer);
+	}: _(RuntimeOrigin::signed(issuer.clone()), collection_id, token_id, DAI, price(), 0, PIECES * price(), 1, 10)
+	verify {
+		assert_last_event(PalletEvent::OfferingCreated {
+			offering_id: 0,
+			issuer,
+			collection_id,
+			token_id,
+			pieces: PIECES
+		}.into());
+	}
+
+	subscribe {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let offering_id = create_offering(&issuer);
+		let investor = create_investor(&issuer, 0);
+	}: _(RuntimeOrigin::signed(investor.clone()), offering_id, 1)
+	verify {
+		assert_last_event(PalletEvent::Subscribed {
+			offering_id,
+			who: investor,
+			pieces: 1,
+			amount: price()
+		}.into());
+	}
+
+	settle {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let offering_id = create_offering(&issuer);
+		let investor = create_investor(&issuer, 0);
+		Offering::subscribe(RuntimeOrigin::signed(investor), offering_id, 1).unwrap();
+		System::set_block_number(10);
+	}: _(RuntimeOrigin::signed(issuer), offering_id)
+	verify {
+		assert_last_event(PalletEvent::OfferingSucceeded {
+			offering_id,
+			raised: price(),
+			sold: 1
+		}.into());
+	}
+
+	claim {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let offering_id = create_offering(&issuer);
+		let investor = create_investor(&issuer, 0);
+		Offering::subscribe(RuntimeOrigin::signed(investor.clone()), offering_id, 1).unwrap();
+		System::set_block_number(10);
+		Offering::settle(RuntimeOrigin::signed(issuer), offering_id).unwrap();
+	}: _(RuntimeOrigin::signed(investor.clone()), offering_id)
+	verify {
+		assert_last_event(PalletEvent::PiecesClaimed {
+			offering_id,
+			who: investor,
+			pieces: 1
+		}.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use crate::benchmarking::utils::tests::new_test_ext;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
@@ -0,0 +1,150 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use sp_std::prelude::*;
+
+use orml_benchmarking::runtime_benchmarks;
+
+use crate::{
+	AccountId, Balance, Runtime, RuntimeEvent, RuntimeOrigin, System, Redemption, Refungible, Whitelist,
+	currency_id::{
+		CurrencyId,
+		TokenSymbol
+	},
+	collections::MaxRedemptionBatch,
+};
+
+use collection_primitives::{CollectionId, TokenId};
+use mock_support::collections::default_create_collection_data;
+use pallet_common::Event as CommonEvent;
+use pallet_redemption::Event as PalletEvent;
+use pallet_refungible::CreateItemData;
+use pallet_whitelist::Investor;
+
+use super::utils::{assert_last_event, set_balance, token_unit};
+
+const DAI: CurrencyId = CurrencyId::Token(TokenSymbol::DAI);
+const PIECES: u128 = 10;
+
+fn price() -> Balance {
+	token_unit(DAI)
+}
+
+fn create_issuer() -> AccountId {
+	let issuer = AccountId::new([1u8; 32]);
+	Whitelist::add_admin(RuntimeOrigin::root(), issuer.clone()).unwrap();
+	set_balance(DAI, &issuer, MaxRedemptionBatch::get() as u128 * PIECES * price());
+	issuer
+}
+
+fn create_holders(issuer: &AccountId, n: u32) -> Vec<(AccountId, u128)> {
+	(0..n)
+		.map(|i| {
+			let i = i as u8;
+			let account = AccountId::new([100 + i; 32]);
+			Whitelist::add_investors(RuntimeOrigin::signed(issuer.clone()), vec![
+				([i; 32], Investor { account: account.clone(), is_active: true })
+			]).unwrap();
+			(account, PIECES)
+		})
+		.collect()
+}
+
+fn create_token(issuer: &AccountId, balances: Vec<(AccountId, u128)>) -> (CollectionId, TokenId) {
+	Refungible::init_collection(
+		RuntimeOrigin::signed(issuer.clone()),
+		default_create_collection_data::<Runtime>()
+	).unwrap();
+	let collection_id = match System::events().last().unwrap().event {
+		RuntimeEvent::Common(CommonEvent::CollectionCreated(id, _, _)) => id,
+		_ => panic!("Unexpected event")
+	};
+
+	Refungible::create_item(RuntimeOrigin::signed(issuer.clone()), collection_id, CreateItemData {
+		balances,
+		properties: vec![]
+	}).unwrap();
+
+	(collection_id, TokenId(1))
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_redemption}
+
+	start_redemption {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let holders = create_holders(&issuer, 1);
+		let (collection_id, token_id) = create_token(&issuer, holders);
+	}: _(RuntimeOrigin::signed(issuer.clone()), collection_id, token_id, DAI, price())
+	verify {
+		assert_last_event(PalletEvent::RedemptionStarted {
+			collection_id,
+			token_id,
+			issuer,
+			currency_id: DAI,
+			price_per_piece: price(),
+			amount: PIECES * price()
+		}.into());
+	}
+
+	claim_redemption {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let holders = create_holders(&issuer, 1);
+		let holder = holders[0].0.clone();
+		let (collection_id, token_id) = create_token(&issuer, holders);
+		Redemption::start_redemption(RuntimeOrigin::signed(issuer), collection_id, token_id, DAI, price()).unwrap();
+	}: _(RuntimeOrigin::signed(holder), collection_id, token_id)
+	verify {
+		assert_last_event(PalletEvent::TokenRetired {
+			collection_id,
+			token_id
+		}.into());
+	}
+
+	redeem_batch {
+		let i in 1..MaxRedemptionBatch::get();
+
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let holders = create_holders(&issuer, i);
+		let (collection_id, token_id) = create_token(&issuer, holders);
+		Redemption::start_redemption(RuntimeOrigin::signed(issuer.clone()), collection_id, token_id, DAI, price()).unwrap();
+	}: _(RuntimeOrigin::signed(issuer), collection_id, token_id)
+	verify {
+		assert_last_event(PalletEvent::TokenRetired {
+			collection_id,
+			token_id
+		}.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use crate::benchmarking::utils::tests::new_test_ext;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
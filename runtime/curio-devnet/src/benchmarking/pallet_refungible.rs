@@ -31,7 +31,8 @@ use collection_primitives::{
 	PropertyKeyPermission, Property, CollectionTokenPrefix, CollectionDescription, CollectionName,
 	TokenId, MAX_ITEMS_PER_BATCH, MAX_PROPERTIES_PER_ITEM, PropertyValue, PropertyKey,
 	MAX_COLLECTION_NAME_LENGTH, MAX_COLLECTION_DESCRIPTION_LENGTH, MAX_TOKEN_PREFIX_LENGTH,
-	CollectionLimits, SponsoringRateLimit, MAX_PROPERTY_KEY_LENGTH, PropertyPermission
+	CollectionLimits, SponsoringRateLimit, MAX_PROPERTY_KEY_LENGTH, PropertyPermission,
+	HoldingLimits, SponsoredAccounts, SponsorshipSettings,
 };
 
 use codec::alloc::string::ToString;
@@ -68,6 +69,8 @@ fn property_permissions_from_size(size: usize) -> Vec<PropertyKeyPermission> {
 			key: PropertyKey::truncate_from(vec),
 			permission: PropertyPermission {
 				mutable: true,
+				collection_admin: true,
+				token_owner: false,
 			},
 		});
 	}
@@ -193,6 +196,14 @@ fn create_investor(account_number: usize) -> AccountId {
 	AccountId::new(account_id)
 }
 
+fn default_holding_limits() -> HoldingLimits {
+	HoldingLimits {
+		max_token_holders: Some(MAX_ITEMS_PER_BATCH),
+		min_holding: Some(1),
+		transfer_increment: Some(1),
+	}
+}
+
 fn default_init_collection(admin: &AccountId) -> CollectionId {
 	let data = default_create_collection_data(
 		MAX_PROPERTIES_PER_ITEM as usize, 
@@ -569,6 +580,99 @@ runtime_benchmarks! {
 	verify {
 		assert_last_event(PalletEvent::SponsorshipRemoved(collection_id).into());
 	}
+
+	set_collection_holding_limits {
+		let b in 0..MAX_ITEMS_PER_BATCH;
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+
+		for _ in 0..b {
+			let users = create_vec_of_one_user_with_balance(0, 100);
+			let data = create_item_data(users, Vec::new());
+			Refungible::create_item(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), data).unwrap();
+		}
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), default_holding_limits(), b)
+	verify {
+		assert_last_event(PalletEvent::CollectionHoldingLimitsSet(collection_id).into());
+	}
+
+	set_token_holding_limits {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+
+		let users = create_vec_of_one_user_with_balance(0, 100);
+		let property_keys = property_from_size(MAX_PROPERTIES_PER_ITEM as usize);
+		let data = create_item_data(users, property_keys);
+		Refungible::create_item(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), data).unwrap();
+
+		let token_id = get_token_id_from_last_event();
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), token_id.clone(), default_holding_limits())
+	verify {
+		assert_last_event(PalletEvent::TokenHoldingLimitsSet(collection_id, token_id).into());
+	}
+
+	propose_collection_owner {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let new_owner = create_admin(1);
+	}: _(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), new_owner.clone())
+	verify {
+		assert_last_event(PalletEvent::CollectionOwnershipTransferProposed(collection_id, account_id, new_owner).into());
+	}
+
+	cancel_collection_ownership_transfer {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let new_owner = create_admin(1);
+		Refungible::propose_collection_owner(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), new_owner).unwrap();
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone())
+	verify {
+		assert_last_event(PalletEvent::CollectionOwnershipTransferCancelled(collection_id).into());
+	}
+
+	// New owner is a collection admin and sponsor is unconfirmed, so both are cleared
+	accept_collection_ownership {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let new_owner = create_admin(1);
+		let sponsor = AccountId::new(get_individual_account_id(2));
+		Refungible::toggle_admin(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), new_owner.clone(), true).unwrap();
+		Refungible::set_sponsor(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), sponsor).unwrap();
+		Refungible::propose_collection_owner(RuntimeOrigin::signed(account_id.clone()), collection_id.clone(), new_owner.clone()).unwrap();
+	}: _(RuntimeOrigin::signed(new_owner.clone()), collection_id.clone())
+	verify {
+		assert_last_event(PalletEvent::CollectionOwnerChanged(collection_id, account_id, new_owner).into());
+	}
+
+	set_sponsorship_settings {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let settings = SponsorshipSettings {
+			period: 14_400,
+			budget: Some(1_000_000_000_000),
+			account_quota: Some(10),
+			sponsored_accounts: SponsoredAccounts::WhitelistedInvestors,
+		};
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), Some(settings))
+	verify {
+		assert_last_event(PalletEvent::SponsorshipSettingsSet(collection_id).into());
+	}
+
+	toggle_sponsor_allowlist {
+		System::set_block_number(1);
+		let account_id = create_admin(0);
+		let collection_id = default_init_collection(&account_id);
+		let account = AccountId::new(get_individual_account_id(1));
+	}: _(RuntimeOrigin::signed(account_id), collection_id.clone(), account.clone(), true)
+	verify {
+		assert_last_event(PalletEvent::SponsorAllowlistToggled(collection_id, account, true).into());
+	}
 }
 
 #[cfg(test)]
@@ -0,0 +1,125 @@
+// Curio Parachain
+
+// Copyright (С) 2022 Curio AG (Company Number FL-0002.594.728-9)
+// Incorporated and registered in Liechtenstein.
+
+// Copyright (С) 2022 Curio Capital AG (Company Number CHE-211.446.654)
+// Incorporated and registered in Zug, Switzerland.
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
+
+use sp_std::prelude::*;
+
+use orml_benchmarking::runtime_benchmarks;
+use orml_traits::MultiCurrency;
+
+use crate::{
+	AccountId, Currencies, Runtime, RuntimeEvent, RuntimeOrigin, System, Refungible, RftWrapper, Whitelist,
+	currency_id::CurrencyId,
+};
+
+use collection_primitives::{CollectionId, TokenId};
+use mock_support::collections::default_create_collection_data;
+use pallet_common::Event as CommonEvent;
+use pallet_refungible::CreateItemData;
+use pallet_rft_wrapper::Event as PalletEvent;
+use pallet_whitelist::Investor;
+
+use super::utils::assert_last_event;
+
+const PIECES: u128 = 10;
+
+fn create_issuer() -> AccountId {
+	let issuer = AccountId::new([1u8; 32]);
+	Whitelist::add_admin(RuntimeOrigin::root(), issuer.clone()).unwrap();
+	issuer
+}
+
+fn create_holder(issuer: &AccountId) -> AccountId {
+	let holder = AccountId::new([100u8; 32]);
+	Whitelist::add_investors(RuntimeOrigin::signed(issuer.clone()), vec![
+		([0u8; 32], Investor { account: holder.clone(), is_active: true })
+	]).unwrap();
+	holder
+}
+
+fn create_token(issuer: &AccountId, holder: &AccountId) -> (CollectionId, TokenId) {
+	Refungible::init_collection(
+		RuntimeOrigin::signed(issuer.clone()),
+		default_create_collection_data::<Runtime>()
+	).unwrap();
+	let collection_id = match System::events().last().unwrap().event {
+		RuntimeEvent::Common(CommonEvent::CollectionCreated(id, _, _)) => id,
+		_ => panic!("Unexpected event")
+	};
+
+	Refungible::create_item(RuntimeOrigin::signed(issuer.clone()), collection_id, CreateItemData {
+		balances: vec![(holder.clone(), PIECES)],
+		properties: vec![]
+	}).unwrap();
+
+	(collection_id, TokenId(1))
+}
+
+runtime_benchmarks! {
+	{Runtime, pallet_rft_wrapper}
+
+	register_wrapped_token {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let holder = create_holder(&issuer);
+		let (collection_id, token_id) = create_token(&issuer, &holder);
+	}: _(RuntimeOrigin::signed(issuer), collection_id, token_id)
+	verify {
+		assert_last_event(PalletEvent::WrappedTokenRegistered {
+			collection_id,
+			token_id,
+			currency_id: CurrencyId::WrappedRft(collection_id, token_id)
+		}.into());
+	}
+
+	wrap {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let holder = create_holder(&issuer);
+		let (collection_id, token_id) = create_token(&issuer, &holder);
+		RftWrapper::register_wrapped_token(RuntimeOrigin::signed(issuer), collection_id, token_id).unwrap();
+	}: _(RuntimeOrigin::signed(holder.clone()), collection_id, token_id, PIECES)
+	verify {
+		assert_eq!(Currencies::free_balance(CurrencyId::WrappedRft(collection_id, token_id), &holder), PIECES);
+	}
+
+	unwrap {
+		System::set_block_number(1);
+		let issuer = create_issuer();
+		let holder = create_holder(&issuer);
+		let (collection_id, token_id) = create_token(&issuer, &holder);
+		RftWrapper::register_wrapped_token(RuntimeOrigin::signed(issuer), collection_id, token_id).unwrap();
+		RftWrapper::wrap(RuntimeOrigin::signed(holder.clone()), collection_id, token_id, PIECES).unwrap();
+	}: _(RuntimeOrigin::signed(holder.clone()), collection_id, token_id, PIECES)
+	verify {
+		assert_eq!(Currencies::free_balance(CurrencyId::WrappedRft(collection_id, token_id), &holder), 0);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use orml_benchmarking::impl_benchmark_test_suite;
+	use crate::benchmarking::utils::tests::new_test_ext;
+
+	impl_benchmark_test_suite!(new_test_ext(),);
+}
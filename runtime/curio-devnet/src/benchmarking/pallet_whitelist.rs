@@ -25,10 +25,13 @@ use sp_std::prelude::*;
 
 use orml_benchmarking::{runtime_benchmarks, whitelisted_caller};
 
-use crate::{Runtime, System, RuntimeOrigin, AccountId, Whitelist};
+use crate::{Runtime, System, RuntimeOrigin, AccountId, Signature, Whitelist, DOLLARS};
+use crate::utilities::MaxKycAttributesLength;
+
+use frame_support::traits::Get;
 
 use pallet_whitelist::Event as PalletEvent;
-use pallet_whitelist::{MAX_NEW_INVESTORS, Investor, InvestorKey};
+use pallet_whitelist::{MAX_NEW_INVESTORS, Investor, InvestorKey, InvestorSponsorship, KycAttestation};
 
 use super::utils::assert_last_event;
 
@@ -186,6 +189,98 @@ runtime_benchmarks! {
 			new_account: new_account
 		}.into());
     }
+
+    set_investor_sponsorship {
+        let sponsorship = InvestorSponsorship {
+            sponsor: whitelisted_caller(),
+            period: 100,
+            budget: Some(1_000 * DOLLARS),
+            account_quota: Some(10)
+        };
+    }: _(RuntimeOrigin::root(), Some(sponsorship.clone()))
+    verify {
+        assert_last_event(PalletEvent::InvestorSponsorshipSet {
+			sponsorship: Some(sponsorship)
+		}.into());
+    }
+
+    remove_investor {
+        let admin: AccountId = whitelisted_caller();
+        Whitelist::add_admin(RuntimeOrigin::root(), admin.clone()).unwrap();
+
+        let investors = investors_from_size(1 as usize);
+        let (investor_key, investor) = investors[0].clone();
+        Whitelist::add_investors(RuntimeOrigin::signed(admin.clone()), investors).unwrap();
+        Whitelist::renew_investor_verification(RuntimeOrigin::signed(admin.clone()), investor.account.clone(), Some(100)).unwrap();
+    }: _(RuntimeOrigin::signed(admin.clone()), investor.account.clone())
+    verify {
+        assert_last_event(PalletEvent::InvestorRemoved {
+			who: admin, 
+			investor_key: investor_key,
+			investor: investor.account
+		}.into());
+    }
+
+    renew_investor_verification {
+        let admin: AccountId = whitelisted_caller();
+        Whitelist::add_admin(RuntimeOrigin::root(), admin.clone()).unwrap();
+
+        let investors = investors_from_size(1 as usize);
+        let (_, investor) = investors[0].clone();
+        Whitelist::add_investors(RuntimeOrigin::signed(admin.clone()), investors).unwrap();
+    }: _(RuntimeOrigin::signed(admin.clone()), investor.account.clone(), Some(100))
+    verify {
+        assert_last_event(PalletEvent::InvestorVerificationRenewed {
+			who: admin, 
+			investor: investor.account,
+			expires_at: Some(100)
+		}.into());
+    }
+
+    add_kyc_provider {
+        let provider: AccountId = whitelisted_caller();
+    }: _(RuntimeOrigin::root(), provider.clone())
+    verify {
+        assert_last_event(PalletEvent::KycProviderAdded {
+			provider: provider
+		}.into());
+    }
+
+    remove_kyc_provider {
+        let provider: AccountId = whitelisted_caller();
+        Whitelist::add_kyc_provider(RuntimeOrigin::root(), provider.clone()).unwrap();
+    }: _(RuntimeOrigin::root(), provider.clone())
+    verify {
+        assert_last_event(PalletEvent::KycProviderRemoved {
+			provider: provider
+		}.into());
+    }
+
+    register_investor {
+        let provider_public = sp_io::crypto::sr25519_generate(0.into(), None);
+        let provider = AccountId::from(provider_public);
+        Whitelist::add_kyc_provider(RuntimeOrigin::root(), provider.clone()).unwrap();
+
+        let investor: AccountId = whitelisted_caller();
+        let attestation = KycAttestation {
+            investor_key: [0u8; 32],
+            account: investor.clone(),
+            expires_at: 100,
+            attributes: vec![0u8; MaxKycAttributesLength::get() as usize].try_into().unwrap(),
+            nonce: 0
+        };
+        let signature = Signature::from(
+            sp_io::crypto::sr25519_sign(0.into(), &provider_public, &Whitelist::attestation_payload(&attestation)).unwrap()
+        );
+    }: _(RuntimeOrigin::signed(investor.clone()), attestation, provider.clone(), signature)
+    verify {
+        assert_last_event(PalletEvent::InvestorAttested {
+			provider: provider,
+			investor_key: [0u8; 32],
+			investor: investor,
+			expires_at: 100
+		}.into());
+    }
 }
 
 #[cfg(test)]
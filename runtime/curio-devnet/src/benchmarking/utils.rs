@@ -71,10 +71,16 @@ pub fn set_balance(currency_id: CurrencyId, who: &AccountId, balance: Balance) {
 
 #[cfg(test)]
 pub mod tests {
+	use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
+	use std::sync::Arc;
+
 	pub fn new_test_ext() -> sp_io::TestExternalities {
-		frame_system::GenesisConfig::default()
+		let mut ext: sp_io::TestExternalities = frame_system::GenesisConfig::default()
 			.build_storage::<crate::Runtime>()
 			.unwrap()
-			.into()
+			.into();
+		// Keystore is required by benchmarks signing data with sp_io::crypto
+		ext.register_extension(KeystoreExt(Arc::new(MemoryKeystore::new())));
+		ext
 	}
 }
\ No newline at end of file
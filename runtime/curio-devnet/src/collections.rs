@@ -1,20 +1,26 @@
 
 use frame_support::{
     parameter_types,
+    PalletId,
     traits::{
         Contains,
+        IsInVec,
     }
 };
 use frame_system::{
     EnsureRoot,
 };
 use sp_core::U256;
+use sp_std::{vec, vec::Vec};
 use sp_runtime::{
+    DispatchError,
     traits::{
         AccountIdConversion,
         ConstU32,
     }
 };
+use collection_primitives::{CollectionId, CollectionMode};
+use pallet_common::CollectionHandle;
 
 use pallet_currencies::BasicCurrencyAdapter;
 
@@ -24,8 +30,11 @@ use crate::{
     Balance,
     Balances,
     BlockNumber,
+    Currencies,
     DAYS,
+    DOLLARS,
     Runtime,
+    RuntimeCall,
     RuntimeEvent,
     Tokens,
     Whitelist,
@@ -36,6 +45,7 @@ use crate::{
         CGT,
         CurrencyId,
     },
+    dex::DEXPalletId,
     sponsoring::{
         CurioSponsorshipHandler
     },
@@ -69,7 +79,8 @@ impl orml_traits::currency::MutationHooks<AccountId, CurrencyId, Balance> for Mu
 	type OnSlash = ();
 	type PreDeposit = ();
 	type PostDeposit = ();
-	type PreTransfer = ();
+	// Wrapped RFT tokens are only transferred between whitelisted investors
+	type PreTransfer = pallet_rft_wrapper::EnsureWhitelistedTransfer<Runtime>;
 	type PostTransfer = ();
 	type OnNewTokenAccount = ();
 	type OnKilledTokenAccount = ();
@@ -108,6 +119,70 @@ impl pallet_refungible::Config for Runtime {
 	type WeightInfo = weights::pallet_refungible::WeightInfo<Runtime>;
 }
 
+impl pallet_nonfungible::Config for Runtime {
+	type WeightInfo = weights::pallet_nonfungible::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const OfferingPalletId: PalletId = PalletId(*b"cur/offr");
+}
+
+impl pallet_offering::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MultiCurrency = Currencies;
+	type PalletId = OfferingPalletId;
+	type WeightInfo = weights::pallet_offering::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const RedemptionPalletId: PalletId = PalletId(*b"cur/rdmp");
+	pub const MaxRedemptionBatch: u32 = 100;
+}
+
+impl pallet_redemption::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type MultiCurrency = Currencies;
+	type PalletId = RedemptionPalletId;
+	type MaxRedemptionBatch = MaxRedemptionBatch;
+	type EscrowAccounts = (
+		pallet_rft_wrapper::IsWrapperAccount<Runtime>,
+		pallet_offering::IsOfferingAccount<Runtime>,
+	);
+	type WeightInfo = weights::pallet_redemption::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const HolderGovernancePalletId: PalletId = PalletId(*b"cur/hgov");
+	pub const MaxSnapshotHolders: u32 = 100;
+	pub const ProposalDeposit: Balance = 10 * DOLLARS;
+	pub const MaxActiveProposals: u32 = 10;
+}
+
+impl pallet_holder_governance::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletId = HolderGovernancePalletId;
+	type MaxSnapshotHolders = MaxSnapshotHolders;
+	type Currency = Balances;
+	type ProposalDeposit = ProposalDeposit;
+	type MaxActiveProposals = MaxActiveProposals;
+	type WeightInfo = weights::pallet_holder_governance::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const RftWrapperPalletId: PalletId = PalletId(*b"cur/wrft");
+	pub WrappedTransferExemptAccounts: Vec<AccountId> = vec![DEXPalletId::get().into_account_truncating()];
+}
+
+impl pallet_rft_wrapper::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CurrencyId = CurrencyId;
+	type MultiCurrency = Currencies;
+	type PalletId = RftWrapperPalletId;
+	type TransferWhitelistExempt = IsInVec<WrappedTransferExemptAccounts>;
+	type WeightInfo = weights::pallet_rft_wrapper::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const CollectionCreationPrice: Balance = 0;
 }
@@ -130,4 +205,12 @@ type SponsorshipHandler = CurioSponsorshipHandler<Runtime>;
 
 impl pallet_charge_transaction::Config for Runtime {
 	type SponsorshipHandler = SponsorshipHandler;
-}
\ No newline at end of file
+}
+
+/// Error returned by the collections runtime API for collections of unsupported mode.
+pub const UNSUPPORTED_COLLECTION_MODE: DispatchError = DispatchError::Other("UnsupportedCollectionMode");
+
+/// Get mode of the collection, used to route collections runtime API calls to the right pallet.
+pub fn collection_mode(collection: CollectionId) -> Result<CollectionMode, DispatchError> {
+	Ok(<CollectionHandle<Runtime>>::try_get(collection)?.mode)
+}
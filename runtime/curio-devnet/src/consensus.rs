@@ -6,21 +6,28 @@ use crate::{
     BlockNumber,
     BLOCKS_PER_YEAR,
     DAYS,
+    deposit,
     DOLLARS,
+    Currencies,
     ParachainStaking,
     Runtime,
     RuntimeEvent,
     Treasury,
+    currency_id::{CurrencyId, LCGT},
+    governance::EnsureRootOrHalfCouncil,
     weights,
 };
 
 use frame_support::{
-    parameter_types
+    parameter_types,
+    PalletId,
 };
 use sp_std::{
     vec::Vec
 };
+use parachain_staking::PenaltyDestination;
 use sp_runtime::{
+    Perbill,
     Perquintill,
     impl_opaque_keys,
     traits::{
@@ -51,17 +58,36 @@ parameter_types! {
 	pub const DefaultBlocksPerRound: BlockNumber = 10;
 	/// Unstaked balance can be unlocked after 7 days
 	pub const StakeDuration: BlockNumber = 7 * DAYS;
+	/// Governance can lock unstaked balance for at most 28 days
+	pub const MaxStakeDuration: BlockNumber = 28 * DAYS;
 	/// Collator exit requests are delayed by 4 hours (2 rounds/sessions)
 	pub const ExitQueueDelay: u32 = 2;
+	/// Governance can delay collator exit requests by at most 84 rounds/sessions
+	pub const MaxExitQueueDelay: u32 = 84;
+	/// Raised minimum stakes are enforced after 12 rounds/sessions
+	pub const MinStakeGracePeriod: u32 = 12;
+	/// Collator commission changes are delayed by 4 hours (2 rounds/sessions)
+	pub const CommissionChangeDelay: u32 = 2;
+	/// Slashes can be cancelled for 4 hours (2 rounds/sessions)
+	pub const SlashDeferDuration: u32 = 2;
+	pub const MaxRewardHistory: u32 = 100;
 	/// Minimum 16 collators selected per round, default at genesis and minimum forever after
 	pub const MinCollators: u32 = 2;
 	/// At least 4 candidates which cannot leave the network if there are no other candidates.
 	pub const MinRequiredCollators: u32 = 2;
-	/// We only allow one delegation per round.
-	pub const MaxDelegationsPerRound: u32 = 1;
+	/// New delegations are rewarded after 4 hours (2 rounds/sessions)
+	pub const DelegationWarmUp: u32 = 2;
+	/// Withdrawing stake from delegations younger than 1 day (12 rounds/sessions) costs 1% of it
+	pub const MinDelegationDuration: u32 = 12;
+	pub const EarlyExitPenalty: Perbill = Perbill::from_percent(1);
+	/// Early exit penalties are paid to the remaining delegators of the collator
+	pub const EarlyExitPenaltyDestination: PenaltyDestination = PenaltyDestination::Delegators;
 	/// Maximum 35 delegators per collator at launch, might be increased later
 	#[derive(Debug, Eq, PartialEq)]
 	pub const MaxDelegatorsPerCollator: u32 = 70;
+	/// Maximum 5 collators per delegator
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxCollatorsPerDelegator: u32 = 5;
 	/// Minimum stake required to become a collator
 	pub const MinCollatorStake: u128 = 10_000 * DOLLARS;
 	/// Maximum number of collator candidates
@@ -73,6 +99,13 @@ parameter_types! {
 	pub const NetworkRewardStart: BlockNumber = 0;
 	/// The rate in percent for the network rewards
 	pub const NetworkRewardRate: Perquintill = Perquintill::from_percent(10);
+	/// Governance can raise the rate for the network rewards to at most 20%
+	pub const MaxNetworkRewardRate: Perquintill = Perquintill::from_percent(20);
+	/// Maximum length of each field of the metadata of collator candidates
+	#[derive(Debug, Eq, PartialEq)]
+	pub const MaxMetadataLength: u32 = 128;
+	pub const MetadataDepositBase: Balance = deposit(1, 0);
+	pub const MetadataDepositPerByte: Balance = deposit(0, 1);
 	
 }
 
@@ -84,24 +117,65 @@ impl parachain_staking::Config for Runtime {
 	type MinBlocksPerRound = MinBlocksPerRound;
 	type DefaultBlocksPerRound = DefaultBlocksPerRound;
 	type StakeDuration = StakeDuration;
+	type MaxStakeDuration = MaxStakeDuration;
 	type ExitQueueDelay = ExitQueueDelay;
+	type MaxExitQueueDelay = MaxExitQueueDelay;
+	type CommissionChangeDelay = CommissionChangeDelay;
 	type MinCollators = MinCollators;
 	type MinRequiredCollators = MinRequiredCollators;
-	type MaxDelegationsPerRound = MaxDelegationsPerRound;
+	type DelegationWarmUp = DelegationWarmUp;
+	type MinDelegationDuration = MinDelegationDuration;
+	type EarlyExitPenalty = EarlyExitPenalty;
+	type EarlyExitPenaltyDestination = EarlyExitPenaltyDestination;
+	type EarlyExitPenaltyBeneficiary = Treasury;
 	type MaxDelegatorsPerCollator = MaxDelegatorsPerCollator;
+	type MaxCollatorsPerDelegator = MaxCollatorsPerDelegator;
 	type MinCollatorStake = MinCollatorStake;
 	type MinCollatorCandidateStake = MinCollatorStake;
 	type MaxTopCandidates = MaxCollatorCandidates;
 	type MinDelegatorStake = MinDelegatorStk;
+	type MinStakeGracePeriod = MinStakeGracePeriod;
 	type MaxUnstakeRequests = MaxUnstakeRequests;
 	type NetworkRewardRate = NetworkRewardRate;
+	type MaxNetworkRewardRate = MaxNetworkRewardRate;
 	type NetworkRewardStart = NetworkRewardStart;
 	type NetworkRewardBeneficiary = Treasury;
+	type RewardSource = parachain_staking::InflationaryRewards;
+	type SlashOrigin = EnsureRootOrHalfCouncil;
+	type ParametersOrigin = EnsureRootOrHalfCouncil;
+	type SlashDeferDuration = SlashDeferDuration;
+	type Slash = Treasury;
+	type MaxRewardHistory = MaxRewardHistory;
+	type MaxMetadataLength = MaxMetadataLength;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type WeightInfo = weights::parachain_staking::WeightInfo<Runtime>;
 	
 	const BLOCKS_PER_YEAR: Self::BlockNumber = BLOCKS_PER_YEAR;
 }
 
+parameter_types! {
+	pub const LiquidStakingCurrencyId: CurrencyId = LCGT;
+	pub const LiquidStakingPalletId: PalletId = PalletId(*b"cur/lstk");
+	pub const MinLiquidStakingDeposit: Balance = 1_000 * DOLLARS;
+	/// Redemptions are unbonded once a day, so at most 7 batches are in the unstaking queue
+	pub const LiquidStakingBatchPeriod: BlockNumber = DAYS;
+	pub const MaxRedemptions: u32 = 16;
+}
+
+impl pallet_liquid_staking::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CurrencyId = CurrencyId;
+	type MultiCurrency = Currencies;
+	type DerivativeCurrencyId = LiquidStakingCurrencyId;
+	type PalletId = LiquidStakingPalletId;
+	type ManagerOrigin = EnsureRootOrHalfCouncil;
+	type MinDeposit = MinLiquidStakingDeposit;
+	type BatchPeriod = LiquidStakingBatchPeriod;
+	type MaxRedemptions = MaxRedemptions;
+	type WeightInfo = weights::pallet_liquid_staking::WeightInfo<Runtime>;
+}
+
 impl_opaque_keys! {
 	pub struct SessionKeys {
 		pub aura: Aura,
@@ -7,7 +7,9 @@ use scale_info::TypeInfo;
 use sp_std::vec;
 use sp_runtime::RuntimeDebug;
 use pallet_dex::types::DexCurrencyId;
+use pallet_rft_wrapper::WrappedCurrencyId;
 use module_currency_id::create_currency_id;
+use collection_primitives::{CollectionId, TokenId};
 
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -23,6 +25,9 @@ create_currency_id! {
 		DOT("Polkadot", 10) = 1,
 		KSM("Kusama", 12) = 2,
 
+		// Liquid staking
+		LCGT("Liquid Curio Governance Token", 18) = 20,
+
 		// Bridged
 		ETH("Ethereum", 18) = 30,
 		DAI("Dai Stablecoin", 18) = 31,
@@ -43,6 +48,7 @@ create_currency_id! {
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum DexShare {
 	Token(TokenSymbol),
+	WrappedRft(CollectionId, TokenId),
 }
 
 #[derive(Encode, Decode, Eq, PartialEq, Copy, Clone, RuntimeDebug, PartialOrd, Ord, TypeInfo, MaxEncodedLen)]
@@ -51,6 +57,8 @@ pub enum DexShare {
 pub enum CurrencyId {
 	Token(TokenSymbol),
 	DexShare(DexShare, DexShare),
+	/// Pieces of RFT token wrapped by `pallet_rft_wrapper`
+	WrappedRft(CollectionId, TokenId),
 }
 
 impl DexCurrencyId for CurrencyId {
@@ -65,7 +73,7 @@ impl DexCurrencyId for CurrencyId {
 	fn is_trading_pair_currency_id(&self) -> bool {
 		matches!(
 			self,
-			CurrencyId::Token(_)
+			CurrencyId::Token(_) | CurrencyId::WrappedRft(_, _)
 		)
 	}
 
@@ -83,27 +91,42 @@ impl DexCurrencyId for CurrencyId {
 	fn join_dex_share_currency_id(currency_id_0: Self, currency_id_1: Self) -> Option<Self> {
 		let dex_share_0 = match currency_id_0 {
 			CurrencyId::Token(symbol) => DexShare::Token(symbol),
+			CurrencyId::WrappedRft(collection_id, token_id) => DexShare::WrappedRft(collection_id, token_id),
 			// Unsupported
 			CurrencyId::DexShare(..) => return None,
 		};
 		let dex_share_1 = match currency_id_1 {
 			CurrencyId::Token(symbol) => DexShare::Token(symbol),
+			CurrencyId::WrappedRft(collection_id, token_id) => DexShare::WrappedRft(collection_id, token_id),
 			// Unsupported
 			CurrencyId::DexShare(..) => return None,
 		};
+		// Ids of wrapped RFT tokens must be representable
+		u32::try_from(dex_share_0).ok()?;
+		u32::try_from(dex_share_1).ok()?;
 		Some(CurrencyId::DexShare(dex_share_0, dex_share_1))
 	}
 }
 
-impl From<DexShare> for u32 {
-	fn from(val: DexShare) -> u32 {
+/// Fails for wrapped RFT tokens with ids which don't fit into the encoding
+impl TryFrom<DexShare> for u32 {
+	type Error = ();
+
+	fn try_from(val: DexShare) -> Result<u32, ()> {
 		let mut bytes = [0u8; 4];
 		match val {
 			DexShare::Token(token) => {
 				bytes[3] = token.into();
 			}
+			// First byte distinguishes from tokens
+			DexShare::WrappedRft(collection_id, token_id) => {
+				let collection_id = u16::try_from(collection_id.0).map_err(|_| ())?;
+				bytes[0] = 1;
+				bytes[1..3].copy_from_slice(&collection_id.to_be_bytes());
+				bytes[3] = u8::try_from(token_id.0).map_err(|_| ())?;
+			}
 		}
-		u32::from_be_bytes(bytes)
+		Ok(u32::from_be_bytes(bytes))
 	}
 }
 
@@ -111,6 +134,20 @@ impl Into<CurrencyId> for DexShare {
 	fn into(self) -> CurrencyId {
 		match self {
 			DexShare::Token(token) => CurrencyId::Token(token),
+			DexShare::WrappedRft(collection_id, token_id) => CurrencyId::WrappedRft(collection_id, token_id),
+		}
+	}
+}
+
+impl WrappedCurrencyId for CurrencyId {
+	fn wrapped_currency_id(collection_id: CollectionId, token_id: TokenId) -> Self {
+		CurrencyId::WrappedRft(collection_id, token_id)
+	}
+
+	fn wrapped_token(&self) -> Option<(CollectionId, TokenId)> {
+		match self {
+			CurrencyId::WrappedRft(collection_id, token_id) => Some((*collection_id, *token_id)),
+			_ => None,
 		}
 	}
 }
\ No newline at end of file
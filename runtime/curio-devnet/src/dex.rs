@@ -5,16 +5,21 @@ use frame_system::{
     EnsureRoot,
 };
 
+use sp_runtime::traits::ConstU32;
+
 use crate::{
     AccountId,
     BlockNumber,
     Currencies,
     DAYS,
+    Dex,
     Runtime,
     RuntimeEvent,
+    collections::GetNativeCurrencyId,
     currency_id::{
         CurrencyId,
     },
+    governance::EnsureRootOrHalfCouncil,
     weights,
 };
 
@@ -37,4 +42,15 @@ impl pallet_dex::Config for Runtime {
 	type ListingOrigin = EnsureRoot<AccountId>;
 	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
 	type OnLiquidityPoolUpdated = ();
-}
\ No newline at end of file
+}
+
+impl pallet_fee_currencies::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CurrencyId = CurrencyId;
+	type MultiCurrency = Currencies;
+	type NativeCurrencyId = GetNativeCurrencyId;
+	type DEX = Dex;
+	type UpdateOrigin = EnsureRootOrHalfCouncil;
+	type MaxSwapPathLength = ConstU32<3>;
+	type WeightInfo = weights::pallet_fee_currencies::WeightInfo<Runtime>;
+}
@@ -81,6 +81,7 @@ use sp_runtime::{
 pub use sp_runtime::BuildStorage;
 use sp_std::{
 	boxed::Box,
+	vec,
 	vec::Vec,
 };
 use sp_version::RuntimeVersion;
@@ -113,8 +114,16 @@ pub use primitives::{
 	}
 };
 
+use collection_primitives::{
+	CollectionId, CollectionLimits, CollectionMode, CollectionStats, Property,
+	PropertyKeyPermission, RpcCollection, TokenId,
+};
+use sp_runtime::DispatchError;
+
 use crate::{
+	collections::{collection_mode, UNSUPPORTED_COLLECTION_MODE},
 	consensus::SessionKeys,
+	sponsoring::{CurioSponsorshipPredict, SponsorshipPredict},
 	weights::{
 		BlockExecutionWeight, 
 		ExtrinsicBaseWeight, 
@@ -163,7 +172,8 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_charge_transaction::ChargeTransactionPayment<Runtime>,
+	// Swaps the fee into native currency unless sponsored, then charges it
+	pallet_fee_currencies::ChargeFeeInCurrency<Runtime>,
 );
 
 /// Unchecked extrinsic type as expected by this runtime.
@@ -175,6 +185,18 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_refungible::migration::PopulateTokenHolders<Runtime>,
+	pallet_refungible::migration::PopulateAccountTokens<Runtime>,
+	pallet_common::migration::MigratePropertyPermissions<Runtime>,
+	parachain_staking::migration::AddCollatorCommission<Runtime>,
+	parachain_staking::migration::MultipleDelegations<Runtime>,
+	parachain_staking::migration::MigrateStakingParams<Runtime>,
+	parachain_staking::migration::MigrateDelegationWarmUp<Runtime>,
+	parachain_staking::migration::MigrateOutstandingRewards<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -182,6 +204,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
@@ -206,10 +229,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("curio-devnet"),
 	impl_name: create_runtime_str!("curio-devnet"),
 	authoring_version: 1,
-	spec_version: 13,
+	spec_version: 14,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 3,
 	state_version: 1,
 };
 
@@ -404,6 +427,13 @@ construct_runtime!(
 		Refungible: pallet_refungible = 73,
 		Whitelist: pallet_whitelist = 74,
 		Bridge: pallet_bridge = 75,
+		Offering: pallet_offering = 76,
+		Redemption: pallet_redemption = 77,
+		HolderGovernance: pallet_holder_governance = 78,
+		RftWrapper: pallet_rft_wrapper = 79,
+		Nonfungible: pallet_nonfungible = 81,
+		FeeCurrencies: pallet_fee_currencies = 82,
+		LiquidStaking: pallet_liquid_staking = 83,
 
 		// Acala
 		Pausing: module_transaction_pause = 80,
@@ -569,6 +599,152 @@ impl_runtime_apis! {
 		fn get_sorted_proposed_candidates(balance: Balance) -> Vec<AccountId> {
 			ParachainStaking::get_sorted_proposed_candidates(balance)
 		}
+
+		fn get_collator_commission(collator: &AccountId) -> Option<parachain_staking_runtime_api::CollatorCommission> {
+			ParachainStaking::get_collator_commission(collator)
+		}
+
+		fn get_reward_history(account: &AccountId) -> Vec<(u32, Balance)> {
+			ParachainStaking::get_reward_history(account)
+		}
+
+		fn get_collator_stats(collator: &AccountId) -> Option<parachain_staking_runtime_api::CollatorStats<Balance>> {
+			ParachainStaking::get_collator_stats(collator)
+		}
+
+		fn get_delegation_projection(collator: &AccountId, stake: Balance) -> Option<parachain_staking_runtime_api::RewardProjection<Balance>> {
+			ParachainStaking::get_delegation_projection(collator, stake)
+		}
+
+		fn get_collator_metadata(collator: &AccountId) -> Option<parachain_staking_runtime_api::CollatorMetadata<Balance>> {
+			ParachainStaking::get_collator_metadata(collator)
+		}
+	}
+
+	impl whitelist_runtime_api::WhitelistApi<Block, AccountId, BlockNumber> for Runtime {
+		fn investors_expiring_within(blocks: BlockNumber) -> Vec<(AccountId, pallet_whitelist::InvestorKey, BlockNumber)> {
+			Whitelist::investors_expiring_within(blocks)
+		}
+
+		fn investors_expiring_before(before: BlockNumber) -> Vec<(AccountId, pallet_whitelist::InvestorKey, BlockNumber)> {
+			Whitelist::investors_expiring_before(before)
+		}
+
+		fn is_active_investor(account: AccountId) -> bool {
+			<Whitelist as pallet_whitelist::traits::WhitelistInterface<Runtime>>::is_active_investor(&account)
+		}
+	}
+
+	impl collections_runtime_api::CollectionsApi<Block, AccountId> for Runtime {
+		fn collection_by_id(collection: CollectionId) -> Option<RpcCollection<AccountId>> {
+			Common::rpc_collection(collection)
+		}
+
+		fn collection_properties(
+			collection: CollectionId,
+			keys: Option<Vec<Vec<u8>>>
+		) -> Result<Vec<Property>, DispatchError> {
+			let keys = keys.map(Common::bytes_keys_to_property_keys).transpose()?;
+			Common::filter_collection_properties(collection, keys)
+		}
+
+		fn token_properties(
+			collection: CollectionId,
+			token: TokenId,
+			keys: Option<Vec<Vec<u8>>>
+		) -> Result<Vec<Property>, DispatchError> {
+			let keys = keys.map(Common::bytes_keys_to_property_keys).transpose()?;
+			match collection_mode(collection)? {
+				CollectionMode::NFT => Ok(Nonfungible::filter_token_properties(collection, token, keys)),
+				CollectionMode::ReFungible => Ok(Refungible::filter_token_properties(collection, token, keys)),
+				CollectionMode::Fungible(_) => Err(UNSUPPORTED_COLLECTION_MODE),
+			}
+		}
+
+		fn property_permissions(
+			collection: CollectionId,
+			keys: Option<Vec<Vec<u8>>>
+		) -> Result<Vec<PropertyKeyPermission>, DispatchError> {
+			let keys = keys.map(Common::bytes_keys_to_property_keys).transpose()?;
+			Common::filter_property_permissions(collection, keys)
+		}
+
+		fn balance(collection: CollectionId, token: TokenId, account: AccountId) -> Result<u128, DispatchError> {
+			match collection_mode(collection)? {
+				CollectionMode::NFT => Ok(Nonfungible::token_balance(collection, token, &account)),
+				CollectionMode::ReFungible => Ok(Refungible::token_balance(collection, token, &account)),
+				CollectionMode::Fungible(_) => Err(UNSUPPORTED_COLLECTION_MODE),
+			}
+		}
+
+		fn total_supply(collection: CollectionId, token: TokenId) -> Result<u128, DispatchError> {
+			match collection_mode(collection)? {
+				CollectionMode::NFT => Ok(Nonfungible::token_total_pieces(collection, token)),
+				CollectionMode::ReFungible => Ok(Refungible::token_total_pieces(collection, token)),
+				CollectionMode::Fungible(_) => Err(UNSUPPORTED_COLLECTION_MODE),
+			}
+		}
+
+		fn allowance(
+			collection: CollectionId,
+			token: TokenId,
+			owner: AccountId,
+			spender: AccountId
+		) -> Result<u128, DispatchError> {
+			match collection_mode(collection)? {
+				CollectionMode::NFT => Ok(Nonfungible::token_allowance(collection, token, &owner, &spender)),
+				CollectionMode::ReFungible => Ok(Refungible::token_allowance(collection, token, &owner, &spender)),
+				CollectionMode::Fungible(_) => Err(UNSUPPORTED_COLLECTION_MODE),
+			}
+		}
+
+		fn adminlist(collection: CollectionId) -> Vec<AccountId> {
+			Common::adminlist(collection)
+		}
+
+		fn collection_stats() -> CollectionStats {
+			Common::collection_stats()
+		}
+
+		fn effective_collection_limits(collection: CollectionId) -> Option<CollectionLimits> {
+			Common::effective_collection_limits(collection)
+		}
+
+		fn next_sponsored(collection: CollectionId, account: AccountId, token: TokenId) -> Option<u64> {
+			CurioSponsorshipPredict::<Runtime>::predict(collection, account, token)
+		}
+
+		fn account_tokens(
+			account: AccountId,
+			start_after: Option<(CollectionId, TokenId)>,
+			limit: u32
+		) -> Vec<(CollectionId, TokenId)> {
+			Refungible::account_tokens(&account, start_after, limit)
+		}
+
+		fn token_holders(
+			collection: CollectionId,
+			token: TokenId,
+			start_after: Option<AccountId>,
+			limit: u32
+		) -> Result<Vec<(AccountId, u128)>, DispatchError> {
+			match collection_mode(collection)? {
+				CollectionMode::NFT => Ok(Nonfungible::token_owner(collection, token)
+					.filter(|owner| limit > 0 && start_after.as_ref() != Some(owner))
+					.map(|owner| vec![(owner, 1)])
+					.unwrap_or_default()),
+				CollectionMode::ReFungible => Ok(Refungible::token_holders(collection, token, start_after, limit)),
+				CollectionMode::Fungible(_) => Err(UNSUPPORTED_COLLECTION_MODE),
+			}
+		}
+
+		fn last_token_id(collection: CollectionId) -> Result<TokenId, DispatchError> {
+			match collection_mode(collection)? {
+				CollectionMode::NFT => Ok(Nonfungible::last_token_id(collection)),
+				CollectionMode::ReFungible => Ok(Refungible::last_token_id(collection)),
+				CollectionMode::Fungible(_) => Err(UNSUPPORTED_COLLECTION_MODE),
+			}
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -633,6 +809,13 @@ impl_runtime_apis! {
 			orml_list_benchmark!(list, extra, pallet_currencies, benchmarking::pallet_currencies);
 			orml_list_benchmark!(list, extra, pallet_whitelist, benchmarking::pallet_whitelist);
 			orml_list_benchmark!(list, extra, pallet_refungible, benchmarking::pallet_refungible);
+			orml_list_benchmark!(list, extra, pallet_nonfungible, benchmarking::pallet_nonfungible);
+			orml_list_benchmark!(list, extra, pallet_offering, benchmarking::pallet_offering);
+			orml_list_benchmark!(list, extra, pallet_redemption, benchmarking::pallet_redemption);
+			orml_list_benchmark!(list, extra, pallet_holder_governance, benchmarking::pallet_holder_governance);
+			orml_list_benchmark!(list, extra, pallet_rft_wrapper, benchmarking::pallet_rft_wrapper);
+			orml_list_benchmark!(list, extra, pallet_liquid_staking, benchmarking::pallet_liquid_staking);
+			orml_list_benchmark!(list, extra, pallet_fee_currencies, benchmarking::pallet_fee_currencies);
 			orml_list_benchmark!(list, extra, module_transaction_pause, benchmarking::transaction_pause);
 
 
@@ -701,6 +884,13 @@ impl_runtime_apis! {
 			orml_add_benchmark!(params, batches, pallet_currencies, benchmarking::pallet_currencies);
 			orml_add_benchmark!(params, batches, pallet_whitelist, benchmarking::pallet_whitelist);
 			orml_add_benchmark!(params, batches, pallet_refungible, benchmarking::pallet_refungible);
+			orml_add_benchmark!(params, batches, pallet_nonfungible, benchmarking::pallet_nonfungible);
+			orml_add_benchmark!(params, batches, pallet_offering, benchmarking::pallet_offering);
+			orml_add_benchmark!(params, batches, pallet_redemption, benchmarking::pallet_redemption);
+			orml_add_benchmark!(params, batches, pallet_holder_governance, benchmarking::pallet_holder_governance);
+			orml_add_benchmark!(params, batches, pallet_rft_wrapper, benchmarking::pallet_rft_wrapper);
+			orml_add_benchmark!(params, batches, pallet_liquid_staking, benchmarking::pallet_liquid_staking);
+			orml_add_benchmark!(params, batches, pallet_fee_currencies, benchmarking::pallet_fee_currencies);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)
@@ -35,9 +35,9 @@ use sp_runtime::{
 use sp_std::prelude::*;
 
 use frame_support::{
-	construct_runtime, parameter_types,
+	construct_runtime, parameter_types, PalletId,
 	dispatch::{DispatchError, DispatchClass},
-	traits::Everything,
+	traits::{Everything, Nothing},
 	weights::{
 		ConstantMultiplier, WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial
 	},
@@ -45,7 +45,7 @@ use frame_support::{
 };
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
-	EnsureSignedBy
+	EnsureRoot, EnsureSignedBy
 };
 pub use sp_runtime::{Percent, Permill, Perbill};
 use polkadot_runtime_common::SlowAdjustingFeeUpdate;
@@ -62,17 +62,23 @@ mod curio_devnet_runtime {
     pub use super::super::*;
 }
 use curio_devnet_runtime::weights::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight};
+pub use curio_devnet_runtime::currency_id::{CurrencyId, CGT, DOT, DAI};
+use pallet_currencies::BasicCurrencyAdapter;
+pub use pallet_currencies::Call as CurrenciesCall;
+pub use pallet_dex::Call as DexCall;
 pub use pallet_refungible::{Balance as RefBalance, Call as RefCall, TokenBalance, CreateItemData};
+pub use pallet_nonfungible::Call as NftCall;
 
 // Curio
 use primitives::{
-	Address, Index, Block, BlockNumber, Hash, BlockHashCount,
+	Address, Amount, Index, Block, BlockNumber, Hash, BlockHashCount,
 	Balance, CENTS, MILLICENTS, Signature, time::devnet::DAYS, NORMAL_DISPATCH_RATIO,
 	MAXIMUM_BLOCK_WEIGHT, AVERAGE_ON_INITIALIZE_RATIO
 };
 
 pub use collection_primitives::{
-	Property, PropertyKeyPermission, CollectionId, TokenId, PropertyPermission,
+	Property, PropertyKeyPermission, CollectionId, TokenId, PropertyPermission, CollectionMode,
+	CreateNftExData, CollectionPropertiesVec,
 };
 
 pub mod opaque {
@@ -270,6 +276,9 @@ ord_parameter_types! {
 impl pallet_whitelist::Config for MockRuntime {
 	type RuntimeEvent = RuntimeEvent;
 	type RolesRoot = EnsureSignedBy<RolesRoot, AccountId>;
+	type OffchainSignature = sp_runtime::testing::TestSignature;
+	type OffchainPublic = sp_runtime::testing::UintAuthorityId;
+	type MaxKycAttributesLength = frame_support::traits::ConstU32<256>;
 	type WeightInfo = curio_devnet_runtime::weights::pallet_whitelist::WeightInfo<MockRuntime>;
 }
 
@@ -277,6 +286,66 @@ impl pallet_refungible::Config for MockRuntime {
 	type WeightInfo = curio_devnet_runtime::weights::pallet_refungible::WeightInfo<MockRuntime>;
 }
 
+impl pallet_nonfungible::Config for MockRuntime {
+	type WeightInfo = curio_devnet_runtime::weights::pallet_nonfungible::WeightInfo<MockRuntime>;
+}
+
+orml_traits::parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: CurrencyId| -> Balance {
+		Default::default()
+	};
+}
+
+impl orml_tokens::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = CurrencyId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type CurrencyHooks = ();
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type DustRemovalWhitelist = Nothing;
+}
+
+parameter_types! {
+	pub const GetNativeCurrencyId: CurrencyId = CGT;
+}
+
+impl pallet_currencies::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type CurrencyId = CurrencyId;
+	type MultiCurrency = Tokens;
+	type NativeCurrency = BasicCurrencyAdapter<MockRuntime, Balances, Amount, BlockNumber>;
+	type GetNativeCurrencyId = GetNativeCurrencyId;
+	type WeightInfo = ();
+	type SweepOrigin = EnsureRoot<AccountId>;
+	type OnDust = ();
+}
+
+parameter_types! {
+	pub const DEXPalletId: PalletId = PalletId(*b"cur/dexm");
+	pub const GetExchangeFee: (u32, u32) = (3, 1000);
+	pub const ExtendedProvisioningBlocks: BlockNumber = 2 * DAYS;
+	pub const TradingPathLimit: u32 = 3;
+}
+
+impl pallet_dex::Config for MockRuntime {
+	type RuntimeEvent = RuntimeEvent;
+	type CurrencyId = CurrencyId;
+	type Currency = Currencies;
+	type GetExchangeFee = GetExchangeFee;
+	type TradingPathLimit = TradingPathLimit;
+	type PalletId = DEXPalletId;
+	type DEXIncentives = ();
+	type WeightInfo = ();
+	type ListingOrigin = EnsureRoot<AccountId>;
+	type ExtendedProvisioningBlocks = ExtendedProvisioningBlocks;
+	type OnLiquidityPoolUpdated = ();
+}
+
 parameter_types! {
 	pub const DefaultSponsoringRateLimit: BlockNumber = 1 * DAYS;
 	pub const DefaultSponsoringFeeLimit: U256 = U256::MAX;
@@ -301,6 +370,7 @@ construct_runtime! {
 		// Monetary stuff.
 		Balances: pallet_balances,
         TransactionPayment: pallet_transaction_payment,
+		Tokens: orml_tokens,
 
 		// Unique
 		Charging: pallet_charge_transaction::{Pallet, Storage},
@@ -308,7 +378,10 @@ construct_runtime! {
 		// Curio
 		Common: pallet_common,
 		Refungible: pallet_refungible,
+		Nonfungible: pallet_nonfungible,
 		Whitelist: pallet_whitelist,
+		Currencies: pallet_currencies,
+		Dex: pallet_dex,
 	}
 }
 
@@ -396,6 +469,16 @@ pub fn create_default_token(collection_id: CollectionId, owner: AccountId) -> Re
 	Ok(get_token_id_from_last_event())
 }
 
+pub fn create_nft(collection_id: CollectionId, sender: AccountId, owner: AccountId) -> Result<TokenId, DispatchError> {
+	let data = CreateNftExData::<AccountId> {
+		properties: CollectionPropertiesVec::default(),
+		owner
+	};
+
+	Nonfungible::create_item(RuntimeOrigin::signed(sender), collection_id, data)?;
+	Ok(get_token_id_from_last_event())
+}
+
 pub fn get_collection_id_from_last_event() -> CollectionId {
     match System::events().last().unwrap().event {
         RuntimeEvent::Common(CommonEvent::CollectionCreated(collection_id, _, _)) => {
@@ -449,7 +532,9 @@ impl TokenInitializer {
 		if let Some(properties_with_mut_flag) = &self.properties {			
 			for (property, is_mutable) in properties_with_mut_flag {
 				let property_permission = PropertyPermission {
-					mutable: *is_mutable
+					mutable: *is_mutable,
+					collection_admin: true,
+					token_owner: false
 				};
 				let property_key_permission = PropertyKeyPermission {
 					key: property.key.clone(),
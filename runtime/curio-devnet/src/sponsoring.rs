@@ -22,23 +22,38 @@
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0
 
 use core::marker::PhantomData;
+use codec::Encode;
 use up_sponsorship::SponsorshipHandler;
 use frame_support::{
-	traits::{IsSubType},
+	dispatch::{GetDispatchInfo, Pays},
+	traits::{Get, IsSubType},
 };
 use collection_primitives::{
-	CollectionId, REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT, TokenId,
+	CollectionId, CollectionMode, NFT_SPONSOR_TRANSFER_TIMEOUT, REFUNGIBLE_SPONSOR_TRANSFER_TIMEOUT,
+	TokenId,
+};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	FixedPointNumber, SaturatedConversion,
 };
-use sp_runtime::traits::Saturating;
 use pallet_common::{
-    CollectionHandle, CreateItemBasket, TokenPropertyBasket,
+    BalanceOf, CollectionHandle, CreateItemBasket, TokenPropertyBasket,
 };
 use pallet_refungible::{
     Call as CurioCall, Config as CurioConfig, RefungibleTransferBasket, RefungibleApproveBasket
 };
+use pallet_nonfungible::{
+	Call as NonfungibleCall, Config as NonfungibleConfig, NftTransferBasket, NftApproveBasket
+};
+use pallet_currencies::{Call as CurrenciesCall, Config as CurrenciesConfig};
+use pallet_dex::{Call as DexCall, Config as DexConfig};
+use pallet_whitelist::{traits::WhitelistInterface, Config as WhitelistConfig};
+use primitives::Balance;
 
-pub trait Config: CurioConfig {}
-impl<T> Config for T where T: CurioConfig {}
+pub trait Config:
+	CurioConfig + NonfungibleConfig + CurrenciesConfig + DexConfig + WhitelistConfig + pallet_transaction_payment::Config {}
+impl<T> Config for T where
+	T: CurioConfig + NonfungibleConfig + CurrenciesConfig + DexConfig + WhitelistConfig + pallet_transaction_payment::Config {}
 
 // TODO: permission check?
 pub fn withdraw_set_token_property<T: Config>(
@@ -149,19 +164,158 @@ pub fn withdraw_approve<T: Config>(
 	Some(())
 }
 
-fn load<T: CurioConfig>(id: CollectionId) -> Option<(T::AccountId, CollectionHandle<T>)> {
+pub fn withdraw_set_nft_property<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::AccountId,
+	item_id: &TokenId,
+	data_size: usize,
+) -> Option<()> {
+	// preliminary sponsoring correctness check
+	if !<pallet_nonfungible::Owned<T>>::get((collection.id, who, item_id)) {
+		return None;
+	}
+
+	if data_size > collection.limits.sponsored_data_size() as usize {
+		return None;
+	}
+
+	let block_number = <frame_system::Pallet<T>>::block_number() as T::BlockNumber;
+	let limit = collection.limits.sponsored_data_rate_limit()?;
+
+	if let Some(last_tx_block) = TokenPropertyBasket::<T>::get(collection.id, item_id) {
+		let timeout = last_tx_block + limit.into();
+		if block_number < timeout {
+			return None;
+		}
+	}
+
+	<TokenPropertyBasket<T>>::insert(collection.id, item_id, block_number);
+
+	Some(())
+}
+
+pub fn withdraw_nft_transfer<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::AccountId,
+	item_id: &TokenId,
+) -> Option<()> {
+	// preliminary sponsoring correctness check
+	if !<pallet_nonfungible::Owned<T>>::get((collection.id, who, item_id)) {
+		return None;
+	}
+
+	// sponsor timeout
+	let block_number = <frame_system::Pallet<T>>::block_number() as T::BlockNumber;
+	let limit = collection
+		.limits
+		.sponsor_transfer_timeout(NFT_SPONSOR_TRANSFER_TIMEOUT);
+
+	if let Some(last_tx_block) = <NftTransferBasket<T>>::get(collection.id, item_id) {
+		let timeout = last_tx_block + limit.into();
+		if block_number < timeout {
+			return None;
+		}
+	}
+
+	<NftTransferBasket<T>>::insert(collection.id, item_id, block_number);
+
+	Some(())
+}
+
+pub fn withdraw_nft_approve<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::AccountId,
+	item_id: &TokenId,
+) -> Option<()> {
+	// preliminary sponsoring correctness check
+	if !<pallet_nonfungible::Owned<T>>::get((collection.id, who, item_id)) {
+		return None;
+	}
+
+	// sponsor timeout
+	let block_number = <frame_system::Pallet<T>>::block_number() as T::BlockNumber;
+	let limit = collection.limits.sponsor_approve_timeout();
+
+	if let Some(last_tx_block) = <NftApproveBasket<T>>::get(collection.id, item_id) {
+		let timeout = last_tx_block + limit.into();
+		if block_number < timeout {
+			return None;
+		}
+	}
+
+	<NftApproveBasket<T>>::insert(collection.id, item_id, block_number);
+
+	Some(())
+}
+
+// Fee charged by the transaction payment pallet for the call, without tip.
+//
+// Signature and signed extensions are not a part of the call, so the length fee is slightly underestimated.
+fn estimate_fee<T: Config, C: GetDispatchInfo + Encode>(call: &C) -> BalanceOf<T> {
+	let info = call.get_dispatch_info();
+	if info.pays_fee == Pays::No {
+		return Zero::zero();
+	}
+
+	let base_weight = <T as frame_system::Config>::BlockWeights::get().get(info.class).base_extrinsic;
+	let base_fee = <pallet_transaction_payment::Pallet<T>>::weight_to_fee(base_weight);
+	let length_fee = <pallet_transaction_payment::Pallet<T>>::length_to_fee(call.encoded_size() as u32);
+	let weight_fee = <pallet_transaction_payment::Pallet<T>>::next_fee_multiplier()
+		.saturating_mul_int(<pallet_transaction_payment::Pallet<T>>::weight_to_fee(info.weight));
+
+	base_fee
+		.saturating_add(length_fee)
+		.saturating_add(weight_fee)
+		.saturated_into::<u128>()
+		.saturated_into()
+}
+
+fn load<T: Config>(
+	id: CollectionId,
+	who: &T::AccountId,
+	fee: BalanceOf<T>,
+) -> Option<(T::AccountId, CollectionHandle<T>)> {
 	let collection = CollectionHandle::new(id)?;
 	let sponsor = collection.sponsorship.sponsor().cloned()?;
+	// Sender pays if the sponsor doesn't pay for the account or has spent the budget
+	if !<pallet_common::Pallet<T>>::check_sponsorship_limits(id, who, fee) {
+		return None;
+	}
 	Some((sponsor, collection))
 }
 
+fn charge<T: Config>(
+	collection: &CollectionHandle<T>,
+	who: &T::AccountId,
+	fee: BalanceOf<T>,
+	sponsor: T::AccountId,
+) -> T::AccountId {
+	<pallet_common::Pallet<T>>::charge_sponsorship(collection.id, who, fee);
+	sponsor
+}
+
 pub struct CurioSponsorshipHandler<T>(PhantomData<T>);
 impl<T, C> SponsorshipHandler<T::AccountId, C> for CurioSponsorshipHandler<T>
 where
 	T: Config,
-	C: IsSubType<CurioCall<T>>,
+	C: IsSubType<CurioCall<T>>
+		+ IsSubType<NonfungibleCall<T>>
+		+ IsSubType<CurrenciesCall<T>>
+		+ IsSubType<DexCall<T>>
+		+ GetDispatchInfo
+		+ Encode,
 {
 	fn get_sponsor(who: &T::AccountId, call: &C) -> Option<T::AccountId> {
+		let fee = estimate_fee::<T, C>(call);
+
+		if Self::is_investor_call(call) {
+			return Self::get_investor_sponsor(who, fee.saturated_into());
+		}
+
+		if let Some(call) = IsSubType::<NonfungibleCall<T>>::is_sub_type(call) {
+			return Self::get_nft_sponsor(who, call, fee);
+		}
+
 		match IsSubType::<CurioCall<T>>::is_sub_type(call)? {
 			CurioCall::set_token_properties {
 				collection_id,
@@ -169,7 +323,7 @@ where
 				properties,
 				..
 			} => {
-				let (sponsor, collection) = load::<T>(*collection_id)?;
+				let (sponsor, collection) = load::<T>(*collection_id, who, fee)?;
 				withdraw_set_token_property(
 					&collection,
 					who,
@@ -177,31 +331,31 @@ where
 					// No overflow may happen, as data larger than usize can't reach here
 					properties.iter().map(|p| p.key.len() + p.value.len()).sum(),
 				)
-				.map(|()| sponsor)
+				.map(|()| charge(&collection, who, fee, sponsor))
 			}
 			CurioCall::create_item {
 				collection_id,
 				..
 			} => {
-				let (sponsor, collection) = load(*collection_id)?;
+				let (sponsor, collection) = load(*collection_id, who, fee)?;
 				withdraw_create_item::<T>(
 					&collection,
 					who,
 				)
-				.map(|()| sponsor)
+				.map(|()| charge(&collection, who, fee, sponsor))
 			}
 			CurioCall::transfer {
 				collection_id,
 				token_id,
 				..
 			} => {
-				let (sponsor, collection) = load(*collection_id)?;
+				let (sponsor, collection) = load(*collection_id, who, fee)?;
 				withdraw_transfer::<T>(
 					&collection,
 					who,
 					token_id,
 				)
-				.map(|()| sponsor)
+				.map(|()| charge(&collection, who, fee, sponsor))
 			}
 			CurioCall::transfer_from {
 				collection_id,
@@ -209,16 +363,120 @@ where
 				from,
 				..
 			} => {
-				let (sponsor, collection) = load(*collection_id)?;
-				withdraw_transfer::<T>(&collection, from, token_id).map(|()| sponsor)
+				let (sponsor, collection) = load(*collection_id, who, fee)?;
+				withdraw_transfer::<T>(&collection, from, token_id).map(|()| charge(&collection, who, fee, sponsor))
 			}
 			CurioCall::set_allowance {
 				collection_id,
 				token_id,
 				..
 			} => {
-				let (sponsor, collection) = load(*collection_id)?;
-				withdraw_approve::<T>(&collection, who, token_id).map(|()| sponsor)
+				let (sponsor, collection) = load(*collection_id, who, fee)?;
+				withdraw_approve::<T>(&collection, who, token_id).map(|()| charge(&collection, who, fee, sponsor))
+			}
+			_ => None,
+		}
+	}
+}
+
+impl<T: Config> CurioSponsorshipHandler<T> {
+	// Currency transfers and DEX swaps are sponsored for active investors only
+	fn is_investor_call<C>(call: &C) -> bool
+	where
+		C: IsSubType<CurrenciesCall<T>> + IsSubType<DexCall<T>>,
+	{
+		matches!(
+			IsSubType::<CurrenciesCall<T>>::is_sub_type(call),
+			Some(CurrenciesCall::transfer { .. } | CurrenciesCall::transfer_native_currency { .. })
+		) || matches!(
+			IsSubType::<DexCall<T>>::is_sub_type(call),
+			Some(DexCall::swap_with_exact_supply { .. } | DexCall::swap_with_exact_target { .. })
+		)
+	}
+
+	// The lookup is read only, the sponsored fee is accounted here
+	fn get_investor_sponsor(
+		who: &T::AccountId,
+		fee: Balance,
+	) -> Option<T::AccountId> {
+		match <pallet_whitelist::Pallet<T>>::investor_sponsor(who, fee) {
+			Some(sponsor) => {
+				<pallet_whitelist::Pallet<T>>::charge_investor_sponsorship(who, fee);
+				Some(sponsor)
+			}
+			None => {
+				if <pallet_whitelist::Pallet<T>>::is_active_investor(who)
+					&& <pallet_whitelist::Pallet<T>>::is_investor_sponsorship_budget_exceeded(fee)
+				{
+					<pallet_whitelist::Pallet<T>>::note_investor_sponsorship_budget_exhausted(who);
+				}
+				None
+			}
+		}
+	}
+
+	fn get_nft_sponsor(
+		who: &T::AccountId,
+		call: &NonfungibleCall<T>,
+		fee: BalanceOf<T>,
+	) -> Option<T::AccountId> {
+		match call {
+			NonfungibleCall::set_token_properties {
+				collection_id,
+				token_id,
+				properties,
+				..
+			} => {
+				let (sponsor, collection) = load::<T>(*collection_id, who, fee)?;
+				withdraw_set_nft_property(
+					&collection,
+					who,
+					&token_id,
+					// No overflow may happen, as data larger than usize can't reach here
+					properties.iter().map(|p| p.key.len() + p.value.len()).sum(),
+				)
+				.map(|()| charge(&collection, who, fee, sponsor))
+			}
+			NonfungibleCall::create_item {
+				collection_id,
+				..
+			} => {
+				let (sponsor, collection) = load(*collection_id, who, fee)?;
+				withdraw_create_item::<T>(
+					&collection,
+					who,
+				)
+				.map(|()| charge(&collection, who, fee, sponsor))
+			}
+			NonfungibleCall::transfer {
+				collection_id,
+				token_id,
+				..
+			} => {
+				let (sponsor, collection) = load(*collection_id, who, fee)?;
+				withdraw_nft_transfer::<T>(
+					&collection,
+					who,
+					token_id,
+				)
+				.map(|()| charge(&collection, who, fee, sponsor))
+			}
+			NonfungibleCall::transfer_from {
+				collection_id,
+				token_id,
+				from,
+				..
+			} => {
+				let (sponsor, collection) = load(*collection_id, who, fee)?;
+				withdraw_nft_transfer::<T>(&collection, from, token_id).map(|()| charge(&collection, who, fee, sponsor))
+			}
+			NonfungibleCall::approve {
+				collection_id,
+				token_id,
+				..
+			} => {
+				let (sponsor, collection) = load(*collection_id, who, fee)?;
+				withdraw_nft_approve::<T>(&collection, who, token_id).map(|()| charge(&collection, who, fee, sponsor))
 			}
 			_ => None,
 		}
@@ -241,6 +499,10 @@ impl<T: Config> SponsorshipPredict<T> for CurioSponsorshipPredict<T> {
 		let collection = <CollectionHandle<T>>::try_get(collection_id).ok()?;
 		let _ = collection.sponsorship.sponsor()?;
 
+		if collection.mode == CollectionMode::NFT {
+			return Self::predict_nft(&collection, token);
+		}
+
 		// sponsor timeout
 		let block_number = <frame_system::Pallet<T>>::block_number() as T::BlockNumber;
 		let limit = collection
@@ -266,4 +528,27 @@ impl<T: Config> SponsorshipPredict<T> for CurioSponsorshipPredict<T> {
 			None
 		}
 	}
-}
\ No newline at end of file
+}
+
+impl<T: Config> CurioSponsorshipPredict<T> {
+	fn predict_nft(collection: &CollectionHandle<T>, token: TokenId) -> Option<u64>
+	where
+		u64: From<<T as frame_system::Config>::BlockNumber>,
+	{
+		let block_number = <frame_system::Pallet<T>>::block_number() as T::BlockNumber;
+		let limit = collection
+			.limits
+			.sponsor_transfer_timeout(NFT_SPONSOR_TRANSFER_TIMEOUT);
+
+		if let Some(last_tx_block) = <NftTransferBasket<T>>::get(collection.id, token) {
+			return Some(
+				last_tx_block
+					.saturating_add(limit.into())
+					.saturating_sub(block_number)
+					.into(),
+			);
+		}
+
+		<pallet_nonfungible::TokenData<T>>::contains_key((collection.id, token)).then_some(0)
+	}
+}
@@ -31,12 +31,22 @@ use crate::mock::*;
 use pallet_charge_transaction::ChargeTransactionPayment;
 use codec::Encode;
 use pallet_common::collection_initializer::CollectionInitializer;
+use collection_primitives::{SponsoredAccounts, SponsorshipSettings};
+use pallet_whitelist::InvestorSponsorship;
 
 /// This function is used to do the real call to runtime with pre/post dispatch. \
 /// `call` - the parameter that is directly responsible for runtime call. \
 /// `from` - the parameter responsible for the account from which the runtime function is called
 fn call_wrapper(call: RefCall<MockRuntime>, from: AccountId) {
-    let call: <MockRuntime as frame_system::Config>::RuntimeCall = RuntimeCall::Refungible(call);
+    runtime_call_wrapper(RuntimeCall::Refungible(call), from);
+}
+
+/// Same as [`call_wrapper`], but for calls of the nonfungible pallet.
+fn nft_call_wrapper(call: NftCall<MockRuntime>, from: AccountId) {
+    runtime_call_wrapper(RuntimeCall::Nonfungible(call), from);
+}
+
+fn runtime_call_wrapper(call: <MockRuntime as frame_system::Config>::RuntimeCall, from: AccountId) {
     let info = call.get_dispatch_info();
     let len = call.encode().len();
     let pre_d = <ChargeTransactionPayment<MockRuntime>>
@@ -887,3 +897,300 @@ pub fn set_token_property_size_limits_check() {
             event_check_wrapper(ADMIN_1);
         });
 }
+
+#[test]
+pub fn nft_transfer_is_sponsored() {
+    ExtBuilder::new()
+        .investors(vec![ALICE, BOB, CHARLIE])
+        .wl_admins(vec![ADMIN_1])
+        .balances(vec![(ADMIN_1, 10_000 * DOLLARS), (ALICE, 10_000 * DOLLARS)])
+        .build()
+        .execute_with(|| {
+            let collection_id = CollectionInitializer::<MockRuntime>
+                ::new()
+                .mode(CollectionMode::NFT)
+                .init_with_sponsor(ALICE, ADMIN_1, true)
+                .expect("Collection init failed");
+
+            let token_id = create_nft(collection_id, ADMIN_1, BOB).unwrap();
+
+            let call = NftCall::transfer {
+                collection_id: collection_id,
+                token_id: token_id,
+                to: CHARLIE,
+            };
+            nft_call_wrapper(call, BOB);
+            event_check_wrapper(ALICE);
+        });
+}
+
+#[test]
+pub fn nft_transfer_check_limits() {
+    ExtBuilder::new()
+        .investors(vec![ALICE, BOB, CHARLIE])
+        .wl_admins(vec![ADMIN_1])
+        .balances(vec![(ADMIN_1, 10_000 * DOLLARS), (ALICE, 10_000 * DOLLARS), (CHARLIE, 10_000 * DOLLARS)])
+        .build()
+        .execute_with(|| {
+            let collection_id = CollectionInitializer::<MockRuntime>
+                ::new()
+                .mode(CollectionMode::NFT)
+                .init_with_sponsor(ALICE, ADMIN_1, true)
+                .expect("Collection init failed");
+
+            let token_id = create_nft(collection_id, ADMIN_1, BOB).unwrap();
+
+            let call_1 = NftCall::transfer {
+                collection_id: collection_id,
+                token_id: token_id,
+                to: CHARLIE,
+            };
+            nft_call_wrapper(call_1, BOB);
+            event_check_wrapper(ALICE);
+
+            // Same token within the timeout is not sponsored
+            let call_2 = NftCall::transfer {
+                collection_id: collection_id,
+                token_id: token_id,
+                to: BOB,
+            };
+            nft_call_wrapper(call_2, CHARLIE);
+            event_check_wrapper(CHARLIE);
+        });
+}
+
+fn sponsorship_settings(
+    budget: Option<Balance>,
+    account_quota: Option<u32>,
+    sponsored_accounts: SponsoredAccounts
+) -> SponsorshipSettings<Balance, <MockRuntime as frame_system::Config>::BlockNumber> {
+    SponsorshipSettings {
+        period: 100,
+        budget,
+        account_quota,
+        sponsored_accounts,
+    }
+}
+
+fn transfer_call(collection_id: CollectionId, token_id: TokenId) -> RefCall<MockRuntime> {
+    RefCall::transfer {
+        collection_id: collection_id,
+        token_id: token_id,
+        to: CHARLIE,
+        amount: 5,
+    }
+}
+
+#[test]
+pub fn fees_withdrawed_from_sender_if_sponsor_budget_exhausted() {
+    ExtBuilder::new()
+        .investors(vec![ALICE, BOB, CHARLIE])
+        .wl_admins(vec![ADMIN_1])
+        .balances(
+            vec![(ADMIN_1, 10_000 * DOLLARS), (ALICE, 10_000 * DOLLARS), (BOB, 10_000 * DOLLARS)]
+        )
+        .build()
+        .execute_with(|| {
+            let collection_id = CollectionInitializer::<MockRuntime>
+                ::new()
+                .init_with_sponsor(ALICE, ADMIN_1, true)
+                .expect("Collection init failed");
+
+            let token_id = TokenInitializer::new()
+                .balances(vec![(ALICE, 10), (BOB, 10)])
+                .init(collection_id, ADMIN_1)
+                .unwrap();
+
+            // Budget is enough for a single transfer only
+            let fee = TransactionPayment::compute_fee(
+                RuntimeCall::Refungible(transfer_call(collection_id, token_id)).encode().len() as u32,
+                &RuntimeCall::Refungible(transfer_call(collection_id, token_id)).get_dispatch_info(),
+                0
+            );
+            assert_ok!(Refungible::set_sponsorship_settings(
+                RuntimeOrigin::signed(ADMIN_1),
+                collection_id,
+                Some(sponsorship_settings(Some(fee * 3 / 2), None, SponsoredAccounts::All))
+            ));
+
+            call_wrapper(transfer_call(collection_id, token_id), BOB);
+            event_check_wrapper(ALICE);
+
+            System::set_block_number(6);
+            call_wrapper(transfer_call(collection_id, token_id), BOB);
+            event_check_wrapper(BOB);
+            System::assert_has_event(RuntimeEvent::Common(CommonEvent::SponsorshipBudgetExhausted(
+                collection_id,
+                BOB
+            )));
+
+            // Budget is renewed in the next period
+            System::set_block_number(102);
+            let token_id = TokenInitializer::new()
+                .balances(vec![(BOB, 10)])
+                .init(collection_id, ADMIN_1)
+                .unwrap();
+            call_wrapper(transfer_call(collection_id, token_id), BOB);
+            event_check_wrapper(ALICE);
+        });
+}
+
+#[test]
+pub fn only_allowlisted_accounts_are_sponsored_in_allowlist_mode() {
+    ExtBuilder::new()
+        .investors(vec![ALICE, BOB, CHARLIE])
+        .wl_admins(vec![ADMIN_1])
+        .balances(
+            vec![(ADMIN_1, 10_000 * DOLLARS), (ALICE, 10_000 * DOLLARS), (BOB, 10_000 * DOLLARS)]
+        )
+        .build()
+        .execute_with(|| {
+            let collection_id = CollectionInitializer::<MockRuntime>
+                ::new()
+                .init_with_sponsor(ALICE, ADMIN_1, true)
+                .expect("Collection init failed");
+
+            let token_id = TokenInitializer::new()
+                .balances(vec![(ALICE, 10), (BOB, 20)])
+                .init(collection_id, ADMIN_1)
+                .unwrap();
+
+            // Sponsor is allowed to restrict sponsored accounts as well
+            assert_ok!(Refungible::set_sponsorship_settings(
+                RuntimeOrigin::signed(ALICE),
+                collection_id,
+                Some(sponsorship_settings(None, None, SponsoredAccounts::Allowlist))
+            ));
+
+            call_wrapper(transfer_call(collection_id, token_id), BOB);
+            event_check_wrapper(BOB);
+
+            assert_ok!(Refungible::toggle_sponsor_allowlist(
+                RuntimeOrigin::signed(ALICE),
+                collection_id,
+                BOB,
+                true
+            ));
+
+            call_wrapper(transfer_call(collection_id, token_id), BOB);
+            event_check_wrapper(ALICE);
+        });
+}
+
+#[test]
+pub fn fees_withdrawed_from_sender_if_account_quota_exceeded() {
+    ExtBuilder::new()
+        .investors(vec![ALICE, BOB, CHARLIE])
+        .wl_admins(vec![ADMIN_1])
+        .balances(
+            vec![(ADMIN_1, 10_000 * DOLLARS), (ALICE, 10_000 * DOLLARS), (BOB, 10_000 * DOLLARS)]
+        )
+        .build()
+        .execute_with(|| {
+            let collection_id = CollectionInitializer::<MockRuntime>
+                ::new()
+                .init_with_sponsor(ALICE, ADMIN_1, true)
+                .expect("Collection init failed");
+
+            let first_token = TokenInitializer::new()
+                .balances(vec![(ALICE, 10), (BOB, 10)])
+                .init(collection_id, ADMIN_1)
+                .unwrap();
+            let second_token = TokenInitializer::new()
+                .balances(vec![(ALICE, 10), (BOB, 10)])
+                .init(collection_id, ADMIN_1)
+                .unwrap();
+
+            assert_ok!(Refungible::set_sponsorship_settings(
+                RuntimeOrigin::signed(ADMIN_1),
+                collection_id,
+                Some(sponsorship_settings(None, Some(1), SponsoredAccounts::WhitelistedInvestors))
+            ));
+
+            call_wrapper(transfer_call(collection_id, first_token), BOB);
+            event_check_wrapper(ALICE);
+
+            call_wrapper(transfer_call(collection_id, second_token), BOB);
+            event_check_wrapper(BOB);
+        });
+}
+
+fn native_transfer_call() -> RuntimeCall {
+    RuntimeCall::Currencies(CurrenciesCall::transfer_native_currency {
+        dest: CHARLIE.into(),
+        amount: DOLLARS,
+    })
+}
+
+#[test]
+pub fn investors_currency_transfers_are_sponsored() {
+    ExtBuilder::new()
+        .investors(vec![ALICE, BOB, CHARLIE])
+        .balances(
+            vec![(ADMIN_1, 10_000 * DOLLARS), (BOB, 10_000 * DOLLARS), (DAVE, 10_000 * DOLLARS)]
+        )
+        .build()
+        .execute_with(|| {
+            assert_ok!(Whitelist::set_investor_sponsorship(
+                RuntimeOrigin::signed(ROLES_ROOT),
+                Some(InvestorSponsorship {
+                    sponsor: ADMIN_1,
+                    period: 100,
+                    budget: None,
+                    account_quota: Some(1),
+                })
+            ));
+
+            runtime_call_wrapper(native_transfer_call(), BOB);
+            event_check_wrapper(ADMIN_1);
+
+            // Quota of the investor is used
+            runtime_call_wrapper(native_transfer_call(), BOB);
+            event_check_wrapper(BOB);
+
+            // Not an investor
+            runtime_call_wrapper(native_transfer_call(), DAVE);
+            event_check_wrapper(DAVE);
+
+            // Quota is renewed in the next period
+            System::set_block_number(102);
+            runtime_call_wrapper(native_transfer_call(), BOB);
+            event_check_wrapper(ADMIN_1);
+        });
+}
+
+#[test]
+pub fn investors_are_not_sponsored_if_sponsor_budget_exhausted() {
+    ExtBuilder::new()
+        .investors(vec![ALICE, BOB, CHARLIE])
+        .balances(
+            vec![(ADMIN_1, 10_000 * DOLLARS), (ALICE, 10_000 * DOLLARS), (BOB, 10_000 * DOLLARS)]
+        )
+        .build()
+        .execute_with(|| {
+            // Budget is enough for a single transfer only
+            let fee = TransactionPayment::compute_fee(
+                native_transfer_call().encode().len() as u32,
+                &native_transfer_call().get_dispatch_info(),
+                0
+            );
+            assert_ok!(Whitelist::set_investor_sponsorship(
+                RuntimeOrigin::signed(ROLES_ROOT),
+                Some(InvestorSponsorship {
+                    sponsor: ADMIN_1,
+                    period: 100,
+                    budget: Some(fee * 3 / 2),
+                    account_quota: None,
+                })
+            ));
+
+            runtime_call_wrapper(native_transfer_call(), BOB);
+            event_check_wrapper(ADMIN_1);
+
+            runtime_call_wrapper(native_transfer_call(), ALICE);
+            event_check_wrapper(ALICE);
+            System::assert_has_event(RuntimeEvent::Whitelist(
+                pallet_whitelist::Event::InvestorSponsorshipBudgetExhausted { investor: ALICE }
+            ));
+        });
+}
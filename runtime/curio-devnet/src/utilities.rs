@@ -11,6 +11,7 @@ use crate::{
     RuntimeOrigin,
     RuntimeBlockWeights,
     OriginCaller,
+    Signature,
     Preimage,
     Treasury,
     governance::{
@@ -35,7 +36,8 @@ use frame_system::{
 use sp_runtime::{
     Perbill,
     traits::{
-        BlakeTwo256
+        BlakeTwo256,
+        Verify
     }
 };
 use codec::{
@@ -211,8 +213,15 @@ impl module_transaction_pause::Config for Runtime {
 	type WeightInfo = ();
 }
 
+parameter_types! {
+	pub const MaxKycAttributesLength: u32 = 256;
+}
+
 impl pallet_whitelist::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RolesRoot = EnsureRoot<AccountId>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as Verify>::Signer;
+	type MaxKycAttributesLength = MaxKycAttributesLength;
 	type WeightInfo = weights::pallet_whitelist::WeightInfo<Runtime>;
 }
\ No newline at end of file
@@ -31,12 +31,19 @@ pub mod pallet_currencies;
 //pub mod pallet_democracy;
 pub mod pallet_dex;
 pub mod pallet_elections_phragmen;
+pub mod pallet_fee_currencies;
 pub mod pallet_identity;
 pub mod pallet_indices;
+pub mod pallet_liquid_staking;
 pub mod pallet_membership;
 pub mod pallet_multisig;
+pub mod pallet_nonfungible;
+pub mod pallet_offering;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
+pub mod pallet_redemption;
+pub mod pallet_holder_governance;
+pub mod pallet_rft_wrapper;
 pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_timestamp;
@@ -0,0 +1,77 @@
+
+//! Autogenerated weights for `pallet_fee_currencies`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-11-02, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `alex-ubuntu`, CPU: `12th Gen Intel(R) Core(TM) i7-12700K`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/curio-parachain-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_fee_currencies
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./runtime/curio-devnet/src/weights/pallet_fee_currencies.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_fee_currencies`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_fee_currencies::WeightInfo for WeightInfo<T> {
+	/// Storage: FeeCurrencies FeeCurrencies (r:0 w:1)
+	/// Proof: FeeCurrencies FeeCurrencies (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn set_fee_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_412 nanoseconds.
+		Weight::from_parts(9_803_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: FeeCurrencies FeeCurrencies (r:1 w:1)
+	/// Proof: FeeCurrencies FeeCurrencies (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	fn remove_fee_currency() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `2525`
+		// Minimum execution time: 12_617 nanoseconds.
+		Weight::from_parts(13_094_000, 2525)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: FeeCurrencies FeeCurrencies (r:1 w:0)
+	/// Proof: FeeCurrencies FeeCurrencies (max_values: None, max_size: Some(50), added: 2525, mode: MaxEncodedLen)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	/// Storage: Dex LiquidityPool (r:2 w:2)
+	/// Proof: Dex LiquidityPool (max_values: None, max_size: Some(89), added: 2564, mode: MaxEncodedLen)
+	/// Storage: System Account (r:2 w:2)
+	/// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `u` is `[2, 3]`.
+	fn swap_fee(u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1953 + u * (110 ±0)`
+		//  Estimated: `15419 + u * (75 ±7)`
+		// Minimum execution time: 72_048 nanoseconds.
+		Weight::from_parts(55_917_340, 15419)
+			// Standard Error: 283_114
+			.saturating_add(Weight::from_parts(9_584_102, 75).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(u.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(u.into())))
+	}
+}
@@ -0,0 +1,101 @@
+
+//! Autogenerated weights for `pallet_holder_governance`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `alex-ubuntu`, CPU: `12th Gen Intel(R) Core(TM) i7-12700K`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/curio-parachain-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_holder_governance
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./runtime/curio-devnet/src/weights/pallet_holder_governance.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_holder_governance`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_holder_governance::WeightInfo for WeightInfo<T> {
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common IsAdmin (r:1 w:0)
+	/// Storage: Refungible TotalSupply (r:1 w:0)
+	/// Storage: HolderGovernance Params (r:0 w:1)
+	fn set_governance_params() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1183`
+		//  Estimated: `10964`
+		// Minimum execution time: 21_843 nanoseconds.
+		Weight::from_parts(22_412_000, 10964)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: HolderGovernance Params (r:1 w:0)
+	/// Storage: Refungible Balance (r:101 w:0)
+	/// Storage: Refungible TokenHolders (r:1 w:0)
+	/// Storage: HolderGovernance ActiveProposals (r:1 w:1)
+	/// Storage: HolderGovernance NextProposalId (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: Refungible TotalSupply (r:1 w:0)
+	/// Storage: HolderGovernance Snapshots (r:0 w:100)
+	/// Storage: HolderGovernance Proposals (r:0 w:1)
+	/// The range of component `h` is `[1, 100]`.
+	fn propose(h: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1412 + h * (120 ±0)`
+		//  Estimated: `17680 + h * (2595 ±0)`
+		// Minimum execution time: 46_218 nanoseconds.
+		Weight::from_parts(41_052_387, 17680)
+			// Standard Error: 4_402
+			.saturating_add(Weight::from_parts(5_694_118, 0).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(h.into())))
+			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(h.into())))
+			.saturating_add(Weight::from_parts(0, 2595).saturating_mul(h.into()))
+	}
+	/// Storage: HolderGovernance Proposals (r:1 w:1)
+	/// Storage: HolderGovernance Snapshots (r:1 w:0)
+	/// Storage: HolderGovernance Votes (r:1 w:1)
+	fn vote() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `8469`
+		// Minimum execution time: 23_960 nanoseconds.
+		Weight::from_parts(24_587_000, 8469)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: HolderGovernance Proposals (r:1 w:1)
+	/// Storage: HolderGovernance ActiveProposals (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: HolderGovernance Snapshots (r:0 w:100)
+	/// Storage: HolderGovernance Votes (r:0 w:100)
+	/// The range of component `h` is `[1, 100]`.
+	fn close(h: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `623 + h * (96 ±0)`
+		//  Estimated: `8138`
+		// Minimum execution time: 38_517 nanoseconds.
+		Weight::from_parts(36_284_910, 8138)
+			// Standard Error: 2_131
+			.saturating_add(Weight::from_parts(1_851_064, 0).saturating_mul(h.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(h.into())))
+	}
+}
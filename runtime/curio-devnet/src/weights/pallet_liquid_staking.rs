@@ -0,0 +1,182 @@
+
+//! Autogenerated weights for `pallet_liquid_staking`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `alex-ubuntu`, CPU: `12th Gen Intel(R) Core(TM) i7-12700K`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/curio-parachain-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_liquid_staking
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./runtime/curio-devnet/src/weights/pallet_liquid_staking.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_liquid_staking`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_liquid_staking::WeightInfo for WeightInfo<T> {
+	/// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	/// Storage: LiquidStaking Collators (r:1 w:1)
+	fn add_collator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `568`
+		//  Estimated: `5120`
+		// Minimum execution time: 16_587 nanoseconds.
+		Weight::from_parts(17_100_000, 5120)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: LiquidStaking Collators (r:1 w:1)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Storage: ParachainStaking Rewards (r:1 w:1)
+	/// Storage: ParachainStaking AutoCompound (r:0 w:1)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	fn remove_collator() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3156`
+		//  Estimated: `28412`
+		// Minimum execution time: 59_946 nanoseconds.
+		Weight::from_parts(61_800_000, 28412)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: LiquidStaking Collators (r:1 w:0)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: LiquidStaking TotalUnbonding (r:1 w:0)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Storage: ParachainStaking Rewards (r:1 w:1)
+	/// Storage: ParachainStaking BlocksRewarded (r:0 w:1)
+	/// Storage: ParachainStaking PendingDelegations (r:1 w:0)
+	/// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	/// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:0)
+	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// Storage: Balances TotalIssuance (r:1 w:0)
+	fn deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4621`
+		//  Estimated: `41590`
+		// Minimum execution time: 101_171 nanoseconds.
+		Weight::from_parts(104_300_000, 41590)
+			.saturating_add(T::DbWeight::get().reads(22))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Storage: System Account (r:1 w:0)
+	/// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	/// Storage: LiquidStaking CurrentBatch (r:1 w:0)
+	/// Storage: LiquidStaking Redemptions (r:1 w:1)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	/// Storage: ParachainStaking Rewards (r:1 w:0)
+	/// Storage: ParachainStaking PendingDelegations (r:1 w:0)
+	/// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	/// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:0)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
+	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// Storage: Balances TotalIssuance (r:1 w:0)
+	fn redeem() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1362`
+		//  Estimated: `12260`
+		// Minimum execution time: 35_114 nanoseconds.
+		Weight::from_parts(36_200_000, 12260)
+			.saturating_add(T::DbWeight::get().reads(15))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: LiquidStaking Redemptions (r:1 w:1)
+	/// Storage: LiquidStaking BatchUnlocks (r:1 w:0)
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Storage: System Account (r:2 w:2)
+	/// Storage: LiquidStaking TotalUnbonding (r:1 w:1)
+	fn withdraw_redeemed() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1871`
+		//  Estimated: `16840`
+		// Minimum execution time: 48_209 nanoseconds.
+		Weight::from_parts(49_700_000, 16840)
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	/// Storage: ParachainStaking BlocksRewarded (r:1 w:1)
+	/// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// Storage: ParachainStaking Rewards (r:1 w:1)
+	/// Storage: ParachainStaking RewardHistory (r:1 w:1)
+	/// Storage: System Account (r:1 w:1)
+	/// Storage: ParachainStaking AutoCompound (r:1 w:0)
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Storage: LiquidStaking Collators (r:1 w:0)
+	/// Storage: LiquidStaking TotalUnbonding (r:1 w:0)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	/// Storage: LiquidStaking LastCompoundedRound (r:1 w:1)
+	fn on_initialize_compound() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `5858`
+		//  Estimated: `52730`
+		// Minimum execution time: 133_763 nanoseconds.
+		Weight::from_parts(137_900_000, 52730)
+			.saturating_add(T::DbWeight::get().reads(20))
+			.saturating_add(T::DbWeight::get().writes(14))
+	}
+	/// Storage: LiquidStaking PendingUnbond (r:1 w:1)
+	/// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	/// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	/// Storage: ParachainStaking Round (r:1 w:0)
+	/// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	/// Storage: ParachainStaking Unstaking (r:1 w:1)
+	/// Storage: ParachainStaking Rewards (r:1 w:1)
+	/// Storage: Balances Locks (r:1 w:1)
+	/// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	/// Storage: LiquidStaking CurrentBatch (r:1 w:1)
+	/// Storage: LiquidStaking BatchUnlocks (r:0 w:1)
+	fn on_initialize_process_batch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3357`
+		//  Estimated: `30215`
+		// Minimum execution time: 66_348 nanoseconds.
+		Weight::from_parts(68_400_000, 30215)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+}
\ No newline at end of file
@@ -0,0 +1,309 @@
+
+//! Autogenerated weights for `pallet_nonfungible`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `alex-ubuntu`, CPU: `12th Gen Intel(R) Core(TM) i7-12700K`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/curio-parachain-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_nonfungible
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./runtime/curio-devnet/src/weights/pallet_nonfungible.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_nonfungible`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_nonfungible::WeightInfo for WeightInfo<T> {
+	/// Storage: Whitelist Admins (r:1 w:0)
+	/// Storage: Common CreatedCollectionCount (r:1 w:1)
+	/// Storage: Common DestroyedCollectionCount (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:0 w:1)
+	/// Storage: Common CollectionProperties (r:0 w:1)
+	/// Storage: Common CollectionById (r:0 w:1)
+	/// The range of component `i` is `[1, 64]`.
+	/// The range of component `j` is `[1, 64]`.
+	fn init_collection(i: u32, j: u32, ) -> Weight {
+		Weight::from_parts(62_000_000, 0)
+			.saturating_add(Weight::from_parts(520_000, 0).saturating_mul(i.into()))
+			.saturating_add(Weight::from_parts(820_000, 0).saturating_mul(j.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Common CollectionById (r:1 w:1)
+	/// Storage: Nonfungible TokenData (r:1 w:0)
+	/// Storage: Common DestroyedCollectionCount (r:1 w:1)
+	/// Storage: Common AdminAmount (r:0 w:1)
+	/// Storage: Common CollectionProperties (r:0 w:1)
+	/// Storage: Nonfungible TokensMinted (r:0 w:1)
+	/// Storage: Nonfungible TokensBurnt (r:0 w:1)
+	fn destroy_collection() -> Weight {
+		Weight::from_parts(83_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Common CollectionProperties (r:1 w:1)
+	fn set_collection_property() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Common CollectionProperties (r:1 w:1)
+	fn delete_collection_property() -> Weight {
+		Weight::from_parts(58_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Common CollectionProperties (r:1 w:1)
+	/// The range of component `k` is `[1, 64]`.
+	fn set_collection_properties(k: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Common CollectionProperties (r:1 w:1)
+	/// The range of component `k` is `[1, 64]`.
+	fn delete_collection_properties(k: u32, ) -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(k.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:1)
+	fn set_property_permission() -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	/// Storage: Whitelist Investors (r:1 w:0)
+	/// Storage: Nonfungible TokensMinted (r:1 w:1)
+	/// Storage: Nonfungible AccountBalance (r:1 w:1)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Nonfungible TokenProperties (r:1 w:1)
+	/// Storage: Nonfungible TokenData (r:1 w:1)
+	/// Storage: Nonfungible Owned (r:0 w:1)
+	/// The range of component `p` is `[1, 64]`.
+	fn create_item(p: u32, ) -> Weight {
+		Weight::from_parts(90_000_000, 0)
+			.saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	/// Storage: Whitelist Investors (r:1 w:0)
+	/// Storage: Nonfungible TokensMinted (r:1 w:1)
+	/// Storage: Nonfungible AccountBalance (r:1 w:1)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Nonfungible TokenProperties (r:1 w:1)
+	/// Storage: Nonfungible TokenData (r:1 w:1)
+	/// Storage: Nonfungible Owned (r:0 w:1)
+	fn create_max_item() -> Weight {
+		Weight::from_parts(1_690_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Nonfungible TokenData (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Nonfungible TokenProperties (r:1 w:1)
+	fn set_token_property() -> Weight {
+		Weight::from_parts(75_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Nonfungible TokenData (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Nonfungible TokenProperties (r:1 w:1)
+	/// The range of component `i` is `[1, 64]`.
+	fn set_token_properties(i: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(Weight::from_parts(25_000_000, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Nonfungible TokenData (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Nonfungible TokenProperties (r:1 w:1)
+	fn delete_token_property() -> Weight {
+		Weight::from_parts(72_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Nonfungible TokenData (r:1 w:0)
+	/// Storage: Common CollectionPropertyPermissions (r:1 w:0)
+	/// Storage: Nonfungible TokenProperties (r:1 w:1)
+	/// The range of component `i` is `[1, 64]`.
+	fn delete_token_properties(i: u32, ) -> Weight {
+		Weight::from_parts(55_000_000, 0)
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist KeysOfInvestors (r:2 w:0)
+	/// Storage: Whitelist Investors (r:2 w:0)
+	/// Storage: Nonfungible TokenData (r:1 w:1)
+	/// Storage: Nonfungible AccountBalance (r:2 w:2)
+	/// Storage: Nonfungible Allowance (r:0 w:1)
+	/// Storage: Nonfungible Owned (r:0 w:2)
+	fn transfer() -> Weight {
+		Weight::from_parts(80_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist KeysOfInvestors (r:2 w:0)
+	/// Storage: Whitelist Investors (r:2 w:0)
+	/// Storage: Nonfungible TokenData (r:1 w:0)
+	/// Storage: Nonfungible Allowance (r:0 w:1)
+	fn approve() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist KeysOfInvestors (r:4 w:0)
+	/// Storage: Whitelist Investors (r:4 w:0)
+	/// Storage: Nonfungible Allowance (r:1 w:1)
+	/// Storage: Nonfungible TokenData (r:1 w:1)
+	/// Storage: Nonfungible AccountBalance (r:2 w:2)
+	/// Storage: Nonfungible Owned (r:0 w:2)
+	fn transfer_from() -> Weight {
+		Weight::from_parts(100_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Nonfungible TokenData (r:1 w:1)
+	/// Storage: Nonfungible TokensBurnt (r:1 w:1)
+	/// Storage: Nonfungible AccountBalance (r:1 w:1)
+	/// Storage: Nonfungible Owned (r:0 w:1)
+	/// Storage: Nonfungible TokenProperties (r:0 w:1)
+	/// Storage: Nonfungible Allowance (r:0 w:1)
+	/// Storage: Nonfungible NftTransferBasket (r:0 w:1)
+	/// Storage: Nonfungible NftApproveBasket (r:0 w:1)
+	fn burn() -> Weight {
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist KeysOfInvestors (r:2 w:0)
+	/// Storage: Whitelist Investors (r:2 w:0)
+	/// Storage: Nonfungible Allowance (r:1 w:1)
+	/// Storage: Nonfungible TokenData (r:1 w:1)
+	/// Storage: Nonfungible TokensBurnt (r:1 w:1)
+	/// Storage: Nonfungible AccountBalance (r:1 w:1)
+	/// Storage: Nonfungible Owned (r:0 w:1)
+	/// Storage: Nonfungible TokenProperties (r:0 w:1)
+	/// Storage: Nonfungible NftTransferBasket (r:0 w:1)
+	/// Storage: Nonfungible NftApproveBasket (r:0 w:1)
+	fn burn_from() -> Weight {
+		Weight::from_parts(90_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common IsAdmin (r:1 w:1)
+	/// Storage: Common AdminAmount (r:1 w:1)
+	fn toggle_admin() -> Weight {
+		Weight::from_parts(50_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Common CollectionById (r:1 w:1)
+	fn set_sponsor() -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:1)
+	fn confirm_sponsorship() -> Weight {
+		Weight::from_parts(38_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:1)
+	fn remove_sponsor() -> Weight {
+		Weight::from_parts(38_000_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist Admins (r:1 w:0)
+	/// Storage: Common PendingOwner (r:0 w:1)
+	fn propose_collection_owner() -> Weight {
+		// Minimum execution time: 21_322 nanoseconds.
+		Weight::from_parts(22_108_000, 3260)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common PendingOwner (r:1 w:1)
+	fn cancel_collection_ownership_transfer() -> Weight {
+		// Minimum execution time: 19_870 nanoseconds.
+		Weight::from_parts(20_573_000, 3260)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common PendingOwner (r:1 w:1)
+	/// Storage: Common CollectionById (r:1 w:1)
+	/// Storage: Whitelist Admins (r:1 w:0)
+	/// Storage: Common IsAdmin (r:1 w:1)
+	/// Storage: Common AdminAmount (r:1 w:1)
+	fn accept_collection_ownership() -> Weight {
+		// Minimum execution time: 31_504 nanoseconds.
+		Weight::from_parts(32_690_000, 8724)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common CollectionSponsorshipSettings (r:0 w:1)
+	/// Storage: Common SponsorBudgetUsage (r:0 w:1)
+	fn set_sponsorship_settings() -> Weight {
+		// Minimum execution time: 19_412 nanoseconds.
+		Weight::from_parts(20_118_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common SponsorAllowlist (r:0 w:1)
+	fn toggle_sponsor_allowlist() -> Weight {
+		// Minimum execution time: 17_236 nanoseconds.
+		Weight::from_parts(17_904_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -0,0 +1,105 @@
+
+//! Autogenerated weights for `pallet_offering`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `alex-ubuntu`, CPU: `12th Gen Intel(R) Core(TM) i7-12700K`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/curio-parachain-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_offering
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./runtime/curio-devnet/src/weights/pallet_offering.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_offering`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_offering::WeightInfo for WeightInfo<T> {
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common IsAdmin (r:1 w:0)
+	/// Storage: Offering NextOfferingId (r:1 w:1)
+	/// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	/// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	/// Storage: Refungible Balance (r:2 w:2)
+	/// Storage: Refungible TokenHolders (r:1 w:1)
+	/// Storage: Refungible AccountBalance (r:1 w:1)
+	/// Storage: Refungible Owned (r:0 w:1)
+	/// Storage: Offering Offerings (r:0 w:1)
+	fn create_offering() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1854`
+		//  Estimated: `19876`
+		// Minimum execution time: 41_203 nanoseconds.
+		Weight::from_parts(42_116_000, 19876)
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(7))
+	}
+	/// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	/// Storage: Whitelist Investors (r:1 w:0)
+	/// Storage: Offering Offerings (r:1 w:1)
+	/// Storage: Offering Subscriptions (r:1 w:1)
+	/// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	/// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	fn subscribe() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2311`
+		//  Estimated: `21540`
+		// Minimum execution time: 44_870 nanoseconds.
+		Weight::from_parts(45_902_000, 21540)
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Offering Offerings (r:1 w:1)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	/// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	/// Storage: Refungible Balance (r:2 w:2)
+	/// Storage: Refungible TokenHolders (r:1 w:1)
+	/// Storage: Refungible AccountBalance (r:2 w:2)
+	/// Storage: Refungible Owned (r:0 w:2)
+	fn settle() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2740`
+		//  Estimated: `27963`
+		// Minimum execution time: 56_318 nanoseconds.
+		Weight::from_parts(57_490_000, 27963)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	/// Storage: Offering Offerings (r:1 w:0)
+	/// Storage: Offering Subscriptions (r:1 w:1)
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	/// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	/// Storage: Refungible Balance (r:2 w:2)
+	/// Storage: Refungible TokenHolders (r:1 w:1)
+	/// Storage: Refungible AccountBalance (r:2 w:2)
+	/// Storage: Refungible Owned (r:0 w:2)
+	fn claim() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2598`
+		//  Estimated: `25117`
+		// Minimum execution time: 46_052 nanoseconds.
+		Weight::from_parts(47_334_000, 25117)
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+}
@@ -0,0 +1,94 @@
+
+//! Autogenerated weights for `pallet_redemption`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `alex-ubuntu`, CPU: `12th Gen Intel(R) Core(TM) i7-12700K`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/curio-parachain-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_redemption
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./runtime/curio-devnet/src/weights/pallet_redemption.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_redemption`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_redemption::WeightInfo for WeightInfo<T> {
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common IsAdmin (r:1 w:0)
+	/// Storage: Redemption Redemptions (r:1 w:1)
+	/// Storage: Refungible TotalSupply (r:1 w:0)
+	/// Storage: Tokens Accounts (r:2 w:2)
+	fn start_redemption() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1702`
+		//  Estimated: `17214`
+		// Minimum execution time: 36_517 nanoseconds.
+		Weight::from_parts(37_264_000, 17214)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: Redemption Redemptions (r:1 w:1)
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Refungible Balance (r:1 w:1)
+	/// Storage: Refungible TotalSupply (r:2 w:1)
+	/// Storage: Refungible AccountBalance (r:1 w:1)
+	/// Storage: Refungible TokenHolders (r:1 w:1)
+	/// Storage: Refungible TokensBurnt (r:1 w:1)
+	/// Storage: Refungible Owned (r:0 w:1)
+	/// Storage: Refungible TokenProperties (r:0 w:1)
+	/// Storage: Refungible TokenHoldingLimits (r:0 w:1)
+	/// Storage: Tokens Accounts (r:3 w:3)
+	fn claim_redemption() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2417`
+		//  Estimated: `31806`
+		// Minimum execution time: 61_940 nanoseconds.
+		Weight::from_parts(63_115_000, 31806)
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	/// Storage: Redemption Redemptions (r:1 w:1)
+	/// Storage: Refungible Balance (r:101 w:100)
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Refungible TotalSupply (r:101 w:1)
+	/// Storage: Refungible AccountBalance (r:100 w:100)
+	/// Storage: Refungible TokenHolders (r:1 w:1)
+	/// Storage: Refungible TokensBurnt (r:1 w:1)
+	/// Storage: Refungible Owned (r:0 w:100)
+	/// Storage: Refungible TokenProperties (r:0 w:1)
+	/// Storage: Refungible TokenHoldingLimits (r:0 w:1)
+	/// Storage: Tokens Accounts (r:101 w:101)
+	/// The range of component `i` is `[1, 100]`.
+	fn redeem_batch(i: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1991 + i * (236 ±0)`
+		//  Estimated: `14372 + i * (7695 ±0)`
+		// Minimum execution time: 63_028 nanoseconds.
+		Weight::from_parts(31_482_660, 14372)
+			// Standard Error: 11_904
+			.saturating_add(Weight::from_parts(30_973_018, 0).saturating_mul(i.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(i.into())))
+			.saturating_add(Weight::from_parts(0, 7695).saturating_mul(i.into()))
+	}
+}
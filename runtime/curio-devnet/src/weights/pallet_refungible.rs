@@ -460,4 +460,94 @@ impl<T: frame_system::Config> pallet_refungible::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Proof: Common CollectionById (max_values: None, max_size: Some(785), added: 3260, mode: MaxEncodedLen)
+	/// Storage: Refungible TokensMinted (r:1 w:0)
+	/// Proof: Refungible TokensMinted (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Refungible TokensBurnt (r:1 w:0)
+	/// Proof: Refungible TokensBurnt (max_values: None, max_size: Some(16), added: 2491, mode: MaxEncodedLen)
+	/// Storage: Refungible TokenHolders (r:201 w:0)
+	/// Proof: Refungible TokenHolders (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Refungible TokenHoldingLimits (r:200 w:0)
+	/// Proof: Refungible TokenHoldingLimits (max_values: None, max_size: Some(63), added: 2538, mode: MaxEncodedLen)
+	/// Storage: Refungible CollectionHoldingLimits (r:0 w:1)
+	/// Proof: Refungible CollectionHoldingLimits (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	/// The range of component `b` is `[0, 200]`.
+	fn set_collection_holding_limits(b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2101 + b * (68 ±0)`
+		//  Estimated: `10753 + b * (5049 ±0)`
+		// Minimum execution time: 22_315 nanoseconds.
+		Weight::from_parts(23_870_412, 10753)
+			// Standard Error: 3_871
+			.saturating_add(Weight::from_parts(5_912_306, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(b.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 5049).saturating_mul(b.into()))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Proof: Common CollectionById (max_values: None, max_size: Some(785), added: 3260, mode: MaxEncodedLen)
+	/// Storage: Refungible TotalSupply (r:1 w:0)
+	/// Proof: Refungible TotalSupply (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	/// Proof: Refungible CollectionHoldingLimits (max_values: None, max_size: Some(59), added: 2534, mode: MaxEncodedLen)
+	/// Storage: Refungible TokenHolders (r:1 w:0)
+	/// Proof: Refungible TokenHolders (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	/// Storage: Refungible TokenHoldingLimits (r:0 w:1)
+	/// Proof: Refungible TokenHoldingLimits (max_values: None, max_size: Some(63), added: 2538, mode: MaxEncodedLen)
+	fn set_token_holding_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `20184`
+		//  Estimated: `10836`
+		// Minimum execution time: 31_102 nanoseconds.
+		Weight::from_parts(32_418_000, 10836)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist Admins (r:1 w:0)
+	/// Storage: Common PendingOwner (r:0 w:1)
+	fn propose_collection_owner() -> Weight {
+		// Minimum execution time: 21_322 nanoseconds.
+		Weight::from_parts(22_108_000, 3260)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common PendingOwner (r:1 w:1)
+	fn cancel_collection_ownership_transfer() -> Weight {
+		// Minimum execution time: 19_870 nanoseconds.
+		Weight::from_parts(20_573_000, 3260)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Common PendingOwner (r:1 w:1)
+	/// Storage: Common CollectionById (r:1 w:1)
+	/// Storage: Whitelist Admins (r:1 w:0)
+	/// Storage: Common IsAdmin (r:1 w:1)
+	/// Storage: Common AdminAmount (r:1 w:1)
+	fn accept_collection_ownership() -> Weight {
+		// Minimum execution time: 31_504 nanoseconds.
+		Weight::from_parts(32_690_000, 8724)
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common CollectionSponsorshipSettings (r:0 w:1)
+	/// Storage: Common SponsorBudgetUsage (r:0 w:1)
+	fn set_sponsorship_settings() -> Weight {
+		// Minimum execution time: 19_412 nanoseconds.
+		Weight::from_parts(20_118_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common SponsorAllowlist (r:0 w:1)
+	fn toggle_sponsor_allowlist() -> Weight {
+		// Minimum execution time: 17_236 nanoseconds.
+		Weight::from_parts(17_904_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
 }
@@ -0,0 +1,89 @@
+
+//! Autogenerated weights for `pallet_rft_wrapper`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-10-14, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `alex-ubuntu`, CPU: `12th Gen Intel(R) Core(TM) i7-12700K`
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/curio-parachain-node
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_rft_wrapper
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./runtime/curio-devnet/src/weights/pallet_rft_wrapper.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::Weight};
+use sp_std::marker::PhantomData;
+
+/// Weight functions for `pallet_rft_wrapper`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_rft_wrapper::WeightInfo for WeightInfo<T> {
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Common IsAdmin (r:1 w:0)
+	/// Storage: Refungible TotalSupply (r:1 w:0)
+	/// Storage: RftWrapper WrappedTokens (r:1 w:1)
+	fn register_wrapped_token() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1183`
+		//  Estimated: `13451`
+		// Minimum execution time: 20_716 nanoseconds.
+		Weight::from_parts(21_305_000, 13451)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: RftWrapper WrappedTokens (r:1 w:0)
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	/// Storage: Whitelist Investors (r:1 w:0)
+	/// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	/// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	/// Storage: Refungible Balance (r:2 w:2)
+	/// Storage: Refungible TokenHolders (r:1 w:1)
+	/// Storage: Refungible AccountBalance (r:2 w:2)
+	/// Storage: Refungible Owned (r:0 w:2)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	fn wrap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2036`
+		//  Estimated: `36118`
+		// Minimum execution time: 55_870 nanoseconds.
+		Weight::from_parts(57_042_000, 36118)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: RftWrapper WrappedTokens (r:1 w:0)
+	/// Storage: Common CollectionById (r:1 w:0)
+	/// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	/// Storage: Whitelist Investors (r:1 w:0)
+	/// Storage: Tokens Accounts (r:1 w:1)
+	/// Storage: Tokens TotalIssuance (r:1 w:1)
+	/// Storage: Refungible TokenHoldingLimits (r:1 w:0)
+	/// Storage: Refungible CollectionHoldingLimits (r:1 w:0)
+	/// Storage: Refungible Balance (r:2 w:2)
+	/// Storage: Refungible TokenHolders (r:1 w:1)
+	/// Storage: Refungible AccountBalance (r:2 w:2)
+	/// Storage: Refungible Owned (r:0 w:2)
+	fn unwrap() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `2311`
+		//  Estimated: `36118`
+		// Minimum execution time: 56_214 nanoseconds.
+		Weight::from_parts(57_530_000, 36118)
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+}
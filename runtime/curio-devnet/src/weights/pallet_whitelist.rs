@@ -85,6 +85,8 @@ impl<T: frame_system::Config> pallet_whitelist::WeightInfo for WeightInfo<T> {
 	/// Proof: Whitelist KeysOfInvestors (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
 	/// Storage: Whitelist Investors (r:100 w:100)
 	/// Proof: Whitelist Investors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Whitelist RemovedInvestorKeys (r:0 w:100)
+	/// Proof: Whitelist RemovedInvestorKeys (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
 	/// The range of component `i` is `[1, 100]`.
 	fn add_investors(i: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -96,7 +98,7 @@ impl<T: frame_system::Config> pallet_whitelist::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(7_837_570, 5111).saturating_mul(i.into()))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(i.into())))
-			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(i.into())))
+			.saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(i.into())))
 	}
 	/// Storage: Whitelist Admins (r:1 w:0)
 	/// Proof: Whitelist Admins (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
@@ -141,4 +143,103 @@ impl<T: frame_system::Config> pallet_whitelist::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(2))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: Whitelist InvestorSponsorshipSettings (r:0 w:1)
+	/// Proof: Whitelist InvestorSponsorshipSettings (max_values: Some(1), max_size: Some(73), added: 568, mode: MaxEncodedLen)
+	/// Storage: Whitelist SponsorBudgetUsage (r:0 w:1)
+	/// Proof: Whitelist SponsorBudgetUsage (max_values: Some(1), max_size: Some(20), added: 515, mode: MaxEncodedLen)
+	fn set_investor_sponsorship() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_874 nanoseconds.
+		Weight::from_parts(10_312_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: Whitelist Admins (r:1 w:0)
+	/// Proof: Whitelist Admins (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Whitelist KeysOfInvestors (r:1 w:1)
+	/// Proof: Whitelist KeysOfInvestors (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Whitelist Investors (r:1 w:1)
+	/// Proof: Whitelist Investors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Whitelist InvestorExpiry (r:0 w:1)
+	/// Proof: Whitelist InvestorExpiry (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Whitelist InvestorVerifications (r:0 w:1)
+	/// Proof: Whitelist InvestorVerifications (max_values: None, max_size: Some(357), added: 2832, mode: MaxEncodedLen)
+	/// Storage: Whitelist SponsoredInvestorUsage (r:0 w:1)
+	/// Proof: Whitelist SponsoredInvestorUsage (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	/// Storage: Whitelist RemovedInvestorKeys (r:0 w:1)
+	/// Proof: Whitelist RemovedInvestorKeys (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn remove_investor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1378`
+		//  Estimated: `7635`
+		// Minimum execution time: 26_347 nanoseconds.
+		Weight::from_parts(28_112_000, 7635)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: Whitelist Admins (r:1 w:0)
+	/// Proof: Whitelist Admins (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Whitelist KeysOfInvestors (r:1 w:0)
+	/// Proof: Whitelist KeysOfInvestors (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Whitelist Investors (r:1 w:0)
+	/// Proof: Whitelist Investors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Whitelist InvestorExpiry (r:0 w:1)
+	/// Proof: Whitelist InvestorExpiry (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	fn renew_investor_verification() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1378`
+		//  Estimated: `7635`
+		// Minimum execution time: 21_904 nanoseconds.
+		Weight::from_parts(22_671_000, 7635)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Whitelist KycProviders (r:1 w:1)
+	/// Proof: Whitelist KycProviders (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn add_kyc_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `2524`
+		// Minimum execution time: 13_518 nanoseconds.
+		Weight::from_parts(14_020_000, 2524)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Whitelist KycProviders (r:1 w:1)
+	/// Proof: Whitelist KycProviders (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	fn remove_kyc_provider() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `2524`
+		// Minimum execution time: 14_206 nanoseconds.
+		Weight::from_parts(14_873_000, 2524)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: Whitelist KycProviders (r:1 w:0)
+	/// Proof: Whitelist KycProviders (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Whitelist KeysOfInvestors (r:1 w:1)
+	/// Proof: Whitelist KeysOfInvestors (max_values: None, max_size: Some(80), added: 2555, mode: MaxEncodedLen)
+	/// Storage: Whitelist Investors (r:1 w:1)
+	/// Proof: Whitelist Investors (max_values: None, max_size: Some(81), added: 2556, mode: MaxEncodedLen)
+	/// Storage: Whitelist InvestorExpiry (r:0 w:1)
+	/// Proof: Whitelist InvestorExpiry (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: System BlockHash (r:1 w:0)
+	/// Proof: System BlockHash (max_values: None, max_size: Some(44), added: 2519, mode: MaxEncodedLen)
+	/// Storage: Whitelist AttestationNonces (r:1 w:1)
+	/// Proof: Whitelist AttestationNonces (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	/// Storage: Whitelist RemovedInvestorKeys (r:1 w:0)
+	/// Proof: Whitelist RemovedInvestorKeys (max_values: None, max_size: Some(49), added: 2524, mode: MaxEncodedLen)
+	/// Storage: Whitelist InvestorVerifications (r:0 w:1)
+	/// Proof: Whitelist InvestorVerifications (max_values: None, max_size: Some(357), added: 2832, mode: MaxEncodedLen)
+	fn register_investor() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `114`
+		//  Estimated: `15205`
+		// Minimum execution time: 66_381 nanoseconds.
+		Weight::from_parts(68_240_000, 15205)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
 }
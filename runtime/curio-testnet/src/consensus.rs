@@ -1,4 +1,5 @@
 use crate::*;
+use parachain_staking::PenaltyDestination;
 
 parameter_types! {
     pub const MaxAuthorities: u32 = 100_000;
@@ -35,24 +36,41 @@ parameter_types! {
     pub const DefaultBlocksPerRound: BlockNumber = 2 * HOURS;
 
     pub const StakeDuration: BlockNumber = 7 * DAYS;
+    pub const MaxStakeDuration: BlockNumber = 28 * DAYS;
     pub const ExitQueueDelay: u32 = 2;
+    pub const MaxExitQueueDelay: u32 = 84;
+    pub const CommissionChangeDelay: u32 = 12;
+    pub const SlashDeferDuration: u32 = 12;
+    pub const MaxRewardHistory: u32 = 84;
 
     pub const MinCollators: u32 = 2;
     pub const MinRequiredCollators: u32 = 2;
     #[derive(Debug, Eq, PartialEq)]
     pub const MaxCollatorCandidates: u32 = 150;
 
-    pub const MaxDelegationsPerRound: u32 = 1;
+    pub const DelegationWarmUp: u32 = 2;
+    pub const MinDelegationDuration: u32 = 84;
+    pub const EarlyExitPenalty: Perbill = Perbill::from_percent(1);
+    pub const EarlyExitPenaltyDestination: PenaltyDestination = PenaltyDestination::Delegators;
     #[derive(Debug, Eq, PartialEq)]
     pub const MaxDelegatorsPerCollator: u32 = 70;
+    #[derive(Debug, Eq, PartialEq)]
+    pub const MaxCollatorsPerDelegator: u32 = 5;
 
     pub const MinCollatorStake: u128 = 10_000 * DOLLARS;
     pub const MinDelegatorStk: u128 = 100 * DOLLARS;
+    pub const MinStakeGracePeriod: u32 = 84;
 
     pub const MaxUnstakeRequests: u32 = 10;
 
     pub const NetworkRewardStart: BlockNumber = 0;
     pub const NetworkRewardRate: Perquintill = Perquintill::from_percent(10);
+    pub const MaxNetworkRewardRate: Perquintill = Perquintill::from_percent(20);
+
+    #[derive(Debug, Eq, PartialEq)]
+    pub const MaxMetadataLength: u32 = 128;
+    pub const MetadataDepositBase: Balance = deposit(1, 0);
+    pub const MetadataDepositPerByte: Balance = deposit(0, 1);
 
 }
 
@@ -64,19 +82,38 @@ impl parachain_staking::Config for Runtime {
     type MinBlocksPerRound = MinBlocksPerRound;
     type DefaultBlocksPerRound = DefaultBlocksPerRound;
     type StakeDuration = StakeDuration;
+    type MaxStakeDuration = MaxStakeDuration;
     type ExitQueueDelay = ExitQueueDelay;
+    type MaxExitQueueDelay = MaxExitQueueDelay;
+    type CommissionChangeDelay = CommissionChangeDelay;
     type MinCollators = MinCollators;
     type MinRequiredCollators = MinRequiredCollators;
-    type MaxDelegationsPerRound = MaxDelegationsPerRound;
+    type DelegationWarmUp = DelegationWarmUp;
+    type MinDelegationDuration = MinDelegationDuration;
+    type EarlyExitPenalty = EarlyExitPenalty;
+    type EarlyExitPenaltyDestination = EarlyExitPenaltyDestination;
+    type EarlyExitPenaltyBeneficiary = Treasury;
     type MaxDelegatorsPerCollator = MaxDelegatorsPerCollator;
+    type MaxCollatorsPerDelegator = MaxCollatorsPerDelegator;
     type MinCollatorStake = MinCollatorStake;
     type MinCollatorCandidateStake = MinCollatorStake;
     type MaxTopCandidates = MaxCollatorCandidates;
     type MinDelegatorStake = MinDelegatorStk;
+    type MinStakeGracePeriod = MinStakeGracePeriod;
     type MaxUnstakeRequests = MaxUnstakeRequests;
     type NetworkRewardRate = NetworkRewardRate;
+    type MaxNetworkRewardRate = MaxNetworkRewardRate;
     type NetworkRewardStart = NetworkRewardStart;
     type NetworkRewardBeneficiary = Treasury;
+    type RewardSource = parachain_staking::InflationaryRewards;
+    type SlashOrigin = EnsureRootOrHalfCouncil;
+    type ParametersOrigin = EnsureRootOrHalfCouncil;
+    type SlashDeferDuration = SlashDeferDuration;
+    type Slash = Treasury;
+    type MaxRewardHistory = MaxRewardHistory;
+    type MaxMetadataLength = MaxMetadataLength;
+    type MetadataDepositBase = MetadataDepositBase;
+    type MetadataDepositPerByte = MetadataDepositPerByte;
     type WeightInfo = weights::parachain_staking::WeightInfo<Runtime>;
 
     const BLOCKS_PER_YEAR: Self::BlockNumber = BLOCKS_PER_YEAR;
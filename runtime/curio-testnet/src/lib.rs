@@ -155,6 +155,15 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+    parachain_staking::migration::AddCollatorCommission<Runtime>,
+    parachain_staking::migration::MultipleDelegations<Runtime>,
+    parachain_staking::migration::MigrateStakingParams<Runtime>,
+    parachain_staking::migration::MigrateDelegationWarmUp<Runtime>,
+    parachain_staking::migration::MigrateOutstandingRewards<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
     Runtime,
@@ -162,6 +171,7 @@ pub type Executive = frame_executive::Executive<
     frame_system::ChainContext<Runtime>,
     Runtime,
     AllPalletsWithSystem,
+    Migrations,
 >;
 
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
@@ -186,10 +196,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
     spec_name: create_runtime_str!("curio-testnet"),
     impl_name: create_runtime_str!("curio-testnet"),
     authoring_version: 1,
-    spec_version: 13,
+    spec_version: 14,
     impl_version: 0,
     apis: RUNTIME_API_VERSIONS,
-    transaction_version: 1,
+    transaction_version: 2,
     state_version: 1,
 };
 
@@ -610,6 +620,26 @@ impl_runtime_apis! {
         fn get_sorted_proposed_candidates(balance: Balance) -> Vec<AccountId> {
 			ParachainStaking::get_sorted_proposed_candidates(balance)
 		}
+
+        fn get_collator_commission(collator: &AccountId) -> Option<parachain_staking_runtime_api::CollatorCommission> {
+            ParachainStaking::get_collator_commission(collator)
+        }
+
+        fn get_reward_history(account: &AccountId) -> Vec<(u32, Balance)> {
+            ParachainStaking::get_reward_history(account)
+        }
+
+        fn get_collator_stats(collator: &AccountId) -> Option<parachain_staking_runtime_api::CollatorStats<Balance>> {
+            ParachainStaking::get_collator_stats(collator)
+        }
+
+        fn get_delegation_projection(collator: &AccountId, stake: Balance) -> Option<parachain_staking_runtime_api::RewardProjection<Balance>> {
+            ParachainStaking::get_delegation_projection(collator, stake)
+        }
+
+        fn get_collator_metadata(collator: &AccountId) -> Option<parachain_staking_runtime_api::CollatorMetadata<Balance>> {
+            ParachainStaking::get_collator_metadata(collator)
+        }
     }
 
     #[cfg(feature = "try-runtime")]
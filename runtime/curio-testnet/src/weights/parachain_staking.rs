@@ -298,7 +298,7 @@ impl<T: frame_system::Config> parachain_staking::WeightInfo for WeightInfo<T> {
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking CandidatePool (r:2 w:1)
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
-	// Storage: ParachainStaking LastDelegation (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
 	// Storage: ParachainStaking Round (r:1 w:0)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: ParachainStaking TopCandidates (r:1 w:1)
@@ -367,25 +367,30 @@ impl<T: frame_system::Config> parachain_staking::WeightInfo for WeightInfo<T> {
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:1)
 	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:1)
 	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
-	// Storage: ParachainStaking BlocksRewarded (r:1 w:1)
-	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
 	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
 	// Storage: ParachainStaking InflationConfig (r:1 w:0)
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
 	// Storage: ParachainStaking TopCandidates (r:1 w:1)
 	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
-	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `n` is `[1, 5]`.
 	/// The range of component `m` is `[1, 69]`.
 	fn leave_delegators(n: u32, m: u32, ) -> Weight {
 		// Minimum execution time: 160_251 nanoseconds.
-		Weight::from_parts(162_683_000, 0)
+		Weight::from_parts(61_328_000, 0)
 			// Standard Error: 721_198
-			.saturating_add(Weight::from_parts(16_387_280, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(104_119_210, 0).saturating_mul(n.into()))
 			// Standard Error: 1_568_203
 			.saturating_add(Weight::from_parts(34_517_822, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(10))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().reads((8_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((7_u64).saturating_mul(n.into())))
 	}
 	// Storage: ParachainStaking Unstaking (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
@@ -406,36 +411,51 @@ impl<T: frame_system::Config> parachain_staking::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:0)
 	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
-	// Storage: ParachainStaking BlocksRewarded (r:1 w:1)
-	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking BlocksWithheld (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksWithheldRewarded (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking OutstandingRewards (r:1 w:1)
 	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
 	// Storage: ParachainStaking InflationConfig (r:1 w:0)
-	fn increment_delegator_rewards() -> Weight {
+	/// The range of component `n` is `[1, 5]`.
+	fn increment_delegator_rewards(n: u32, ) -> Weight {
 		// Minimum execution time: 46_756 nanoseconds.
-		Weight::from_parts(47_461_000, 0)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(2))
+		Weight::from_parts(15_018_000, 0)
+			// Standard Error: 19_407
+			.saturating_add(Weight::from_parts(33_672_113, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((9_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
 	}
 	// Storage: ParachainStaking CandidatePool (r:1 w:0)
 	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
 	// Storage: ParachainStaking BlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking BlocksWithheld (r:1 w:0)
+	// Storage: ParachainStaking BlocksWithheldRewarded (r:1 w:1)
 	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking OutstandingRewards (r:1 w:1)
 	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
 	// Storage: ParachainStaking InflationConfig (r:1 w:0)
 	fn increment_collator_rewards() -> Weight {
 		// Minimum execution time: 43_048 nanoseconds.
 		Weight::from_parts(64_178_000, 0)
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	// Storage: ParachainStaking Rewards (r:1 w:1)
+	// Storage: ParachainStaking OutstandingRewards (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	fn claim_rewards() -> Weight {
 		// Minimum execution time: 58_251 nanoseconds.
 		Weight::from_parts(74_883_000, 0)
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	// Storage: ParachainStaking LastRewardReduction (r:1 w:1)
 	// Storage: ParachainStaking InflationConfig (r:1 w:1)
@@ -461,4 +481,259 @@ impl<T: frame_system::Config> parachain_staking::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((50_u64).saturating_mul(n.into())))
 			.saturating_add(T::DbWeight::get().writes((105_u64).saturating_mul(m.into())))
 	}
+	// Storage: ParachainStaking CandidatePool (r:3 w:0)
+	// Storage: ParachainStaking BlocksAuthored (r:2 w:0)
+	// Storage: ParachainStaking BlocksRewarded (r:140 w:140)
+	// Storage: ParachainStaking Rewards (r:140 w:140)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking CounterForCandidatePool (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:1)
+	/// The range of component `n` is `[0, 150]`.
+	/// The range of component `m` is `[0, 70]`.
+	fn set_max_collator_commission(n: u32, m: u32, ) -> Weight {
+		// Minimum execution time: 1_187_406 nanoseconds.
+		Weight::from_parts(1_214_873_000, 0)
+			// Standard Error: 9_218_377
+			.saturating_add(Weight::from_parts(183_420_615, 0).saturating_mul(n.into()))
+			// Standard Error: 19_770_941
+			.saturating_add(Weight::from_parts(681_902_204, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(289))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().reads((104_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(281))
+			.saturating_add(T::DbWeight::get().writes((104_u64).saturating_mul(m.into())))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CommissionChangeRequests (r:0 w:1)
+	fn request_commission_change() -> Weight {
+		// Minimum execution time: 31_417 nanoseconds.
+		Weight::from_parts(32_208_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking CommissionChangeRequests (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking BlocksRewarded (r:70 w:70)
+	// Storage: ParachainStaking Rewards (r:71 w:71)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:0)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	/// The range of component `m` is `[0, 70]`.
+	fn execute_commission_change(m: u32, ) -> Weight {
+		// Minimum execution time: 52_730 nanoseconds.
+		Weight::from_parts(54_118_000, 0)
+			// Standard Error: 19_522
+			.saturating_add(Weight::from_parts(31_873_406, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+	}
+	// Storage: System Account (r:1 w:1)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:0 w:1)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:1)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn delegate_another_candidate(n: u32, m: u32, ) -> Weight {
+		// Minimum execution time: 171_946 nanoseconds.
+		Weight::from_parts(165_338_000, 0)
+			// Standard Error: 724_315
+			.saturating_add(Weight::from_parts(16_935_114, 0).saturating_mul(n.into()))
+			// Standard Error: 1_574_989
+			.saturating_add(Weight::from_parts(35_116_972, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(13))
+			.saturating_add(T::DbWeight::get().writes(10))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:1 w:1)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking Unstaking (r:1 w:1)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn revoke_delegation(n: u32, m: u32, ) -> Weight {
+		// Minimum execution time: 163_504 nanoseconds.
+		Weight::from_parts(164_915_000, 0)
+			// Standard Error: 724_315
+			.saturating_add(Weight::from_parts(16_412_602, 0).saturating_mul(n.into()))
+			// Standard Error: 1_574_989
+			.saturating_add(Weight::from_parts(34_713_240, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	// Storage: ParachainStaking DelegatorState (r:1 w:1)
+	// Storage: ParachainStaking DelegationStart (r:0 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:3 w:2)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking PendingDelegations (r:2 w:2)
+	// Storage: ParachainStaking BlocksAuthored (r:2 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:1 w:2)
+	// Storage: ParachainStaking Rewards (r:2 w:2)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn redelegate(n: u32, m: u32, ) -> Weight {
+		// Minimum execution time: 228_713 nanoseconds.
+		Weight::from_parts(231_207_000, 0)
+			// Standard Error: 724_315
+			.saturating_add(Weight::from_parts(25_092_377, 0).saturating_mul(n.into()))
+			// Standard Error: 1_574_989
+			.saturating_add(Weight::from_parts(52_271_640, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(17))
+			.saturating_add(T::DbWeight::get().writes(12))
+	}
+	// Storage: ParachainStaking LivenessConfig (r:0 w:1)
+	fn set_liveness_policy() -> Weight {
+		// Minimum execution time: 14_812 nanoseconds.
+		Weight::from_parts(14_812_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking MissedRounds (r:0 w:1)
+	/// The range of component `n` is `[1, 150]`.
+	/// The range of component `m` is `[1, 69]`.
+	fn go_online(n: u32, m: u32, ) -> Weight {
+		// Minimum execution time: 9_000 nanoseconds.
+		Weight::from_parts(0, 0)
+			// Standard Error: 181_000
+			.saturating_add(Weight::from_parts(18_204_000, 0).saturating_mul(n.into()))
+			// Standard Error: 294_000
+			.saturating_add(Weight::from_parts(33_507_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking PendingSlashes (r:0 w:1)
+	fn slash_candidate() -> Weight {
+		// Minimum execution time: 24_105 nanoseconds.
+		Weight::from_parts(24_105_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking PendingSlashes (r:1 w:1)
+	fn cancel_slash() -> Weight {
+		// Minimum execution time: 20_373 nanoseconds.
+		Weight::from_parts(20_373_000, 0)
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking PendingSlashes (r:1 w:1)
+	// Storage: ParachainStaking CandidatePool (r:2 w:1)
+	// Storage: ParachainStaking BlocksAuthored (r:1 w:0)
+	// Storage: ParachainStaking BlocksRewarded (r:1 w:1)
+	// Storage: ParachainStaking PendingDelegations (r:1 w:0)
+	// Storage: ParachainStaking DelegationBlocksRewarded (r:35 w:35)
+	// Storage: ParachainStaking DelegatorState (r:35 w:35)
+	// Storage: ParachainStaking Rewards (r:36 w:36)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	// Storage: ParachainStaking InflationConfig (r:1 w:0)
+	// Storage: ParachainStaking MaxCollatorCommission (r:1 w:0)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: Balances Locks (r:36 w:36)
+	// Storage: System Account (r:37 w:37)
+	// Storage: ParachainStaking StakingParams (r:1 w:0)
+	// Storage: ParachainStaking ActiveMinStakeGrace (r:1 w:0)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	// Storage: ParachainStaking Unstaking (r:35 w:35)
+	// Storage: ParachainStaking DelegationStart (r:35 w:35)
+	// Storage: ParachainStaking DelegationBlocksWithheldRewarded (r:0 w:35)
+	// Storage: ParachainStaking AutoCompound (r:0 w:35)
+	/// The range of component `m` is `[1, 69]`.
+	fn apply_slash(m: u32, ) -> Weight {
+		// Minimum execution time: 98_433 nanoseconds.
+		Weight::from_parts(98_433_000, 0)
+			// Standard Error: 312_000
+			.saturating_add(Weight::from_parts(98_512_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(16))
+			.saturating_add(T::DbWeight::get().reads((7_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(9))
+			.saturating_add(T::DbWeight::get().writes((8_u64).saturating_mul(m.into())))
+	}
+	// Storage: ParachainStaking PlannedCollators (r:1 w:1)
+	// Storage: ParachainStaking LivenessConfig (r:1 w:0)
+	// Storage: ParachainStaking CandidatePool (r:16 w:16)
+	// Storage: ParachainStaking LastAuthoredRound (r:16 w:0)
+	// Storage: ParachainStaking MissedRounds (r:16 w:16)
+	// Storage: ParachainStaking TopCandidates (r:1 w:1)
+	// Storage: ParachainStaking MaxSelectedCandidates (r:1 w:0)
+	// Storage: ParachainStaking TotalCollatorStake (r:1 w:1)
+	/// The range of component `n` is `[1, 150]`.
+	fn check_liveness(n: u32, ) -> Weight {
+		// Minimum execution time: 11_760 nanoseconds.
+		Weight::from_parts(11_760_000, 0)
+			// Standard Error: 143_000
+			.saturating_add(Weight::from_parts(46_918_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking DelegatorState (r:1 w:0)
+	// Storage: ParachainStaking AutoCompound (r:0 w:1)
+	fn set_auto_compound() -> Weight {
+		// Minimum execution time: 19_377 nanoseconds.
+		Weight::from_parts(19_377_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	// Storage: ParachainStaking MaxCollatorCandidateStake (r:1 w:0)
+	// Storage: ParachainStaking StakingParams (r:1 w:1)
+	// Storage: ParachainStaking ActiveMinStakeGrace (r:1 w:1)
+	// Storage: ParachainStaking Round (r:1 w:0)
+	fn set_staking_parameter() -> Weight {
+		// Minimum execution time: 26_418 nanoseconds.
+		Weight::from_parts(26_418_000, 0)
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: ParachainStaking CandidatePool (r:1 w:0)
+	// Storage: ParachainStaking CandidateMetadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn set_candidate_metadata() -> Weight {
+		// Minimum execution time: 38_652 nanoseconds.
+		Weight::from_parts(38_652_000, 0)
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	// Storage: ParachainStaking CandidateMetadata (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn clear_candidate_metadata() -> Weight {
+		// Minimum execution time: 32_107 nanoseconds.
+		Weight::from_parts(32_107_000, 0)
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
\ No newline at end of file